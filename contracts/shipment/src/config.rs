@@ -12,22 +12,59 @@
 //!
 //! ## Configuration Parameters
 //!
-//! | Parameter                    | Default | Description                                    |
-//! |------------------------------|---------|------------------------------------------------|
-//! | shipment_ttl_threshold       | 17,280  | Min ledgers before TTL extension (~1 day)      |
-//! | shipment_ttl_extension       | 518,400 | Ledgers to extend TTL by (~30 days)            |
-//! | min_status_update_interval   | 60      | Min seconds between status updates             |
-//! | batch_operation_limit        | 10      | Max items per batch operation                  |
-//! | max_metadata_entries         | 5       | Max metadata key-value pairs per shipment      |
-//! | default_shipment_limit       | 100     | Default active shipments per company           |
-//! | multisig_min_admins          | 2       | Min admins for multi-sig                       |
-//! | multisig_max_admins          | 10      | Max admins for multi-sig                       |
-//! | proposal_expiry_seconds      | 604,800 | Proposal expiry time (7 days)                  |
-//! | deadline_grace_seconds       | 0       | Grace window after deadline before expiry fires |
+//! | Parameter                                 | Default | Description                                      |
+//! |-------------------------------------------|---------|--------------------------------------------------|
+//! | shipment_ttl_threshold                    | 17,280  | Min ledgers before TTL extension (~1 day)        |
+//! | shipment_ttl_extension                    | 518,400 | Ledgers to extend TTL by (~30 days)              |
+//! | min_status_update_interval                | 60      | Min seconds between status updates               |
+//! | batch_operation_limit                     | 10      | Max items per batch operation                    |
+//! | max_metadata_entries                      | 5       | Max metadata key-value pairs per shipment        |
+//! | default_shipment_limit                    | 100     | Default active shipments per company             |
+//! | multisig_min_admins                       | 2       | Min admins for multi-sig                         |
+//! | multisig_max_admins                       | 10      | Max admins for multi-sig                         |
+//! | proposal_expiry_seconds                   | 604,800 | Proposal expiry time (7 days)                    |
+//! | deadline_grace_seconds                    | 0       | Grace past deadline before in-transit expiry     |
+//! | rounding_favors_carrier                   | false   | Round milestone payouts up for the carrier       |
+//! | require_registered_receiver               | false   | Reject shipments to unregistered receivers       |
+//! | dust_threshold                            | 0       | Escrow residue below this is sweepable dust      |
+//! | require_receiver_approval                 | false   | Receiver must whitelist the carrier              |
+//! | carrier_policy.penalize_deadline_miss     | false   | Penalize carrier when deadline expires           |
+//! | role_change_cooldown                      | 0       | Min seconds between role changes per address     |
+//! | price_oracle                              | None    | Oracle queried for escrow token USD price        |
+//! | min_escrow_usd                            | 0       | Min USD value of escrow deposit (10^7 scale)     |
+//! | index_company_shipments                   | false   | Record shipment ids per company                  |
+//! | max_whitelist_size                        | 100     | Max carriers per company whitelist               |
+//! | min_deadline_buffer_seconds               | 3600    | Min seconds from creation to deadline            |
+//! | breach_weights                            | see doc | Per-breach-type reputation penalty               |
+//! | settled_ttl_extension                     | 120960  | TTL extension for settled shipments              |
+//! | dispute_policy.dispute_spike_threshold    | 0       | Disputes in window that trigger auto-pause       |
+//! | dispute_policy.dispute_spike_window       | 3600    | Rolling window for dispute spike detection       |
+//! | departure_checkpoint                      | None    | Milestone that auto-advances Created shipments   |
+//! | max_notification_recipients               | 6       | Max parties notified per lifecycle action        |
+//! | revoke_old_admin_company_role             | false   | Revoke outgoing admin Company role on transfer   |
+//! | escrow_policy.release_delay_seconds       | 0       | Delay between delivery and escrow release        |
+//! | escrow_policy.allow_third_party_escrow    | false   | Let any Company fund a shipment escrow           |
+//! | min_report_interval                       | 0       | Min seconds between same-type carrier reports    |
+//! | escrow_policy.insurance_bps               | 0       | Deposit share reserved for dispute insurance     |
+//! | carrier_policy.handoff_cooldown_seconds   | 0       | Min seconds between handoffs of a shipment       |
+//! | archive_retention_seconds                 | 2592000 | Idle time before a settled shipment compacts     |
+//! | escrow_policy.allow_milestone_holdback    | false   | Allow milestones under 100% with a holdback      |
+//! | carrier_policy.auto_whitelist_on_delivery | false   | Whitelist a carrier after it delivers            |
+//! | escrow_policy.late_delivery_penalty_bps   | 0       | Escrow share paid to receiver on late delivery   |
+//! | carrier_policy.require_carrier_acceptance | false   | Carrier must accept before InTransit             |
+//! | carrier_policy.initial_update_delay       | 0       | Min seconds from creation to first status update |
+//! | carrier_policy.max_checkpoint_dwell       | 0       | Max seconds at a checkpoint before penalty       |
+//! | dispute_policy.dispute_window_seconds     | 0       | Seconds before a dispute may be auto-resolved    |
+//! | dispute_policy.max_disputes_per_shipment  | 3       | Max disputes a single shipment may have          |
+//! | carrier_policy.acceptance_timeout_seconds | 0       | Seconds a carrier has to accept before refund    |
 
 use crate::errors::NavinError;
-use crate::types::DataKey;
-use soroban_sdk::{contracttype, BytesN, Env};
+use crate::types::{BreachWeights, CarrierPolicy, DataKey, DisputePolicy, EscrowPolicy};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+/// Upper bound for `dust_threshold`, so a misconfigured threshold cannot
+/// sweep real escrow balances as dust.
+pub const MAX_DUST_THRESHOLD: i128 = 1_000_000;
 
 /// Contract configuration parameters stored in instance storage.
 ///
@@ -80,9 +117,11 @@ pub struct ContractConfig {
     /// Default: 604,800 seconds (7 days).
     pub proposal_expiry_seconds: u64,
 
-    /// Grace window (in seconds) added to a shipment's deadline before expiry
-    /// logic fires. A caller invoking `check_deadline` must wait until
-    /// `ledger_timestamp >= deadline + deadline_grace_seconds`.
+    /// Grace window (in seconds) added to the deadline of shipments in carrier
+    /// custody (`InTransit`, `AtCheckpoint`, `PartiallyDelivered`) before expiry
+    /// logic fires. For those, a caller invoking `check_deadline` must wait
+    /// until `ledger_timestamp >= deadline + deadline_grace_seconds`; other
+    /// shipments expire at the deadline itself.
     ///
     /// Setting this to 0 (the default) preserves the original behaviour where
     /// expiry triggers the moment the deadline timestamp is reached.
@@ -137,6 +176,123 @@ pub struct ContractConfig {
     /// Only meaningful when `creation_quota_max > 0`.
     /// Default: 3600 (1 hour).
     pub creation_quota_window_seconds: u64,
+
+    /// Rounding direction for percentage-based milestone releases.
+    /// When `false`, each milestone payout truncates and the final milestone
+    /// absorbs the remainder. When `true`, each payout rounds up in the
+    /// carrier's favor so dust is paid out as early as possible; the final
+    /// milestone still releases whatever is left, so totals are conserved.
+    /// Default: `false` (truncate — existing behavior preserved).
+    pub rounding_favors_carrier: bool,
+
+    /// Require receivers to be registered via `add_receiver` before a
+    /// shipment can be created for them (e.g. KYC'd deployments).
+    /// Default: `false` (any receiver address is accepted).
+    pub require_registered_receiver: bool,
+
+    /// Escrow residue strictly below this amount on a `Delivered` or
+    /// `Cancelled` shipment is treated as dust and can be swept to a treasury
+    /// via `sweep_dust`. Must be between 0 and `MAX_DUST_THRESHOLD`.
+    /// Default: `0` (nothing qualifies as dust — sweeping disabled).
+    pub dust_threshold: i128,
+
+    /// Require the receiver to have whitelisted the carrier via
+    /// `receiver_whitelist_carrier` before a shipment can be created.
+    /// Default: `false` (receivers have no veto over carrier assignment).
+    pub require_receiver_approval: bool,
+
+    /// Minimum seconds between role changes (`add_company`, `add_carrier`,
+    /// `revoke_role`) targeting the same address, to prevent rapid
+    /// grant/revoke flip-flopping. Must be <= 2,592,000 (30 days).
+    /// Default: `0` (no cooldown).
+    pub role_change_cooldown: u64,
+
+    /// Price oracle queried by `deposit_escrow` for the escrow token's USD
+    /// price (see `min_escrow_usd`). The oracle must expose
+    /// `price(asset: Address) -> i128`, returning the USD value of one whole
+    /// token scaled by `ORACLE_PRICE_SCALE` (10^7).
+    /// Default: `None` (no oracle — USD minimum check skipped).
+    pub price_oracle: Option<Address>,
+
+    /// Minimum USD-equivalent value of an escrow deposit, scaled by
+    /// `ORACLE_PRICE_SCALE` (10^7). Only enforced when `price_oracle` is set.
+    /// Must be non-negative.
+    /// Default: `0` (no minimum).
+    pub min_escrow_usd: i128,
+
+    /// When `true`, every created shipment id is appended to a per-company
+    /// index. Required by `recalc_active_count` to recompute a company's
+    /// active shipment counter.
+    /// Default: `false` (no per-company index is kept).
+    pub index_company_shipments: bool,
+
+    /// Maximum number of carriers a single company may whitelist.
+    /// Bounds per-company whitelist storage. Must be >= 1 and <= 1000.
+    /// Default: 100 carriers per company.
+    pub max_whitelist_size: u32,
+
+    /// Minimum number of seconds between creation time and a shipment's
+    /// deadline. Creation with `deadline < now + min_deadline_buffer_seconds`
+    /// is rejected so `check_deadline` has a meaningful window to act on.
+    /// Must be <= 2,592,000 (30 days).
+    /// Default: 3600 (1 hour).
+    pub min_deadline_buffer_seconds: u64,
+
+    /// Reputation penalty weight per breach type, accumulated on the
+    /// carrier's breach penalty score by `report_condition_breach`.
+    /// Each weight must be <= 100.
+    /// Default: 1 for temperature/humidity, 3 for impact, 5 for tampering.
+    pub breach_weights: BreachWeights,
+
+    /// Number of ledgers to extend TTL by for settled (`Delivered` or
+    /// `Cancelled`) shipments. Active shipments use `shipment_ttl_extension`;
+    /// settled ones rarely change, so a shorter extension saves storage rent.
+    /// Must be > 0 and <= 10,000,000.
+    /// Default: 120,960 ledgers (~7 days at 5s/ledger).
+    pub settled_ttl_extension: u32,
+
+    /// Checkpoint that marks departure. Recording this milestone on a
+    /// `Created` shipment first advances it to `InTransit`, saving the carrier
+    /// a separate status update. `None` disables auto-advance.
+    /// Default: None.
+    pub departure_checkpoint: Option<Symbol>,
+
+    /// Maximum parties notified per lifecycle action: the sender, receiver,
+    /// and carrier plus any extra recipients a company registers via
+    /// `set_notification_recipients`.
+    /// Must be >= 3 and <= 20.
+    /// Default: 6.
+    pub max_notification_recipients: u32,
+
+    /// When `true`, `accept_admin_transfer` revokes the outgoing admin's
+    /// Company role, which `initialize` and earlier transfers grant
+    /// automatically, so a former admin keeps no lingering privileges.
+    /// Default: false.
+    pub revoke_old_admin_company_role: bool,
+
+    /// Minimum seconds between successive geofence or condition-breach reports
+    /// of the same kind for a shipment. Admin bypasses. 0 disables the limit.
+    /// Must be <= 86,400 (1 day).
+    /// Default: 0.
+    pub min_report_interval: u64,
+
+    /// Seconds a finalized shipment must sit untouched before anyone may
+    /// compact it with `archive_settled_shipment`.
+    /// Must be <= 31,536,000 (365 days).
+    /// Default: 2,592,000 (30 days).
+    pub archive_retention_seconds: u64,
+
+    /// Escrow release, funding, insurance, holdback and late-penalty knobs.
+    /// See `EscrowPolicy` for the individual fields and their bounds.
+    pub escrow_policy: EscrowPolicy,
+
+    /// Carrier acceptance, handoff, dwell and deadline-penalty knobs.
+    /// See `CarrierPolicy` for the individual fields and their bounds.
+    pub carrier_policy: CarrierPolicy,
+
+    /// Dispute spike, auto-resolution and per-shipment limit knobs.
+    /// See `DisputePolicy` for the individual fields and their bounds.
+    pub dispute_policy: DisputePolicy,
 }
 
 impl Default for ContractConfig {
@@ -167,6 +323,27 @@ impl Default for ContractConfig {
             max_breaches_per_shipment: 255,      // 255 breaches
             creation_quota_max: 0,               // disabled by default
             creation_quota_window_seconds: 3600, // 1 hour window
+            rounding_favors_carrier: false,      // truncate by default
+            require_registered_receiver: false,  // open to any receiver
+            dust_threshold: 0,                   // sweeping disabled
+            require_receiver_approval: false,    // no receiver veto
+            role_change_cooldown: 0,             // no cooldown
+            price_oracle: None,                  // no oracle
+            min_escrow_usd: 0,                   // no minimum
+            index_company_shipments: false,      // index disabled
+            max_whitelist_size: 100,             // 100 carriers
+            min_deadline_buffer_seconds: 3600,   // 1 hour
+            // Tampering and impacts weigh more than environmental drift.
+            breach_weights: BreachWeights::default(),
+            settled_ttl_extension: 120_960,       // ~7 days
+            departure_checkpoint: None,           // no auto-advance
+            max_notification_recipients: 6,       // 3 parties + 3 extra
+            revoke_old_admin_company_role: false, // old admin keeps role
+            min_report_interval: 0,               // no throttling
+            archive_retention_seconds: 2_592_000, // 30 days
+            escrow_policy: EscrowPolicy::default(),
+            carrier_policy: CarrierPolicy::default(),
+            dispute_policy: DisputePolicy::default(),
         }
     }
 }
@@ -249,6 +426,20 @@ pub fn set_config(env: &Env, config: &ContractConfig) -> Result<(), NavinError>
 /// - `multisig_min_admins` must be >= 2
 /// - `multisig_max_admins` must be >= `multisig_min_admins` and <= 50
 /// - `proposal_expiry_seconds` must be >= 3,600 (1 hour) and <= 2,592,000 (30 days)
+/// - `max_whitelist_size` must be >= 1 and <= 1000
+/// - `min_deadline_buffer_seconds` must be <= 2,592,000 (30 days)
+/// - `dust_threshold` must be >= 0 and <= `MAX_DUST_THRESHOLD`
+/// - each `breach_weights` entry must be <= 100
+/// - `settled_ttl_extension` must be > 0 and <= 10,000,000
+/// - `dispute_policy.dispute_spike_threshold` must be <= 100
+/// - `dispute_policy.dispute_spike_window` must be > 0 and <= 2,592,000 (30 days)
+/// - `max_notification_recipients` must be >= 3 and <= 20
+/// - `escrow_policy.release_delay_seconds` must be <= 2,592,000 (30 days)
+/// - `min_report_interval` must be <= 86,400 (1 day)
+/// - `escrow_policy.insurance_bps` must be <= 1000 (10%)
+/// - `carrier_policy.handoff_cooldown_seconds` must be <= 604,800 (7 days)
+/// - `archive_retention_seconds` must be <= 31,536,000 (365 days)
+/// - `escrow_policy.late_delivery_penalty_bps` must be <= 10,000 (100%)
 ///
 /// # Examples
 /// ```rust
@@ -318,6 +509,91 @@ pub fn validate_config(config: &ContractConfig) -> Result<(), &'static str> {
         return Err("deadline_grace_seconds must be <= 604,800 (7 days)");
     }
 
+    // Validate dust threshold (0 = disabled)
+    if config.dust_threshold < 0 || config.dust_threshold > MAX_DUST_THRESHOLD {
+        return Err("dust_threshold must be >= 0 and <= MAX_DUST_THRESHOLD");
+    }
+
+    // Validate role change cooldown (0 = disabled, max 30 days)
+    if config.role_change_cooldown > 2_592_000 {
+        return Err("role_change_cooldown must be <= 2,592,000 (30 days)");
+    }
+
+    // Validate minimum escrow USD value (0 = disabled)
+    if config.min_escrow_usd < 0 {
+        return Err("min_escrow_usd must be >= 0");
+    }
+
+    // Validate whitelist cap
+    if config.max_whitelist_size == 0 || config.max_whitelist_size > 1000 {
+        return Err("max_whitelist_size must be >= 1 and <= 1000");
+    }
+
+    // Validate deadline buffer (max 30 days)
+    if config.min_deadline_buffer_seconds > 2_592_000 {
+        return Err("min_deadline_buffer_seconds must be <= 2,592,000 (30 days)");
+    }
+
+    // Validate breach penalty weights
+    let w = &config.breach_weights;
+    if [
+        w.temperature_high,
+        w.temperature_low,
+        w.humidity_high,
+        w.impact,
+        w.tamper_detected,
+    ]
+    .iter()
+    .any(|&weight| weight > 100)
+    {
+        return Err("breach_weights must each be <= 100");
+    }
+
+    // Validate settled-shipment TTL extension
+    if config.settled_ttl_extension == 0 || config.settled_ttl_extension > 10_000_000 {
+        return Err("settled_ttl_extension must be > 0 and <= 10,000,000");
+    }
+
+    // Validate dispute spike auto-pause settings
+    if config.dispute_policy.dispute_spike_threshold > 100 {
+        return Err("dispute_spike_threshold must be <= 100");
+    }
+    if config.dispute_policy.dispute_spike_window == 0
+        || config.dispute_policy.dispute_spike_window > 2_592_000
+    {
+        return Err("dispute_spike_window must be > 0 and <= 2,592,000 (30 days)");
+    }
+
+    // Validate notification recipient cap
+    if config.max_notification_recipients < 3 || config.max_notification_recipients > 20 {
+        return Err("max_notification_recipients must be >= 3 and <= 20");
+    }
+
+    // Validate post-delivery release delay
+    if config.escrow_policy.release_delay_seconds > 2_592_000 {
+        return Err("release_delay_seconds must be <= 2,592,000 (30 days)");
+    }
+
+    if config.min_report_interval > 86_400 {
+        return Err("min_report_interval must be <= 86,400 (1 day)");
+    }
+
+    if config.escrow_policy.insurance_bps > 1000 {
+        return Err("insurance_bps must be <= 1000 (10%)");
+    }
+
+    if config.carrier_policy.handoff_cooldown_seconds > 604_800 {
+        return Err("handoff_cooldown_seconds must be <= 604,800 (7 days)");
+    }
+
+    if config.archive_retention_seconds > 31_536_000 {
+        return Err("archive_retention_seconds must be <= 31,536,000 (365 days)");
+    }
+
+    if config.escrow_policy.late_delivery_penalty_bps > 10_000 {
+        return Err("late_delivery_penalty_bps must be <= 10,000 (100%)");
+    }
+
     Ok(())
 }
 
@@ -579,6 +855,30 @@ mod tests {
         assert!(validate_config(&config).is_err());
     }
 
+    #[test]
+    fn test_validate_dust_threshold() {
+        // Valid: exactly at the cap
+        let config = ContractConfig {
+            dust_threshold: MAX_DUST_THRESHOLD,
+            ..Default::default()
+        };
+        assert!(validate_config(&config).is_ok());
+
+        // Invalid: above the cap
+        let config = ContractConfig {
+            dust_threshold: MAX_DUST_THRESHOLD + 1,
+            ..Default::default()
+        };
+        assert!(validate_config(&config).is_err());
+
+        // Invalid: negative
+        let config = ContractConfig {
+            dust_threshold: -1,
+            ..Default::default()
+        };
+        assert!(validate_config(&config).is_err());
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Checksum Tests — Deterministic Config Drift Detection
     // ─────────────────────────────────────────────────────────────────────────
@@ -770,6 +1070,45 @@ mod tests {
             max_breaches_per_shipment: 100,
             creation_quota_max: 0,
             creation_quota_window_seconds: 3600,
+            rounding_favors_carrier: false,
+            require_registered_receiver: false,
+            dust_threshold: 0,
+            require_receiver_approval: false,
+            role_change_cooldown: 0,
+            price_oracle: None,
+            min_escrow_usd: 0,
+            index_company_shipments: false,
+            max_whitelist_size: 100,
+            min_deadline_buffer_seconds: 3600,
+            breach_weights: BreachWeights::default(),
+            settled_ttl_extension: 120_960,
+            departure_checkpoint: None,
+            max_notification_recipients: 6,
+            revoke_old_admin_company_role: false,
+            min_report_interval: 0,
+            archive_retention_seconds: 2_592_000,
+            escrow_policy: EscrowPolicy {
+                release_delay_seconds: 0,
+                allow_third_party_escrow: false,
+                insurance_bps: 0,
+                allow_milestone_holdback: false,
+                late_delivery_penalty_bps: 0,
+            },
+            carrier_policy: CarrierPolicy {
+                penalize_deadline_miss: false,
+                handoff_cooldown_seconds: 0,
+                auto_whitelist_on_delivery: false,
+                require_carrier_acceptance: false,
+                acceptance_timeout_seconds: 0,
+                initial_update_delay: 0,
+                max_checkpoint_dwell: 0,
+            },
+            dispute_policy: DisputePolicy {
+                dispute_spike_threshold: 0,
+                dispute_spike_window: 3600,
+                dispute_window_seconds: 0,
+                max_disputes_per_shipment: 3,
+            },
         };
 
         let checksums = [
@@ -859,6 +1198,45 @@ mod tests {
             max_breaches_per_shipment: 1,
             creation_quota_max: 0,
             creation_quota_window_seconds: 3600,
+            rounding_favors_carrier: false,
+            require_registered_receiver: false,
+            dust_threshold: 0,
+            require_receiver_approval: false,
+            role_change_cooldown: 0,
+            price_oracle: None,
+            min_escrow_usd: 0,
+            index_company_shipments: false,
+            max_whitelist_size: 1,
+            min_deadline_buffer_seconds: 0,
+            breach_weights: BreachWeights::default(),
+            settled_ttl_extension: 1,
+            departure_checkpoint: None,
+            max_notification_recipients: 3,
+            revoke_old_admin_company_role: false,
+            min_report_interval: 0,
+            archive_retention_seconds: 0,
+            escrow_policy: EscrowPolicy {
+                release_delay_seconds: 0,
+                allow_third_party_escrow: false,
+                insurance_bps: 0,
+                allow_milestone_holdback: false,
+                late_delivery_penalty_bps: 0,
+            },
+            carrier_policy: CarrierPolicy {
+                penalize_deadline_miss: false,
+                handoff_cooldown_seconds: 0,
+                auto_whitelist_on_delivery: false,
+                require_carrier_acceptance: false,
+                acceptance_timeout_seconds: 0,
+                initial_update_delay: 0,
+                max_checkpoint_dwell: 0,
+            },
+            dispute_policy: DisputePolicy {
+                dispute_spike_threshold: 0,
+                dispute_spike_window: 1,
+                dispute_window_seconds: 0,
+                max_disputes_per_shipment: 0,
+            },
         };
 
         let config_max = ContractConfig {
@@ -880,6 +1258,45 @@ mod tests {
             max_breaches_per_shipment: 1000,
             creation_quota_max: 100,
             creation_quota_window_seconds: 86_400,
+            rounding_favors_carrier: true,
+            require_registered_receiver: true,
+            dust_threshold: 1_000_000,
+            require_receiver_approval: true,
+            role_change_cooldown: 2_592_000,
+            price_oracle: None,
+            min_escrow_usd: 1_000_000_000,
+            index_company_shipments: true,
+            max_whitelist_size: 1000,
+            min_deadline_buffer_seconds: 2_592_000,
+            breach_weights: BreachWeights::default(),
+            settled_ttl_extension: 10_000_000,
+            departure_checkpoint: None,
+            max_notification_recipients: 20,
+            revoke_old_admin_company_role: true,
+            min_report_interval: 86_400,
+            archive_retention_seconds: 31_536_000,
+            escrow_policy: EscrowPolicy {
+                release_delay_seconds: 2_592_000,
+                allow_third_party_escrow: true,
+                insurance_bps: 1000,
+                allow_milestone_holdback: true,
+                late_delivery_penalty_bps: 10_000,
+            },
+            carrier_policy: CarrierPolicy {
+                penalize_deadline_miss: true,
+                handoff_cooldown_seconds: 604_800,
+                auto_whitelist_on_delivery: true,
+                require_carrier_acceptance: true,
+                acceptance_timeout_seconds: 2_592_000,
+                initial_update_delay: 86_400,
+                max_checkpoint_dwell: 3_600,
+            },
+            dispute_policy: DisputePolicy {
+                dispute_spike_threshold: 100,
+                dispute_spike_window: 2_592_000,
+                dispute_window_seconds: 604_800,
+                max_disputes_per_shipment: 100,
+            },
         };
 
         let checksum_min = compute_config_checksum(&config_min, &env);
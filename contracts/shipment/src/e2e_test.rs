@@ -40,6 +40,7 @@ fn deploy_token<'a>(env: &'a Env, admin: &Address) -> (Address, NavinTokenClient
         admin,
         &String::from_str(env, "NavinToken"),
         &String::from_str(env, "NVN"),
+        &7,
         &1_000_000_i128,
     );
     (token_id, token)
@@ -795,7 +796,7 @@ fn test_regression_deadline_refund_event_ordering() {
         &carrier,
         &hash(&env, 0x41),
         &Vec::new(&env),
-        &(env.ledger().timestamp() + 1),
+        &(env.ledger().timestamp() + 3600),
     );
     shipment.deposit_escrow(&company, &shipment_id, &1_000_i128);
 
@@ -122,6 +122,12 @@ pub fn error_info(error: NavinError) -> ContractErrorInfo {
             NoRetry,
             "Internal counter overflowed; contact the contract operator.",
         ),
+        NavinError::CarrierNotWhitelisted => (
+            12,
+            Unauthorized,
+            NoRetry,
+            "Carrier has not been whitelisted by the receiver.",
+        ),
         NavinError::InvalidAmount => (
             14,
             InvalidInput,
@@ -150,7 +156,7 @@ pub fn error_info(error: NavinError) -> ContractErrorInfo {
             18,
             InvalidInput,
             NoRetry,
-            "Milestone percentages must sum to exactly 100.",
+            "Milestone percentages must sum to exactly 100, or fixed amounts to the escrow.",
         ),
         NavinError::MilestoneAlreadyPaid => (
             19,
@@ -457,6 +463,132 @@ pub fn error_info(error: NavinError) -> ContractErrorInfo {
             NoRetry,
             "Carrier is already on the company's whitelist; duplicate addition is not allowed.",
         ),
+        NavinError::DisputeEscalated => (
+            69,
+            Unauthorized,
+            NoRetry,
+            "Dispute is escalated; resolve it through a multisig proposal.",
+        ),
+        NavinError::ReceiverNotRegistered => (
+            70,
+            InvalidInput,
+            NoRetry,
+            "Receiver is not registered; register it with add_receiver first.",
+        ),
+        NavinError::RoleChangeCooldown => (
+            71,
+            LimitExceeded,
+            RetryAfterDelay,
+            "Role for this address changed too recently; wait for the cooldown to elapse.",
+        ),
+        NavinError::EscrowBelowMinimumValue => (
+            72,
+            InvalidInput,
+            NoRetry,
+            "Escrow deposit is worth less than the configured USD minimum.",
+        ),
+        NavinError::PriceOracleUnavailable => (
+            73,
+            Transient,
+            RetryAfterDelay,
+            "Price oracle could not be queried or returned an invalid price.",
+        ),
+        NavinError::CompanyIndexDisabled => (
+            74,
+            Configuration,
+            NoRetry,
+            "Per-company shipment index is disabled; enable index_company_shipments.",
+        ),
+        NavinError::WhitelistFull => (
+            75,
+            LimitExceeded,
+            RetryAfterStateChange,
+            "Company carrier whitelist is full; remove a carrier before adding another.",
+        ),
+        NavinError::CheckpointNotAcknowledged => (
+            76,
+            InvalidState,
+            RetryAfterStateChange,
+            "Receiver must acknowledge the checkpoint before its milestone is paid.",
+        ),
+        NavinError::CheckpointAckMismatch => (
+            77,
+            InvalidInput,
+            NoRetry,
+            "Acknowledged hash does not match the recorded checkpoint hash.",
+        ),
+        NavinError::TooManyNotificationRecipients => (
+            78,
+            LimitExceeded,
+            NoRetry,
+            "Notification recipients exceed max_notification_recipients.",
+        ),
+        NavinError::ReleaseDelayActive => (
+            79,
+            InvalidState,
+            RetryAfterDelay,
+            "Escrow release is still in its cooling-off period; retry after the delay.",
+        ),
+        NavinError::HandoffCooldown => (
+            80,
+            LimitExceeded,
+            RetryAfterDelay,
+            "Shipment was handed off too recently; retry after the handoff cooldown.",
+        ),
+        NavinError::ArchiveRetentionActive => (
+            81,
+            InvalidState,
+            RetryAfterDelay,
+            "Shipment is still within its archive retention period; retry later.",
+        ),
+        NavinError::HasActiveShipments => (
+            82,
+            InvalidState,
+            RetryAfterStateChange,
+            "Company still has active shipments; complete or cancel them first.",
+        ),
+        NavinError::CarrierKeyNotRegistered => (
+            83,
+            Configuration,
+            RetryAfterStateChange,
+            "Carrier has no registered public key; register one before submitting signed milestones.",
+        ),
+        NavinError::AssignmentNotAccepted => (
+            84,
+            InvalidState,
+            RetryAfterStateChange,
+            "Assigned carrier has not accepted the shipment; wait for accept_assignment.",
+        ),
+        NavinError::NotFunded => (
+            85,
+            InvalidState,
+            NoRetry,
+            "Shipment escrow was never funded; there is nothing to release.",
+        ),
+        NavinError::InvalidRating => (
+            86,
+            InvalidInput,
+            NoRetry,
+            "Carrier rating must be between 1 and 5.",
+        ),
+        NavinError::AlreadyRated => (
+            87,
+            InvalidState,
+            NoRetry,
+            "The carrier has already been rated for this shipment.",
+        ),
+        NavinError::DisputeLimitReached => (
+            88,
+            InvalidState,
+            NoRetry,
+            "Shipment has reached the maximum number of disputes.",
+        ),
+        NavinError::WasmNotAllowlisted => (
+            89,
+            InvalidInput,
+            NoRetry,
+            "Upgrade target WASM hash is not on the allowlist.",
+        ),
     };
 
     ContractErrorInfo {
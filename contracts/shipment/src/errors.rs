@@ -36,8 +36,8 @@ pub enum NavinError {
     InvalidTimestamp = 10,
     /// Counter value overflowed the maximum capacity.
     CounterOverflow = 11,
-    //    /// Carrier is not listed in the company's whitelist.
-    //    CarrierNotWhitelisted = 12,
+    /// Carrier has not been approved by the shipment's receiver.
+    CarrierNotWhitelisted = 12,
     //    /// Carrier is not authorized to perform the action.
     //    CarrierNotAuthorized = 13,
     /// Amount provided is invalid (zero or negative).
@@ -48,7 +48,8 @@ pub enum NavinError {
     BatchTooLarge = 16,
     /// Shipment input contained invalid parameters (e.g., receiver equals carrier).
     InvalidShipmentInput = 17,
-    /// Milestone percentages do not sum to 100%.
+    /// Milestone percentages do not sum to 100%, or fixed milestone amounts
+    /// do not sum to the funded escrow.
     MilestoneSumInvalid = 18,
     /// Attempting to pay a milestone that was already paid.
     MilestoneAlreadyPaid = 19,
@@ -157,4 +158,189 @@ pub enum NavinError {
     /// off-chain monitors can distinguish a no-op from a real failure
     /// without falling back on the generic `AlreadyInitialized` code.
     CarrierAlreadyWhitelisted = 68,
+    /// Dispute was escalated and can only be resolved through a multisig proposal.
+    DisputeEscalated = 69,
+    /// Receiver is not registered and the contract requires registered receivers.
+    ReceiverNotRegistered = 70,
+    /// Role change for this address was attempted before the cooldown elapsed.
+    RoleChangeCooldown = 71,
+    /// Escrow deposit's USD-equivalent value is below `min_escrow_usd`.
+    EscrowBelowMinimumValue = 72,
+    /// Configured price oracle could not be queried or returned an invalid price.
+    PriceOracleUnavailable = 73,
+    /// Operation requires the per-company shipment index, which is disabled.
+    CompanyIndexDisabled = 74,
+    /// Company whitelist already holds `max_whitelist_size` carriers.
+    WhitelistFull = 75,
+    /// Milestone payment requires the receiver to acknowledge the checkpoint first.
+    CheckpointNotAcknowledged = 76,
+    /// Acknowledged hash does not match the hash recorded for the checkpoint arrival.
+    CheckpointAckMismatch = 77,
+    /// Notification recipients would exceed `max_notification_recipients`.
+    TooManyNotificationRecipients = 78,
+    /// Escrow release is still inside the post-delivery cooling-off period.
+    ReleaseDelayActive = 79,
+    /// The shipment was handed off too recently; `handoff_cooldown_seconds` has not elapsed.
+    HandoffCooldown = 80,
+    /// Shipment has not been idle for `archive_retention_seconds` yet.
+    ArchiveRetentionActive = 81,
+    /// Company still has active shipments and cannot be removed.
+    HasActiveShipments = 82,
+    /// Carrier has not registered a public key for signed milestone attestations.
+    CarrierKeyNotRegistered = 83,
+    /// Assigned carrier has not accepted the shipment yet.
+    AssignmentNotAccepted = 84,
+    /// Escrow was never deposited for the shipment.
+    NotFunded = 85,
+    /// Carrier rating is outside the accepted 1-5 range.
+    InvalidRating = 86,
+    /// The receiver has already rated the carrier for this shipment.
+    AlreadyRated = 87,
+    /// Shipment has reached `max_disputes_per_shipment`.
+    DisputeLimitReached = 88,
+    /// Upgrade target WASM hash has not been allowlisted by governance.
+    WasmNotAllowlisted = 89,
 }
+
+/// Every `NavinError` variant paired with its symbolic name, in declaration
+/// order. Codes come from the enum discriminants, so only the names need to
+/// be kept in step when a variant is added.
+pub const ERROR_CATALOG: &[(NavinError, &str)] = &[
+    (NavinError::AlreadyInitialized, "AlreadyInitialized"),
+    (NavinError::NotInitialized, "NotInitialized"),
+    (NavinError::Unauthorized, "Unauthorized"),
+    (NavinError::ShipmentNotFound, "ShipmentNotFound"),
+    (NavinError::InvalidStatus, "InvalidStatus"),
+    (NavinError::InvalidHash, "InvalidHash"),
+    (NavinError::EscrowLocked, "EscrowLocked"),
+    (NavinError::InsufficientFunds, "InsufficientFunds"),
+    (
+        NavinError::ShipmentAlreadyCompleted,
+        "ShipmentAlreadyCompleted",
+    ),
+    (NavinError::InvalidTimestamp, "InvalidTimestamp"),
+    (NavinError::CounterOverflow, "CounterOverflow"),
+    (NavinError::CarrierNotWhitelisted, "CarrierNotWhitelisted"),
+    (NavinError::InvalidAmount, "InvalidAmount"),
+    (NavinError::ReentrancyDetected, "ReentrancyDetected"),
+    (NavinError::BatchTooLarge, "BatchTooLarge"),
+    (NavinError::InvalidShipmentInput, "InvalidShipmentInput"),
+    (NavinError::MilestoneSumInvalid, "MilestoneSumInvalid"),
+    (NavinError::MilestoneAlreadyPaid, "MilestoneAlreadyPaid"),
+    (NavinError::MetadataLimitExceeded, "MetadataLimitExceeded"),
+    (NavinError::RateLimitExceeded, "RateLimitExceeded"),
+    (NavinError::ProposalNotFound, "ProposalNotFound"),
+    (
+        NavinError::ProposalAlreadyExecuted,
+        "ProposalAlreadyExecuted",
+    ),
+    (NavinError::ProposalExpired, "ProposalExpired"),
+    (NavinError::AlreadyApproved, "AlreadyApproved"),
+    (NavinError::InsufficientApprovals, "InsufficientApprovals"),
+    (NavinError::NotAnAdmin, "NotAnAdmin"),
+    (NavinError::InvalidMultiSigConfig, "InvalidMultiSigConfig"),
+    (NavinError::NotExpired, "NotExpired"),
+    (NavinError::ShipmentLimitReached, "ShipmentLimitReached"),
+    (NavinError::InvalidConfig, "InvalidConfig"),
+    (NavinError::CannotSelfRevoke, "CannotSelfRevoke"),
+    (NavinError::CarrierSuspended, "CarrierSuspended"),
+    (
+        NavinError::ForceCancelReasonHashMissing,
+        "ForceCancelReasonHashMissing",
+    ),
+    (NavinError::ArithmeticError, "ArithmeticError"),
+    (
+        NavinError::DisputeReasonHashMissing,
+        "DisputeReasonHashMissing",
+    ),
+    (NavinError::CompanySuspended, "CompanySuspended"),
+    (NavinError::ShipmentFinalized, "ShipmentFinalized"),
+    (NavinError::TokenTransferFailed, "TokenTransferFailed"),
+    (NavinError::TokenMintFailed, "TokenMintFailed"),
+    (NavinError::DuplicateAction, "DuplicateAction"),
+    (NavinError::ShipmentUnavailable, "ShipmentUnavailable"),
+    (NavinError::ContractPaused, "ContractPaused"),
+    (NavinError::StatusHashNotFound, "StatusHashNotFound"),
+    (NavinError::DataHashMismatch, "DataHashMismatch"),
+    (NavinError::CircuitBreakerOpen, "CircuitBreakerOpen"),
+    (NavinError::InvalidMigrationEdge, "InvalidMigrationEdge"),
+    (NavinError::MilestoneLimitExceeded, "MilestoneLimitExceeded"),
+    (NavinError::NoteLimitExceeded, "NoteLimitExceeded"),
+    (NavinError::EvidenceLimitExceeded, "EvidenceLimitExceeded"),
+    (NavinError::BreachLimitExceeded, "BreachLimitExceeded"),
+    (NavinError::InvalidTokenDecimals, "InvalidTokenDecimals"),
+    (NavinError::CreationQuotaExceeded, "CreationQuotaExceeded"),
+    (NavinError::DependenciesNotMet, "DependenciesNotMet"),
+    (NavinError::CircularDependency, "CircularDependency"),
+    (NavinError::ProposalSaltReused, "ProposalSaltReused"),
+    (
+        NavinError::InvalidShipmentParticipants,
+        "InvalidShipmentParticipants",
+    ),
+    (
+        NavinError::InvalidShipmentDeadline,
+        "InvalidShipmentDeadline",
+    ),
+    (
+        NavinError::InvalidPaymentMilestones,
+        "InvalidPaymentMilestones",
+    ),
+    (
+        NavinError::DuplicatePaymentMilestone,
+        "DuplicatePaymentMilestone",
+    ),
+    (NavinError::InvalidTokenAddress, "InvalidTokenAddress"),
+    (
+        NavinError::InvalidPaymentMilestoneName,
+        "InvalidPaymentMilestoneName",
+    ),
+    (
+        NavinError::MetadataSymbolCollision,
+        "MetadataSymbolCollision",
+    ),
+    (
+        NavinError::ExternalIntegrationFailed,
+        "ExternalIntegrationFailed",
+    ),
+    (NavinError::InvalidSymbol, "InvalidSymbol"),
+    (NavinError::NoteNotFound, "NoteNotFound"),
+    (NavinError::EvidenceNotFound, "EvidenceNotFound"),
+    (NavinError::RoleAlreadyAssigned, "RoleAlreadyAssigned"),
+    (
+        NavinError::CarrierAlreadyWhitelisted,
+        "CarrierAlreadyWhitelisted",
+    ),
+    (NavinError::DisputeEscalated, "DisputeEscalated"),
+    (NavinError::ReceiverNotRegistered, "ReceiverNotRegistered"),
+    (NavinError::RoleChangeCooldown, "RoleChangeCooldown"),
+    (
+        NavinError::EscrowBelowMinimumValue,
+        "EscrowBelowMinimumValue",
+    ),
+    (NavinError::PriceOracleUnavailable, "PriceOracleUnavailable"),
+    (NavinError::CompanyIndexDisabled, "CompanyIndexDisabled"),
+    (NavinError::WhitelistFull, "WhitelistFull"),
+    (
+        NavinError::CheckpointNotAcknowledged,
+        "CheckpointNotAcknowledged",
+    ),
+    (NavinError::CheckpointAckMismatch, "CheckpointAckMismatch"),
+    (
+        NavinError::TooManyNotificationRecipients,
+        "TooManyNotificationRecipients",
+    ),
+    (NavinError::ReleaseDelayActive, "ReleaseDelayActive"),
+    (NavinError::HandoffCooldown, "HandoffCooldown"),
+    (NavinError::ArchiveRetentionActive, "ArchiveRetentionActive"),
+    (NavinError::HasActiveShipments, "HasActiveShipments"),
+    (
+        NavinError::CarrierKeyNotRegistered,
+        "CarrierKeyNotRegistered",
+    ),
+    (NavinError::AssignmentNotAccepted, "AssignmentNotAccepted"),
+    (NavinError::NotFunded, "NotFunded"),
+    (NavinError::InvalidRating, "InvalidRating"),
+    (NavinError::AlreadyRated, "AlreadyRated"),
+    (NavinError::DisputeLimitReached, "DisputeLimitReached"),
+    (NavinError::WasmNotAllowlisted, "WasmNotAllowlisted"),
+];
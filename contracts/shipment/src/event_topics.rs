@@ -34,6 +34,9 @@ pub const STATUS_UPDATED: &str = "status_updated";
 /// Emitted when a carrier records a checkpoint milestone.
 pub const MILESTONE_RECORDED: &str = "milestone_recorded";
 
+/// Emitted when the carrier records proof of pickup and the shipment departs.
+pub const PICKUP_RECORDED: &str = "pickup_recorded";
+
 /// Emitted when a shipment is cancelled (non-admin path).
 pub const SHIPMENT_CANCELLED: &str = "shipment_cancelled";
 
@@ -57,6 +60,18 @@ pub const ESCROW_RELEASED: &str = "escrow_released";
 /// Emitted when escrowed funds are returned to the company.
 pub const ESCROW_REFUNDED: &str = "escrow_refunded";
 
+/// Emitted when dust escrow residue is swept to a treasury.
+pub const DUST_SWEPT: &str = "dust_swept";
+
+/// Emitted when a company claims a shipment's insurance reserve after winning a dispute.
+pub const INSURANCE_CLAIMED: &str = "insurance_claimed";
+
+/// Emitted when part of a late shipment's escrow is paid to the receiver as a penalty.
+pub const LATE_DELIVERY_PENALTY: &str = "late_delivery_penalty";
+
+/// Emitted when an admin recalculates a company's drifted active shipment count.
+pub const COUNT_CORRECTED: &str = "count_corrected";
+
 /// Emitted when a partial milestone-based escrow release is triggered.
 pub const MILESTONE_PAYMENT_RELEASED: &str = "milestone_payment_released";
 
@@ -71,6 +86,21 @@ pub const DISPUTE_RAISED: &str = "dispute_raised";
 /// Emitted when an admin resolves a dispute.
 pub const DISPUTE_RESOLVED: &str = "dispute_resolved";
 
+/// Emitted when a dispute is resolved with a carrier/company escrow split.
+pub const DISPUTE_SPLIT_RESOLVED: &str = "dispute_split_resolved";
+
+/// Emitted when a party escalates a dispute to multisig resolution.
+pub const DISPUTE_ESCALATED: &str = "dispute_escalated";
+
+/// Emitted when a disputer locks a bond alongside a dispute.
+pub const DISPUTE_BOND_POSTED: &str = "dispute_bond_posted";
+
+/// Emitted when a dispute bond is returned to the disputer or forfeited.
+pub const DISPUTE_BOND_SETTLED: &str = "dispute_bond_settled";
+
+/// Emitted when the receiver acknowledges a checkpoint arrival.
+pub const CHECKPOINT_ACKNOWLEDGED: &str = "checkpoint_acknowledged";
+
 // ── Condition breaches ────────────────────────────────────────────────────────
 
 /// Emitted when a carrier reports an out-of-range sensor reading.
@@ -84,6 +114,15 @@ pub const CARRIER_BREACH: &str = "carrier_breach";
 /// Emitted when a dispute is resolved against the carrier.
 pub const CARRIER_DISPUTE_LOSS: &str = "carrier_dispute_loss";
 
+/// Emitted when a carrier-held shipment expires past its deadline.
+pub const CARRIER_DEADLINE_MISS: &str = "carrier_deadline_miss";
+
+/// Emitted when a carrier keeps a shipment at a checkpoint past `max_checkpoint_dwell`.
+pub const CARRIER_CHECKPOINT_OVERSTAY: &str = "carrier_checkpoint_overstay";
+
+/// Emitted when a receiver rates the carrier of a delivered shipment.
+pub const CARRIER_RATED: &str = "carrier_rated";
+
 /// Emitted when a carrier completes delivery after the deadline.
 pub const CARRIER_LATE_DELIVERY: &str = "carrier_late_delivery";
 
@@ -93,6 +132,24 @@ pub const CARRIER_ON_TIME_DELIVERY: &str = "carrier_on_time_delivery";
 /// Emitted when a carrier-to-carrier handoff is completed.
 pub const CARRIER_HANDOFF_COMPLETED: &str = "carrier_handoff_completed";
 
+/// Emitted when a shipment's owning company (sender) is reassigned.
+pub const OWNERSHIP_TRANSFERRED: &str = "ownership_transferred";
+
+/// Emitted when a shipment's delivery deadline is pushed back.
+pub const DEADLINE_EXTENDED: &str = "deadline_extended";
+
+/// Emitted when the assigned carrier accepts a shipment.
+pub const ASSIGNMENT_ACCEPTED: &str = "assignment_accepted";
+
+/// Emitted when the assigned carrier declines a shipment.
+pub const ASSIGNMENT_DECLINED: &str = "assignment_declined";
+
+/// Emitted when a shipment's carrier is replaced before pickup.
+pub const CARRIER_REASSIGNED: &str = "carrier_reassigned";
+
+/// Emitted when a carrier reports additional travelled distance for a shipment.
+pub const DISTANCE_RECORDED: &str = "distance_recorded";
+
 /// Emitted to track the ratio of checkpoints hit vs expected for a carrier.
 pub const CARRIER_MILESTONE_RATE: &str = "carrier_milestone_rate";
 
@@ -107,6 +164,9 @@ pub const ADMIN_TRANSFERRED: &str = "admin_transferred";
 /// Emitted when the contract WASM is upgraded.
 pub const CONTRACT_UPGRADED: &str = "contract_upgraded";
 
+/// Emitted when governance allowlists a WASM hash as an upgrade target.
+pub const WASM_HASH_ALLOWED: &str = "wasm_hash_allowed";
+
 /// Emitted when a migration report is generated after an upgrade.
 pub const MIGRATION_REPORTED: &str = "migration_reported";
 
@@ -116,6 +176,9 @@ pub const CONTRACT_PAUSED: &str = "contract_paused";
 /// Emitted when the contract is unpaused.
 pub const CONTRACT_UNPAUSED: &str = "contract_unpaused";
 
+/// Emitted when dispute spike detection pauses the contract automatically.
+pub const AUTO_PAUSED: &str = "auto_paused";
+
 /// Emitted when an admin forcibly cancels a shipment (privileged path).
 pub const FORCE_CANCELLED: &str = "force_cancelled";
 
@@ -177,7 +240,8 @@ pub const HASH_DOMAIN_SHIPMENT: u8 = 0x01;
 pub const HASH_DOMAIN_ESCROW: u8 = 0x02;
 
 /// Domain tag for dispute-related events
-/// (`dispute_raised`, `dispute_resolved`).
+/// (`dispute_raised`, `dispute_resolved`, `dispute_split_resolved`,
+///  `dispute_escalated`).
 pub const HASH_DOMAIN_DISPUTE: u8 = 0x03;
 
 /// Domain tag for condition-breach / sensor-data events
@@ -260,6 +324,7 @@ mod tests {
             SHIPMENT_CREATED,
             STATUS_UPDATED,
             MILESTONE_RECORDED,
+            PICKUP_RECORDED,
             SHIPMENT_CANCELLED,
             SHIPMENT_EXPIRED,
             SHIPMENT_ARCHIVED,
@@ -267,20 +332,41 @@ mod tests {
             ESCROW_DEPOSITED,
             ESCROW_RELEASED,
             ESCROW_REFUNDED,
+            DUST_SWEPT,
+            INSURANCE_CLAIMED,
+            LATE_DELIVERY_PENALTY,
+            COUNT_CORRECTED,
             DISPUTE_RAISED,
             DISPUTE_RESOLVED,
+            DISPUTE_SPLIT_RESOLVED,
+            DISPUTE_ESCALATED,
+            DISPUTE_BOND_POSTED,
+            DISPUTE_BOND_SETTLED,
+            CHECKPOINT_ACKNOWLEDGED,
             CONDITION_BREACH,
             CARRIER_BREACH,
             CARRIER_DISPUTE_LOSS,
+            CARRIER_DEADLINE_MISS,
+            CARRIER_CHECKPOINT_OVERSTAY,
+            CARRIER_RATED,
             CARRIER_LATE_DELIVERY,
             CARRIER_ON_TIME_DELIVERY,
             CARRIER_HANDOFF_COMPLETED,
+            OWNERSHIP_TRANSFERRED,
+            DEADLINE_EXTENDED,
+            DEADLINE_EXTENDED,
+            ASSIGNMENT_ACCEPTED,
+            ASSIGNMENT_DECLINED,
+            CARRIER_REASSIGNED,
+            DISTANCE_RECORDED,
             CARRIER_MILESTONE_RATE,
             ADMIN_PROPOSED,
             ADMIN_TRANSFERRED,
             CONTRACT_UPGRADED,
+            WASM_HASH_ALLOWED,
             CONTRACT_PAUSED,
             CONTRACT_UNPAUSED,
+            AUTO_PAUSED,
             FORCE_CANCELLED,
             ROLE_REVOKED,
             ROLE_CHANGED,
@@ -321,6 +407,7 @@ mod tests {
         assert_eq!(SHIPMENT_CREATED, "shipment_created");
         assert_eq!(STATUS_UPDATED, "status_updated");
         assert_eq!(MILESTONE_RECORDED, "milestone_recorded");
+        assert_eq!(PICKUP_RECORDED, "pickup_recorded");
         assert_eq!(SHIPMENT_CANCELLED, "shipment_cancelled");
         assert_eq!(SHIPMENT_EXPIRED, "shipment_expired");
         assert_eq!(SHIPMENT_ARCHIVED, "shipment_archived");
@@ -328,20 +415,40 @@ mod tests {
         assert_eq!(ESCROW_DEPOSITED, "escrow_deposited");
         assert_eq!(ESCROW_RELEASED, "escrow_released");
         assert_eq!(ESCROW_REFUNDED, "escrow_refunded");
+        assert_eq!(DUST_SWEPT, "dust_swept");
+        assert_eq!(INSURANCE_CLAIMED, "insurance_claimed");
+        assert_eq!(LATE_DELIVERY_PENALTY, "late_delivery_penalty");
+        assert_eq!(COUNT_CORRECTED, "count_corrected");
         assert_eq!(DISPUTE_RAISED, "dispute_raised");
         assert_eq!(DISPUTE_RESOLVED, "dispute_resolved");
+        assert_eq!(DISPUTE_SPLIT_RESOLVED, "dispute_split_resolved");
+        assert_eq!(DISPUTE_ESCALATED, "dispute_escalated");
+        assert_eq!(DISPUTE_BOND_POSTED, "dispute_bond_posted");
+        assert_eq!(DISPUTE_BOND_SETTLED, "dispute_bond_settled");
+        assert_eq!(CHECKPOINT_ACKNOWLEDGED, "checkpoint_acknowledged");
         assert_eq!(CONDITION_BREACH, "condition_breach");
         assert_eq!(CARRIER_BREACH, "carrier_breach");
         assert_eq!(CARRIER_DISPUTE_LOSS, "carrier_dispute_loss");
+        assert_eq!(CARRIER_DEADLINE_MISS, "carrier_deadline_miss");
+        assert_eq!(CARRIER_CHECKPOINT_OVERSTAY, "carrier_checkpoint_overstay");
+        assert_eq!(CARRIER_RATED, "carrier_rated");
         assert_eq!(CARRIER_LATE_DELIVERY, "carrier_late_delivery");
         assert_eq!(CARRIER_ON_TIME_DELIVERY, "carrier_on_time_delivery");
         assert_eq!(CARRIER_HANDOFF_COMPLETED, "carrier_handoff_completed");
+        assert_eq!(OWNERSHIP_TRANSFERRED, "ownership_transferred");
+        assert_eq!(DEADLINE_EXTENDED, "deadline_extended");
+        assert_eq!(ASSIGNMENT_ACCEPTED, "assignment_accepted");
+        assert_eq!(ASSIGNMENT_DECLINED, "assignment_declined");
+        assert_eq!(CARRIER_REASSIGNED, "carrier_reassigned");
+        assert_eq!(DISTANCE_RECORDED, "distance_recorded");
         assert_eq!(CARRIER_MILESTONE_RATE, "carrier_milestone_rate");
         assert_eq!(ADMIN_PROPOSED, "admin_proposed");
         assert_eq!(ADMIN_TRANSFERRED, "admin_transferred");
         assert_eq!(CONTRACT_UPGRADED, "contract_upgraded");
+        assert_eq!(WASM_HASH_ALLOWED, "wasm_hash_allowed");
         assert_eq!(CONTRACT_PAUSED, "contract_paused");
         assert_eq!(CONTRACT_UNPAUSED, "contract_unpaused");
+        assert_eq!(AUTO_PAUSED, "auto_paused");
         assert_eq!(FORCE_CANCELLED, "force_cancelled");
         assert_eq!(ROLE_REVOKED, "role_revoked");
         assert_eq!(ROLE_CHANGED, "role_changed");
@@ -370,6 +477,7 @@ mod tests {
             SHIPMENT_CREATED,
             STATUS_UPDATED,
             MILESTONE_RECORDED,
+            PICKUP_RECORDED,
             SHIPMENT_CANCELLED,
             SHIPMENT_EXPIRED,
             SHIPMENT_ARCHIVED,
@@ -377,20 +485,39 @@ mod tests {
             ESCROW_DEPOSITED,
             ESCROW_RELEASED,
             ESCROW_REFUNDED,
+            DUST_SWEPT,
+            INSURANCE_CLAIMED,
+            LATE_DELIVERY_PENALTY,
+            COUNT_CORRECTED,
             DISPUTE_RAISED,
             DISPUTE_RESOLVED,
+            DISPUTE_SPLIT_RESOLVED,
+            DISPUTE_ESCALATED,
+            DISPUTE_BOND_POSTED,
+            DISPUTE_BOND_SETTLED,
+            CHECKPOINT_ACKNOWLEDGED,
             CONDITION_BREACH,
             CARRIER_BREACH,
             CARRIER_DISPUTE_LOSS,
+            CARRIER_DEADLINE_MISS,
+            CARRIER_CHECKPOINT_OVERSTAY,
+            CARRIER_RATED,
             CARRIER_LATE_DELIVERY,
             CARRIER_ON_TIME_DELIVERY,
             CARRIER_HANDOFF_COMPLETED,
+            OWNERSHIP_TRANSFERRED,
+            ASSIGNMENT_ACCEPTED,
+            ASSIGNMENT_DECLINED,
+            CARRIER_REASSIGNED,
+            DISTANCE_RECORDED,
             CARRIER_MILESTONE_RATE,
             ADMIN_PROPOSED,
             ADMIN_TRANSFERRED,
             CONTRACT_UPGRADED,
+            WASM_HASH_ALLOWED,
             CONTRACT_PAUSED,
             CONTRACT_UNPAUSED,
+            AUTO_PAUSED,
             FORCE_CANCELLED,
             ROLE_REVOKED,
             ROLE_CHANGED,
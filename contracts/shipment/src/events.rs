@@ -260,6 +260,35 @@ pub fn emit_milestone_recorded(
     crate::storage::increment_milestone_event_count(env, shipment_id);
 }
 
+/// Emits a `pickup_recorded` event when the carrier records proof of pickup.
+pub fn emit_pickup_recorded(
+    env: &Env,
+    shipment_id: u64,
+    carrier: &Address,
+    pickup_hash: &BytesN<32>,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::PICKUP_RECORDED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::PICKUP_RECORDED),),
+        (
+            shipment_id,
+            carrier.clone(),
+            pickup_hash.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
 /// Emits an `escrow_deposited` event when funds are locked for a shipment.
 ///
 /// # Event Data
@@ -420,6 +449,79 @@ pub fn emit_escrow_refunded(env: &Env, shipment_id: u64, to: &Address, amount: i
     crate::storage::increment_event_count(env, shipment_id);
 }
 
+/// Emits a `dust_swept` event when residual escrow below the dust threshold
+/// is moved from a completed shipment to a treasury.
+pub fn emit_dust_swept(env: &Env, shipment_id: u64, to: &Address, amount: i128) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_ESCROW,
+        shipment_id,
+        crate::event_topics::DUST_SWEPT,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::DUST_SWEPT),),
+        (
+            shipment_id,
+            to.clone(),
+            amount,
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits an `insurance_claimed` event when a company claims a shipment's insurance reserve.
+pub fn emit_insurance_claimed(env: &Env, shipment_id: u64, to: &Address, amount: i128) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_ESCROW,
+        shipment_id,
+        crate::event_topics::INSURANCE_CLAIMED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::INSURANCE_CLAIMED),),
+        (
+            shipment_id,
+            to.clone(),
+            amount,
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `late_delivery_penalty` event when a late delivery's penalty is paid to the receiver.
+pub fn emit_late_delivery_penalty(env: &Env, shipment_id: u64, receiver: &Address, amount: i128) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_ESCROW,
+        shipment_id,
+        crate::event_topics::LATE_DELIVERY_PENALTY,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::LATE_DELIVERY_PENALTY),),
+        (
+            shipment_id,
+            receiver.clone(),
+            amount,
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
 /// Emits a `milestone_payment_released` event when a partial escrow release occurs.
 pub fn emit_milestone_payment_released(
     env: &Env,
@@ -498,6 +600,308 @@ pub fn emit_dispute_raised(
     );
 }
 
+/// Emits a `dispute_escalated` event when a party escalates a dispute so it
+/// can only be settled through a multisig proposal.
+///
+/// # Event Data
+///
+/// | Field        | Type      | Description                             |
+/// |--------------|-----------|-----------------------------------------|
+/// | shipment_id  | `u64`     | Disputed shipment                        |
+/// | escalated_by | `Address` | Party that escalated the dispute         |
+///
+/// # Listeners
+///
+/// - **Express backend**: Routes the case to the multisig signer queue.
+pub fn emit_dispute_escalated(env: &Env, shipment_id: u64, escalated_by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::DISPUTE_ESCALATED),),
+        (shipment_id, escalated_by.clone()),
+    );
+}
+
+/// Emits a `count_corrected` event when an admin recalculates a company's
+/// active shipment counter from the per-company shipment index.
+pub fn emit_count_corrected(env: &Env, company: &Address, old_count: u32, new_count: u32) {
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::COUNT_CORRECTED),),
+        (company.clone(), old_count, new_count, EVENT_SCHEMA_VERSION),
+    );
+}
+
+/// Emits an `ownership_transferred` event when a shipment's sender is
+/// reassigned to another company.
+pub fn emit_ownership_transferred(
+    env: &Env,
+    shipment_id: u64,
+    old_sender: &Address,
+    new_sender: &Address,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::OWNERSHIP_TRANSFERRED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::OWNERSHIP_TRANSFERRED),),
+        (
+            shipment_id,
+            old_sender.clone(),
+            new_sender.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `deadline_extended` event when a shipment's deadline is pushed back.
+///
+/// # Event Data
+///
+/// | Field           | Type         | Description                        |
+/// |-----------------|--------------|------------------------------------|
+/// | shipment_id     | `u64`        | Shipment whose deadline changed    |
+/// | old_deadline    | `u64`        | Previous deadline timestamp        |
+/// | new_deadline    | `u64`        | New deadline timestamp             |
+/// | extended_by     | `Address`    | Sender or admin that extended it   |
+/// | schema_version  | `u32`        | Event schema version               |
+/// | event_counter   | `u32`        | Per-shipment event sequence number |
+/// | idempotency_key | `BytesN<32>` | Deduplication key for indexers     |
+///
+/// # Listeners
+/// - **Express backend**: Updates the shipment's deadline and any SLA reminders.
+pub fn emit_deadline_extended(
+    env: &Env,
+    shipment_id: u64,
+    old_deadline: u64,
+    new_deadline: u64,
+    extended_by: &Address,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::DEADLINE_EXTENDED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::DEADLINE_EXTENDED),),
+        (
+            shipment_id,
+            old_deadline,
+            new_deadline,
+            extended_by.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits an `assignment_accepted` event when the assigned carrier accepts a shipment.
+pub fn emit_assignment_accepted(env: &Env, shipment_id: u64, carrier: &Address) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::ASSIGNMENT_ACCEPTED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::ASSIGNMENT_ACCEPTED),),
+        (
+            shipment_id,
+            carrier.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits an `assignment_declined` event so the sender can reassign the shipment.
+pub fn emit_assignment_declined(env: &Env, shipment_id: u64, carrier: &Address, sender: &Address) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::ASSIGNMENT_DECLINED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::ASSIGNMENT_DECLINED),),
+        (
+            shipment_id,
+            carrier.clone(),
+            sender.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `carrier_reassigned` event when a shipment's carrier is replaced before pickup.
+pub fn emit_carrier_reassigned(
+    env: &Env,
+    shipment_id: u64,
+    old_carrier: &Address,
+    new_carrier: &Address,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::CARRIER_REASSIGNED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::CARRIER_REASSIGNED),),
+        (
+            shipment_id,
+            old_carrier.clone(),
+            new_carrier.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `dispute_bond_posted` event when a disputer locks a bond.
+pub fn emit_dispute_bond_posted(env: &Env, shipment_id: u64, disputer: &Address, amount: i128) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_DISPUTE,
+        shipment_id,
+        crate::event_topics::DISPUTE_BOND_POSTED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::DISPUTE_BOND_POSTED),),
+        (
+            shipment_id,
+            disputer.clone(),
+            amount,
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `dispute_bond_settled` event when a dispute bond is paid out.
+/// `returned` is `true` when the disputer won and got the bond back, and
+/// `false` when it was forfeited to the winning party.
+pub fn emit_dispute_bond_settled(
+    env: &Env,
+    shipment_id: u64,
+    recipient: &Address,
+    amount: i128,
+    returned: bool,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_DISPUTE,
+        shipment_id,
+        crate::event_topics::DISPUTE_BOND_SETTLED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::DISPUTE_BOND_SETTLED),),
+        (
+            shipment_id,
+            recipient.clone(),
+            amount,
+            returned,
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `checkpoint_acknowledged` event when the receiver co-signs a
+/// checkpoint arrival.
+pub fn emit_checkpoint_acknowledged(
+    env: &Env,
+    shipment_id: u64,
+    receiver: &Address,
+    ack_hash: &BytesN<32>,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_SHIPMENT,
+        shipment_id,
+        crate::event_topics::CHECKPOINT_ACKNOWLEDGED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(
+            env,
+            crate::event_topics::CHECKPOINT_ACKNOWLEDGED,
+        ),),
+        (
+            shipment_id,
+            receiver.clone(),
+            ack_hash.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
+/// Emits a `distance_recorded` event when the carrier reports travelled distance.
+pub fn emit_distance_recorded(
+    env: &Env,
+    shipment_id: u64,
+    carrier: &Address,
+    meters: u64,
+    total_meters: u64,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_CARRIER,
+        shipment_id,
+        crate::event_topics::DISTANCE_RECORDED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::DISTANCE_RECORDED),),
+        (
+            shipment_id,
+            carrier.clone(),
+            meters,
+            total_meters,
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
 /// Emits a `shipment_cancelled` event when a shipment is cancelled.
 ///
 /// # Event Data
@@ -584,6 +988,24 @@ pub fn emit_contract_upgraded(
     );
 }
 
+/// Emits a `wasm_hash_allowed` event when governance approves an upgrade target.
+///
+/// # Event Data
+///
+/// | Field     | Type         | Description                        |
+/// |-----------|--------------|------------------------------------|
+/// | admin     | `Address`    | Admin that approved the hash       |
+/// | wasm_hash | `BytesN<32>` | WASM hash now accepted by upgrades |
+///
+/// # Listeners
+/// - **Express backend**: Records the approved hash in the governance audit log.
+pub fn emit_wasm_hash_allowed(env: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::WASM_HASH_ALLOWED),),
+        (admin.clone(), wasm_hash.clone()),
+    );
+}
+
 /// Emits a `migration_reported` event summarizing the impact of an upgrade.
 ///
 /// # Event Data
@@ -851,6 +1273,77 @@ pub fn emit_carrier_dispute_loss(env: &Env, carrier: &Address, shipment_id: u64)
     );
 }
 
+/// Emits a `carrier_deadline_miss` event when `check_deadline` expires a
+/// shipment the carrier had already taken custody of.
+///
+/// The backend indexes this event to penalise the carrier's reputation score.
+///
+/// # Event Data
+///
+/// | Field       | Type      | Description                                     |
+/// |-------------|-----------|-------------------------------------------------|
+/// | carrier     | `Address` | Carrier that missed the delivery deadline        |
+/// | shipment_id | `u64`     | Shipment that expired                            |
+///
+/// # Listeners
+/// - **Express backend**: Increments deadline-miss counter in carrier reputation index.
+pub fn emit_carrier_deadline_miss(env: &Env, carrier: &Address, shipment_id: u64) {
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::CARRIER_DEADLINE_MISS),),
+        (carrier.clone(), shipment_id),
+    );
+}
+
+/// Emits a `carrier_checkpoint_overstay` event when a shipment leaves
+/// `AtCheckpoint` after dwelling longer than `max_checkpoint_dwell`.
+///
+/// The backend indexes this event to penalise the carrier's reputation score.
+///
+/// # Event Data
+///
+/// | Field       | Type      | Description                                     |
+/// |-------------|-----------|-------------------------------------------------|
+/// | carrier     | `Address` | Carrier holding the shipment at the checkpoint   |
+/// | shipment_id | `u64`     | Shipment that overstayed                         |
+/// | dwell       | `u64`     | Seconds spent at the checkpoint                  |
+///
+/// # Listeners
+/// - **Express backend**: Increments checkpoint-overstay counter in carrier reputation index.
+pub fn emit_carrier_checkpoint_overstay(
+    env: &Env,
+    carrier: &Address,
+    shipment_id: u64,
+    dwell: u64,
+) {
+    env.events().publish(
+        (Symbol::new(
+            env,
+            crate::event_topics::CARRIER_CHECKPOINT_OVERSTAY,
+        ),),
+        (carrier.clone(), shipment_id, dwell),
+    );
+}
+
+/// Emits a `carrier_rated` event when the receiver rates the carrier of a
+/// delivered shipment.
+///
+/// # Event Data
+///
+/// | Field       | Type      | Description                                     |
+/// |-------------|-----------|-------------------------------------------------|
+/// | carrier     | `Address` | Carrier being rated                              |
+/// | shipment_id | `u64`     | Delivered shipment the rating refers to          |
+/// | score       | `u32`     | Rating from 1 to 5                               |
+///
+/// # Listeners
+/// - **Express backend**: Updates the carrier's average rating in the reputation index.
+pub fn emit_carrier_rated(env: &Env, carrier: &Address, shipment_id: u64, score: u32) {
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::CARRIER_RATED),),
+        (carrier.clone(), shipment_id, score),
+    );
+}
+
 /// Emits a `notification` event for backend indexing to trigger push notifications,
 /// emails, or in-app alerts.
 ///
@@ -866,6 +1359,9 @@ pub fn emit_carrier_dispute_loss(env: &Env, carrier: &Address, shipment_id: u64)
 /// # Listeners
 /// - **Express backend**: Triggers push notifications, emails, or in-app alerts.
 ///
+/// Recipients that opted into the on-chain inbox also get the notification
+/// appended to their bounded inbox (see `get_inbox`).
+///
 /// # Arguments
 /// * `env` - Execution environment.
 /// * `recipient` - Address to receive the notification.
@@ -891,11 +1387,19 @@ pub fn emit_notification(
         (Symbol::new(env, crate::event_topics::NOTIFICATION),),
         (
             recipient.clone(),
-            notification_type,
+            notification_type.clone(),
             shipment_id,
             data_hash.clone(),
         ),
     );
+
+    if crate::storage::is_inbox_enabled(env, recipient) {
+        crate::storage::push_inbox_entry(
+            env,
+            recipient,
+            (notification_type, shipment_id, env.ledger().timestamp()),
+        );
+    }
 }
 
 /// Emits a `shipment_archived` event when a shipment is moved to temporary storage.
@@ -1226,6 +1730,53 @@ pub fn emit_dispute_resolved(
     crate::storage::increment_event_count(env, shipment_id);
 }
 
+/// Emits a `dispute_split_resolved` event when a dispute is settled with a
+/// carrier/company split of the escrow.
+///
+/// # Event Data
+///
+/// | Field          | Type      | Description                                  |
+/// |----------------|-----------|----------------------------------------------|
+/// | shipment_id    | `u64`     | Shipment that was disputed                    |
+/// | carrier_bps    | `u32`     | Carrier share in basis points                 |
+/// | carrier_amount | `i128`    | Amount released to the carrier                |
+/// | company_amount | `i128`    | Amount refunded to the company                |
+/// | admin          | `Address` | Admin or guardian that resolved the dispute   |
+pub fn emit_dispute_split_resolved(
+    env: &Env,
+    shipment_id: u64,
+    carrier_bps: u32,
+    carrier_amount: i128,
+    company_amount: i128,
+    admin: &Address,
+) {
+    let event_counter = next_event_counter(env, shipment_id);
+    let idempotency_key = generate_idempotency_key(
+        env,
+        crate::event_topics::HASH_DOMAIN_DISPUTE,
+        shipment_id,
+        crate::event_topics::DISPUTE_SPLIT_RESOLVED,
+        event_counter,
+    );
+    env.events().publish(
+        (Symbol::new(
+            env,
+            crate::event_topics::DISPUTE_SPLIT_RESOLVED,
+        ),),
+        (
+            shipment_id,
+            carrier_bps,
+            carrier_amount,
+            company_amount,
+            admin.clone(),
+            EVENT_SCHEMA_VERSION,
+            event_counter,
+            idempotency_key,
+        ),
+    );
+    crate::storage::increment_event_count(env, shipment_id);
+}
+
 /// Emits a `contract_paused` event when the contract is paused by an admin.
 ///
 /// # Event Data
@@ -1288,6 +1839,28 @@ pub fn emit_contract_unpaused(env: &Env, admin: &Address) {
     );
 }
 
+/// Emits an `auto_paused` event when dispute spike detection pauses the
+/// contract.
+///
+/// # Event Data
+///
+/// | Field         | Type  | Description                                  |
+/// |---------------|-------|----------------------------------------------|
+/// | dispute_count | `u32` | Disputes counted inside the window           |
+/// | window        | `u64` | Length of the rolling window in seconds      |
+/// | timestamp     | `u64` | Ledger timestamp when the pause occurred     |
+///
+/// # Examples
+/// ```rust
+/// // events::emit_auto_paused(&env, 5, 3600);
+/// ```
+pub fn emit_auto_paused(env: &Env, dispute_count: u32, window: u64) {
+    env.events().publish(
+        (Symbol::new(env, crate::event_topics::AUTO_PAUSED),),
+        (dispute_count, window, env.ledger().timestamp()),
+    );
+}
+
 /// Emits a `recovery_event` when a shipment is recovered from a stuck state.
 ///
 /// # Event Data
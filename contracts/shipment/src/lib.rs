@@ -48,65 +48,243 @@ mod test_rollback;
 mod types;
 mod validation;
 
+#[cfg(test)]
+mod test_acceptance_refund;
+#[cfg(test)]
+mod test_admin_company_role;
+#[cfg(test)]
+mod test_allowed_actions;
+#[cfg(test)]
+mod test_arbitrator;
 #[cfg(test)]
 mod test_archive_restore_consistency;
 #[cfg(test)]
+mod test_archive_settled;
+#[cfg(test)]
+mod test_assignment_acceptance;
+#[cfg(test)]
 mod test_auth;
 #[cfg(test)]
 mod test_auth_matrix;
 #[cfg(test)]
 mod test_auto_dispute;
 #[cfg(test)]
+mod test_auto_whitelist;
+#[cfg(test)]
+mod test_breach_weights;
+#[cfg(test)]
+mod test_can_cancel;
+#[cfg(test)]
+mod test_cancel_batch;
+#[cfg(test)]
+mod test_cancel_compensation;
+#[cfg(test)]
+mod test_carrier_disputes;
+#[cfg(test)]
 mod test_carrier_relationship;
 #[cfg(test)]
+mod test_carrier_reputation;
+#[cfg(test)]
+mod test_carrier_shipment_index;
+#[cfg(test)]
+mod test_checkpoint_ack;
+#[cfg(test)]
+mod test_checkpoint_dwell;
+#[cfg(test)]
+mod test_company_shipments_page;
+#[cfg(test)]
 mod test_counter_overflow;
 #[cfg(test)]
+mod test_creation_hash;
+#[cfg(test)]
 mod test_creation_quota;
 #[cfg(test)]
+mod test_deadline_batch;
+#[cfg(test)]
+mod test_deadline_buffer;
+#[cfg(test)]
 mod test_deadline_grace;
 #[cfg(test)]
+mod test_deadline_penalty;
+#[cfg(test)]
+mod test_delivery_receipt;
+#[cfg(test)]
+mod test_departure_checkpoint;
+#[cfg(test)]
 mod test_diagnostics;
 #[cfg(test)]
+mod test_dispute_bond;
+#[cfg(test)]
+mod test_dispute_escalation;
+#[cfg(test)]
+mod test_dispute_evidence;
+#[cfg(test)]
+mod test_dispute_limit;
+#[cfg(test)]
+mod test_dispute_spike;
+#[cfg(test)]
+mod test_dispute_split;
+#[cfg(test)]
+mod test_dispute_window;
+#[cfg(test)]
+mod test_disputed_escrow;
+#[cfg(test)]
+mod test_distance;
+#[cfg(test)]
+mod test_error_catalog;
+#[cfg(test)]
 mod test_escrow_arithmetic;
 #[cfg(test)]
+mod test_escrow_breakdown;
+#[cfg(test)]
+mod test_escrow_min_value;
+#[cfg(test)]
+mod test_escrow_third_party;
+#[cfg(test)]
+mod test_escrow_top_up;
+#[cfg(test)]
+mod test_extend_deadline;
+#[cfg(test)]
+mod test_feature_flags;
+#[cfg(test)]
+mod test_fee_on_transfer;
+#[cfg(test)]
+mod test_fixed_milestones;
+#[cfg(test)]
+mod test_handoff_cooldown;
+#[cfg(test)]
 mod test_hash_domain_separation;
 #[cfg(test)]
+mod test_initial_update_delay;
+#[cfg(test)]
+mod test_insurance_reserve;
+#[cfg(test)]
 mod test_iot_verification;
 #[cfg(test)]
+mod test_is_initialized;
+#[cfg(test)]
+mod test_late_delivery_penalty;
+#[cfg(test)]
+mod test_liabilities;
+#[cfg(test)]
+mod test_milestone_holdback;
+#[cfg(test)]
+mod test_milestone_paid;
+#[cfg(test)]
 mod test_milestone_payout_order;
 #[cfg(test)]
+mod test_milestone_remainder;
+#[cfg(test)]
+mod test_milestone_rounding;
+#[cfg(test)]
+mod test_needs_approval;
+#[cfg(test)]
+mod test_notification_inbox;
+#[cfg(test)]
+mod test_notification_recipients;
+#[cfg(test)]
+mod test_on_time_rate;
+#[cfg(test)]
 mod test_panic_free_invariants;
 #[cfg(test)]
 mod test_pause;
 #[cfg(test)]
+mod test_peek_next_id;
+#[cfg(test)]
+mod test_pending_governance;
+#[cfg(test)]
+mod test_pickup_proof;
+#[cfg(test)]
 mod test_precondition_guards;
 #[cfg(test)]
+mod test_proposal_detail;
+#[cfg(test)]
 mod test_proposal_digest;
 #[cfg(test)]
+mod test_reassign_carrier;
+#[cfg(test)]
+mod test_recalc_active_count;
+#[cfg(test)]
+mod test_receiver_whitelist;
+#[cfg(test)]
+mod test_refund_ttl;
+#[cfg(test)]
+mod test_registered_receiver;
+#[cfg(test)]
+mod test_reinitialize;
+#[cfg(test)]
+mod test_relationship;
+#[cfg(test)]
+mod test_release_delay;
+#[cfg(test)]
+mod test_release_not_funded;
+#[cfg(test)]
+mod test_remove_roles;
+#[cfg(test)]
 mod test_replay_protection;
 #[cfg(test)]
+mod test_report_rate_limit;
+#[cfg(test)]
+mod test_reputations_batch;
+#[cfg(test)]
 mod test_require_auth_for_args;
 #[cfg(test)]
+mod test_required_auth;
+#[cfg(test)]
+mod test_resolution_note;
+#[cfg(test)]
+mod test_role_cooldown;
+#[cfg(test)]
+mod test_role_counts;
+#[cfg(test)]
 mod test_settlement;
 #[cfg(test)]
 mod test_settlement_machine;
 #[cfg(test)]
 mod test_settlement_transitions;
 #[cfg(test)]
+mod test_shipments_in_range;
+#[cfg(test)]
+mod test_shipments_page;
+#[cfg(test)]
 mod test_signature_argument_ordering;
 #[cfg(test)]
+mod test_signed_milestone;
+#[cfg(test)]
+mod test_signer_delegate;
+#[cfg(test)]
+mod test_simulate_resolution;
+#[cfg(test)]
+mod test_stale_shipments;
+#[cfg(test)]
+mod test_status_batch;
+#[cfg(test)]
+mod test_status_history;
+#[cfg(test)]
 mod test_suspension;
 #[cfg(test)]
 mod test_suspension_cascade;
 #[cfg(test)]
+mod test_sweep_dust;
+#[cfg(test)]
 mod test_symbol_validation;
 #[cfg(test)]
+mod test_token_return_value;
+#[cfg(test)]
+mod test_transfer_ownership;
+#[cfg(test)]
 mod test_ttl_health;
 #[cfg(test)]
+mod test_ttl_status;
+#[cfg(test)]
 mod test_utils;
 #[cfg(test)]
 mod test_verification;
 #[cfg(test)]
+mod test_wasm_allowlist;
+#[cfg(test)]
+mod test_whitelist_cap;
+#[cfg(test)]
 mod test_whitelist_multicompany;
 #[cfg(test)]
 mod test_zero_amount_escrow;
@@ -140,13 +318,29 @@ pub use validation::*;
 
 const MAX_BATCH_QUERY_SIZE: u32 = 50;
 
+/// Extend a shipment's TTL, using the shorter `settled_ttl_extension` once
+/// the shipment is `Delivered` or `Cancelled`.
 fn extend_shipment_ttl(env: &Env, shipment_id: u64) {
     let config = config::get_config(env);
+    let settled = storage::get_shipment(env, shipment_id)
+        .map(|s| {
+            matches!(
+                s.status,
+                ShipmentStatus::Delivered | ShipmentStatus::Cancelled
+            )
+        })
+        .unwrap_or(false);
+    let extension = if settled {
+        config.settled_ttl_extension
+    } else {
+        config.shipment_ttl_extension
+    };
+    // The threshold may not exceed the extension target.
     storage::extend_shipment_ttl(
         env,
         shipment_id,
-        config.shipment_ttl_threshold,
-        config.shipment_ttl_extension,
+        config.shipment_ttl_threshold.min(extension),
+        extension,
     );
 }
 
@@ -174,7 +368,13 @@ fn validate_milestones(env: &Env, milestones: &Vec<(Symbol, u32)>) -> Result<(),
         total_percentage += milestone.1;
     }
 
-    if total_percentage != 100 {
+    // With holdback enabled the unallocated share is released on delivery.
+    if total_percentage > 100
+        || (total_percentage < 100
+            && !config::get_config(env)
+                .escrow_policy
+                .allow_milestone_holdback)
+    {
         return Err(NavinError::MilestoneSumInvalid);
     }
 
@@ -278,7 +478,12 @@ fn internal_release_escrow(
                 shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
                 persist_shipment(env, shipment)?;
 
+                storage::record_escrow_released(env, actual_release);
                 events::emit_escrow_released(env, shipment.id, &shipment.carrier, actual_release);
+
+                if shipment.escrow_amount == 0 {
+                    settle_insurance_reserve(env, shipment, true)?;
+                }
             }
             Err(e) => {
                 fail_settlement(env, settlement_id, shipment.id, e as u32)?;
@@ -289,6 +494,82 @@ fn internal_release_escrow(
     Ok(())
 }
 
+/// Pay `late_delivery_penalty_bps` of the remaining escrow to the receiver
+/// when a shipment is delivered after its deadline. The carrier is paid from
+/// what is left.
+fn apply_late_delivery_penalty(
+    env: &Env,
+    shipment: &mut Shipment,
+    now: u64,
+) -> Result<(), NavinError> {
+    let penalty_bps = config::get_config(env)
+        .escrow_policy
+        .late_delivery_penalty_bps;
+    if penalty_bps == 0 || now <= shipment.deadline || shipment.escrow_amount <= 0 {
+        return Ok(());
+    }
+
+    let penalty = checked_mul_div_i128(shipment.escrow_amount, penalty_bps as i128, 10_000)?;
+    if penalty > 0 {
+        let token_contract = storage::get_token_contract(env).ok_or(NavinError::NotInitialized)?;
+        invoke_token_transfer(
+            env,
+            &token_contract,
+            &env.current_contract_address(),
+            &shipment.receiver,
+            penalty,
+        )?;
+        shipment.escrow_amount = checked_sub_escrow(shipment.escrow_amount, penalty)?;
+        storage::record_escrow_released(env, penalty);
+        events::emit_late_delivery_penalty(env, shipment.id, &shipment.receiver, penalty);
+    }
+    Ok(())
+}
+
+/// Assembles the reputation counters stored for `carrier`.
+fn carrier_reputation(env: &Env, carrier: Address) -> CarrierReputation {
+    let (ratings, rating_sum) = storage::get_carrier_ratings(env, &carrier);
+    let average_rating = if ratings == 0 {
+        0
+    } else {
+        ((rating_sum * 100) / ratings as u64) as u32
+    };
+    CarrierReputation {
+        deliveries: storage::get_carrier_deliveries(env, &carrier),
+        on_time_deliveries: storage::get_carrier_on_time_deliveries(env, &carrier),
+        deadline_misses: storage::get_carrier_deadline_misses(env, &carrier),
+        breach_penalty: storage::get_carrier_breach_penalty(env, &carrier),
+        breaches: storage::get_carrier_breaches(env, &carrier),
+        dispute_losses: storage::get_carrier_dispute_losses(env, &carrier),
+        checkpoint_overstays: storage::get_carrier_checkpoint_overstays(env, &carrier),
+        ratings,
+        average_rating,
+        carrier,
+    }
+}
+
+/// Tracks time spent `AtCheckpoint`: stamps the arrival time on entry and, on
+/// the next transition out, penalizes the carrier when the stay exceeded
+/// `ContractConfig.max_checkpoint_dwell`.
+fn track_checkpoint_dwell(env: &Env, shipment: &Shipment, old_status: &ShipmentStatus, now: u64) {
+    if shipment.status == ShipmentStatus::AtCheckpoint {
+        storage::set_checkpoint_entered_at(env, shipment.id, now);
+        return;
+    }
+    if *old_status != ShipmentStatus::AtCheckpoint {
+        return;
+    }
+    if let Some(entered_at) = storage::get_checkpoint_entered_at(env, shipment.id) {
+        storage::remove_checkpoint_entered_at(env, shipment.id);
+        let max_dwell = config::get_config(env).carrier_policy.max_checkpoint_dwell;
+        let dwell = now.saturating_sub(entered_at);
+        if max_dwell > 0 && dwell > max_dwell {
+            storage::increment_carrier_checkpoint_overstays(env, &shipment.carrier);
+            events::emit_carrier_checkpoint_overstay(env, &shipment.carrier, shipment.id, dwell);
+        }
+    }
+}
+
 pub(crate) fn checked_mul_div_i128(
     value: i128,
     multiplier: i128,
@@ -303,2655 +584,6189 @@ pub(crate) fn checked_mul_div_i128(
     Ok(product / divisor)
 }
 
-fn with_reentrancy_lock<T, F>(env: &Env, operation: F) -> Result<T, NavinError>
-where
-    F: FnOnce() -> Result<T, NavinError>,
-{
-    if storage::is_reentrancy_locked(env) {
-        return Err(NavinError::ReentrancyDetected);
-    }
-
-    storage::set_reentrancy_lock(env, true);
-    let result = operation();
-    storage::set_reentrancy_lock(env, false);
-    result
-}
-
-fn effective_batch_query_limit(env: &Env) -> u32 {
-    let _ = env;
-    MAX_BATCH_QUERY_SIZE
-}
-
-fn finalize_if_settled(_env: &Env, shipment: &mut Shipment) {
-    if (shipment.status == ShipmentStatus::Delivered
-        || shipment.status == ShipmentStatus::Cancelled)
-        && shipment.escrow_amount == 0
-    {
-        shipment.finalized = true;
+/// Rejects unregistered receivers when `require_registered_receiver` is set.
+fn require_registered_receiver(env: &Env, receiver: &Address) -> Result<(), NavinError> {
+    if config::get_config(env).require_registered_receiver && !storage::is_receiver(env, receiver) {
+        return Err(NavinError::ReceiverNotRegistered);
     }
-}
-
-/// Create a new settlement record and mark it as active for the shipment.
-fn create_settlement(
-    env: &Env,
-    shipment_id: u64,
-    operation: SettlementOperation,
-    amount: i128,
-    from: &Address,
-    to: &Address,
-) -> Result<u64, NavinError> {
-    let settlement_id = storage::increment_settlement_counter(env);
-    let settlement = SettlementRecord {
-        settlement_id,
-        shipment_id,
-        operation,
-        state: SettlementState::Pending,
-        amount,
-        from: from.clone(),
-        to: to.clone(),
-        initiated_at: env.ledger().timestamp(),
-        completed_at: None,
-        error_code: None,
-    };
-    storage::set_settlement(env, &settlement);
-    storage::set_active_settlement(env, shipment_id, settlement_id);
-    Ok(settlement_id)
-}
-
-/// Mark a settlement as completed.
-fn complete_settlement(env: &Env, settlement_id: u64, shipment_id: u64) -> Result<(), NavinError> {
-    let mut settlement =
-        storage::get_settlement(env, settlement_id).ok_or(NavinError::ShipmentNotFound)?; // Reusing error for simplicity
-    settlement.state = SettlementState::Completed;
-    settlement.completed_at = Some(env.ledger().timestamp());
-    storage::set_settlement(env, &settlement);
-    storage::clear_active_settlement(env, shipment_id);
     Ok(())
 }
 
-/// Mark a settlement as failed with an error code.
-fn fail_settlement(
+/// Rejects carriers the receiver has not approved when
+/// `require_receiver_approval` is set.
+fn require_receiver_approved_carrier(
     env: &Env,
-    settlement_id: u64,
-    shipment_id: u64,
-    error_code: u32,
+    receiver: &Address,
+    carrier: &Address,
 ) -> Result<(), NavinError> {
-    let mut settlement =
-        storage::get_settlement(env, settlement_id).ok_or(NavinError::ShipmentNotFound)?; // Reusing error for simplicity
-    settlement.state = SettlementState::Failed;
-    settlement.completed_at = Some(env.ledger().timestamp());
-    settlement.error_code = Some(error_code);
-    storage::set_settlement(env, &settlement);
-    storage::clear_active_settlement(env, shipment_id);
+    if config::get_config(env).require_receiver_approval
+        && !storage::is_receiver_whitelisted(env, receiver, carrier)
+    {
+        return Err(NavinError::CarrierNotWhitelisted);
+    }
     Ok(())
 }
 
-fn require_not_finalized(shipment: &Shipment) -> Result<(), NavinError> {
-    if shipment.finalized {
-        return Err(NavinError::ShipmentFinalized);
+/// Compute a milestone's share of `total_escrow` for `pct` percent.
+///
+/// Truncates by default; rounds up when `rounding_favors_carrier` is set so
+/// the carrier receives the dust. Callers still let the final milestone
+/// release the remaining escrow, which keeps the payout total exact.
+fn milestone_release_amount(env: &Env, total_escrow: i128, pct: u32) -> Result<i128, NavinError> {
+    let product = total_escrow
+        .checked_mul(pct as i128)
+        .ok_or(NavinError::ArithmeticError)?;
+    let floor = product / 100;
+    if config::get_config(env).rounding_favors_carrier && product % 100 != 0 {
+        checked_add_i128(floor, 1)
+    } else {
+        Ok(floor)
     }
-    Ok(())
 }
 
-/// Centralized state machine guardrail for all shipment lifecycle transitions.
-pub(crate) fn validate_shipment_transition(
-    from: &ShipmentStatus,
-    to: &ShipmentStatus,
-) -> Result<(), NavinError> {
-    if !from.is_valid_transition(to) {
-        return Err(NavinError::InvalidStatus);
+/// Amount to release for a just-paid milestone worth `pct` percent.
+///
+/// `shipment.paid_milestones` must already include the milestone. Once every
+/// milestone is paid the whole remaining escrow is released, so truncated
+/// shares never leave dust locked in the contract.
+fn milestone_payout(env: &Env, shipment: &Shipment, pct: u32) -> Result<i128, NavinError> {
+    let mut total_pct_paid = 0;
+    for (m_sym, m_pct) in shipment.payment_milestones.iter() {
+        if shipment.paid_milestones.iter().any(|p| p == m_sym) {
+            total_pct_paid += m_pct;
+        }
     }
-    Ok(())
-}
 
-/// Build a 32-byte action hash from arbitrary bytes and check/set the idempotency window.
-/// Returns `DuplicateAction` if the hash is already present in temporary storage.
-fn check_idempotency(env: &Env, payload: soroban_sdk::Bytes) -> Result<(), NavinError> {
-    let action_hash: BytesN<32> = env.crypto().sha256(&payload).into();
-    if storage::has_idempotency_window(env, &action_hash) {
-        return Err(NavinError::DuplicateAction);
+    if total_pct_paid == 100 {
+        Ok(shipment.escrow_amount)
+    } else {
+        milestone_release_amount(env, shipment.total_escrow, pct)
     }
-    let window = config::get_config(env).idempotency_window_seconds;
-    storage::set_idempotency_window(env, &action_hash, window);
-    Ok(())
 }
 
-#[derive(Copy, Clone)]
-enum TokenOperation {
-    Transfer,
-    #[cfg(test)]
-    Mint,
+/// Absolute payout configured for `checkpoint` when the shipment uses
+/// fixed-amount milestones instead of percentages.
+fn fixed_milestone_amount(env: &Env, shipment_id: u64, checkpoint: &Symbol) -> Option<i128> {
+    storage::get_fixed_payment_milestones(env, shipment_id)
+        .iter()
+        .find(|(name, _)| name == checkpoint)
+        .map(|(_, amount)| amount)
 }
 
-impl TokenOperation {
-    fn symbol(self) -> Symbol {
-        match self {
-            TokenOperation::Transfer => symbol_short!("transfer"),
-            #[cfg(test)]
-            TokenOperation::Mint => symbol_short!("mint"),
-        }
-    }
+/// Amount to release for a just-paid fixed-amount milestone, capped at the
+/// escrow still held so refunds or penalties cannot overdraw it.
+fn fixed_milestone_payout(shipment: &Shipment, amount: i128) -> i128 {
+    amount.min(shipment.escrow_amount)
+}
 
-    fn error(self) -> NavinError {
-        match self {
-            TokenOperation::Transfer => NavinError::TokenTransferFailed,
-            #[cfg(test)]
-            TokenOperation::Mint => NavinError::TokenMintFailed,
-        }
+/// Reject funding that leaves `total_escrow` different from the sum of the
+/// shipment's fixed-amount milestones, if it has any.
+fn require_fixed_milestones_funded(env: &Env, shipment: &Shipment) -> Result<(), NavinError> {
+    let fixed = storage::get_fixed_payment_milestones(env, shipment.id);
+    if fixed.is_empty() {
+        return Ok(());
+    }
+    let mut total: i128 = 0;
+    for (_, amount) in fixed.iter() {
+        total = checked_add_i128(total, amount)?;
     }
+    if total != shipment.total_escrow {
+        return Err(NavinError::MilestoneSumInvalid);
+    }
+    Ok(())
 }
 
-/// Validates that the token contract reports the expected number of decimal places (7).
+/// Cancel an expired shipment, refund its escrow to the sender and record the
+/// deadline miss. Shared by `check_deadline` and `check_deadlines_batch`;
+/// callers must hold the reentrancy lock.
 ///
-/// The Navin contract assumes all amounts are expressed in the Stellar standard
-/// unit where 1 token = 10_000_000 stroops (7 decimal places). Tokens returning
-/// a different value from `decimals()` would cause mismatched amount calculations
-/// in escrow operations, so they are rejected early.
-///
-/// # Errors
-/// Returns `NavinError::InvalidTokenDecimals` if the token returns ≠ 7 decimals,
-/// or if the call to the token contract fails (treated as an incompatible token).
-fn validate_token_decimals(env: &Env, token_contract: &Address) -> Result<(), NavinError> {
-    let args: Vec<soroban_sdk::Val> = Vec::new(env);
-    let result = env.try_invoke_contract::<u32, soroban_sdk::Error>(
-        token_contract,
-        &Symbol::new(env, "decimals"),
-        args,
+/// Only shipments the carrier has taken custody of get the
+/// `deadline_grace_seconds` buffer; anything else expires at the deadline.
+fn expire_shipment(env: &Env, shipment_id: u64) -> Result<(), NavinError> {
+    let mut shipment =
+        storage::get_shipment(env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+    let config = config::get_config(env);
+    let carrier_had_custody = matches!(
+        shipment.status,
+        ShipmentStatus::InTransit
+            | ShipmentStatus::AtCheckpoint
+            | ShipmentStatus::PartiallyDelivered
     );
-    match result {
-        Ok(Ok(decimals)) if decimals == crate::types::EXPECTED_TOKEN_DECIMALS => Ok(()),
-        _ => Err(NavinError::InvalidTokenDecimals),
+    let grace = if carrier_had_custody {
+        config.deadline_grace_seconds
+    } else {
+        0
+    };
+    let expiry_threshold = shipment.deadline.saturating_add(grace);
+
+    if env.ledger().timestamp() < expiry_threshold {
+        return Err(NavinError::NotExpired);
     }
-}
 
-fn invoke_token_operation(
-    env: &Env,
-    token_contract: &Address,
-    operation: TokenOperation,
-    args: Vec<soroban_sdk::Val>,
-) -> Result<(), NavinError> {
-    match env.try_invoke_contract::<(), soroban_sdk::Error>(
-        token_contract,
-        &operation.symbol(),
-        args,
-    ) {
-        Ok(Ok(())) => Ok(()),
-        _ => Err(operation.error()),
+    match shipment.status {
+        ShipmentStatus::Delivered | ShipmentStatus::Disputed | ShipmentStatus::Cancelled => {
+            return Err(NavinError::ShipmentAlreadyCompleted);
+        }
+        _ => {}
     }
-}
 
-fn invoke_token_transfer(
-    env: &Env,
-    token_contract: &Address,
-    from: &Address,
-    to: &Address,
-    amount: i128,
-) -> Result<(), NavinError> {
-    let cb_config = circuit_breaker::CircuitBreakerConfig::default();
-    circuit_breaker::check_transfer_allowed(env, &cb_config)?;
+    let escrow_amount = shipment.escrow_amount;
+    let old_status = shipment.status.clone();
+    shipment.status = ShipmentStatus::Cancelled;
+    shipment.escrow_amount = 0;
+    shipment.updated_at = env.ledger().timestamp();
+    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
 
-    let mut args: soroban_sdk::Vec<soroban_sdk::Val> = Vec::new(env);
-    args.push_back(from.clone().into_val(env));
-    args.push_back(to.clone().into_val(env));
-    args.push_back(amount.into_val(env));
+    persist_shipment(env, &shipment)?;
+    storage::decrement_status_count(env, &old_status);
+    storage::increment_status_count(env, &ShipmentStatus::Cancelled);
+    storage::push_status_history(
+        env,
+        shipment_id,
+        &ShipmentStatus::Cancelled,
+        &env.current_contract_address(),
+    );
+    storage::decrement_active_shipment_count(env, &shipment.sender);
 
-    match invoke_token_operation(env, token_contract, TokenOperation::Transfer, args) {
-        Ok(()) => {
-            circuit_breaker::record_transfer_success(env);
-            Ok(())
-        }
-        Err(e) => {
-            circuit_breaker::record_transfer_failure(env, &cb_config);
-            Err(e)
-        }
+    if escrow_amount > 0 {
+        storage::remove_escrow_balance(env, shipment_id);
+
+        let token_contract = storage::get_token_contract(env).ok_or(NavinError::NotInitialized)?;
+        let contract_address = env.current_contract_address();
+        invoke_token_transfer(
+            env,
+            &token_contract,
+            &contract_address,
+            &shipment.sender,
+            escrow_amount,
+        )?;
+        storage::record_escrow_refunded(env, escrow_amount);
+        events::emit_escrow_refunded(env, shipment_id, &shipment.sender, escrow_amount);
     }
-}
+    settle_insurance_reserve(env, &shipment, false)?;
 
-#[cfg(test)]
-fn invoke_token_mint(
-    env: &Env,
-    token_contract: &Address,
-    admin: &Address,
-    to: &Address,
-    amount: i128,
-) -> Result<(), NavinError> {
-    let mut args: soroban_sdk::Vec<soroban_sdk::Val> = Vec::new(env);
-    args.push_back(admin.clone().into_val(env));
-    args.push_back(to.clone().into_val(env));
-    args.push_back(amount.into_val(env));
-    invoke_token_operation(env, token_contract, TokenOperation::Mint, args)
-}
+    extend_shipment_ttl(env, shipment_id);
+    events::emit_shipment_expired(env, shipment_id);
 
-fn require_initialized(env: &Env) -> Result<(), NavinError> {
-    if !storage::is_initialized(env) {
-        return Err(NavinError::NotInitialized);
+    // Reputation: only penalize the carrier if it had taken custody; a
+    // shipment that never left `Created` is not the carrier's fault.
+    if config.carrier_policy.penalize_deadline_miss && carrier_had_custody {
+        storage::increment_carrier_deadline_misses(env, &shipment.carrier);
+        events::emit_carrier_deadline_miss(env, &shipment.carrier, shipment_id);
     }
-    Ok(())
-}
 
-fn require_not_paused(env: &Env) -> Result<(), NavinError> {
-    if storage::is_paused(env) {
-        return Err(NavinError::ContractPaused);
-    }
     Ok(())
 }
 
-fn require_admin_or_guardian(env: &Env, address: &Address) -> Result<(), NavinError> {
-    require_initialized(env)?;
-    if storage::get_admin(env) == *address {
-        return Ok(());
+/// Record a milestone reported by `carrier` and release any milestone payment
+/// it unlocks. Shared by `record_milestone` and `record_milestone_signed`;
+/// callers authenticate the carrier and hold the reentrancy lock.
+fn record_milestone_for(
+    env: &Env,
+    carrier: &Address,
+    shipment_id: u64,
+    checkpoint: &Symbol,
+    data_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    require_role(env, carrier, Role::Carrier)?;
+    require_active_carrier(env, carrier)?;
+
+    // Verify shipment exists, carrier is assigned, and status
+    let mut shipment =
+        storage::get_shipment(env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+    require_not_finalized(&shipment)?;
+
+    // Validate checkpoint symbol
+    validation::validate_checkpoint_symbol(env, checkpoint)?;
+
+    // Validate hash before storage
+    validation::validate_hash(data_hash)?;
+
+    if shipment.carrier != *carrier {
+        return Err(NavinError::Unauthorized);
     }
-    if storage::has_role(env, address, &Role::Guardian)
-        && !storage::is_role_suspended(env, address, &Role::Guardian)
+
+    let config = config::get_config(env);
+
+    // Recording the departure checkpoint on a Created shipment starts transit.
+    if shipment.status == ShipmentStatus::Created
+        && config.departure_checkpoint.as_ref() == Some(checkpoint)
     {
-        return Ok(());
+        advance_to_in_transit(env, &mut shipment, carrier, data_hash)?;
     }
-    Err(NavinError::Unauthorized)
-}
 
-fn require_admin_or_operator(env: &Env, address: &Address) -> Result<(), NavinError> {
-    require_initialized(env)?;
-    if storage::get_admin(env) == *address {
-        return Ok(());
+    if shipment.status != ShipmentStatus::InTransit {
+        return Err(NavinError::InvalidStatus);
     }
-    if storage::has_role(env, address, &Role::Operator)
-        && !storage::is_role_suspended(env, address, &Role::Operator)
-    {
-        return Ok(());
+
+    // Enforce milestone event payload size guard
+    let current_milestone_count = storage::get_milestone_event_count(env, shipment_id);
+    if current_milestone_count >= config.max_milestones_per_shipment {
+        return Err(NavinError::MilestoneLimitExceeded);
     }
-    Err(NavinError::Unauthorized)
-}
 
-fn require_role(env: &Env, address: &Address, role: Role) -> Result<(), NavinError> {
-    require_initialized(env)?;
+    let timestamp = env.ledger().timestamp();
 
-    match role {
-        Role::Company => {
-            if storage::has_company_role(env, address) {
-                // Check if role is suspended via generic role suspension
-                if storage::is_role_suspended(env, address, &Role::Company) {
-                    return Err(NavinError::Unauthorized);
-                }
-                // Check if company specifically is suspended
-                if storage::is_company_suspended(env, address) {
-                    return Err(NavinError::CompanySuspended);
-                }
-                Ok(())
-            } else {
-                Err(NavinError::Unauthorized)
-            }
+    let _milestone = Milestone {
+        shipment_id,
+        checkpoint: checkpoint.clone(),
+        data_hash: data_hash.clone(),
+        timestamp,
+        reporter: carrier.clone(),
+    };
+
+    // Do NOT store the milestone on-chain
+    // Emit the milestone_recorded event (Hash-and-Emit pattern)
+    events::emit_milestone_recorded(env, shipment_id, checkpoint, data_hash, carrier);
+
+    // Check for milestone-based payments
+    let mut mut_shipment = shipment;
+    let mut found_index = None;
+    for (i, milestone) in mut_shipment.payment_milestones.iter().enumerate() {
+        if milestone.0 == *checkpoint {
+            found_index = Some(i);
+            break;
         }
-        Role::Carrier => {
-            if storage::has_carrier_role(env, address) {
-                // Check if role is suspended
-                if storage::is_role_suspended(env, address, &Role::Carrier) {
-                    return Err(NavinError::Unauthorized);
-                }
-                // Also check legacy carrier-specific suspension
-                if storage::is_carrier_suspended(env, address) {
-                    return Err(NavinError::CarrierSuspended);
-                }
-                Ok(())
-            } else {
-                Err(NavinError::Unauthorized)
+    }
+
+    let payout_pct =
+        found_index.map(|idx| mut_shipment.payment_milestones.get(idx as u32).unwrap().1);
+    let fixed_amount = match payout_pct {
+        Some(_) => None,
+        None => fixed_milestone_amount(env, shipment_id, checkpoint),
+    };
+
+    if payout_pct.is_some() || fixed_amount.is_some() {
+        let mut already_paid = false;
+        for paid_symbol in mut_shipment.paid_milestones.iter() {
+            if paid_symbol == *checkpoint {
+                already_paid = true;
+                break;
             }
         }
-        Role::Guardian => {
-            if storage::has_role(env, address, &Role::Guardian) {
-                if storage::is_role_suspended(env, address, &Role::Guardian) {
-                    return Err(NavinError::Unauthorized);
-                }
-                Ok(())
-            } else {
-                Err(NavinError::Unauthorized)
-            }
+
+        if already_paid {
+            return Err(NavinError::MilestoneAlreadyPaid);
         }
-        Role::Operator => {
-            if storage::has_role(env, address, &Role::Operator) {
-                if storage::is_role_suspended(env, address, &Role::Operator) {
-                    return Err(NavinError::Unauthorized);
-                }
-                Ok(())
-            } else {
-                Err(NavinError::Unauthorized)
+
+        // Co-signed checkpoints pay out only once the receiver has acked the arrival.
+        if storage::is_checkpoint_ack_required(env, shipment_id) {
+            if storage::get_checkpoint_ack(env, shipment_id).is_none() {
+                return Err(NavinError::CheckpointNotAcknowledged);
             }
+            storage::remove_checkpoint_ack(env, shipment_id);
         }
-        Role::Unassigned => Err(NavinError::Unauthorized),
-    }
-}
 
-fn require_active_company(env: &Env, company: &Address) -> Result<(), NavinError> {
-    if storage::is_company_suspended(env, company) {
-        return Err(NavinError::CompanySuspended);
-    }
-    // Also check generic role suspension for completeness
-    if storage::is_role_suspended(env, company, &Role::Company) {
-        return Err(NavinError::Unauthorized);
+        mut_shipment
+            .milestones_completed
+            .push_back(checkpoint.clone());
+        if !mut_shipment
+            .paid_milestones
+            .iter()
+            .any(|m| m == *checkpoint)
+        {
+            mut_shipment.paid_milestones.push_back(checkpoint.clone());
+        }
+
+        let release_amount = match payout_pct {
+            Some(pct) => milestone_payout(env, &mut_shipment, pct)?,
+            None => fixed_milestone_payout(&mut_shipment, fixed_amount.unwrap_or(0)),
+        };
+
+        events::emit_milestone_payment_released(
+            env,
+            shipment_id,
+            checkpoint,
+            release_amount,
+            &mut_shipment.carrier,
+        );
+        internal_release_escrow(env, &mut mut_shipment, release_amount)?;
     }
+
+    finalize_if_settled(env, &mut mut_shipment);
+    storage::set_shipment(env, &mut_shipment);
+
     Ok(())
 }
 
-fn require_active_carrier(env: &Env, carrier: &Address) -> Result<(), NavinError> {
-    if storage::is_carrier_suspended(env, carrier) {
-        return Err(NavinError::CarrierSuspended);
-    }
-    // Also check generic role suspension
-    if storage::is_role_suspended(env, carrier, &Role::Carrier) {
-        return Err(NavinError::Unauthorized);
+/// Run `operation` while holding the contract-wide reentrancy lock.
+///
+/// Every entry point that invokes the escrow token wraps its body in this
+/// guard, so a malicious token that calls back into the contract during a
+/// transfer is rejected with `NavinError::ReentrancyDetected` before it can
+/// observe or act on partially updated escrow state. The lock is released
+/// whether `operation` succeeds or fails.
+fn with_reentrancy_lock<T, F>(env: &Env, operation: F) -> Result<T, NavinError>
+where
+    F: FnOnce() -> Result<T, NavinError>,
+{
+    if storage::is_reentrancy_locked(env) {
+        return Err(NavinError::ReentrancyDetected);
     }
-    Ok(())
+
+    storage::set_reentrancy_lock(env, true);
+    let result = operation();
+    storage::set_reentrancy_lock(env, false);
+    result
 }
 
-/// Require that `caller` is the contract admin. Centralizes the repeated
-/// `if storage::get_admin(&env) != admin { return Err(Unauthorized) }` pattern.
-fn require_admin(env: &Env, caller: &Address) -> Result<(), NavinError> {
-    if storage::get_admin(env) != *caller {
-        return Err(NavinError::Unauthorized);
+/// Throttle repeated carrier reports of the same `kind` on a shipment.
+///
+/// The admin bypasses the limit. On success the report time is recorded, so
+/// callers must invoke this only once all other checks have passed.
+fn enforce_report_interval(
+    env: &Env,
+    caller: &Address,
+    shipment_id: u64,
+    kind: Symbol,
+) -> Result<(), NavinError> {
+    let now = env.ledger().timestamp();
+    if *caller != storage::get_admin(env) {
+        let interval = config::get_config(env).min_report_interval;
+        if let Some(last) = storage::get_last_report(env, shipment_id, &kind) {
+            if now.saturating_sub(last) < interval {
+                return Err(NavinError::RateLimitExceeded);
+            }
+        }
     }
+    storage::set_last_report(env, shipment_id, &kind, now);
     Ok(())
 }
 
-#[contract]
-pub struct NavinShipment;
+/// SHA-256 over the XDR of a shipment's creation parameters, in order:
+/// sender, receiver, carrier, data_hash, payment_milestones, deadline.
+fn compute_creation_hash(env: &Env, shipment: &Shipment) -> BytesN<32> {
+    let mut payload = soroban_sdk::Bytes::new(env);
+    payload.append(&shipment.sender.clone().to_xdr(env));
+    payload.append(&shipment.receiver.clone().to_xdr(env));
+    payload.append(&shipment.carrier.clone().to_xdr(env));
+    payload.append(&shipment.data_hash.clone().to_xdr(env));
+    payload.append(&shipment.payment_milestones.clone().to_xdr(env));
+    payload.append(&shipment.deadline.to_xdr(env));
+    env.crypto().sha256(&payload).into()
+}
 
-#[contractimpl]
-impl NavinShipment {
-    /// Set metadata key-value pair for a shipment. Only Company (sender) or Admin can set.
-    /// Max 5 metadata entries allowed.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    /// * `caller` - The address attempting to set the metadata.
-    /// * `shipment_id` - ID of the shipment.
-    /// * `key` - The metadata key (max 32 chars).
-    /// * `value` - The metadata value (max 32 chars).
-    ///
-    /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully set.
-    ///
+/// Message a carrier signs to attest a milestone off-chain: the XDR of
+/// shipment_id, checkpoint and data_hash, in that order.
+fn milestone_attestation_payload(
+    env: &Env,
+    shipment_id: u64,
+    checkpoint: &Symbol,
+    data_hash: &BytesN<32>,
+) -> soroban_sdk::Bytes {
+    let mut payload = soroban_sdk::Bytes::new(env);
+    payload.append(&shipment_id.to_xdr(env));
+    payload.append(&checkpoint.clone().to_xdr(env));
+    payload.append(&data_hash.clone().to_xdr(env));
+    payload
+}
+
+fn effective_batch_query_limit(env: &Env) -> u32 {
+    let _ = env;
+    MAX_BATCH_QUERY_SIZE
+}
+
+/// Move a shipment to `Cancelled`, refunding any held escrow to the sender.
+/// Shared by `cancel_shipment` and `cancel_shipments_batch`; callers perform
+/// authorization and status checks first and must hold the reentrancy lock.
+fn apply_cancellation(
+    env: &Env,
+    caller: &Address,
+    shipment: &mut Shipment,
+    reason_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    if shipment.escrow_amount > 0 {
+        refund_and_cancel(env, shipment, caller)?;
+    } else {
+        let old_status = shipment.status.clone();
+        shipment.status = ShipmentStatus::Cancelled;
+        shipment.updated_at = env.ledger().timestamp();
+        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+        finalize_if_settled(env, shipment);
+        persist_shipment(env, shipment)?;
+        storage::decrement_status_count(env, &old_status);
+        storage::increment_status_count(env, &ShipmentStatus::Cancelled);
+        storage::push_status_history(env, shipment.id, &ShipmentStatus::Cancelled, caller);
+
+        // Decrement active shipment count if it was not already cancelled
+        if old_status != ShipmentStatus::Cancelled {
+            storage::decrement_active_shipment_count(env, &shipment.sender);
+        }
+        extend_shipment_ttl(env, shipment.id);
+        settle_insurance_reserve(env, shipment, false)?;
+    }
+    storage::remove_escrow_balance(env, shipment.id);
+
+    events::emit_shipment_cancelled(env, shipment.id, caller, reason_hash);
+
+    Ok(())
+}
+
+/// Load a shipment that a dispute resolution could settle: it must exist, be
+/// unfinalized, be `Disputed`, and hold escrow.
+fn load_resolvable_dispute(env: &Env, shipment_id: u64) -> Result<Shipment, NavinError> {
+    let shipment = storage::get_shipment(env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+    require_not_finalized(&shipment)?;
+    if shipment.status != ShipmentStatus::Disputed {
+        return Err(NavinError::InvalidStatus);
+    }
+    if shipment.escrow_amount == 0 {
+        return Err(NavinError::InsufficientFunds);
+    }
+    Ok(shipment)
+}
+
+fn finalize_if_settled(_env: &Env, shipment: &mut Shipment) {
+    if (shipment.status == ShipmentStatus::Delivered
+        || shipment.status == ShipmentStatus::Cancelled)
+        && shipment.escrow_amount == 0
+    {
+        shipment.finalized = true;
+    }
+}
+
+/// Create a new settlement record and mark it as active for the shipment.
+fn create_settlement(
+    env: &Env,
+    shipment_id: u64,
+    operation: SettlementOperation,
+    amount: i128,
+    from: &Address,
+    to: &Address,
+) -> Result<u64, NavinError> {
+    let settlement_id = storage::increment_settlement_counter(env);
+    let settlement = SettlementRecord {
+        settlement_id,
+        shipment_id,
+        operation,
+        state: SettlementState::Pending,
+        amount,
+        from: from.clone(),
+        to: to.clone(),
+        initiated_at: env.ledger().timestamp(),
+        completed_at: None,
+        error_code: None,
+    };
+    storage::set_settlement(env, &settlement);
+    storage::set_active_settlement(env, shipment_id, settlement_id);
+    Ok(settlement_id)
+}
+
+/// Mark a settlement as completed.
+fn complete_settlement(env: &Env, settlement_id: u64, shipment_id: u64) -> Result<(), NavinError> {
+    let mut settlement =
+        storage::get_settlement(env, settlement_id).ok_or(NavinError::ShipmentNotFound)?; // Reusing error for simplicity
+    settlement.state = SettlementState::Completed;
+    settlement.completed_at = Some(env.ledger().timestamp());
+    storage::set_settlement(env, &settlement);
+    storage::clear_active_settlement(env, shipment_id);
+    Ok(())
+}
+
+/// Mark a settlement as failed with an error code.
+fn fail_settlement(
+    env: &Env,
+    settlement_id: u64,
+    shipment_id: u64,
+    error_code: u32,
+) -> Result<(), NavinError> {
+    let mut settlement =
+        storage::get_settlement(env, settlement_id).ok_or(NavinError::ShipmentNotFound)?; // Reusing error for simplicity
+    settlement.state = SettlementState::Failed;
+    settlement.completed_at = Some(env.ledger().timestamp());
+    settlement.error_code = Some(error_code);
+    storage::set_settlement(env, &settlement);
+    storage::clear_active_settlement(env, shipment_id);
+    Ok(())
+}
+
+fn require_not_finalized(shipment: &Shipment) -> Result<(), NavinError> {
+    if shipment.finalized {
+        return Err(NavinError::ShipmentFinalized);
+    }
+    Ok(())
+}
+
+/// Reject deadlines that are not at least `buffer` seconds after `now`.
+/// A zero buffer still requires the deadline to be strictly in the future.
+fn validate_deadline_buffer(now: u64, deadline: u64, buffer: u64) -> Result<(), NavinError> {
+    if deadline <= now || deadline < now.saturating_add(buffer) {
+        return Err(NavinError::InvalidTimestamp);
+    }
+    Ok(())
+}
+
+/// Centralized state machine guardrail for all shipment lifecycle transitions.
+pub(crate) fn validate_shipment_transition(
+    from: &ShipmentStatus,
+    to: &ShipmentStatus,
+) -> Result<(), NavinError> {
+    if !from.is_valid_transition(to) {
+        return Err(NavinError::InvalidStatus);
+    }
+    Ok(())
+}
+
+/// Build a 32-byte action hash from arbitrary bytes and check/set the idempotency window.
+/// Returns `DuplicateAction` if the hash is already present in temporary storage.
+fn check_idempotency(env: &Env, payload: soroban_sdk::Bytes) -> Result<(), NavinError> {
+    let action_hash: BytesN<32> = env.crypto().sha256(&payload).into();
+    if storage::has_idempotency_window(env, &action_hash) {
+        return Err(NavinError::DuplicateAction);
+    }
+    let window = config::get_config(env).idempotency_window_seconds;
+    storage::set_idempotency_window(env, &action_hash, window);
+    Ok(())
+}
+
+#[derive(Copy, Clone)]
+enum TokenOperation {
+    Transfer,
+    #[cfg(test)]
+    Mint,
+}
+
+impl TokenOperation {
+    fn symbol(self) -> Symbol {
+        match self {
+            TokenOperation::Transfer => symbol_short!("transfer"),
+            #[cfg(test)]
+            TokenOperation::Mint => symbol_short!("mint"),
+        }
+    }
+
+    fn error(self) -> NavinError {
+        match self {
+            TokenOperation::Transfer => NavinError::TokenTransferFailed,
+            #[cfg(test)]
+            TokenOperation::Mint => NavinError::TokenMintFailed,
+        }
+    }
+}
+
+/// Validates that the token contract reports the expected number of decimal places (7).
+///
+/// The Navin contract assumes all amounts are expressed in the Stellar standard
+/// unit where 1 token = 10_000_000 stroops (7 decimal places). Tokens returning
+/// a different value from `decimals()` would cause mismatched amount calculations
+/// in escrow operations, so they are rejected early.
+///
+/// # Errors
+/// Returns `NavinError::InvalidTokenDecimals` if the token returns ≠ 7 decimals,
+/// or if the call to the token contract fails (treated as an incompatible token).
+fn validate_token_decimals(env: &Env, token_contract: &Address) -> Result<(), NavinError> {
+    let args: Vec<soroban_sdk::Val> = Vec::new(env);
+    let result = env.try_invoke_contract::<u32, soroban_sdk::Error>(
+        token_contract,
+        &Symbol::new(env, "decimals"),
+        args,
+    );
+    match result {
+        Ok(Ok(decimals)) if decimals == crate::types::EXPECTED_TOKEN_DECIMALS => Ok(()),
+        _ => Err(NavinError::InvalidTokenDecimals),
+    }
+}
+
+/// Rejects escrow deposits whose USD-equivalent value, as quoted by the
+/// configured price oracle, is below `min_escrow_usd`.
+///
+/// Skipped entirely when no oracle is configured or the minimum is zero.
+///
+/// # Errors
+/// Returns `NavinError::EscrowBelowMinimumValue` if the deposit is worth too
+/// little, or `NavinError::PriceOracleUnavailable` if the oracle call fails or
+/// returns a non-positive price.
+fn validate_min_escrow_value(
+    env: &Env,
+    token_contract: &Address,
+    amount: i128,
+) -> Result<(), NavinError> {
+    let config = config::get_config(env);
+    let oracle = match config.price_oracle {
+        Some(oracle) if config.min_escrow_usd > 0 => oracle,
+        _ => return Ok(()),
+    };
+
+    let mut args: Vec<soroban_sdk::Val> = Vec::new(env);
+    args.push_back(token_contract.clone().into_val(env));
+    let price = match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        &oracle,
+        &Symbol::new(env, "price"),
+        args,
+    ) {
+        Ok(Ok(price)) if price > 0 => price,
+        _ => return Err(NavinError::PriceOracleUnavailable),
+    };
+
+    let usd_value = checked_mul_div_i128(amount, price, ORACLE_PRICE_SCALE)?;
+    if usd_value < config.min_escrow_usd {
+        return Err(NavinError::EscrowBelowMinimumValue);
+    }
+    Ok(())
+}
+
+/// Invoke a token operation, treating any non-error return as success.
+///
+/// SEP-41 tokens return unit from `transfer`, but some tokens return a value
+/// (e.g. a `Result<(), _>` or a bool). Decoding the return as a raw `Val`
+/// accepts both, while a trapped or erroring call still maps to the
+/// operation's error.
+fn invoke_token_operation(
+    env: &Env,
+    token_contract: &Address,
+    operation: TokenOperation,
+    args: Vec<soroban_sdk::Val>,
+) -> Result<(), NavinError> {
+    match env.try_invoke_contract::<soroban_sdk::Val, soroban_sdk::Error>(
+        token_contract,
+        &operation.symbol(),
+        args,
+    ) {
+        Ok(Ok(_)) => Ok(()),
+        _ => Err(operation.error()),
+    }
+}
+
+/// Locked escrow (including insurance reserves) plus held dispute bonds.
+fn outstanding_liabilities(env: &Env) -> i128 {
+    storage::get_total_escrow_locked(env).saturating_add(storage::get_total_dispute_bonds(env))
+}
+
+/// Query `owner`'s balance of `token_contract`, or `None` if the token does
+/// not expose a SEP-41 `balance` function.
+fn query_token_balance(env: &Env, token_contract: &Address, owner: &Address) -> Option<i128> {
+    let mut args: Vec<soroban_sdk::Val> = Vec::new(env);
+    args.push_back(owner.clone().into_val(env));
+    match env.try_invoke_contract::<i128, soroban_sdk::Error>(
+        token_contract,
+        &Symbol::new(env, "balance"),
+        args,
+    ) {
+        Ok(Ok(balance)) => Some(balance),
+        _ => None,
+    }
+}
+
+/// Pull `amount` of escrow from `from` into the contract for a shipment.
+///
+/// Measures the balance delta so fee-on-transfer tokens only credit what
+/// actually arrived, then takes the platform fee and the insurance reserve
+/// share. Records volume and locked-escrow totals and returns the net amount
+/// to credit to the shipment's escrow.
+fn collect_escrow_deposit(
+    env: &Env,
+    token_contract: &Address,
+    shipment_id: u64,
+    from: &Address,
+    amount: i128,
+) -> Result<i128, NavinError> {
+    // Create settlement record in Pending state
+    let contract_address = env.current_contract_address();
+    let settlement_id = create_settlement(
+        env,
+        shipment_id,
+        SettlementOperation::Deposit,
+        amount,
+        from,
+        &contract_address,
+    )?;
+
+    let balance_before = query_token_balance(env, token_contract, &contract_address);
+    if let Err(e) = invoke_token_transfer(env, token_contract, from, &contract_address, amount) {
+        fail_settlement(env, settlement_id, shipment_id, e as u32)?;
+        return Err(e);
+    }
+    complete_settlement(env, settlement_id, shipment_id)?;
+
+    let received = match (
+        balance_before,
+        query_token_balance(env, token_contract, &contract_address),
+    ) {
+        (Some(before), Some(after)) => checked_sub_i128(after, before)?,
+        _ => amount,
+    };
+    if received <= 0 {
+        return Err(NavinError::InvalidAmount);
+    }
+
+    let mut net_amount = received;
+    if let Some(fee_config) = storage::get_fee_config(env) {
+        if fee_config.fee_bps > 0 {
+            let fee_amount = checked_mul_div_i128(received, fee_config.fee_bps as i128, 10000)?;
+            if fee_amount > 0 {
+                // Transfer fee from this contract to treasury
+                invoke_token_transfer(
+                    env,
+                    token_contract,
+                    &contract_address,
+                    &fee_config.treasury,
+                    fee_amount,
+                )?;
+                net_amount = checked_sub_i128(received, fee_amount)?;
+                events::emit_platform_fee_collected(
+                    env,
+                    shipment_id,
+                    &fee_config.treasury,
+                    fee_amount,
+                );
+            }
+        }
+    }
+
+    let fee_taken = checked_sub_i128(received, net_amount)?;
+
+    // Divert the configured share into the shipment's insurance reserve.
+    let insurance_bps = config::get_config(env).escrow_policy.insurance_bps;
+    if insurance_bps > 0 {
+        let reserve = checked_mul_div_i128(net_amount, insurance_bps as i128, 10_000)?;
+        if reserve > 0 {
+            let held = storage::get_insurance_reserve(env, shipment_id);
+            storage::set_insurance_reserve(env, shipment_id, checked_add_i128(held, reserve)?);
+            net_amount = checked_sub_i128(net_amount, reserve)?;
+        }
+    }
+
+    storage::add_total_escrow_volume(env, received)?;
+    storage::record_escrow_locked(env, received);
+    if fee_taken > 0 {
+        // The platform fee leaves escrow as soon as it is deposited.
+        storage::record_escrow_released(env, fee_taken);
+    }
+
+    Ok(net_amount)
+}
+
+fn invoke_token_transfer(
+    env: &Env,
+    token_contract: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), NavinError> {
+    let cb_config = circuit_breaker::CircuitBreakerConfig::default();
+    circuit_breaker::check_transfer_allowed(env, &cb_config)?;
+
+    let mut args: soroban_sdk::Vec<soroban_sdk::Val> = Vec::new(env);
+    args.push_back(from.clone().into_val(env));
+    args.push_back(to.clone().into_val(env));
+    args.push_back(amount.into_val(env));
+
+    match invoke_token_operation(env, token_contract, TokenOperation::Transfer, args) {
+        Ok(()) => {
+            circuit_breaker::record_transfer_success(env);
+            Ok(())
+        }
+        Err(e) => {
+            circuit_breaker::record_transfer_failure(env, &cb_config);
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+fn invoke_token_mint(
+    env: &Env,
+    token_contract: &Address,
+    admin: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), NavinError> {
+    let mut args: soroban_sdk::Vec<soroban_sdk::Val> = Vec::new(env);
+    args.push_back(admin.clone().into_val(env));
+    args.push_back(to.clone().into_val(env));
+    args.push_back(amount.into_val(env));
+    invoke_token_operation(env, token_contract, TokenOperation::Mint, args)
+}
+
+fn require_initialized(env: &Env) -> Result<(), NavinError> {
+    if !storage::is_initialized(env) {
+        return Err(NavinError::NotInitialized);
+    }
+    Ok(())
+}
+
+fn require_not_paused(env: &Env) -> Result<(), NavinError> {
+    if storage::is_paused(env) {
+        return Err(NavinError::ContractPaused);
+    }
+    Ok(())
+}
+
+fn require_admin_or_guardian(env: &Env, address: &Address) -> Result<(), NavinError> {
+    require_initialized(env)?;
+    if storage::get_admin(env) == *address {
+        return Ok(());
+    }
+    if storage::has_role(env, address, &Role::Guardian)
+        && !storage::is_role_suspended(env, address, &Role::Guardian)
+    {
+        return Ok(());
+    }
+    Err(NavinError::Unauthorized)
+}
+
+/// Require that `address` may resolve disputes: the admin, an active guardian
+/// or an active arbitrator.
+fn require_dispute_resolver(env: &Env, address: &Address) -> Result<(), NavinError> {
+    if require_admin_or_guardian(env, address).is_ok() {
+        return Ok(());
+    }
+    require_role(env, address, Role::Arbitrator)
+}
+
+/// Reject departure of a shipment its carrier has not accepted yet, when
+/// `require_carrier_acceptance` is enabled.
+fn require_assignment_accepted(env: &Env, shipment_id: u64) -> Result<(), NavinError> {
+    if config::get_config(env)
+        .carrier_policy
+        .require_carrier_acceptance
+        && !storage::is_assignment_accepted(env, shipment_id)
+    {
+        return Err(NavinError::AssignmentNotAccepted);
+    }
+    Ok(())
+}
+
+/// Load a `Created` shipment on behalf of its assigned `carrier` for
+/// `accept_assignment` and `decline_assignment`.
+fn pending_assignment(
+    env: &Env,
+    carrier: &Address,
+    shipment_id: u64,
+) -> Result<Shipment, NavinError> {
+    let shipment = storage::get_shipment(env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+    if shipment.carrier != *carrier {
+        return Err(NavinError::Unauthorized);
+    }
+    require_active_carrier(env, carrier)?;
+    if shipment.status != ShipmentStatus::Created {
+        return Err(NavinError::InvalidStatus);
+    }
+    Ok(shipment)
+}
+
+/// Refund a shipment's whole escrow to its sender and cancel it, settling the
+/// transfer and recording the change the same way `refund_escrow` does.
+/// Callers must hold the reentrancy lock and have checked the status.
+fn refund_and_cancel(
+    env: &Env,
+    shipment: &mut Shipment,
+    actor: &Address,
+) -> Result<(), NavinError> {
+    let shipment_id = shipment.id;
+    let escrow_amount = shipment.escrow_amount;
+    let token_contract = storage::get_token_contract(env).ok_or(NavinError::NotInitialized)?;
+    let contract_address = env.current_contract_address();
+
+    let settlement_id = create_settlement(
+        env,
+        shipment_id,
+        SettlementOperation::Refund,
+        escrow_amount,
+        &contract_address,
+        &shipment.sender,
+    )?;
+    invoke_token_transfer(
+        env,
+        &token_contract,
+        &contract_address,
+        &shipment.sender,
+        escrow_amount,
+    )?;
+    complete_settlement(env, settlement_id, shipment_id)?;
+
+    shipment.escrow_amount = 0;
+    let old_status = shipment.status.clone();
+    shipment.status = ShipmentStatus::Cancelled;
+    shipment.updated_at = env.ledger().timestamp();
+    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+    finalize_if_settled(env, shipment);
+    persist_shipment(env, shipment)?;
+    storage::decrement_status_count(env, &old_status);
+    storage::increment_status_count(env, &ShipmentStatus::Cancelled);
+    storage::push_status_history(env, shipment_id, &ShipmentStatus::Cancelled, actor);
+
+    if old_status != ShipmentStatus::Cancelled {
+        storage::decrement_active_shipment_count(env, &shipment.sender);
+    }
+
+    extend_shipment_ttl(env, shipment_id);
+
+    storage::record_escrow_refunded(env, escrow_amount);
+    events::emit_escrow_refunded(env, shipment_id, &shipment.sender, escrow_amount);
+    settle_insurance_reserve(env, shipment, false)
+}
+
+/// Move a `Created` shipment to `InTransit` on behalf of `carrier`, recording
+/// the transition the same way `update_status` does.
+fn advance_to_in_transit(
+    env: &Env,
+    shipment: &mut Shipment,
+    carrier: &Address,
+    data_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    let old_status = shipment.status.clone();
+    validate_shipment_transition(&old_status, &ShipmentStatus::InTransit)?;
+    require_assignment_accepted(env, shipment.id)?;
+
+    shipment.status = ShipmentStatus::InTransit;
+    shipment.data_hash = data_hash.clone();
+    shipment.updated_at = env.ledger().timestamp();
+    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+    storage::decrement_status_count(env, &old_status);
+    storage::increment_status_count(env, &ShipmentStatus::InTransit);
+    storage::push_status_history(env, shipment.id, &ShipmentStatus::InTransit, carrier);
+    storage::set_last_status_update(env, shipment.id, env.ledger().timestamp());
+    storage::set_status_hash(env, shipment.id, &ShipmentStatus::InTransit, data_hash);
+
+    events::emit_status_updated(
+        env,
+        shipment.id,
+        &old_status,
+        &ShipmentStatus::InTransit,
+        data_hash,
+    );
+    Ok(())
+}
+
+/// Reject the call while a delivered shipment's escrow is still inside the
+/// post-delivery cooling-off period set by `release_delay_seconds`.
+fn require_release_delay_elapsed(env: &Env, shipment_id: u64) -> Result<(), NavinError> {
+    if let Some(release_at) = storage::get_release_at(env, shipment_id) {
+        if env.ledger().timestamp() < release_at {
+            return Err(NavinError::ReleaseDelayActive);
+        }
+    }
+    Ok(())
+}
+
+/// Notify the extra recipients `company` registered via
+/// `set_notification_recipients`, alongside the usual shipment parties.
+///
+/// At most `max_notification_recipients - 3` extra recipients are notified, so
+/// lowering the cap also bounds lists registered before the change.
+fn notify_extra_recipients(
+    env: &Env,
+    company: &Address,
+    notification_type: NotificationType,
+    shipment_id: u64,
+    data_hash: &BytesN<32>,
+) {
+    let recipients = storage::get_notification_recipients(env, company);
+    if recipients.is_empty() {
+        return;
+    }
+    let limit = config::get_config(env)
+        .max_notification_recipients
+        .saturating_sub(3);
+    for recipient in recipients.iter().take(limit as usize) {
+        events::emit_notification(
+            env,
+            &recipient,
+            notification_type.clone(),
+            shipment_id,
+            data_hash,
+        );
+    }
+}
+
+/// Whether `shipment_id` has used up its `max_disputes_per_shipment` allowance.
+fn dispute_limit_reached(env: &Env, shipment_id: u64) -> bool {
+    let max = config::get_config(env)
+        .dispute_policy
+        .max_disputes_per_shipment;
+    max > 0 && storage::get_dispute_count(env, shipment_id) >= max
+}
+
+/// Count a newly raised dispute, add its escrow to the disputed total, stamp
+/// the time it was raised for `auto_resolve_dispute`, and pause the contract
+/// if disputes within `dispute_spike_window` reach `dispute_spike_threshold`.
+fn record_dispute(env: &Env, shipment_id: u64, escrow_amount: i128) -> Result<(), NavinError> {
+    storage::increment_total_disputes(env);
+    storage::increment_dispute_count(env, shipment_id);
+    storage::add_disputed_escrow(env, escrow_amount)?;
+    storage::set_dispute_raised_at(env, shipment_id, env.ledger().timestamp());
+
+    let config = config::get_config(env);
+    if config.dispute_policy.dispute_spike_threshold == 0 || storage::is_paused(env) {
+        return Ok(());
+    }
+    let recent = storage::record_recent_dispute(
+        env,
+        env.ledger().timestamp(),
+        config.dispute_policy.dispute_spike_window,
+        config.dispute_policy.dispute_spike_threshold,
+    );
+    if recent >= config.dispute_policy.dispute_spike_threshold {
+        storage::set_paused(env, true);
+        storage::set_auto_paused(env, true);
+        events::emit_auto_paused(env, recent, config.dispute_policy.dispute_spike_window);
+    }
+    Ok(())
+}
+
+/// Reject the call while the contract holds funds it owes to anyone.
+///
+/// Used by recovery paths that swap the token contract: escrow, insurance
+/// reserves and dispute bonds held in the old token would otherwise become
+/// unreachable. The running totals only cover deposits made since they were
+/// introduced, so the contract's live token balance must also be empty when
+/// the token reports one.
+fn require_no_locked_escrow(env: &Env) -> Result<(), NavinError> {
+    if storage::is_reentrancy_locked(env)
+        || outstanding_liabilities(env) > 0
+        || storage::get_total_dispute_bonds(env) > 0
+    {
+        return Err(NavinError::EscrowLocked);
+    }
+    if let Some(token_contract) = storage::get_token_contract(env) {
+        let held = query_token_balance(env, &token_contract, &env.current_contract_address());
+        if held.is_some_and(|balance| balance > 0) {
+            return Err(NavinError::EscrowLocked);
+        }
+    }
+    Ok(())
+}
+
+fn require_admin_or_operator(env: &Env, address: &Address) -> Result<(), NavinError> {
+    require_initialized(env)?;
+    if storage::get_admin(env) == *address {
+        return Ok(());
+    }
+    if storage::has_role(env, address, &Role::Operator)
+        && !storage::is_role_suspended(env, address, &Role::Operator)
+    {
+        return Ok(());
+    }
+    Err(NavinError::Unauthorized)
+}
+
+fn require_role(env: &Env, address: &Address, role: Role) -> Result<(), NavinError> {
+    require_initialized(env)?;
+
+    match role {
+        Role::Company => {
+            if storage::has_company_role(env, address) {
+                // Check if role is suspended via generic role suspension
+                if storage::is_role_suspended(env, address, &Role::Company) {
+                    return Err(NavinError::Unauthorized);
+                }
+                // Check if company specifically is suspended
+                if storage::is_company_suspended(env, address) {
+                    return Err(NavinError::CompanySuspended);
+                }
+                Ok(())
+            } else {
+                Err(NavinError::Unauthorized)
+            }
+        }
+        Role::Carrier => {
+            if storage::has_carrier_role(env, address) {
+                // Check if role is suspended
+                if storage::is_role_suspended(env, address, &Role::Carrier) {
+                    return Err(NavinError::Unauthorized);
+                }
+                // Also check legacy carrier-specific suspension
+                if storage::is_carrier_suspended(env, address) {
+                    return Err(NavinError::CarrierSuspended);
+                }
+                Ok(())
+            } else {
+                Err(NavinError::Unauthorized)
+            }
+        }
+        Role::Guardian => {
+            if storage::has_role(env, address, &Role::Guardian) {
+                if storage::is_role_suspended(env, address, &Role::Guardian) {
+                    return Err(NavinError::Unauthorized);
+                }
+                Ok(())
+            } else {
+                Err(NavinError::Unauthorized)
+            }
+        }
+        Role::Operator => {
+            if storage::has_role(env, address, &Role::Operator) {
+                if storage::is_role_suspended(env, address, &Role::Operator) {
+                    return Err(NavinError::Unauthorized);
+                }
+                Ok(())
+            } else {
+                Err(NavinError::Unauthorized)
+            }
+        }
+        Role::Arbitrator => {
+            if storage::has_role(env, address, &Role::Arbitrator) {
+                if storage::is_role_suspended(env, address, &Role::Arbitrator) {
+                    return Err(NavinError::Unauthorized);
+                }
+                Ok(())
+            } else {
+                Err(NavinError::Unauthorized)
+            }
+        }
+        Role::Unassigned => Err(NavinError::Unauthorized),
+    }
+}
+
+/// Rejects a role change for `target` while `role_change_cooldown` has not
+/// elapsed since its previous grant or revocation.
+fn require_role_change_allowed(env: &Env, target: &Address) -> Result<(), NavinError> {
+    let cooldown = config::get_config(env).role_change_cooldown;
+    if cooldown == 0 {
+        return Ok(());
+    }
+    if let Some(changed_at) = storage::get_role_changed_at(env, target) {
+        if env.ledger().timestamp() < changed_at.saturating_add(cooldown) {
+            return Err(NavinError::RoleChangeCooldown);
+        }
+    }
+    Ok(())
+}
+
+fn require_active_company(env: &Env, company: &Address) -> Result<(), NavinError> {
+    if storage::is_company_suspended(env, company) {
+        return Err(NavinError::CompanySuspended);
+    }
+    // Also check generic role suspension for completeness
+    if storage::is_role_suspended(env, company, &Role::Company) {
+        return Err(NavinError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn require_active_carrier(env: &Env, carrier: &Address) -> Result<(), NavinError> {
+    if storage::is_carrier_suspended(env, carrier) {
+        return Err(NavinError::CarrierSuspended);
+    }
+    // Also check generic role suspension
+    if storage::is_role_suspended(env, carrier, &Role::Carrier) {
+        return Err(NavinError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Require that `caller` is the contract admin. Centralizes the repeated
+/// `if storage::get_admin(&env) != admin { return Err(Unauthorized) }` pattern.
+fn require_admin(env: &Env, caller: &Address) -> Result<(), NavinError> {
+    if storage::get_admin(env) != *caller {
+        return Err(NavinError::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Revoke `role` from `target` on behalf of the admin, emitting the same
+/// events as `revoke_role`. Shared by `remove_company` and `remove_carrier`;
+/// callers must already have checked that `admin` is the admin.
+fn remove_role_holder(
+    env: &Env,
+    admin: &Address,
+    target: &Address,
+    role: Role,
+) -> Result<(), NavinError> {
+    if !storage::has_role(env, target, &role) {
+        return Err(NavinError::Unauthorized);
+    }
+    require_role_change_allowed(env, target)?;
+
+    storage::revoke_role(env, target, &role);
+    storage::set_role_changed_at(env, target, env.ledger().timestamp());
+
+    events::emit_role_revoked(env, admin, target, &role);
+    events::emit_role_changed(env, &RoleChangeAction::Revoked, admin, target, &role);
+    Ok(())
+}
+
+/// Check that `caller` may move `shipment` to `new_status` right now,
+/// without mutating the shipment. Shared by `update_status` and
+/// `update_status_batch`; callers must have authenticated `caller`.
+fn validate_status_update(
+    env: &Env,
+    caller: &Address,
+    admin: &Address,
+    shipment: &Shipment,
+    new_status: &ShipmentStatus,
+    data_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    let shipment_id = shipment.id;
+    if *caller != shipment.carrier && caller != admin {
+        return Err(NavinError::Unauthorized);
+    }
+    require_not_finalized(shipment)?;
+    if *caller == shipment.carrier {
+        require_active_carrier(env, caller)?;
+    }
+
+    // Idempotency: reject duplicate (shipment_id, new_status, data_hash) within the window.
+    let mut payload = soroban_sdk::Bytes::new(env);
+    payload.append(&soroban_sdk::Bytes::from_array(
+        env,
+        &shipment_id.to_be_bytes(),
+    ));
+    payload.append(&new_status.clone().to_xdr(env));
+    payload.append(&data_hash.clone().into());
+    check_idempotency(env, payload)?;
+
+    // Rate-limit check: admin bypasses; all other callers must wait the minimum interval.
+    // The first update is measured from creation against `initial_update_delay`.
+    if caller != admin {
+        let now = env.ledger().timestamp();
+        let config = config::get_config(env);
+        match storage::get_last_status_update(env, shipment_id) {
+            Some(last) => {
+                if now.saturating_sub(last) < config.min_status_update_interval {
+                    return Err(NavinError::RateLimitExceeded);
+                }
+            }
+            None => {
+                if now.saturating_sub(shipment.created_at)
+                    < config.carrier_policy.initial_update_delay
+                {
+                    return Err(NavinError::RateLimitExceeded);
+                }
+            }
+        }
+    }
+
+    crate::validate_shipment_transition(&shipment.status, new_status)?;
+    if shipment.status == ShipmentStatus::Created && *new_status == ShipmentStatus::InTransit {
+        require_assignment_accepted(env, shipment_id)?;
+    }
+    if *new_status == ShipmentStatus::Disputed && dispute_limit_reached(env, shipment_id) {
+        return Err(NavinError::DisputeLimitReached);
+    }
+    Ok(())
+}
+
+/// Apply a status transition already checked by `validate_status_update`,
+/// persisting the shipment and emitting the status and notification events.
+fn apply_status_update(
+    env: &Env,
+    caller: &Address,
+    shipment: &mut Shipment,
+    new_status: &ShipmentStatus,
+    data_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    let shipment_id = shipment.id;
+    let old_status = shipment.status.clone();
+    shipment.status = new_status.clone();
+    shipment.data_hash = data_hash.clone();
+    shipment.updated_at = env.ledger().timestamp();
+    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+    storage::decrement_status_count(env, &old_status);
+    storage::increment_status_count(env, &shipment.status);
+    storage::push_status_history(env, shipment_id, &shipment.status, caller);
+
+    finalize_if_settled(env, shipment);
+    persist_shipment(env, shipment)?;
+
+    if shipment.status == ShipmentStatus::Disputed {
+        record_dispute(env, shipment_id, shipment.escrow_amount)?;
+    } else if old_status == ShipmentStatus::Disputed {
+        storage::remove_disputed_escrow(env, shipment.escrow_amount)?;
+    }
+
+    storage::set_last_status_update(env, shipment_id, env.ledger().timestamp());
+    extend_shipment_ttl(env, shipment_id);
+    track_checkpoint_dwell(env, shipment, &old_status, shipment.updated_at);
+
+    // Store the data hash for this status transition (IoT verification)
+    storage::set_status_hash(env, shipment_id, new_status, data_hash);
+
+    // A new checkpoint arrival supersedes any earlier receiver acknowledgement.
+    if *new_status == ShipmentStatus::AtCheckpoint {
+        storage::remove_checkpoint_ack(env, shipment_id);
+    }
+
+    events::emit_status_updated(env, shipment_id, &old_status, new_status, data_hash);
+    events::emit_notification(
+        env,
+        &shipment.sender,
+        NotificationType::StatusChanged,
+        shipment_id,
+        data_hash,
+    );
+    notify_extra_recipients(
+        env,
+        &shipment.sender,
+        NotificationType::StatusChanged,
+        shipment_id,
+        data_hash,
+    );
+    events::emit_notification(
+        env,
+        &shipment.receiver,
+        NotificationType::StatusChanged,
+        shipment_id,
+        data_hash,
+    );
+
+    Ok(())
+}
+
+#[contract]
+pub struct NavinShipment;
+
+#[contractimpl]
+impl NavinShipment {
+    /// Set metadata key-value pair for a shipment. Only Company (sender) or Admin can set.
+    /// Max 5 metadata entries allowed.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `caller` - The address attempting to set the metadata.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `key` - The metadata key (max 32 chars).
+    /// * `value` - The metadata value (max 32 chars).
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully set.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
+    /// * `NavinError::Unauthorized` - If the caller is not the sender or admin.
+    /// * `NavinError::MetadataLimitExceeded` - If adding would exceed the 5 key limit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.set_shipment_metadata(&env, &caller, 1, &Symbol::new(&env, "weight"), &Symbol::new(&env, "kg_100"));
+    /// ```
+    pub fn set_shipment_metadata(
+        env: Env,
+        caller: Address,
+        shipment_id: u64,
+        key: Symbol,
+        value: Symbol,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        caller.require_auth();
+
+        // Guard against empty / whitespace-only Symbol inputs before the
+        // length-and-collision validation that follows.
+        validate_symbol_not_whitespace_only(&env, &key)?;
+        validate_symbol_not_whitespace_only(&env, &value)?;
+        // Validate metadata symbols for bounded usage before storage
+        validation::validate_metadata_symbols(&env, &key, &value)?;
+
+        let admin = storage::get_admin(&env);
+        let mut shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        require_not_finalized(&shipment)?;
+        // Only sender or admin can set
+        if caller != shipment.sender && caller != admin {
+            return Err(NavinError::Unauthorized);
+        }
+        // If caller is the company (sender), check for suspension
+        if caller == shipment.sender {
+            require_active_company(&env, &caller)?;
+        }
+        // Initialize metadata map if not present
+        let mut metadata = shipment.metadata.unwrap_or(Map::new(&env));
+        // Enforce max metadata entries from config
+        let config = config::get_config(&env);
+        if !metadata.contains_key(key.clone()) && metadata.len() >= config.max_metadata_entries {
+            return Err(NavinError::MetadataLimitExceeded);
+        }
+        metadata.set(key.clone(), value.clone());
+        shipment.metadata = Some(metadata);
+        shipment.updated_at = env.ledger().timestamp();
+        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+        persist_shipment(&env, &shipment)?;
+        Ok(())
+    }
+
+    /// Append a hash-only note to a shipment for commentary.
+    /// Only the sender, receiver, assigned carrier, or admin can append notes.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `reporter` - The address appending the note.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `note_hash` - SHA-256 hash of the off-chain note text.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully appended.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
+    /// * `NavinError::Unauthorized` - If the caller is not involved in the shipment or admin.
+    pub fn append_note_hash(
+        env: Env,
+        reporter: Address,
+        shipment_id: u64,
+        note_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        reporter.require_auth();
+
+        // Validate note hash length (32 bytes) and reject malformed sentinels.
+        validation::validate_note_hash(&note_hash)?;
+
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        let admin = storage::get_admin(&env);
+
+        // Authorization: Sender, Receiver, Carrier, or Admin
+        if reporter != shipment.sender
+            && reporter != shipment.receiver
+            && reporter != shipment.carrier
+            && reporter != admin
+        {
+            return Err(NavinError::Unauthorized);
+        }
+
+        // If reporter is the company (sender), check for suspension
+        if reporter == shipment.sender {
+            require_active_company(&env, &reporter)?;
+        }
+
+        // Check note event payload size guard
+        let config = config::get_config(&env);
+        let current_note_count = storage::get_note_count(&env, shipment_id);
+        if current_note_count >= config.max_notes_per_shipment {
+            return Err(NavinError::NoteLimitExceeded);
+        }
+
+        // notes are append-only; we just increment the counter and store at the next index.
+        let index = storage::increment_note_count(&env, shipment_id);
+        storage::set_note_hash(&env, shipment_id, index, &note_hash);
+
+        // Emit the event following the Hash-and-Emit pattern.
+        events::emit_note_appended(&env, shipment_id, index, &note_hash, &reporter);
+
+        Ok(())
+    }
+
+    /// Add an evidence hash to an active shipment dispute.
+    /// Only in Disputed state. Authorization: Sender, Receiver, Carrier, or Admin.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `reporter` - The address adding the evidence.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `evidence_hash` - SHA-256 hash of the off-chain evidence.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully added.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If `evidence_hash` is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Disputed`.
+    /// * `NavinError::Unauthorized` - If caller is not a shipment party or the admin.
+    /// * `NavinError::EvidenceLimitExceeded` - If `max_evidence_per_dispute` entries already exist.
+    pub fn add_dispute_evidence_hash(
+        env: Env,
+        reporter: Address,
+        shipment_id: u64,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        reporter.require_auth();
+
+        // Validate hash before storage
+        validation::validate_hash(&evidence_hash)?;
+
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        require_not_finalized(&shipment)?;
+        let admin = storage::get_admin(&env);
+
+        // State check: Only in Disputed state
+        if shipment.status != ShipmentStatus::Disputed {
+            return Err(NavinError::InvalidStatus);
+        }
+
+        // Authorization: Sender, Receiver, Carrier, or Admin
+        if reporter != shipment.sender
+            && reporter != shipment.receiver
+            && reporter != shipment.carrier
+            && reporter != admin
+        {
+            return Err(NavinError::Unauthorized);
+        }
+
+        // If reporter is the company (sender), check for suspension
+        if reporter == shipment.sender {
+            require_active_company(&env, &reporter)?;
+        }
+
+        // Check evidence count payload size guard
+        let config = config::get_config(&env);
+        let current_evidence_count = storage::get_evidence_count(&env, shipment_id);
+        if current_evidence_count >= config.max_evidence_per_dispute {
+            return Err(NavinError::EvidenceLimitExceeded);
+        }
+
+        // Increment counter and store hash
+        let index = storage::increment_evidence_count(&env, shipment_id);
+        storage::set_evidence_hash(&env, shipment_id, index, &evidence_hash);
+
+        // Increment integration nonce
+        let mut shipment_mut = shipment;
+        shipment_mut.integration_nonce = shipment_mut.integration_nonce.saturating_add(1);
+        storage::set_shipment(&env, &shipment_mut);
+
+        // Emit event
+        events::emit_evidence_added(&env, shipment_id, index, &evidence_hash, &reporter);
+
+        Ok(())
+    }
+
+    /// Get the total number of evidence hashes for a shipment dispute.
+    pub fn get_dispute_evidence_count(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_evidence_count(&env, shipment_id))
+    }
+
+    /// Get every evidence hash submitted for a shipment dispute, oldest first.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    ///
+    /// # Returns
+    /// * `Result<Vec<BytesN<32>>, NavinError>` - Evidence hashes in submission order.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let evidence = contract.get_dispute_evidence(&env, 1);
+    /// ```
+    pub fn get_dispute_evidence(env: Env, shipment_id: u64) -> Result<Vec<BytesN<32>>, NavinError> {
+        require_initialized(&env)?;
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        let mut evidence = Vec::new(&env);
+        for index in 0..storage::get_evidence_count(&env, shipment_id) {
+            if let Some(hash) = storage::get_evidence_hash(&env, shipment_id, index) {
+                evidence.push_back(hash);
+            }
+        }
+        Ok(evidence)
+    }
+
+    /// Get a specific evidence hash for a shipment dispute by its sequence index.
+    pub fn get_dispute_evidence_hash(
+        env: Env,
+        shipment_id: u64,
+        index: u32,
+    ) -> Result<Option<BytesN<32>>, NavinError> {
+        require_initialized(&env)?;
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        let count = storage::get_evidence_count(&env, shipment_id);
+        if index >= count {
+            return Err(NavinError::EvidenceNotFound);
+        }
+        Ok(storage::get_evidence_hash(&env, shipment_id, index))
+    }
+
+    /// Get the current integration nonce for a shipment.
+    /// Nonce increments on critical transitions like status changes and escrow movements.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - The current nonce.
+    pub fn get_integration_nonce(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        Ok(shipment.integration_nonce)
+    }
+
+    /// Get the total number of notes appended to a shipment.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - Number of notes for the shipment.
+    pub fn get_note_count(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        // Verify existence or check archived
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_note_count(&env, shipment_id))
+    }
+
+    /// Get a specific note hash for a shipment by its sequence index.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `index` - The 0-based index of the note.
+    ///
+    /// # Returns
+    /// * `Result<Option<BytesN<32>>, NavinError>` - The note hash if found.
+    pub fn get_note_hash(
+        env: Env,
+        shipment_id: u64,
+        index: u32,
+    ) -> Result<Option<BytesN<32>>, NavinError> {
+        require_initialized(&env)?;
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        let count = storage::get_note_count(&env, shipment_id);
+        if index >= count {
+            return Err(NavinError::NoteNotFound);
+        }
+        Ok(storage::get_note_hash(&env, shipment_id, index))
+    }
+    /// Initialize the contract with an admin address and token contract address.
+    /// Can only be called once. Sets the admin and shipment counter to 0.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - The address designated as the administrator.
+    /// * `token_contract` - The address of the token contract used for escrow.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if initialized.
+    ///
+    /// # Errors
+    /// * `NavinError::AlreadyInitialized` - If called when already initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use soroban_sdk::{Env, Address};
+    /// # use soroban_sdk::testutils::Address as _;
+    /// # use shipment::{NavinShipment, NavinShipmentClient};
+    /// # let env = Env::default();
+    /// # env.mock_all_auths();
+    /// # let contract_id = env.register(NavinShipment, ());
+    /// # let client = NavinShipmentClient::new(&env, &contract_id);
+    /// let admin = Address::generate(&env);
+    /// let token_contract = Address::generate(&env); // replace with deployed token address
+    ///
+    /// client.initialize(&admin, &token_contract);
+    /// ```
+    pub fn initialize(env: Env, admin: Address, token_contract: Address) -> Result<(), NavinError> {
+        if storage::is_initialized(&env) {
+            return Err(NavinError::AlreadyInitialized);
+        }
+
+        // Reject obviously invalid token addresses: the token contract must not be
+        // the admin account or the shipment contract itself.
+        if token_contract == admin || token_contract == env.current_contract_address() {
+            return Err(NavinError::InvalidTokenAddress);
+        }
+
+        storage::set_admin(&env, &admin);
+        storage::set_token_contract(&env, &token_contract);
+        storage::set_shipment_counter(&env, 0);
+        storage::set_version(&env, 1);
+        storage::set_company_role(&env, &admin);
+
+        // Initialize with default configuration
+        let default_config = ContractConfig::default();
+        config::set_config(&env, &default_config).map_err(|_| NavinError::InvalidConfig)?;
+        storage::set_shipment_limit(&env, default_config.default_shipment_limit);
+
+        events::emit_contract_initialized(&env, &admin, &token_contract);
+
+        // Extend contract instance TTL to prevent premature archival
+        let config = config::get_config(&env);
+        env.storage()
+            .instance()
+            .extend_ttl(config.shipment_ttl_threshold, config.shipment_ttl_extension);
+
+        Ok(())
+    }
+
+    /// Propose re-running initialization for a controlled migration or recovery.
+    ///
+    /// Only the designated safe fields are reset: the token contract address and
+    /// the contract configuration (back to defaults). Shipments, roles, counters
+    /// and the admin are left untouched. The reset is gated behind the multisig:
+    /// this call opens an `AdminAction::Reinitialize` proposal that executes once
+    /// the approval threshold is met.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Admin in the multisig admin list proposing the reset.
+    /// * `token_contract` - Token contract address to install.
+    ///
+    /// # Returns
+    /// * `Result<u64, NavinError>` - The ID of the created proposal.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::NotAnAdmin` - If caller is not in the multisig admin list.
+    /// * `NavinError::InvalidTokenAddress` - If the token address is the admin or this contract.
+    /// * `NavinError::EscrowLocked` - If the contract still holds escrow, dispute bonds
+    ///   or any balance of the current token.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let proposal_id = contract.reinitialize(&env, &admin, &new_token);
+    /// // contract.approve_action(&env, &other_admin, &proposal_id);
+    /// ```
+    pub fn reinitialize(
+        env: Env,
+        admin: Address,
+        token_contract: Address,
+    ) -> Result<u64, NavinError> {
+        require_initialized(&env)?;
+
+        if token_contract == admin || token_contract == env.current_contract_address() {
+            return Err(NavinError::InvalidTokenAddress);
+        }
+        require_no_locked_escrow(&env)?;
+
+        Self::propose_action(
+            env,
+            admin,
+            crate::types::AdminAction::Reinitialize(token_contract),
+        )
+    }
+
+    /// Set the configurable limit on the number of active shipments a company can have.
+    /// Only the admin can call this.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin address.
+    /// * `limit` - The new active shipment limit.
+    pub fn set_shipment_limit(env: Env, admin: Address, limit: u32) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        storage::set_shipment_limit(&env, limit);
+
+        events::emit_shipment_limit_updated(&env, &admin, limit);
+
+        Ok(())
+    }
+
+    /// Get the current shipment limit.
+    pub fn get_shipment_limit(env: Env) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_shipment_limit(&env))
+    }
+
+    /// Set a company-specific active shipment limit override.
+    pub fn set_company_shipment_limit(
+        env: Env,
+        admin: Address,
+        company: Address,
+        limit: u32,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        storage::set_company_shipment_limit(&env, &company, limit);
+        events::emit_company_limit_updated(&env, &admin, &company, limit);
+        Ok(())
+    }
+
+    /// Get effective shipment limit for a company (override or global fallback).
+    pub fn get_effective_shipment_limit(env: Env, company: Address) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_effective_shipment_limit(&env, &company))
+    }
+
+    /// Get the current active shipment count for a company.
+    pub fn get_active_shipment_count(env: Env, company: Address) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_active_shipment_count(&env, &company))
+    }
+
+    /// Recompute a company's active shipment count from its shipment index.
+    ///
+    /// Counts every indexed shipment that is not `Delivered` or `Cancelled`,
+    /// stores the result and emits a `count_corrected` event. Only shipments
+    /// created while `index_company_shipments` was enabled are indexed.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin address.
+    /// * `company` - Company whose counter should be recalculated.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - The corrected active shipment count.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not the admin.
+    /// * `NavinError::CompanyIndexDisabled` - If the per-company index is disabled.
+    pub fn recalc_active_count(
+        env: Env,
+        admin: Address,
+        company: Address,
+    ) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if !config::get_config(&env).index_company_shipments {
+            return Err(NavinError::CompanyIndexDisabled);
+        }
+
+        let mut active: u32 = 0;
+        for shipment_id in storage::get_company_shipments(&env, &company).iter() {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                // Shipments transferred away stay in the old owner's index.
+                if shipment.sender != company {
+                    continue;
+                }
+                if !matches!(
+                    shipment.status,
+                    ShipmentStatus::Delivered | ShipmentStatus::Cancelled
+                ) {
+                    active = active.saturating_add(1);
+                }
+            }
+        }
+
+        let old_count = storage::get_active_shipment_count(&env, &company);
+        storage::set_active_shipment_count(&env, &company, active);
+        events::emit_count_corrected(&env, &company, old_count, active);
+        Ok(active)
+    }
+
+    /// Check whether the contract has been initialized.
+    ///
+    /// Unlike the other getters this never returns `NotInitialized`, so
+    /// clients can probe deployment state without handling an error.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `bool` - `true` once `initialize` has completed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let ready = contract.is_initialized(&env);
+    /// ```
+    pub fn is_initialized(env: Env) -> bool {
+        storage::is_initialized(&env)
+    }
+
+    /// List every contract error code with its symbolic name, so clients can
+    /// translate raw error codes without a hand-maintained table.
+    ///
+    /// Built from `errors::ERROR_CATALOG`; a code shared by several variants
+    /// maps to the last one declared.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Map<u32, Symbol>` - Error code to variant name.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let catalog = contract.error_catalog(&env);
+    /// ```
+    pub fn error_catalog(env: Env) -> Map<u32, Symbol> {
+        let mut catalog = Map::new(&env);
+        for (error, name) in errors::ERROR_CATALOG.iter() {
+            catalog.set(*error as u32, Symbol::new(&env, name));
+        }
+        catalog
+    }
+
+    /// Get the contract admin address.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<Address, NavinError>` - The current admin address.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let admin = contract.get_admin(&env);
+    /// ```
+    pub fn get_admin(env: Env) -> Result<Address, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_admin(&env))
+    }
+
+    /// Get the contract version number.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - The version number of the contract.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let version = contract.get_version(&env);
+    /// ```
+    pub fn get_version(env: Env) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_version(&env))
+    }
+
+    /// Get the current hash algorithm version used for data verification.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - The hash algorithm version constant.
+    pub fn get_hash_algo_version(env: Env) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(DEFAULT_HASH_ALGO)
+    }
+
+    /// Get the token decimals policy expected by escrow math normalization.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - Expected token decimals (7).
+    pub fn get_expected_token_decimals(env: Env) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(crate::types::EXPECTED_TOKEN_DECIMALS)
+    }
+
+    /// Get on-chain metadata for this contract.
+    /// Returns version, admin, shipment count, and initialization status.
+    /// Read-only — no authentication required.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<ContractMetadata, NavinError>` - Snapshot of contract metadata.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let metadata = contract.get_contract_metadata(&env);
+    /// ```
+    pub fn get_contract_metadata(env: Env) -> Result<ContractMetadata, NavinError> {
+        require_initialized(&env)?;
+        Ok(ContractMetadata {
+            version: storage::get_version(&env),
+            admin: storage::get_admin(&env),
+            shipment_count: storage::get_shipment_counter(&env),
+            initialized: true,
+            hash_algo_version: DEFAULT_HASH_ALGO,
+        })
+    }
+
+    /// Get an address's relationship to a shipment.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `address` - Address to classify, typically the connected wallet.
+    ///
+    /// # Returns
+    /// * `Result<Relationship, NavinError>` - Sender, Receiver, Carrier, Admin, or None.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let relationship = contract.get_relationship(&env, &1, &wallet);
+    /// ```
+    pub fn get_relationship(
+        env: Env,
+        shipment_id: u64,
+        address: Address,
+    ) -> Result<Relationship, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        let relationship = if address == shipment.sender {
+            Relationship::Sender
+        } else if address == shipment.receiver {
+            Relationship::Receiver
+        } else if address == shipment.carrier {
+            Relationship::Carrier
+        } else if address == storage::get_admin(&env) {
+            Relationship::Admin
+        } else {
+            Relationship::None
+        };
+        Ok(relationship)
+    }
+
+    /// Check whether a milestone checkpoint has already been paid out.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `checkpoint` - Milestone checkpoint symbol.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - True if the checkpoint is in `paid_milestones`.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let paid = contract.is_milestone_paid(&env, &1, &symbol_short!("pickup"));
+    /// ```
+    pub fn is_milestone_paid(
+        env: Env,
+        shipment_id: u64,
+        checkpoint: Symbol,
+    ) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        Ok(shipment.paid_milestones.iter().any(|m| m == checkpoint))
+    }
+
+    /// Get the current shipment counter.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<u64, NavinError>` - The total number of shipments created.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let count = contract.get_shipment_counter(&env);
+    /// ```
+    pub fn get_shipment_counter(env: Env) -> Result<u64, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_shipment_counter(&env))
+    }
+
+    /// Get the ID the next created shipment will receive, without reserving it.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<u64, NavinError>` - The shipment counter plus one.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::CounterOverflow` - If the shipment counter is exhausted.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let next_id = contract.peek_next_shipment_id(&env);
+    /// ```
+    pub fn peek_next_shipment_id(env: Env) -> Result<u64, NavinError> {
+        require_initialized(&env)?;
+        storage::get_shipment_counter(&env)
+            .checked_add(1)
+            .ok_or(NavinError::CounterOverflow)
+    }
+
+    /// Get aggregated analytics for the contract.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<Analytics, NavinError>` - Aggregated analytics data.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn get_analytics(env: Env) -> Result<Analytics, NavinError> {
+        require_initialized(&env)?;
+
+        Ok(Analytics {
+            total_shipments: storage::get_shipment_counter(&env),
+            total_escrow_volume: storage::get_total_escrow_volume(&env),
+            total_disputes: storage::get_total_disputes(&env),
+            created_count: storage::get_status_count(&env, &ShipmentStatus::Created),
+            in_transit_count: storage::get_status_count(&env, &ShipmentStatus::InTransit),
+            at_checkpoint_count: storage::get_status_count(&env, &ShipmentStatus::AtCheckpoint),
+            delivered_count: storage::get_status_count(&env, &ShipmentStatus::Delivered),
+            disputed_count: storage::get_status_count(&env, &ShipmentStatus::Disputed),
+            cancelled_count: storage::get_status_count(&env, &ShipmentStatus::Cancelled),
+        })
+    }
+
+    /// Get the global escrow breakdown for treasury reconciliation.
+    ///
+    /// Platform fees and swept dust count as released. For every deposit
+    /// tracked since the breakdown was introduced,
+    /// `locked + released + refunded == total`.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<(i128, i128, i128, i128), NavinError>` - `(locked, released, refunded, total)`.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn get_escrow_breakdown(env: Env) -> Result<(i128, i128, i128, i128), NavinError> {
+        require_initialized(&env)?;
+        Ok((
+            storage::get_total_escrow_locked(&env),
+            storage::get_total_escrow_released(&env),
+            storage::get_total_escrow_refunded(&env),
+            storage::get_total_escrow_volume(&env),
+        ))
+    }
+
+    /// Get the total escrow currently held by shipments in `Disputed` status.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<i128, NavinError>` - Escrow at risk in open disputes.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let at_risk = contract.get_disputed_escrow_total(&env);
+    /// ```
+    pub fn get_disputed_escrow_total(env: Env) -> Result<i128, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_disputed_escrow_total(&env))
+    }
+
+    /// Get the total the contract owes out: locked escrow plus held dispute bonds.
+    ///
+    /// Insurance reserves are carved out of deposited escrow and stay in the
+    /// locked total until claimed, so they are already included.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<i128, NavinError>` - Outstanding liabilities in token units.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let owed = contract.get_outstanding_liabilities(&env);
+    /// ```
+    pub fn get_outstanding_liabilities(env: Env) -> Result<i128, NavinError> {
+        require_initialized(&env)?;
+        Ok(outstanding_liabilities(&env))
+    }
+
+    /// Check whether the contract's token balance covers its outstanding liabilities.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - `true` if the live balance is at least
+    ///   `get_outstanding_liabilities`; `false` otherwise, or if the token does
+    ///   not report balances.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let solvent = contract.is_solvent(&env);
+    /// ```
+    pub fn is_solvent(env: Env) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+        let token_contract = storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+        let balance = query_token_balance(&env, &token_contract, &env.current_contract_address());
+        Ok(balance.is_some_and(|held| held >= outstanding_liabilities(&env)))
+    }
+
+    /// Retrieve a compact summary of shipment counts aggregated by status.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<ShipmentStatusSummary, NavinError>` - Summary of counts for all statuses.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn get_status_summary(env: Env) -> Result<ShipmentStatusSummary, NavinError> {
+        require_initialized(&env)?;
+        Ok(ShipmentStatusSummary {
+            created: storage::get_status_count(&env, &ShipmentStatus::Created),
+            in_transit: storage::get_status_count(&env, &ShipmentStatus::InTransit),
+            at_checkpoint: storage::get_status_count(&env, &ShipmentStatus::AtCheckpoint),
+            partially_delivered: storage::get_status_count(
+                &env,
+                &ShipmentStatus::PartiallyDelivered,
+            ),
+            delivered: storage::get_status_count(&env, &ShipmentStatus::Delivered),
+            disputed: storage::get_status_count(&env, &ShipmentStatus::Disputed),
+            cancelled: storage::get_status_count(&env, &ShipmentStatus::Cancelled),
+        })
+    }
+
+    /// Retrieve the total number of non-terminal shipments currently tracked.
+    ///
+    /// Non-terminal shipments are those in one of the following states:
+    /// 'Created', 'InTransit', 'AtCheckpoint', or 'PartiallyDelivered'.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<u64, NavinError>` - Total count of active (non-terminal) shipments.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn get_non_terminal_count(env: Env) -> Result<u64, NavinError> {
+        require_initialized(&env)?;
+        let count = storage::get_status_count(&env, &ShipmentStatus::Created)
+            + storage::get_status_count(&env, &ShipmentStatus::InTransit)
+            + storage::get_status_count(&env, &ShipmentStatus::AtCheckpoint)
+            + storage::get_status_count(&env, &ShipmentStatus::PartiallyDelivered)
+            + storage::get_status_count(&env, &ShipmentStatus::Disputed);
+        Ok(count)
+    }
+
+    /// Get the deterministic SHA-256 checksum of critical config fields.
+    ///
+    /// This query exposes the config checksum to help indexers and operators
+    /// detect unintended configuration drift. The checksum is computed from
+    /// all config fields serialized in a fixed order and is automatically
+    /// updated whenever the config changes.
+    ///
+    /// # Serialization Order
+    /// Fields are serialized in declaration order:
+    /// 1. shipment_ttl_threshold (u32)
+    /// 2. shipment_ttl_extension (u32)
+    /// 3. min_status_update_interval (u64)
+    /// 4. batch_operation_limit (u32)
+    /// 5. max_metadata_entries (u32)
+    /// 6. default_shipment_limit (u32)
+    /// 7. multisig_min_admins (u32)
+    /// 8. multisig_max_admins (u32)
+    /// 9. proposal_expiry_seconds (u64)
+    /// 10. deadline_grace_seconds (u64)
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<BytesN<32>, NavinError>` - The SHA-256 checksum of the config.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let checksum = contract.get_config_checksum(&env)?;
+    /// // Indexer can verify: checksum == sha256(serialized_config)
+    /// ```
+    pub fn get_config_checksum(env: Env) -> Result<BytesN<32>, NavinError> {
+        require_initialized(&env)?;
+
+        // Retrieve stored checksum, or compute it if not yet stored
+        match config::get_config_checksum(&env) {
+            Some(checksum) => Ok(checksum),
+            None => {
+                // Fallback: compute checksum from current config
+                let current_config = config::get_config(&env);
+                Ok(config::compute_config_checksum(&current_config, &env))
+            }
+        }
+    }
+
+    /// Compute the idempotency key for a shipment event.
+    ///
+    /// This helper enables off-chain indexers to recompute the same idempotency
+    /// key that the contract emits in events. The key is used to deduplicate
+    /// events during indexing and to protect against duplicate submissions of
+    /// high-impact operations (e.g., dispute resolution).
+    ///
+    /// Canonical serialization order:
+    /// 1. `shipment_id` as big-endian u64 (8 bytes)
+    /// 2. `event_type` as XDR-encoded Symbol (variable-length)
+    /// 3. `event_counter` as big-endian u32 (4 bytes)
+    ///
+    /// The concatenated byte vector is hashed with SHA-256 to produce a
+    /// 32-byte idempotency key.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - The shipment identifier.
+    /// * `event_type` - The event type symbol (e.g., "shipment_created").
+    /// * `event_counter` - The per-shipment event counter value.
+    ///
+    /// # Returns
+    /// * `BytesN<32>` - The idempotency key.
+    ///
+    /// # Examples
+    /// ```rust
+    /// let key = contract.compute_idempotency_key(&env, 1, Symbol::new(&env, "shipment_created"), 1);
+    /// ```
+    pub fn compute_idempotency_key(
+        env: Env,
+        shipment_id: u64,
+        event_type: Symbol,
+        event_counter: u32,
+    ) -> BytesN<32> {
+        use soroban_sdk::Bytes;
+
+        let mut payload = Bytes::new(&env);
+
+        // Domain prefix (HASH_DOMAIN_SHIPMENT = 0x01)
+        let domain = crate::event_topics::HASH_DOMAIN_SHIPMENT;
+        let domain_bytes = domain.to_be_bytes();
+        let domain_len = (domain_bytes.len() as u32).to_be_bytes();
+        payload.append(&Bytes::from_array(&env, &domain_len));
+        payload.append(&Bytes::from_slice(&env, &domain_bytes));
+
+        // Shipment ID (raw bytes)
+        payload.append(&Bytes::from_array(&env, &shipment_id.to_be_bytes()));
+
+        // Event type: use the same XDR encoding as generate_idempotency_key
+        // (as_bytes() of the &str produces the same result as XDR for symbol strings)
+        payload.append(&event_type.clone().to_xdr(&env));
+
+        // Event counter (raw bytes)
+        payload.append(&Bytes::from_array(&env, &event_counter.to_be_bytes()));
+
+        env.crypto().sha256(&payload).into()
+    }
+
+    /// Add a carrier to a company's whitelist.
+    /// Only the company can add carriers to their own whitelist.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `company` - The company's address acting as caller.
+    /// * `carrier` - The carrier address to whitelist.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully registered.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::CarrierAlreadyWhitelisted` - If the carrier is already whitelisted.
+    /// * `NavinError::WhitelistFull` - If the whitelist holds `max_whitelist_size` carriers.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.add_carrier_to_whitelist(&env, &company, &carrier);
+    /// ```
+    pub fn add_carrier_to_whitelist(
+        env: Env,
+        company: Address,
+        carrier: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        company.require_auth();
+        require_role(&env, &company, Role::Company)?;
+
+        // Issue #539 — reject duplicate whitelist additions. Without this
+        // check the storage write is silently idempotent and emits a
+        // spurious `add_wl` event, making it impossible for off-chain
+        // monitors to distinguish a re-add from a first-time add.
+        if storage::is_carrier_whitelisted(&env, &company, &carrier) {
+            return Err(NavinError::CarrierAlreadyWhitelisted);
+        }
+
+        if storage::get_whitelist_size(&env, &company)
+            >= config::get_config(&env).max_whitelist_size
+        {
+            return Err(NavinError::WhitelistFull);
+        }
+
+        storage::add_carrier_to_whitelist(&env, &company, &carrier);
+
+        env.events().publish(
+            (symbol_short!("add_wl"),),
+            (company.clone(), carrier.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Remove a carrier from a company's whitelist.
+    /// Only the company can remove carriers from their own whitelist.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `company` - The company address removing the carrier.
+    /// * `carrier` - The carrier address to be removed.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully removed.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.remove_carrier_from_whitelist(&env, &company, &carrier);
+    /// ```
+    pub fn remove_carrier_from_whitelist(
+        env: Env,
+        company: Address,
+        carrier: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        company.require_auth();
+        require_role(&env, &company, Role::Company)?;
+
+        storage::remove_carrier_from_whitelist(&env, &company, &carrier);
+
+        env.events().publish(
+            (symbol_short!("rm_wl"),),
+            (company.clone(), carrier.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Check if a carrier is whitelisted for a company.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `company` - The company address.
+    /// * `carrier` - The carrier address in question.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - True if the carrier is whitelisted.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let is_whitelisted = contract.is_carrier_whitelisted(&env, &company, &carrier);
+    /// ```
+    pub fn is_carrier_whitelisted(
+        env: Env,
+        company: Address,
+        carrier: Address,
+    ) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+
+        Ok(storage::is_carrier_whitelisted(&env, &company, &carrier))
+    }
+
+    /// Register a receiver address.
+    /// Only meaningful when `ContractConfig.require_registered_receiver` is set.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Admin or operator registering the receiver.
+    /// * `receiver` - The receiver address to register.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful registration.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by a non-admin.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.add_receiver(&env, &admin, &receiver);
+    /// ```
+    pub fn add_receiver(env: Env, admin: Address, receiver: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+        require_admin_or_operator(&env, &admin)?;
+
+        storage::add_receiver(&env, &receiver);
+
+        env.events().publish(
+            (symbol_short!("add_rcv"),),
+            (admin.clone(), receiver.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Check if a receiver address is registered.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `receiver` - The receiver address in question.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - True if the receiver is registered.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let registered = contract.is_receiver(&env, &receiver);
+    /// ```
+    pub fn is_receiver(env: Env, receiver: Address) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+
+        Ok(storage::is_receiver(&env, &receiver))
+    }
+
+    /// Approve a carrier to deliver to the calling receiver.
+    /// Only registered receivers can approve carriers; the approval is only
+    /// enforced when `ContractConfig.require_receiver_approval` is set.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `receiver` - The registered receiver approving the carrier.
+    /// * `carrier` - The carrier address to approve.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully added.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ReceiverNotRegistered` - If the caller is not a registered receiver.
+    /// * `NavinError::CarrierAlreadyWhitelisted` - If the carrier is already approved.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.receiver_whitelist_carrier(&env, &receiver, &carrier);
+    /// ```
+    pub fn receiver_whitelist_carrier(
+        env: Env,
+        receiver: Address,
+        carrier: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        receiver.require_auth();
+        if !storage::is_receiver(&env, &receiver) {
+            return Err(NavinError::ReceiverNotRegistered);
+        }
+
+        if storage::is_receiver_whitelisted(&env, &receiver, &carrier) {
+            return Err(NavinError::CarrierAlreadyWhitelisted);
+        }
+
+        storage::add_receiver_whitelisted_carrier(&env, &receiver, &carrier);
+
+        env.events().publish(
+            (symbol_short!("rcv_wl"),),
+            (receiver.clone(), carrier.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw a receiver's approval of a carrier.
+    /// Only the registered receiver can remove carriers from their own whitelist.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `receiver` - The registered receiver removing the carrier.
+    /// * `carrier` - The carrier address to remove.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully removed.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ReceiverNotRegistered` - If the caller is not a registered receiver.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.receiver_unwhitelist_carrier(&env, &receiver, &carrier);
+    /// ```
+    pub fn receiver_unwhitelist_carrier(
+        env: Env,
+        receiver: Address,
+        carrier: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        receiver.require_auth();
+        if !storage::is_receiver(&env, &receiver) {
+            return Err(NavinError::ReceiverNotRegistered);
+        }
+
+        storage::remove_receiver_whitelisted_carrier(&env, &receiver, &carrier);
+
+        env.events().publish(
+            (symbol_short!("rcv_rm_wl"),),
+            (receiver.clone(), carrier.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Check if a carrier has been approved by a receiver.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `receiver` - The receiver address.
+    /// * `carrier` - The carrier address in question.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - True if the receiver approved the carrier.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let approved = contract.is_receiver_whitelisted(&env, &receiver, &carrier);
+    /// ```
+    pub fn is_receiver_whitelisted(
+        env: Env,
+        receiver: Address,
+        carrier: Address,
+    ) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+
+        Ok(storage::is_receiver_whitelisted(&env, &receiver, &carrier))
+    }
+
+    /// Returns the role assigned to a given address.
+    /// Returns Role::Unassigned if no role is assigned.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `address` - The address to check.
+    ///
+    /// # Returns
+    /// * `Result<Role, NavinError>` - The role assigned to the address.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let role = contract.get_role(&env, &address);
+    /// ```
+    pub fn get_role(env: Env, address: Address) -> Result<Role, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_role(&env, &address).unwrap_or(Role::Unassigned))
+    }
+
+    /// Returns how many addresses currently hold the Company and Carrier roles.
+    /// Counters move on every grant and revocation; suspension does not affect them.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<(u64, u64), NavinError>` - `(companies, carriers)`.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let (companies, carriers) = contract.get_role_counts(&env);
+    /// ```
+    pub fn get_role_counts(env: Env) -> Result<(u64, u64), NavinError> {
+        require_initialized(&env)?;
+        Ok((
+            storage::get_role_count(&env, &Role::Company),
+            storage::get_role_count(&env, &Role::Carrier),
+        ))
+    }
+
+    /// Allow admin to grant Company role.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the role grant.
+    /// * `company` - The address receiving the company role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by a non-admin.
+    /// * `NavinError::RoleChangeCooldown` - If the address's role changed within the cooldown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.add_company(&env, &admin, &new_company_addr);
+    /// ```
+    pub fn add_company(env: Env, admin: Address, company: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        require_admin_or_operator(&env, &admin)?;
+
+        if storage::has_role(&env, &company, &Role::Company) {
+            return Err(NavinError::RoleAlreadyAssigned);
+        }
+        require_role_change_allowed(&env, &company)?;
+
+        storage::set_company_role(&env, &company);
+        storage::set_role_changed_at(&env, &company, env.ledger().timestamp());
+
+        // Emit role history event
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Assigned,
+            &admin,
+            &company,
+            &Role::Company,
+        );
+
+        Ok(())
+    }
+
+    /// Allow admin to grant Carrier role.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the role grant.
+    /// * `carrier` - The address receiving the carrier role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by a non-admin.
+    /// * `NavinError::RoleChangeCooldown` - If the address's role changed within the cooldown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.add_carrier(&env, &admin, &new_carrier_addr);
+    /// ```
+    pub fn add_carrier(env: Env, admin: Address, carrier: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        require_admin_or_operator(&env, &admin)?;
+
+        if storage::has_role(&env, &carrier, &Role::Carrier) {
+            return Err(NavinError::RoleAlreadyAssigned);
+        }
+        require_role_change_allowed(&env, &carrier)?;
+
+        storage::set_carrier_role(&env, &carrier);
+        storage::set_role_changed_at(&env, &carrier, env.ledger().timestamp());
+
+        // Emit role history event
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Assigned,
+            &admin,
+            &carrier,
+            &Role::Carrier,
+        );
+
+        Ok(())
+    }
+
+    /// Allow admin to grant Guardian role.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the role grant.
+    /// * `guardian` - The address receiving the guardian role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    pub fn add_guardian(env: Env, admin: Address, guardian: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        storage::set_role(&env, &guardian, &Role::Guardian);
+
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Assigned,
+            &admin,
+            &guardian,
+            &Role::Guardian,
+        );
+
+        Ok(())
+    }
+
+    /// Allow admin to grant Operator role.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the role grant.
+    /// * `operator` - The address receiving the operator role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    pub fn add_operator(env: Env, admin: Address, operator: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        storage::set_role(&env, &operator, &Role::Operator);
+
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Assigned,
+            &admin,
+            &operator,
+            &Role::Operator,
+        );
+
+        Ok(())
+    }
+
+    /// Allow admin to grant the Arbitrator role. Arbitrators can resolve
+    /// disputed shipments but hold no other privileges.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the role grant.
+    /// * `arbitrator` - The address receiving the arbitrator role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not the admin.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.add_arbitrator(&env, &admin, &arbitrator);
+    /// ```
+    pub fn add_arbitrator(env: Env, admin: Address, arbitrator: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        storage::set_role(&env, &arbitrator, &Role::Arbitrator);
+
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Assigned,
+            &admin,
+            &arbitrator,
+            &Role::Arbitrator,
+        );
+
+        Ok(())
+    }
+
+    /// Revoke the Arbitrator role. Only the admin can call this.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the removal.
+    /// * `arbitrator` - Arbitrator losing its role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful removal.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not the admin or `arbitrator` holds no Arbitrator role.
+    /// * `NavinError::RoleChangeCooldown` - If the address's role changed within the cooldown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.remove_arbitrator(&env, &admin, &arbitrator);
+    /// ```
+    pub fn remove_arbitrator(
+        env: Env,
+        admin: Address,
+        arbitrator: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        remove_role_holder(&env, &admin, &arbitrator, Role::Arbitrator)
+    }
+
+    /// Suspend a carrier from carrier-only operations.
+    ///
+    /// Only the admin can call this function.
+    pub fn suspend_carrier(env: Env, admin: Address, carrier: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        require_admin_or_operator(&env, &admin)?;
+
+        storage::suspend_carrier(&env, &carrier);
+        events::emit_carrier_suspended(&env, &admin, &carrier);
+        Ok(())
+    }
+
+    /// Reactivate a previously suspended carrier.
+    ///
+    /// Only the admin can call this function.
+    pub fn reactivate_carrier(
+        env: Env,
+        admin: Address,
+        carrier: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        require_admin_or_operator(&env, &admin)?;
+
+        storage::reactivate_carrier(&env, &carrier);
+        events::emit_carrier_reactivated(&env, &admin, &carrier);
+        Ok(())
+    }
+
+    /// Return whether a carrier is currently suspended.
+    pub fn is_carrier_suspended(env: Env, carrier: Address) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::is_carrier_suspended(&env, &carrier))
+    }
+
+    /// Revoke a previously assigned role from an address.
+    ///
+    /// Only the admin can revoke roles. The admin cannot revoke their own role;
+    /// use `transfer_admin` instead.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the revocation.
+    /// * `target` - The address whose role is being revoked.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful role revocation.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by a non-admin.
+    /// * `NavinError::CannotSelfRevoke` - If admin tries to revoke their own role.
+    /// * `NavinError::RoleChangeCooldown` - If the address's role changed within the cooldown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.revoke_role(&env, &admin, &target_addr);
+    /// ```
+    pub fn revoke_role(env: Env, admin: Address, target: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        if admin == target {
+            return Err(NavinError::CannotSelfRevoke);
+        }
+        require_role_change_allowed(&env, &target)?;
+
+        let current_role = storage::get_role(&env, &target).unwrap_or(Role::Unassigned);
+
+        match current_role {
+            Role::Company => storage::revoke_role(&env, &target, &Role::Company),
+            Role::Carrier => storage::revoke_role(&env, &target, &Role::Carrier),
+            Role::Guardian => storage::revoke_role(&env, &target, &Role::Guardian),
+            Role::Operator => storage::revoke_role(&env, &target, &Role::Operator),
+            Role::Arbitrator => storage::revoke_role(&env, &target, &Role::Arbitrator),
+            Role::Unassigned => {}
+        }
+        storage::set_role_changed_at(&env, &target, env.ledger().timestamp());
+
+        events::emit_role_revoked(&env, &admin, &target, &current_role);
+
+        // Emit role history event for audit trail
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Revoked,
+            &admin,
+            &target,
+            &current_role,
+        );
+
+        Ok(())
+    }
+
+    /// Revoke the Company role from an offboarded or compromised company.
+    /// Only the admin can call this.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the removal.
+    /// * `company` - Company losing its role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful removal.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not the admin or `company` holds no Company role.
+    /// * `NavinError::HasActiveShipments` - If the company still has active shipments.
+    /// * `NavinError::RoleChangeCooldown` - If the address's role changed within the cooldown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.remove_company(&env, &admin, &company);
+    /// ```
+    pub fn remove_company(env: Env, admin: Address, company: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if storage::get_active_shipment_count(&env, &company) > 0 {
+            return Err(NavinError::HasActiveShipments);
+        }
+
+        remove_role_holder(&env, &admin, &company, Role::Company)
+    }
+
+    /// Revoke the Carrier role from an offboarded or compromised carrier.
+    /// Only the admin can call this. Shipments already assigned to the
+    /// carrier stay assigned, but it can no longer act on them.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the removal.
+    /// * `carrier` - Carrier losing its role.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful removal.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not the admin or `carrier` holds no Carrier role.
+    /// * `NavinError::RoleChangeCooldown` - If the address's role changed within the cooldown.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.remove_carrier(&env, &admin, &carrier);
+    /// ```
+    pub fn remove_carrier(env: Env, admin: Address, carrier: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        remove_role_holder(&env, &admin, &carrier, Role::Carrier)
+    }
+
+    /// Suspend a role temporarily (e.g., for investigation or compliance review).
+    ///
+    /// Only the admin can suspend roles. Suspended addresses retain their role
+    /// assignment but cannot perform role-gated actions until reactivated.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the suspension.
+    /// * `target` - The address whose role is being suspended.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful suspension.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by a non-admin.
+    /// * `NavinError::CannotSelfRevoke` - If admin tries to suspend their own role.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.suspend_role(&env, &admin, &target_addr);
+    /// ```
+    pub fn suspend_role(env: Env, admin: Address, target: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        if admin == target {
+            return Err(NavinError::CannotSelfRevoke);
+        }
+
+        let current_role = storage::get_role(&env, &target).unwrap_or(Role::Unassigned);
+
+        if current_role == Role::Unassigned {
+            return Err(NavinError::Unauthorized);
+        }
+
+        // Mark as suspended in storage
+        storage::suspend_role(&env, &target, &current_role);
+
+        // Emit role history event
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Suspended,
+            &admin,
+            &target,
+            &current_role,
+        );
+
+        Ok(())
+    }
+
+    /// Reactivate a previously suspended role.
+    ///
+    /// Only the admin can reactivate roles. This restores the address's
+    /// ability to perform role-gated actions.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the reactivation.
+    /// * `target` - The address whose role is being reactivated.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful reactivation.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by a non-admin or target not suspended.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.reactivate_role(&env, &admin, &target_addr);
+    /// ```
+    pub fn reactivate_role(env: Env, admin: Address, target: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        if storage::get_admin(&env) != admin {
+            return Err(NavinError::Unauthorized);
+        }
+
+        let current_role = storage::get_role(&env, &target).unwrap_or(Role::Unassigned);
+
+        if current_role == Role::Unassigned {
+            return Err(NavinError::Unauthorized);
+        }
+
+        // Reactivate the role
+        storage::reactivate_role(&env, &target, &current_role);
+
+        // Emit role history event
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Reactivated,
+            &admin,
+            &target,
+            &current_role,
+        );
+
+        Ok(())
+    }
+
+    /// Suspend a company from creating or updating shipments.
+    pub fn suspend_company(env: Env, admin: Address, company: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        require_admin_or_operator(&env, &admin)?;
+
+        if !storage::has_company_role(&env, &company) {
+            return Err(NavinError::Unauthorized);
+        }
+
+        storage::suspend_company(&env, &company);
+
+        // Emit role history event (reusing Reactive/Suspended for audit)
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Suspended,
+            &admin,
+            &company,
+            &Role::Company,
+        );
+
+        Ok(())
+    }
+
+    /// Reactivate a suspended company.
+    pub fn reactivate_company(
+        env: Env,
+        admin: Address,
+        company: Address,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+
+        require_admin_or_operator(&env, &admin)?;
+
+        storage::reactivate_company(&env, &company);
+
+        // Emit role history event
+        events::emit_role_changed(
+            &env,
+            &RoleChangeAction::Reactivated,
+            &admin,
+            &company,
+            &Role::Company,
+        );
+
+        Ok(())
+    }
+
+    /// Create a shipment and emit the shipment_created event.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `sender` - Company address creating the shipment.
+    /// * `receiver` - Destination address for the shipment.
+    /// * `carrier` - Carrier address assigned to the shipment.
+    /// * `data_hash` - Off-chain data hash of shipment details.
+    /// * `payment_milestones` - Schedule for escrow releases based on checkpoints.
+    /// * `deadline` - Timestamp after which shipment is considered expired and can be auto-cancelled.
+    ///
+    /// # Returns
+    /// * `Result<u64, NavinError>` - Newly created shipment ID.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller isn't a Company.
+    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
+    /// * `NavinError::MilestoneSumInvalid` - If milestone percentages do not equal 100%
+    ///   (or exceed 100% when `allow_milestone_holdback` is set).
+    /// * `NavinError::CounterOverflow` - If total shipment count overflows max u64.
+    /// * `NavinError::InvalidTimestamp` - If the deadline is earlier than
+    ///   `now + min_deadline_buffer_seconds` (or not strictly in the future).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
+    /// # use soroban_sdk::testutils::Address as _;
+    /// # use shipment::{NavinShipment, NavinShipmentClient};
+    /// # let env = Env::default();
+    /// # env.mock_all_auths();
+    /// # let contract_id = env.register(NavinShipment, ());
+    /// # let client = NavinShipmentClient::new(&env, &contract_id);
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// let sender = admin.clone();
+    /// let receiver = Address::generate(&env);
+    /// let carrier = Address::generate(&env);
+    /// let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// let milestones: Vec<(Symbol, u32)> = Vec::new(&env); // no milestone splits
+    /// let deadline = env.ledger().timestamp() + 86_400; // 1 day from now
+    ///
+    /// let shipment_id = client.create_shipment(
+    ///     &sender, &receiver, &carrier, &data_hash, &milestones, &deadline,
+    /// );
+    /// assert_eq!(shipment_id, 1);
+    /// ```
+    pub fn create_shipment(
+        env: Env,
+        sender: Address,
+        receiver: Address,
+        carrier: Address,
+        data_hash: BytesN<32>,
+        payment_milestones: Vec<(Symbol, u32)>,
+        deadline: u64,
+    ) -> Result<u64, NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        sender.require_auth();
+        require_role(&env, &sender, Role::Company)?;
+        validate_milestones(&env, &payment_milestones)?;
+        validate_hash(&data_hash)?;
+        require_registered_receiver(&env, &receiver)?;
+        require_receiver_approved_carrier(&env, &receiver, &carrier)?;
+
+        // Idempotency: reject duplicate (sender, data_hash) within the window.
+        let mut payload = soroban_sdk::Bytes::new(&env);
+        payload.append(&sender.clone().to_xdr(&env));
+        payload.append(&data_hash.clone().into());
+        check_idempotency(&env, payload)?;
+
+        let now = env.ledger().timestamp();
+        let buffer = config::get_config(&env).min_deadline_buffer_seconds;
+        validate_deadline_buffer(now, deadline, buffer)?;
+
+        // Check company active shipment limit
+        let current_active = storage::get_active_shipment_count(&env, &sender);
+        let limit = storage::get_effective_shipment_limit(&env, &sender);
+        if current_active >= limit {
+            return Err(NavinError::ShipmentLimitReached);
+        }
+
+        // Check per-company creation quota window (issue #296).
+        check_and_update_creation_quota(&env, &sender)?;
+
+        let shipment_id = storage::get_shipment_counter(&env)
+            .checked_add(1)
+            .ok_or(NavinError::CounterOverflow)?;
+
+        let shipment = Shipment {
+            id: shipment_id,
+            sender: sender.clone(),
+            receiver: receiver.clone(),
+            carrier,
+            data_hash: data_hash.clone(),
+            status: ShipmentStatus::Created,
+            created_at: now,
+            updated_at: now,
+            escrow_amount: 0,
+            total_escrow: 0,
+            payment_milestones,
+            paid_milestones: Vec::new(&env),
+            milestones_completed: Vec::new(&env),
+            metadata: None,
+            deadline,
+            integration_nonce: 0,
+            finalized: false,
+        };
+
+        persist_shipment(&env, &shipment)?;
+        storage::set_creation_hash(&env, shipment_id, &compute_creation_hash(&env, &shipment));
+        storage::set_shipment_counter(&env, shipment_id);
+        storage::increment_status_count(&env, &ShipmentStatus::Created);
+        storage::push_status_history(&env, shipment_id, &ShipmentStatus::Created, &sender);
+        storage::increment_active_shipment_count(&env, &sender);
+        storage::push_carrier_shipment(&env, &shipment.carrier, shipment_id);
+        if config::get_config(&env).index_company_shipments {
+            storage::push_company_shipment(&env, &sender, shipment_id);
+        }
+        extend_shipment_ttl(&env, shipment_id);
+
+        events::emit_shipment_created(&env, shipment_id, &sender, &receiver, &data_hash);
+        events::emit_notification(
+            &env,
+            &receiver,
+            NotificationType::ShipmentCreated,
+            shipment_id,
+            &data_hash,
+        );
+        events::emit_notification(
+            &env,
+            &shipment.carrier,
+            NotificationType::ShipmentCreated,
+            shipment_id,
+            &data_hash,
+        );
+        notify_extra_recipients(
+            &env,
+            &sender,
+            NotificationType::ShipmentCreated,
+            shipment_id,
+            &data_hash,
+        );
+
+        Ok(shipment_id)
+    }
+
+    /// Create multiple shipments in a single atomic transaction.
+    /// Limit: 10 shipments per batch.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `sender` - Company address creating shipments.
+    /// * `shipments` - Vector of shipment inputs.
+    ///
+    /// # Returns
+    /// * `Result<Vec<u64>, NavinError>` - Vector of newly created shipment IDs.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller isn't a Company.
+    /// * `NavinError::BatchTooLarge` - If more than 10 shipments are submitted.
+    /// * `NavinError::InvalidShipmentInput` - If receiver matches carrier for any shipment.
+    /// * `NavinError::InvalidHash` - If any data_hash is all zeros.
+    /// * `NavinError::MilestoneSumInvalid` - If payment milestones are invalid per item.
+    /// * `NavinError::InvalidTimestamp` - If the deadline is earlier than
+    ///   `now + min_deadline_buffer_seconds` (or not strictly in the future).
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let ids = contract.create_shipments_batch(&env, &sender, inputs_vec);
+    /// ```
+    pub fn create_shipments_batch(
+        env: Env,
+        sender: Address,
+        shipments: Vec<ShipmentInput>,
+    ) -> Result<Vec<u64>, NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        sender.require_auth();
+        require_role(&env, &sender, Role::Company)?;
+
+        let config = config::get_config(&env);
+        if shipments.len() > config.batch_operation_limit {
+            return Err(NavinError::BatchTooLarge);
+        }
+
+        let mut ids = Vec::new(&env);
+        let now = env.ledger().timestamp();
+
+        // Check batch size against limit
+        let current_active = storage::get_active_shipment_count(&env, &sender);
+        let limit = storage::get_effective_shipment_limit(&env, &sender);
+        if current_active.saturating_add(shipments.len()) > limit {
+            return Err(NavinError::ShipmentLimitReached);
+        }
+
+        // Check per-company creation quota window for the entire batch (issue #296).
+        // We check once per item to correctly track the rolling count.
+        {
+            let cfg = config::get_config(&env);
+            if cfg.creation_quota_max > 0 {
+                let now_ts = env.ledger().timestamp();
+                let mut tracker = storage::get_creation_quota(&env, &sender).unwrap_or(
+                    crate::types::CreationQuotaTracker {
+                        count: 0,
+                        window_start: now_ts,
+                    },
+                );
+                if now_ts >= tracker.window_start + cfg.creation_quota_window_seconds {
+                    tracker.window_start = now_ts;
+                    tracker.count = 0;
+                }
+                let batch_len = shipments.len();
+                let new_count = tracker
+                    .count
+                    .checked_add(batch_len)
+                    .ok_or(NavinError::CounterOverflow)?;
+                if new_count > cfg.creation_quota_max {
+                    return Err(NavinError::CreationQuotaExceeded);
+                }
+                tracker.count = new_count;
+                storage::set_creation_quota(&env, &sender, &tracker);
+            }
+        }
+
+        for shipment_input in shipments.iter() {
+            if shipment_input.receiver == shipment_input.carrier {
+                return Err(NavinError::InvalidShipmentInput);
+            }
+            validate_milestones(&env, &shipment_input.payment_milestones)?;
+            validate_hash(&shipment_input.data_hash)?;
+            require_registered_receiver(&env, &shipment_input.receiver)?;
+            require_receiver_approved_carrier(
+                &env,
+                &shipment_input.receiver,
+                &shipment_input.carrier,
+            )?;
+
+            validate_deadline_buffer(
+                now,
+                shipment_input.deadline,
+                config.min_deadline_buffer_seconds,
+            )?;
+
+            let shipment_id = storage::get_shipment_counter(&env)
+                .checked_add(1)
+                .ok_or(NavinError::CounterOverflow)?;
+
+            let shipment = Shipment {
+                id: shipment_id,
+                sender: sender.clone(),
+                receiver: shipment_input.receiver.clone(),
+                carrier: shipment_input.carrier.clone(),
+                data_hash: shipment_input.data_hash.clone(),
+                status: ShipmentStatus::Created,
+                created_at: now,
+                updated_at: now,
+                escrow_amount: 0,
+                total_escrow: 0,
+                payment_milestones: shipment_input.payment_milestones,
+                paid_milestones: Vec::new(&env),
+                milestones_completed: Vec::new(&env),
+                metadata: None,
+                deadline: shipment_input.deadline,
+                integration_nonce: 0,
+                finalized: false,
+            };
+
+            persist_shipment(&env, &shipment)?;
+            storage::set_creation_hash(&env, shipment_id, &compute_creation_hash(&env, &shipment));
+            storage::set_shipment_counter(&env, shipment_id);
+            storage::increment_status_count(&env, &ShipmentStatus::Created);
+            storage::push_status_history(&env, shipment_id, &ShipmentStatus::Created, &sender);
+            storage::increment_active_shipment_count(&env, &sender);
+            storage::push_carrier_shipment(&env, &shipment.carrier, shipment_id);
+            if config.index_company_shipments {
+                storage::push_company_shipment(&env, &sender, shipment_id);
+            }
+            // Use the cached-config variant to avoid re-reading config from storage per item.
+            extend_shipment_ttl_cached(
+                &env,
+                shipment_id,
+                config.shipment_ttl_threshold,
+                config.shipment_ttl_extension,
+            );
+
+            events::emit_shipment_created(
+                &env,
+                shipment_id,
+                &sender,
+                &shipment_input.receiver,
+                &shipment_input.data_hash,
+            );
+            events::emit_notification(
+                &env,
+                &shipment_input.receiver,
+                NotificationType::ShipmentCreated,
+                shipment_id,
+                &shipment_input.data_hash,
+            );
+            events::emit_notification(
+                &env,
+                &shipment_input.carrier,
+                NotificationType::ShipmentCreated,
+                shipment_id,
+                &shipment_input.data_hash,
+            );
+            notify_extra_recipients(
+                &env,
+                &sender,
+                NotificationType::ShipmentCreated,
+                shipment_id,
+                &shipment_input.data_hash,
+            );
+            ids.push_back(shipment_id);
+        }
+
+        Ok(ids)
+    }
+
+    /// Retrieve shipment details by ID.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment to fetch.
+    ///
+    /// # Returns
+    /// * `Result<Shipment, NavinError>` - Reconstructed shipment struct.
+    ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
-    /// * `NavinError::Unauthorized` - If the caller is not the sender or admin.
-    /// * `NavinError::MetadataLimitExceeded` - If adding would exceed the 5 key limit.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.set_shipment_metadata(&env, &caller, 1, &Symbol::new(&env, "weight"), &Symbol::new(&env, "kg_100"));
+    /// // let shipment = contract.get_shipment(&env, 1);
     /// ```
-    pub fn set_shipment_metadata(
-        env: Env,
-        caller: Address,
-        shipment_id: u64,
-        key: Symbol,
-        value: Symbol,
-    ) -> Result<(), NavinError> {
+    pub fn get_shipment(env: Env, shipment_id: u64) -> Result<Shipment, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        caller.require_auth();
-
-        // Guard against empty / whitespace-only Symbol inputs before the
-        // length-and-collision validation that follows.
-        validate_symbol_not_whitespace_only(&env, &key)?;
-        validate_symbol_not_whitespace_only(&env, &value)?;
-        // Validate metadata symbols for bounded usage before storage
-        validation::validate_metadata_symbols(&env, &key, &value)?;
-
-        let admin = storage::get_admin(&env);
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        require_not_finalized(&shipment)?;
-        // Only sender or admin can set
-        if caller != shipment.sender && caller != admin {
-            return Err(NavinError::Unauthorized);
-        }
-        // If caller is the company (sender), check for suspension
-        if caller == shipment.sender {
-            require_active_company(&env, &caller)?;
-        }
-        // Initialize metadata map if not present
-        let mut metadata = shipment.metadata.unwrap_or(Map::new(&env));
-        // Enforce max metadata entries from config
-        let config = config::get_config(&env);
-        if !metadata.contains_key(key.clone()) && metadata.len() >= config.max_metadata_entries {
-            return Err(NavinError::MetadataLimitExceeded);
-        }
-        metadata.set(key.clone(), value.clone());
-        shipment.metadata = Some(metadata);
-        shipment.updated_at = env.ledger().timestamp();
-        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-        persist_shipment(&env, &shipment)?;
-        Ok(())
+        storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)
     }
 
-    /// Append a hash-only note to a shipment for commentary.
-    /// Only the sender, receiver, assigned carrier, or admin can append notes.
+    /// Retrieve the immutable creator identity for a shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `reporter` - The address appending the note.
     /// * `shipment_id` - ID of the shipment.
-    /// * `note_hash` - SHA-256 hash of the off-chain note text.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully appended.
+    /// * `Result<Address, NavinError>` - Address that originally created the shipment.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
-    /// * `NavinError::Unauthorized` - If the caller is not involved in the shipment or admin.
-    pub fn append_note_hash(
-        env: Env,
-        reporter: Address,
-        shipment_id: u64,
-        note_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    pub fn get_shipment_creator(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        reporter.require_auth();
-
-        // Validate note hash length (32 bytes) and reject malformed sentinels.
-        validation::validate_note_hash(&note_hash)?;
-
         let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        let admin = storage::get_admin(&env);
-
-        // Authorization: Sender, Receiver, Carrier, or Admin
-        if reporter != shipment.sender
-            && reporter != shipment.receiver
-            && reporter != shipment.carrier
-            && reporter != admin
-        {
-            return Err(NavinError::Unauthorized);
-        }
-
-        // If reporter is the company (sender), check for suspension
-        if reporter == shipment.sender {
-            require_active_company(&env, &reporter)?;
-        }
-
-        // Check note event payload size guard
-        let config = config::get_config(&env);
-        let current_note_count = storage::get_note_count(&env, shipment_id);
-        if current_note_count >= config.max_notes_per_shipment {
-            return Err(NavinError::NoteLimitExceeded);
-        }
-
-        // notes are append-only; we just increment the counter and store at the next index.
-        let index = storage::increment_note_count(&env, shipment_id);
-        storage::set_note_hash(&env, shipment_id, index, &note_hash);
-
-        // Emit the event following the Hash-and-Emit pattern.
-        events::emit_note_appended(&env, shipment_id, index, &note_hash, &reporter);
-
-        Ok(())
+        Ok(shipment.sender)
     }
 
-    /// Add an evidence hash to an active shipment dispute.
-    /// Only in Disputed state. Authorization: Sender, Receiver, Carrier, or Admin.
+    /// Retrieve the immutable receiver identity for a shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `reporter` - The address adding the evidence.
     /// * `shipment_id` - ID of the shipment.
-    /// * `evidence_hash` - SHA-256 hash of the off-chain evidence.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully added.
-    pub fn add_dispute_evidence_hash(
-        env: Env,
-        reporter: Address,
-        shipment_id: u64,
-        evidence_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
+    /// * `Result<Address, NavinError>` - Address designated as shipment receiver at creation.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    pub fn get_shipment_receiver(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        reporter.require_auth();
-
-        // Validate hash before storage
-        validation::validate_hash(&evidence_hash)?;
-
         let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        require_not_finalized(&shipment)?;
-        let admin = storage::get_admin(&env);
-
-        // State check: Only in Disputed state
-        if shipment.status != ShipmentStatus::Disputed {
-            return Err(NavinError::InvalidStatus);
-        }
-
-        // Authorization: Sender, Receiver, Carrier, or Admin
-        if reporter != shipment.sender
-            && reporter != shipment.receiver
-            && reporter != shipment.carrier
-            && reporter != admin
-        {
-            return Err(NavinError::Unauthorized);
-        }
-
-        // If reporter is the company (sender), check for suspension
-        if reporter == shipment.sender {
-            require_active_company(&env, &reporter)?;
-        }
-
-        // Check evidence count payload size guard
-        let config = config::get_config(&env);
-        let current_evidence_count = storage::get_evidence_count(&env, shipment_id);
-        if current_evidence_count >= config.max_evidence_per_dispute {
-            return Err(NavinError::EvidenceLimitExceeded);
-        }
-
-        // Increment counter and store hash
-        let index = storage::increment_evidence_count(&env, shipment_id);
-        storage::set_evidence_hash(&env, shipment_id, index, &evidence_hash);
-
-        // Increment integration nonce
-        let mut shipment_mut = shipment;
-        shipment_mut.integration_nonce = shipment_mut.integration_nonce.saturating_add(1);
-        storage::set_shipment(&env, &shipment_mut);
-
-        // Emit event
-        events::emit_evidence_added(&env, shipment_id, index, &evidence_hash, &reporter);
-
-        Ok(())
-    }
-
-    /// Get the total number of evidence hashes for a shipment dispute.
-    pub fn get_dispute_evidence_count(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
-        require_initialized(&env)?;
-        if storage::get_shipment(&env, shipment_id).is_none() {
-            return Err(NavinError::ShipmentNotFound);
-        }
-        Ok(storage::get_evidence_count(&env, shipment_id))
-    }
-
-    /// Get a specific evidence hash for a shipment dispute by its sequence index.
-    pub fn get_dispute_evidence_hash(
-        env: Env,
-        shipment_id: u64,
-        index: u32,
-    ) -> Result<Option<BytesN<32>>, NavinError> {
-        require_initialized(&env)?;
-        if storage::get_shipment(&env, shipment_id).is_none() {
-            return Err(NavinError::ShipmentNotFound);
-        }
-        let count = storage::get_evidence_count(&env, shipment_id);
-        if index >= count {
-            return Err(NavinError::EvidenceNotFound);
-        }
-        Ok(storage::get_evidence_hash(&env, shipment_id, index))
+        Ok(shipment.receiver)
     }
 
-    /// Get the current integration nonce for a shipment.
-    /// Nonce increments on critical transitions like status changes and escrow movements.
+    /// Retrieve the creation timestamp for a shipment.
+    /// Retrieve the immutable sender (creator) identity for a shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
     /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<u32, NavinError>` - The current nonce.
-    pub fn get_integration_nonce(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
+    /// * `Result<u64, NavinError>` - Ledger timestamp of creation.
+    pub fn get_shipment_created_at(env: Env, shipment_id: u64) -> Result<u64, NavinError> {
         require_initialized(&env)?;
         let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.integration_nonce)
+        Ok(shipment.created_at)
     }
 
-    /// Get the total number of notes appended to a shipment.
+    /// Retrieve the hash of the parameters a shipment was created with.
+    ///
+    /// The hash is SHA-256 over the XDR encodings of sender, receiver,
+    /// carrier, data_hash, payment_milestones and deadline (in that order), as
+    /// supplied at creation. It is not updated by later handoffs or ownership
+    /// transfers, so auditors can match it against the original off-chain order.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
     /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<u32, NavinError>` - Number of notes for the shipment.
-    pub fn get_note_count(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
+    /// * `Result<Option<BytesN<32>>, NavinError>` - Creation hash, or `None` for
+    ///   shipments created before the hash was recorded.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let hash = contract.get_creation_hash(&env, shipment_id);
+    /// ```
+    pub fn get_creation_hash(env: Env, shipment_id: u64) -> Result<Option<BytesN<32>>, NavinError> {
         require_initialized(&env)?;
-        // Verify existence or check archived
         if storage::get_shipment(&env, shipment_id).is_none() {
             return Err(NavinError::ShipmentNotFound);
         }
-        Ok(storage::get_note_count(&env, shipment_id))
+        Ok(storage::get_creation_hash(&env, shipment_id))
+    }
+
+    /// Retrieve the last update timestamp for a shipment.
+    /// * `Result<Address, NavinError>` - Address that originally created the shipment.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    pub fn get_shipment_sender(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        Ok(shipment.sender)
     }
 
-    /// Get a specific note hash for a shipment by its sequence index.
+    /// Retrieve the immutable carrier identity for a shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
     /// * `shipment_id` - ID of the shipment.
-    /// * `index` - The 0-based index of the note.
     ///
     /// # Returns
-    /// * `Result<Option<BytesN<32>>, NavinError>` - The note hash if found.
-    pub fn get_note_hash(
+    /// * `Result<u64, NavinError>` - Ledger timestamp of the last update.
+    pub fn get_shipment_updated_at(env: Env, shipment_id: u64) -> Result<u64, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        Ok(shipment.updated_at)
+    }
+
+    /// * `Result<Address, NavinError>` - Address designated as shipment carrier at creation.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    pub fn get_shipment_carrier(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        Ok(shipment.carrier)
+    }
+
+    /// Return read-only diagnostics that help operators triage restore requirements.
+    ///
+    /// This query does not mutate state. It classifies the shipment ID as active,
+    /// archived-expected, missing, or inconsistent (both active and archived present).
+    pub fn get_restore_diagnostics(
         env: Env,
         shipment_id: u64,
-        index: u32,
-    ) -> Result<Option<BytesN<32>>, NavinError> {
+    ) -> Result<PersistentRestoreDiagnostics, NavinError> {
         require_initialized(&env)?;
-        if storage::get_shipment(&env, shipment_id).is_none() {
-            return Err(NavinError::ShipmentNotFound);
-        }
-        let count = storage::get_note_count(&env, shipment_id);
-        if index >= count {
-            return Err(NavinError::NoteNotFound);
-        }
-        Ok(storage::get_note_hash(&env, shipment_id, index))
+
+        let persistent_shipment_present = storage::has_persistent_shipment(&env, shipment_id);
+        let archived_shipment_present = storage::is_shipment_archived(&env, shipment_id);
+
+        let state = if persistent_shipment_present && archived_shipment_present {
+            StoragePresenceState::InconsistentDualPresence
+        } else if persistent_shipment_present {
+            StoragePresenceState::ActivePersistent
+        } else if archived_shipment_present {
+            StoragePresenceState::ArchivedExpected
+        } else {
+            StoragePresenceState::Missing
+        };
+
+        Ok(PersistentRestoreDiagnostics {
+            shipment_id,
+            state,
+            persistent_shipment_present,
+            archived_shipment_present,
+            escrow_present: storage::has_escrow_entry(&env, shipment_id),
+            confirmation_hash_present: storage::has_confirmation_hash_entry(&env, shipment_id),
+            last_status_update_present: storage::has_last_status_update_entry(&env, shipment_id),
+            event_count_present: storage::has_event_count_entry(&env, shipment_id),
+        })
     }
-    /// Initialize the contract with an admin address and token contract address.
-    /// Can only be called once. Sets the admin and shipment counter to 0.
+
+    /// Deposit escrow funds for a shipment.
+    /// Only a Company can deposit, and the shipment must be in Created status.
+    /// The escrow is recorded as the contract's actual balance increase, so
+    /// fee-on-transfer tokens are credited with the net amount received.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - The address designated as the administrator.
-    /// * `token_contract` - The address of the token contract used for escrow.
+    /// * `from` - Company address providing escrow.
+    /// * `shipment_id` - Target shipment.
+    /// * `amount` - Balance of tokens deposited into escrow.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if initialized.
+    /// * `Result<(), NavinError>` - Ok on successful deposit.
     ///
     /// # Errors
-    /// * `NavinError::AlreadyInitialized` - If called when already initialized.
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller isn't a Company, or is neither the sender nor admin
+    ///   while `allow_third_party_escrow` is disabled.
+    /// * `NavinError::InvalidAmount` - If amount is zero, negative, or exceeds the maximum.
+    /// * `NavinError::ShipmentNotFound` - If shipment is untracked.
+    /// * `NavinError::InvalidStatus` - If shipment is not in `Created` status.
+    /// * `NavinError::EscrowLocked` - If escrow is already deposited for shipment.
     ///
     /// # Examples
     ///
     /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address};
+    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
     /// # use soroban_sdk::testutils::Address as _;
     /// # use shipment::{NavinShipment, NavinShipmentClient};
     /// # let env = Env::default();
     /// # env.mock_all_auths();
     /// # let contract_id = env.register(NavinShipment, ());
     /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// let admin = Address::generate(&env);
-    /// let token_contract = Address::generate(&env); // replace with deployed token address
-    ///
-    /// client.initialize(&admin, &token_contract);
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    /// # let deadline = env.ledger().timestamp() + 86_400;
+    /// # let receiver = Address::generate(&env);
+    /// # let carrier = Address::generate(&env);
+    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
+    /// // Deposit 5_000_000 stroops (0.5 tokens) into escrow for the shipment.
+    /// // The company must have pre-approved the token transfer allowance.
+    /// client.deposit_escrow(&admin, &shipment_id, &5_000_000_i128);
     /// ```
-    pub fn initialize(env: Env, admin: Address, token_contract: Address) -> Result<(), NavinError> {
-        if storage::is_initialized(&env) {
-            return Err(NavinError::AlreadyInitialized);
-        }
+    pub fn deposit_escrow(
+        env: Env,
+        from: Address,
+        shipment_id: u64,
+        amount: i128,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        from.require_auth();
+        require_role(&env, &from, Role::Company)?;
 
-        // Reject obviously invalid token addresses: the token contract must not be
-        // the admin account or the shipment contract itself.
-        if token_contract == admin || token_contract == env.current_contract_address() {
-            return Err(NavinError::InvalidTokenAddress);
-        }
+        with_reentrancy_lock(&env, || {
+            validation::validate_positive_amount(amount)?;
 
-        storage::set_admin(&env, &admin);
-        storage::set_token_contract(&env, &token_contract);
-        storage::set_shipment_counter(&env, 0);
-        storage::set_version(&env, 1);
-        storage::set_company_role(&env, &admin);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        // Initialize with default configuration
-        let default_config = ContractConfig::default();
-        config::set_config(&env, &default_config).map_err(|_| NavinError::InvalidConfig)?;
-        storage::set_shipment_limit(&env, default_config.default_shipment_limit);
+            require_not_finalized(&shipment)?;
 
-        events::emit_contract_initialized(&env, &admin, &token_contract);
+            // Only the sender or admin may fund escrow unless third-party funding is enabled.
+            if from != shipment.sender
+                && from != storage::get_admin(&env)
+                && !config::get_config(&env)
+                    .escrow_policy
+                    .allow_third_party_escrow
+            {
+                return Err(NavinError::Unauthorized);
+            }
 
-        // Extend contract instance TTL to prevent premature archival
-        let config = config::get_config(&env);
-        env.storage()
-            .instance()
-            .extend_ttl(config.shipment_ttl_threshold, config.shipment_ttl_extension);
+            if shipment.status != ShipmentStatus::Created {
+                return Err(NavinError::InvalidStatus);
+            }
 
-        Ok(())
+            if shipment.escrow_amount > 0 {
+                return Err(NavinError::EscrowLocked);
+            }
+
+            // Get token contract address
+            let token_contract =
+                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+
+            // Validate that the token uses 7 decimal places (Stellar standard).
+            // This prevents silent amount mismatches for non-standard tokens.
+            validate_token_decimals(&env, &token_contract)?;
+            validate_min_escrow_value(&env, &token_contract, amount)?;
+
+            let net_amount =
+                collect_escrow_deposit(&env, &token_contract, shipment_id, &from, amount)?;
+
+            shipment.escrow_amount = net_amount;
+            shipment.total_escrow = net_amount;
+            require_fixed_milestones_funded(&env, &shipment)?;
+            shipment.updated_at = env.ledger().timestamp();
+            shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+            persist_shipment(&env, &shipment)?;
+            storage::set_escrow(&env, shipment_id, net_amount);
+            extend_shipment_ttl(&env, shipment_id);
+
+            events::emit_escrow_deposited(&env, shipment_id, &from, net_amount);
+
+            Ok(())
+        })
     }
 
-    /// Set the configurable limit on the number of active shipments a company can have.
-    /// Only the admin can call this.
+    /// Add funds to a shipment's existing escrow while it is still `Created`.
+    /// Milestone payouts are computed against the resulting `total_escrow`.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin address.
-    /// * `limit` - The new active shipment limit.
-    pub fn set_shipment_limit(env: Env, admin: Address, limit: u32) -> Result<(), NavinError> {
+    /// * `from` - The shipment's sender providing the extra escrow.
+    /// * `shipment_id` - Target shipment.
+    /// * `amount` - Additional tokens to deposit.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful top-up.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller isn't a Company or isn't the shipment's sender.
+    /// * `NavinError::InvalidAmount` - If amount is zero, negative, or exceeds the maximum.
+    /// * `NavinError::ShipmentNotFound` - If shipment is untracked.
+    /// * `NavinError::InvalidStatus` - If shipment is not in `Created` status.
+    /// * `NavinError::InsufficientFunds` - If no escrow has been deposited yet.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.top_up_escrow(&env, &company, &shipment_id, &1_000_000);
+    /// ```
+    pub fn top_up_escrow(
+        env: Env,
+        from: Address,
+        shipment_id: u64,
+        amount: i128,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        admin.require_auth();
+        require_not_paused(&env)?;
+        from.require_auth();
+        require_role(&env, &from, Role::Company)?;
 
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
-        }
+        with_reentrancy_lock(&env, || {
+            validation::validate_positive_amount(amount)?;
 
-        storage::set_shipment_limit(&env, limit);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        events::emit_shipment_limit_updated(&env, &admin, limit);
+            require_not_finalized(&shipment)?;
 
-        Ok(())
-    }
+            if from != shipment.sender {
+                return Err(NavinError::Unauthorized);
+            }
 
-    /// Get the current shipment limit.
-    pub fn get_shipment_limit(env: Env) -> Result<u32, NavinError> {
-        require_initialized(&env)?;
-        Ok(storage::get_shipment_limit(&env))
-    }
+            if shipment.status != ShipmentStatus::Created {
+                return Err(NavinError::InvalidStatus);
+            }
 
-    /// Set a company-specific active shipment limit override.
-    pub fn set_company_shipment_limit(
-        env: Env,
-        admin: Address,
-        company: Address,
-        limit: u32,
-    ) -> Result<(), NavinError> {
-        require_initialized(&env)?;
-        admin.require_auth();
+            // A top-up extends an existing deposit; the first one goes through deposit_escrow.
+            if shipment.escrow_amount <= 0 {
+                return Err(NavinError::InsufficientFunds);
+            }
 
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
-        }
+            let token_contract =
+                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
 
-        storage::set_company_shipment_limit(&env, &company, limit);
-        events::emit_company_limit_updated(&env, &admin, &company, limit);
-        Ok(())
-    }
+            let net_amount =
+                collect_escrow_deposit(&env, &token_contract, shipment_id, &from, amount)?;
 
-    /// Get effective shipment limit for a company (override or global fallback).
-    pub fn get_effective_shipment_limit(env: Env, company: Address) -> Result<u32, NavinError> {
-        require_initialized(&env)?;
-        Ok(storage::get_effective_shipment_limit(&env, &company))
-    }
+            shipment.escrow_amount = checked_add_i128(shipment.escrow_amount, net_amount)?;
+            shipment.total_escrow = checked_add_i128(shipment.total_escrow, net_amount)?;
+            require_fixed_milestones_funded(&env, &shipment)?;
+            shipment.updated_at = env.ledger().timestamp();
+            shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+            persist_shipment(&env, &shipment)?;
+            storage::set_escrow(&env, shipment_id, shipment.escrow_amount);
+            extend_shipment_ttl(&env, shipment_id);
 
-    /// Get the current active shipment count for a company.
-    pub fn get_active_shipment_count(env: Env, company: Address) -> Result<u32, NavinError> {
-        require_initialized(&env)?;
-        Ok(storage::get_active_shipment_count(&env, &company))
+            events::emit_escrow_deposited(&env, shipment_id, &from, net_amount);
+
+            Ok(())
+        })
     }
 
-    /// Get the contract admin address.
+    /// Update shipment status with transition validation.
+    /// Only the carrier or admin can update the status.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `caller` - Carrier or admin address making the update.
+    /// * `shipment_id` - Current shipment identifier.
+    /// * `new_status` - The destination transitional status.
+    /// * `data_hash` - The off-chain data hash tracking context for update.
     ///
     /// # Returns
-    /// * `Result<Address, NavinError>` - The current admin address.
+    /// * `Result<(), NavinError>` - Ok on valid transition.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment doesn't exist.
+    /// * `NavinError::Unauthorized` - If caller is neither the carrier nor admin.
+    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
+    /// * `NavinError::CarrierSuspended` - If the assigned carrier is suspended.
+    /// * `NavinError::RateLimitExceeded` - If status was updated too recently, or the first
+    ///   update comes within `initial_update_delay` of creation (unless Admin).
+    /// * `NavinError::InvalidStatus` - If transitioning to an improperly sequenced state.
     ///
     /// # Examples
     /// ```rust
-    /// // let admin = contract.get_admin(&env);
+    /// ```rust,no_run
+    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
+    /// # use soroban_sdk::testutils::Address as _;
+    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
+    /// # let env = Env::default();
+    /// # env.mock_all_auths();
+    /// # let contract_id = env.register(NavinShipment, ());
+    /// # let client = NavinShipmentClient::new(&env, &contract_id);
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// # let carrier = Address::generate(&env);
+    /// # client.add_carrier(&admin, &carrier);
+    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    /// # let deadline = env.ledger().timestamp() + 86_400;
+    /// # let receiver = Address::generate(&env);
+    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
+    /// let transit_hash = BytesN::from_array(&env, &[2u8; 32]);
+    ///
+    /// // Carrier moves shipment from Created -> InTransit.
+    /// client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &transit_hash);
     /// ```
-    pub fn get_admin(env: Env) -> Result<Address, NavinError> {
+    pub fn update_status(
+        env: Env,
+        caller: Address,
+        shipment_id: u64,
+        new_status: ShipmentStatus,
+        data_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        Ok(storage::get_admin(&env))
+        require_not_paused(&env)?;
+        caller.require_auth();
+
+        // Validate hash before storage
+        validation::validate_hash(&data_hash)?;
+
+        let admin = storage::get_admin(&env);
+        let mut shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        validate_status_update(&env, &caller, &admin, &shipment, &new_status, &data_hash)?;
+        apply_status_update(&env, &caller, &mut shipment, &new_status, &data_hash)
     }
 
-    /// Get the contract version number.
+    /// Update the status of several shipments in one all-or-nothing call,
+    /// e.g. when a carrier moves a convoy through a checkpoint.
+    ///
+    /// Every entry is checked exactly as `update_status` would check it
+    /// (authorization, rate limits, transition rules) before any shipment is
+    /// changed, so a single invalid entry rejects the whole batch.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `caller` - Carrier of every listed shipment, or Admin.
+    /// * `updates` - `(shipment_id, new_status, data_hash)` entries.
     ///
     /// # Returns
-    /// * `Result<u32, NavinError>` - The version number of the contract.
+    /// * `Result<(), NavinError>` - Ok once every shipment has been updated.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::BatchTooLarge` - If more entries than `batch_operation_limit` are given.
+    /// * `NavinError::InvalidShipmentInput` - If a shipment ID appears more than once.
+    /// * `NavinError::InvalidHash` - If any data_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If any shipment doesn't exist.
+    /// * `NavinError::Unauthorized` - If caller is neither the carrier of a listed shipment nor admin.
+    /// * `NavinError::RateLimitExceeded` - If any shipment was updated too recently (unless Admin).
+    /// * `NavinError::InvalidStatus` - If any transition is improperly sequenced.
     ///
     /// # Examples
     /// ```rust
-    /// // let version = contract.get_version(&env);
+    /// // contract.update_status_batch(&env, &carrier, &updates);
     /// ```
-    pub fn get_version(env: Env) -> Result<u32, NavinError> {
+    pub fn update_status_batch(
+        env: Env,
+        caller: Address,
+        updates: Vec<(u64, ShipmentStatus, BytesN<32>)>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        Ok(storage::get_version(&env))
-    }
+        require_not_paused(&env)?;
+        caller.require_auth();
 
-    /// Get the current hash algorithm version used for data verification.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    ///
-    /// # Returns
-    /// * `Result<u32, NavinError>` - The hash algorithm version constant.
-    pub fn get_hash_algo_version(env: Env) -> Result<u32, NavinError> {
-        require_initialized(&env)?;
-        Ok(DEFAULT_HASH_ALGO)
-    }
+        if updates.len() > config::get_config(&env).batch_operation_limit {
+            return Err(NavinError::BatchTooLarge);
+        }
 
-    /// Get the token decimals policy expected by escrow math normalization.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    ///
-    /// # Returns
-    /// * `Result<u32, NavinError>` - Expected token decimals (7).
-    pub fn get_expected_token_decimals(env: Env) -> Result<u32, NavinError> {
-        require_initialized(&env)?;
-        Ok(crate::types::EXPECTED_TOKEN_DECIMALS)
+        let admin = storage::get_admin(&env);
+        let mut shipments: Vec<Shipment> = Vec::new(&env);
+        for (shipment_id, new_status, data_hash) in updates.iter() {
+            validation::validate_hash(&data_hash)?;
+            if shipments.iter().any(|shipment| shipment.id == shipment_id) {
+                return Err(NavinError::InvalidShipmentInput);
+            }
+            let shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            validate_status_update(&env, &caller, &admin, &shipment, &new_status, &data_hash)?;
+            shipments.push_back(shipment);
+        }
+
+        for ((_, new_status, data_hash), mut shipment) in updates.iter().zip(shipments.iter()) {
+            apply_status_update(&env, &caller, &mut shipment, &new_status, &data_hash)?;
+        }
+
+        Ok(())
     }
 
-    /// Get on-chain metadata for this contract.
-    /// Returns version, admin, shipment count, and initialization status.
-    /// Read-only — no authentication required.
+    /// Returns the current escrowed amount for a specific shipment.
+    /// Returns 0 if no escrow has been deposited.
+    /// Returns ShipmentNotFound if the shipment does not exist.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<ContractMetadata, NavinError>` - Snapshot of contract metadata.
+    /// * `Result<i128, NavinError>` - Amount stored in escrow.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
     ///
     /// # Examples
     /// ```rust
-    /// // let metadata = contract.get_contract_metadata(&env);
+    /// // let balance = contract.get_escrow_balance(&env, 1);
     /// ```
-    pub fn get_contract_metadata(env: Env) -> Result<ContractMetadata, NavinError> {
+    pub fn get_escrow_balance(env: Env, shipment_id: u64) -> Result<i128, NavinError> {
         require_initialized(&env)?;
-        Ok(ContractMetadata {
-            version: storage::get_version(&env),
-            admin: storage::get_admin(&env),
-            shipment_count: storage::get_shipment_counter(&env),
-            initialized: true,
-            hash_algo_version: DEFAULT_HASH_ALGO,
-        })
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_escrow_balance(&env, shipment_id))
     }
 
-    /// Get the current shipment counter.
+    /// Get the recorded status transitions for a shipment, oldest first.
+    /// Each entry is `(status, timestamp, actor)`; at most `MAX_STATUS_HISTORY`
+    /// entries are kept, the oldest being dropped first.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<u64, NavinError>` - The total number of shipments created.
+    /// * `Result<Vec<(ShipmentStatus, u64, Address)>, NavinError>` - Transition history.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
     ///
     /// # Examples
     /// ```rust
-    /// // let count = contract.get_shipment_counter(&env);
+    /// // let history = contract.get_status_history(&env, 1);
     /// ```
-    pub fn get_shipment_counter(env: Env) -> Result<u64, NavinError> {
+    pub fn get_status_history(
+        env: Env,
+        shipment_id: u64,
+    ) -> Result<Vec<(ShipmentStatus, u64, Address)>, NavinError> {
         require_initialized(&env)?;
-        Ok(storage::get_shipment_counter(&env))
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_status_history(&env, shipment_id))
     }
 
-    /// Get aggregated analytics for the contract.
+    /// Get the latest structured escrow freeze reason for a shipment, if present.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<Analytics, NavinError>` - Aggregated analytics data.
-    ///
-    /// # Errors
-    /// * `NavinError::NotInitialized` - If contract is not initialized.
-    pub fn get_analytics(env: Env) -> Result<Analytics, NavinError> {
+    /// * `Result<Option<EscrowFreezeReason>, NavinError>` - Latest freeze reason code.
+    pub fn get_escrow_freeze_reason(
+        env: Env,
+        shipment_id: u64,
+    ) -> Result<Option<EscrowFreezeReason>, NavinError> {
         require_initialized(&env)?;
-
-        Ok(Analytics {
-            total_shipments: storage::get_shipment_counter(&env),
-            total_escrow_volume: storage::get_total_escrow_volume(&env),
-            total_disputes: storage::get_total_disputes(&env),
-            created_count: storage::get_status_count(&env, &ShipmentStatus::Created),
-            in_transit_count: storage::get_status_count(&env, &ShipmentStatus::InTransit),
-            at_checkpoint_count: storage::get_status_count(&env, &ShipmentStatus::AtCheckpoint),
-            delivered_count: storage::get_status_count(&env, &ShipmentStatus::Delivered),
-            disputed_count: storage::get_status_count(&env, &ShipmentStatus::Disputed),
-            cancelled_count: storage::get_status_count(&env, &ShipmentStatus::Cancelled),
-        })
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_escrow_freeze_reason(&env, shipment_id))
     }
 
-    /// Retrieve a compact summary of shipment counts aggregated by status.
+    /// Get a settlement record by ID.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `settlement_id` - The ID of the settlement.
     ///
     /// # Returns
-    /// * `Result<ShipmentStatusSummary, NavinError>` - Summary of counts for all statuses.
+    /// * `Result<SettlementRecord, NavinError>` - The settlement record.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    pub fn get_status_summary(env: Env) -> Result<ShipmentStatusSummary, NavinError> {
+    /// * `NavinError::ShipmentNotFound` - If settlement doesn't exist (reusing error).
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let settlement = contract.get_settlement(&env, 1);
+    /// ```
+    pub fn get_settlement(env: Env, settlement_id: u64) -> Result<SettlementRecord, NavinError> {
         require_initialized(&env)?;
-        Ok(ShipmentStatusSummary {
-            created: storage::get_status_count(&env, &ShipmentStatus::Created),
-            in_transit: storage::get_status_count(&env, &ShipmentStatus::InTransit),
-            at_checkpoint: storage::get_status_count(&env, &ShipmentStatus::AtCheckpoint),
-            partially_delivered: storage::get_status_count(
-                &env,
-                &ShipmentStatus::PartiallyDelivered,
-            ),
-            delivered: storage::get_status_count(&env, &ShipmentStatus::Delivered),
-            disputed: storage::get_status_count(&env, &ShipmentStatus::Disputed),
-            cancelled: storage::get_status_count(&env, &ShipmentStatus::Cancelled),
-        })
+        storage::get_settlement(&env, settlement_id).ok_or(NavinError::ShipmentNotFound)
     }
 
-    /// Retrieve the total number of non-terminal shipments currently tracked.
-    ///
-    /// Non-terminal shipments are those in one of the following states:
-    /// 'Created', 'InTransit', 'AtCheckpoint', or 'PartiallyDelivered'.
+    /// Get the active settlement ID for a shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `shipment_id` - The ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<u64, NavinError>` - Total count of active (non-terminal) shipments.
+    /// * `Result<Option<u64>, NavinError>` - The active settlement ID if one exists.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    pub fn get_non_terminal_count(env: Env) -> Result<u64, NavinError> {
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let active_id = contract.get_active_settlement(&env, 1);
+    /// ```
+    pub fn get_active_settlement(env: Env, shipment_id: u64) -> Result<Option<u64>, NavinError> {
         require_initialized(&env)?;
-        let count = storage::get_status_count(&env, &ShipmentStatus::Created)
-            + storage::get_status_count(&env, &ShipmentStatus::InTransit)
-            + storage::get_status_count(&env, &ShipmentStatus::AtCheckpoint)
-            + storage::get_status_count(&env, &ShipmentStatus::PartiallyDelivered)
-            + storage::get_status_count(&env, &ShipmentStatus::Disputed);
-        Ok(count)
+        Ok(storage::get_active_settlement(&env, shipment_id))
     }
 
-    /// Get the deterministic SHA-256 checksum of critical config fields.
-    ///
-    /// This query exposes the config checksum to help indexers and operators
-    /// detect unintended configuration drift. The checksum is computed from
-    /// all config fields serialized in a fixed order and is automatically
-    /// updated whenever the config changes.
-    ///
-    /// # Serialization Order
-    /// Fields are serialized in declaration order:
-    /// 1. shipment_ttl_threshold (u32)
-    /// 2. shipment_ttl_extension (u32)
-    /// 3. min_status_update_interval (u64)
-    /// 4. batch_operation_limit (u32)
-    /// 5. max_metadata_entries (u32)
-    /// 6. default_shipment_limit (u32)
-    /// 7. multisig_min_admins (u32)
-    /// 8. multisig_max_admins (u32)
-    /// 9. proposal_expiry_seconds (u64)
-    /// 10. deadline_grace_seconds (u64)
+    /// Get the total number of settlements created.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
     ///
     /// # Returns
-    /// * `Result<BytesN<32>, NavinError>` - The SHA-256 checksum of the config.
-    ///
-    /// # Errors
-    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `u64` - The total settlement count.
     ///
     /// # Examples
     /// ```rust
-    /// // let checksum = contract.get_config_checksum(&env)?;
-    /// // Indexer can verify: checksum == sha256(serialized_config)
+    /// // let count = contract.get_settlement_count(&env);
     /// ```
-    pub fn get_config_checksum(env: Env) -> Result<BytesN<32>, NavinError> {
-        require_initialized(&env)?;
-
-        // Retrieve stored checksum, or compute it if not yet stored
-        match config::get_config_checksum(&env) {
-            Some(checksum) => Ok(checksum),
-            None => {
-                // Fallback: compute checksum from current config
-                let current_config = config::get_config(&env);
-                Ok(config::compute_config_checksum(&current_config, &env))
-            }
-        }
+    pub fn get_settlement_count(env: Env) -> u64 {
+        storage::get_settlement_counter(&env)
     }
 
-    /// Compute the idempotency key for a shipment event.
-    ///
-    /// This helper enables off-chain indexers to recompute the same idempotency
-    /// key that the contract emits in events. The key is used to deduplicate
-    /// events during indexing and to protect against duplicate submissions of
-    /// high-impact operations (e.g., dispute resolution).
-    ///
-    /// Canonical serialization order:
-    /// 1. `shipment_id` as big-endian u64 (8 bytes)
-    /// 2. `event_type` as XDR-encoded Symbol (variable-length)
-    /// 3. `event_counter` as big-endian u32 (4 bytes)
-    ///
-    /// The concatenated byte vector is hashed with SHA-256 to produce a
-    /// 32-byte idempotency key.
+    /// Returns the total number of shipments created on the platform.
+    /// Returns 0 if the contract has not been initialized.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - The shipment identifier.
-    /// * `event_type` - The event type symbol (e.g., "shipment_created").
-    /// * `event_counter` - The per-shipment event counter value.
     ///
     /// # Returns
-    /// * `BytesN<32>` - The idempotency key.
+    /// * `u64` - Overall total shipments registered.
     ///
     /// # Examples
     /// ```rust
-    /// let key = contract.compute_idempotency_key(&env, 1, Symbol::new(&env, "shipment_created"), 1);
+    /// // let total = contract.get_shipment_count(&env);
     /// ```
-    pub fn compute_idempotency_key(
-        env: Env,
-        shipment_id: u64,
-        event_type: Symbol,
-        event_counter: u32,
-    ) -> BytesN<32> {
-        use soroban_sdk::Bytes;
-
-        let mut payload = Bytes::new(&env);
-
-        // Domain prefix (HASH_DOMAIN_SHIPMENT = 0x01)
-        let domain = crate::event_topics::HASH_DOMAIN_SHIPMENT;
-        let domain_bytes = domain.to_be_bytes();
-        let domain_len = (domain_bytes.len() as u32).to_be_bytes();
-        payload.append(&Bytes::from_array(&env, &domain_len));
-        payload.append(&Bytes::from_slice(&env, &domain_bytes));
+    pub fn get_shipment_count(env: Env) -> u64 {
+        storage::get_shipment_counter(&env)
+    }
 
-        // Shipment ID (raw bytes)
-        payload.append(&Bytes::from_array(&env, &shipment_id.to_be_bytes()));
+    /// Fetch multiple shipments in one call while preserving input order.
+    ///
+    /// Returns `None` for unknown IDs instead of failing the entire request.
+    pub fn get_shipments_batch(
+        env: Env,
+        shipment_ids: Vec<u64>,
+    ) -> Result<Vec<Option<Shipment>>, NavinError> {
+        require_initialized(&env)?;
 
-        // Event type: use the same XDR encoding as generate_idempotency_key
-        // (as_bytes() of the &str produces the same result as XDR for symbol strings)
-        payload.append(&event_type.clone().to_xdr(&env));
+        let max_batch = effective_batch_query_limit(&env);
+        if shipment_ids.len() > max_batch {
+            return Err(NavinError::BatchTooLarge);
+        }
 
-        // Event counter (raw bytes)
-        payload.append(&Bytes::from_array(&env, &event_counter.to_be_bytes()));
+        let mut results = Vec::new(&env);
+        for shipment_id in shipment_ids.iter() {
+            results.push_back(storage::get_shipment(&env, shipment_id));
+        }
 
-        env.crypto().sha256(&payload).into()
+        Ok(results)
     }
 
-    /// Add a carrier to a company's whitelist.
-    /// Only the company can add carriers to their own whitelist.
+    /// Fetch escrow balances for multiple shipments in one call.
+    ///
+    /// Results preserve input order; unknown or unfunded shipments report 0.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `company` - The company's address acting as caller.
-    /// * `carrier` - The carrier address to whitelist.
+    /// * `shipment_ids` - IDs of the shipments to look up.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully registered.
+    /// * `Result<Vec<i128>, NavinError>` - One balance per requested ID.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::BatchTooLarge` - If more IDs than the batch query limit are requested.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.add_carrier_to_whitelist(&env, &company, &carrier);
+    /// // let balances = contract.get_escrow_balances(&env, ids);
     /// ```
-    pub fn add_carrier_to_whitelist(
-        env: Env,
-        company: Address,
-        carrier: Address,
-    ) -> Result<(), NavinError> {
+    pub fn get_escrow_balances(env: Env, shipment_ids: Vec<u64>) -> Result<Vec<i128>, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        company.require_auth();
-        require_role(&env, &company, Role::Company)?;
 
-        // Issue #539 — reject duplicate whitelist additions. Without this
-        // check the storage write is silently idempotent and emits a
-        // spurious `add_wl` event, making it impossible for off-chain
-        // monitors to distinguish a re-add from a first-time add.
-        if storage::is_carrier_whitelisted(&env, &company, &carrier) {
-            return Err(NavinError::CarrierAlreadyWhitelisted);
+        let max_batch = effective_batch_query_limit(&env);
+        if shipment_ids.len() > max_batch {
+            return Err(NavinError::BatchTooLarge);
         }
 
-        storage::add_carrier_to_whitelist(&env, &company, &carrier);
+        let mut results = Vec::new(&env);
+        for shipment_id in shipment_ids.iter() {
+            results.push_back(storage::get_escrow_balance(&env, shipment_id));
+        }
 
-        env.events().publish(
-            (symbol_short!("add_wl"),),
-            (company.clone(), carrier.clone()),
-        );
+        Ok(results)
+    }
 
-        Ok(())
+    /// Filter shipments by sender with optional offset pagination.
+    pub fn get_shipments_by_sender(
+        env: Env,
+        sender: Address,
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
+        Self::get_shipments_by_sender_page(env, sender, 0, limit)
     }
 
-    /// Remove a carrier from a company's whitelist.
-    /// Only the company can remove carriers from their own whitelist.
+    /// Filter shipments by sender with offset pagination.
+    pub fn get_shipments_by_sender_page(
+        env: Env,
+        sender: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
+        require_initialized(&env)?;
+        let max_batch = effective_batch_query_limit(&env);
+        if limit == 0 || limit > max_batch {
+            return Err(NavinError::InvalidConfig);
+        }
+
+        let mut matched = Vec::new(&env);
+        let mut skipped = 0_u32;
+        let mut collected = 0_u32;
+        let total_shipments = storage::get_shipment_counter(&env);
+
+        for shipment_id in 1..=total_shipments {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                if shipment.sender != sender {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                matched.push_back(shipment);
+                collected = collected.saturating_add(1);
+                if collected >= limit {
+                    break;
+                }
+            }
+        }
+
+        Ok(matched)
+    }
+
+    /// Walk shipments in id order starting at `start_id`, returning up to
+    /// `limit` of them. Ids with no stored shipment (never created, archived
+    /// or compacted) are skipped rather than failing the call, so indexers
+    /// can page through the whole contract by resuming after the last id seen.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `company` - The company address removing the carrier.
-    /// * `carrier` - The carrier address to be removed.
+    /// * `start_id` - First shipment id to consider.
+    /// * `limit` - Maximum shipments to return, capped at `batch_operation_limit`.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully removed.
+    /// * `Result<Vec<Shipment>, NavinError>` - Shipments in ascending id order.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.remove_carrier_from_whitelist(&env, &company, &carrier);
+    /// // let page = contract.get_shipments_page(&env, 1, 20);
     /// ```
-    pub fn remove_carrier_from_whitelist(
+    pub fn get_shipments_page(
         env: Env,
-        company: Address,
-        carrier: Address,
-    ) -> Result<(), NavinError> {
+        start_id: u64,
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        company.require_auth();
-        require_role(&env, &company, Role::Company)?;
+        let limit = limit.min(config::get_config(&env).batch_operation_limit);
 
-        storage::remove_carrier_from_whitelist(&env, &company, &carrier);
+        let mut page = Vec::new(&env);
+        let total_shipments = storage::get_shipment_counter(&env);
 
-        env.events().publish(
-            (symbol_short!("rm_wl"),),
-            (company.clone(), carrier.clone()),
-        );
+        for shipment_id in start_id.max(1)..=total_shipments {
+            if page.len() >= limit {
+                break;
+            }
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                page.push_back(shipment);
+            }
+        }
 
-        Ok(())
+        Ok(page)
     }
 
-    /// Check if a carrier is whitelisted for a company.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    /// * `company` - The company address.
-    /// * `carrier` - The carrier address in question.
+    /// Return ids of shipments whose `created_at` falls within
+    /// `[start_ts, end_ts]`, oldest first, up to `max` results.
     ///
-    /// # Returns
-    /// * `Result<bool, NavinError>` - True if the carrier is whitelisted.
+    /// This is a linear scan over shipment ids, suited to modest volumes.
+    /// Ids are assigned in creation order, so the scan stops at the first
+    /// shipment created after `end_ts`.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    ///
-    /// # Examples
-    /// ```rust
-    /// // let is_whitelisted = contract.is_carrier_whitelisted(&env, &company, &carrier);
-    /// ```
-    pub fn is_carrier_whitelisted(
+    /// * `NavinError::InvalidConfig` - If `max` is 0 or above the batch limit,
+    ///   or `start_ts > end_ts`.
+    pub fn get_shipments_in_range(
+        env: Env,
+        start_ts: u64,
+        end_ts: u64,
+        max: u32,
+    ) -> Result<Vec<u64>, NavinError> {
+        require_initialized(&env)?;
+        let max_batch = effective_batch_query_limit(&env);
+        if max == 0 || max > max_batch || start_ts > end_ts {
+            return Err(NavinError::InvalidConfig);
+        }
+
+        let mut matched = Vec::new(&env);
+        let total_shipments = storage::get_shipment_counter(&env);
+
+        for shipment_id in 1..=total_shipments {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                if shipment.created_at > end_ts {
+                    break;
+                }
+                if shipment.created_at < start_ts {
+                    continue;
+                }
+                matched.push_back(shipment_id);
+                if matched.len() >= max {
+                    break;
+                }
+            }
+        }
+
+        Ok(matched)
+    }
+
+    /// Filter shipments by carrier with optional offset pagination.
+    pub fn get_shipments_by_carrier(
         env: Env,
-        company: Address,
         carrier: Address,
-    ) -> Result<bool, NavinError> {
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
+        Self::get_shipments_by_carrier_page(env, carrier, 0, limit)
+    }
+
+    /// Filter shipments by carrier with offset pagination.
+    pub fn get_shipments_by_carrier_page(
+        env: Env,
+        carrier: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
         require_initialized(&env)?;
+        let max_batch = effective_batch_query_limit(&env);
+        if limit == 0 || limit > max_batch {
+            return Err(NavinError::InvalidConfig);
+        }
 
-        Ok(storage::is_carrier_whitelisted(&env, &company, &carrier))
+        let mut matched = Vec::new(&env);
+        let mut skipped = 0_u32;
+        let mut collected = 0_u32;
+        let total_shipments = storage::get_shipment_counter(&env);
+
+        for shipment_id in 1..=total_shipments {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                if shipment.carrier != carrier {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                matched.push_back(shipment);
+                collected = collected.saturating_add(1);
+                if collected >= limit {
+                    break;
+                }
+            }
+        }
+
+        Ok(matched)
     }
 
-    /// Returns the role assigned to a given address.
-    /// Returns Role::Unassigned if no role is assigned.
+    /// List IDs of shipments created by a company, in creation order, from the
+    /// per-company index. `limit` is capped at `batch_operation_limit`.
+    ///
+    /// Only shipments created while `index_company_shipments` was enabled are
+    /// indexed. Shipments transferred to the company are appended when received
+    /// and remain listed under the previous owner.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `address` - The address to check.
+    /// * `company` - Company whose shipments to list.
+    /// * `start` - Number of indexed shipments to skip.
+    /// * `limit` - Maximum number of IDs to return.
     ///
     /// # Returns
-    /// * `Result<Role, NavinError>` - The role assigned to the address.
+    /// * `Result<Vec<u64>, NavinError>` - A page of shipment IDs.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::CompanyIndexDisabled` - If the per-company index is disabled.
     ///
     /// # Examples
     /// ```rust
-    /// // let role = contract.get_role(&env, &address);
+    /// // let page = contract.get_shipments_by_company(&env, &company, 0, 20);
     /// ```
-    pub fn get_role(env: Env, address: Address) -> Result<Role, NavinError> {
+    pub fn get_shipments_by_company(
+        env: Env,
+        company: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, NavinError> {
         require_initialized(&env)?;
-        Ok(storage::get_role(&env, &address).unwrap_or(Role::Unassigned))
+        let config = config::get_config(&env);
+        if !config.index_company_shipments {
+            return Err(NavinError::CompanyIndexDisabled);
+        }
+
+        let ids = storage::get_company_shipments(&env, &company);
+        let end = start
+            .saturating_add(limit.min(config.batch_operation_limit))
+            .min(ids.len());
+        if start >= end {
+            return Ok(Vec::new(&env));
+        }
+        Ok(ids.slice(start..end))
     }
 
-    /// Allow admin to grant Company role.
+    /// List IDs of shipments currently assigned to a carrier, from the
+    /// per-carrier index. `limit` is capped at `batch_operation_limit`.
+    ///
+    /// Unlike `get_shipments_by_carrier`, this reads the index instead of
+    /// scanning every shipment. Handoffs move the shipment to the new
+    /// carrier's list, so reassigned shipments no longer appear under the
+    /// previous carrier.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the role grant.
-    /// * `company` - The address receiving the company role.
+    /// * `carrier` - Carrier whose shipments to list.
+    /// * `start` - Number of indexed shipments to skip.
+    /// * `limit` - Maximum number of IDs to return.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    /// * `Result<Vec<u64>, NavinError>` - A page of shipment IDs.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by a non-admin.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.add_company(&env, &admin, &new_company_addr);
+    /// // let page = contract.get_carrier_shipment_ids(&env, &carrier, 0, 20);
     /// ```
-    pub fn add_company(env: Env, admin: Address, company: Address) -> Result<(), NavinError> {
+    pub fn get_carrier_shipment_ids(
+        env: Env,
+        carrier: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        require_admin_or_operator(&env, &admin)?;
+        let max = config::get_config(&env).batch_operation_limit;
 
-        if storage::has_role(&env, &company, &Role::Company) {
-            return Err(NavinError::RoleAlreadyAssigned);
+        let ids = storage::get_carrier_shipments(&env, &carrier);
+        let end = start.saturating_add(limit.min(max)).min(ids.len());
+        if start >= end {
+            return Ok(Vec::new(&env));
         }
-
-        storage::set_company_role(&env, &company);
-
-        // Emit role history event
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Assigned,
-            &admin,
-            &company,
-            &Role::Company,
-        );
-
-        Ok(())
+        Ok(ids.slice(start..end))
     }
 
-    /// Allow admin to grant Carrier role.
+    /// List IDs of `Disputed` shipments assigned to a carrier, with offset pagination.
+    ///
+    /// The contract keeps no dispute or carrier index, so this scans shipments
+    /// in ID order, like the other paged filters.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the role grant.
-    /// * `carrier` - The address receiving the carrier role.
+    /// * `carrier` - Carrier whose disputes to list.
+    /// * `start` - Number of matching shipments to skip.
+    /// * `limit` - Maximum number of IDs to return (1..=batch query limit).
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful role assignment.
+    /// * `Result<Vec<u64>, NavinError>` - Disputed shipment IDs in ascending order.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by a non-admin.
+    /// * `NavinError::InvalidConfig` - If `limit` is zero or exceeds the batch query limit.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.add_carrier(&env, &admin, &new_carrier_addr);
+    /// // let disputed = contract.get_carrier_disputes(&env, &carrier, 0, 20);
     /// ```
-    pub fn add_carrier(env: Env, admin: Address, carrier: Address) -> Result<(), NavinError> {
+    pub fn get_carrier_disputes(
+        env: Env,
+        carrier: Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        require_admin_or_operator(&env, &admin)?;
-
-        if storage::has_role(&env, &carrier, &Role::Carrier) {
-            return Err(NavinError::RoleAlreadyAssigned);
+        let max_batch = effective_batch_query_limit(&env);
+        if limit == 0 || limit > max_batch {
+            return Err(NavinError::InvalidConfig);
         }
 
-        storage::set_carrier_role(&env, &carrier);
+        let mut matched = Vec::new(&env);
+        let mut skipped = 0_u32;
+        let total_shipments = storage::get_shipment_counter(&env);
 
-        // Emit role history event
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Assigned,
-            &admin,
-            &carrier,
-            &Role::Carrier,
-        );
+        for shipment_id in 1..=total_shipments {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                if shipment.carrier != carrier || shipment.status != ShipmentStatus::Disputed {
+                    continue;
+                }
+                if skipped < start {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                matched.push_back(shipment_id);
+                if matched.len() >= limit {
+                    break;
+                }
+            }
+        }
 
-        Ok(())
+        Ok(matched)
     }
 
-    /// Allow admin to grant Guardian role.
+    /// List IDs of non-terminal shipments with no update for longer than
+    /// `inactivity_seconds`, with offset pagination.
+    ///
+    /// A shipment is stale when it is not `Delivered` or `Cancelled` and
+    /// `now - updated_at > inactivity_seconds`. Scans shipments in ID order.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the role grant.
-    /// * `guardian` - The address receiving the guardian role.
+    /// * `inactivity_seconds` - Idle time after which a shipment needs action.
+    /// * `start` - Number of matching shipments to skip.
+    /// * `limit` - Maximum number of IDs to return (1..=batch query limit).
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful role assignment.
-    pub fn add_guardian(env: Env, admin: Address, guardian: Address) -> Result<(), NavinError> {
+    /// * `Result<Vec<u64>, NavinError>` - Stale shipment IDs in ascending order.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidConfig` - If `limit` is zero or exceeds the batch query limit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let overdue = contract.get_stale_shipments(&env, 86_400, 0, 20);
+    /// ```
+    pub fn get_stale_shipments(
+        env: Env,
+        inactivity_seconds: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
+        let max_batch = effective_batch_query_limit(&env);
+        if limit == 0 || limit > max_batch {
+            return Err(NavinError::InvalidConfig);
         }
 
-        storage::set_role(&env, &guardian, &Role::Guardian);
+        let now = env.ledger().timestamp();
+        let mut matched = Vec::new(&env);
+        let mut skipped = 0_u32;
+        let total_shipments = storage::get_shipment_counter(&env);
 
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Assigned,
-            &admin,
-            &guardian,
-            &Role::Guardian,
-        );
+        for shipment_id in 1..=total_shipments {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                if matches!(
+                    shipment.status,
+                    ShipmentStatus::Delivered | ShipmentStatus::Cancelled
+                ) || now.saturating_sub(shipment.updated_at) <= inactivity_seconds
+                {
+                    continue;
+                }
+                if skipped < start {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                matched.push_back(shipment_id);
+                if matched.len() >= limit {
+                    break;
+                }
+            }
+        }
 
-        Ok(())
+        Ok(matched)
     }
 
-    /// Allow admin to grant Operator role.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the role grant.
-    /// * `operator` - The address receiving the operator role.
-    ///
-    /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful role assignment.
-    pub fn add_operator(env: Env, admin: Address, operator: Address) -> Result<(), NavinError> {
-        require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
+    /// Filter shipments by status with optional offset pagination.
+    pub fn get_shipments_by_status(
+        env: Env,
+        status: ShipmentStatus,
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
+        Self::get_shipments_by_status_page(env, status, 0, limit)
+    }
 
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
+    /// Filter shipments by status with offset pagination.
+    pub fn get_shipments_by_status_page(
+        env: Env,
+        status: ShipmentStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Shipment>, NavinError> {
+        require_initialized(&env)?;
+        let max_batch = effective_batch_query_limit(&env);
+        if limit == 0 || limit > max_batch {
+            return Err(NavinError::InvalidConfig);
         }
 
-        storage::set_role(&env, &operator, &Role::Operator);
+        let mut matched = Vec::new(&env);
+        let mut skipped = 0_u32;
+        let mut collected = 0_u32;
+        let total_shipments = storage::get_shipment_counter(&env);
 
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Assigned,
-            &admin,
-            &operator,
-            &Role::Operator,
-        );
+        for shipment_id in 1..=total_shipments {
+            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
+                if shipment.status != status {
+                    continue;
+                }
+                if skipped < offset {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                matched.push_back(shipment);
+                collected = collected.saturating_add(1);
+                if collected >= limit {
+                    break;
+                }
+            }
+        }
 
-        Ok(())
+        Ok(matched)
     }
 
-    /// Suspend a carrier from carrier-only operations.
+    /// Cursor-based search for shipment IDs by status.
     ///
-    /// Only the admin can call this function.
-    pub fn suspend_carrier(env: Env, admin: Address, carrier: Address) -> Result<(), NavinError> {
+    /// Results are returned in ascending shipment ID order for deterministic pagination.
+    /// `cursor` is the last seen shipment ID from a previous page.
+    pub fn search_shipments_by_status(
+        env: Env,
+        status: ShipmentStatus,
+        cursor: Option<u64>,
+        page_size: u32,
+    ) -> Result<ShipmentStatusCursorPage, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        require_admin_or_operator(&env, &admin)?;
 
-        storage::suspend_carrier(&env, &carrier);
-        events::emit_carrier_suspended(&env, &admin, &carrier);
-        Ok(())
-    }
+        let config = config::get_config(&env);
+        if page_size == 0 || page_size > config.batch_operation_limit {
+            return Err(NavinError::InvalidConfig);
+        }
 
-    /// Reactivate a previously suspended carrier.
-    ///
-    /// Only the admin can call this function.
-    pub fn reactivate_carrier(
-        env: Env,
-        admin: Address,
-        carrier: Address,
-    ) -> Result<(), NavinError> {
-        require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
+        let mut shipment_ids = Vec::new(&env);
+        let mut current_id = cursor.unwrap_or(0);
+        let total_shipments = storage::get_shipment_counter(&env);
+        let mut next_cursor = None;
 
-        require_admin_or_operator(&env, &admin)?;
+        while current_id < total_shipments {
+            current_id = current_id.saturating_add(1);
 
-        storage::reactivate_carrier(&env, &carrier);
-        events::emit_carrier_reactivated(&env, &admin, &carrier);
-        Ok(())
-    }
+            if let Some(shipment) = storage::get_shipment(&env, current_id) {
+                if shipment.status == status {
+                    shipment_ids.push_back(current_id);
+                    if shipment_ids.len() == page_size {
+                        if current_id < total_shipments {
+                            next_cursor = Some(current_id);
+                        }
+                        break;
+                    }
+                }
+            }
+        }
 
-    /// Return whether a carrier is currently suspended.
-    pub fn is_carrier_suspended(env: Env, carrier: Address) -> Result<bool, NavinError> {
-        require_initialized(&env)?;
-        Ok(storage::is_carrier_suspended(&env, &carrier))
+        Ok(ShipmentStatusCursorPage {
+            shipment_ids,
+            next_cursor,
+        })
     }
 
-    /// Revoke a previously assigned role from an address.
-    ///
-    /// Only the admin can revoke roles. The admin cannot revoke their own role;
-    /// use `transfer_admin` instead.
+    /// Get the event count for a shipment.
+    /// Returns the number of events emitted for this shipment.
+    /// Returns 0 for brand-new shipments or shipments with no events yet.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the revocation.
-    /// * `target` - The address whose role is being revoked.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful role revocation.
+    /// * `Result<u32, NavinError>` - The number of events emitted for this shipment.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by a non-admin.
-    /// * `NavinError::CannotSelfRevoke` - If admin tries to revoke their own role.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.revoke_role(&env, &admin, &target_addr);
+    /// // let event_count = contract.get_event_count(&env, 1);
     /// ```
-    pub fn revoke_role(env: Env, admin: Address, target: Address) -> Result<(), NavinError> {
+    pub fn get_event_count(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
-        }
-
-        if admin == target {
-            return Err(NavinError::CannotSelfRevoke);
-        }
-
-        let current_role = storage::get_role(&env, &target).unwrap_or(Role::Unassigned);
-
-        match current_role {
-            Role::Company => storage::revoke_role(&env, &target, &Role::Company),
-            Role::Carrier => storage::revoke_role(&env, &target, &Role::Carrier),
-            Role::Guardian => storage::revoke_role(&env, &target, &Role::Guardian),
-            Role::Operator => storage::revoke_role(&env, &target, &Role::Operator),
-            Role::Unassigned => {}
+        // Verify shipment exists
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
         }
-
-        events::emit_role_revoked(&env, &admin, &target, &current_role);
-
-        // Emit role history event for audit trail
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Revoked,
-            &admin,
-            &target,
-            &current_role,
-        );
-
-        Ok(())
+        Ok(storage::get_event_count(&env, shipment_id))
     }
 
-    /// Suspend a role temporarily (e.g., for investigation or compliance review).
-    ///
-    /// Only the admin can suspend roles. Suspended addresses retain their role
-    /// assignment but cannot perform role-gated actions until reactivated.
+    /// Archive a shipment by moving it from persistent to temporary storage.
+    /// This reduces state rent costs for completed shipments.
+    /// Only admin can archive, and shipment must be in a terminal state (Delivered or Cancelled).
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the suspension.
-    /// * `target` - The address whose role is being suspended.
+    /// * `admin` - Admin address performing the archival.
+    /// * `shipment_id` - ID of the shipment to archive.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful suspension.
+    /// * `Result<(), NavinError>` - Ok if successfully archived.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by a non-admin.
-    /// * `NavinError::CannotSelfRevoke` - If admin tries to suspend their own role.
+    /// * `NavinError::Unauthorized` - If caller is not the admin.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidStatus` - If shipment is not in a terminal state (Delivered or Cancelled).
     ///
     /// # Examples
     /// ```rust
-    /// // contract.suspend_role(&env, &admin, &target_addr);
+    /// // contract.archive_shipment(&env, &admin, 1);
     /// ```
-    pub fn suspend_role(env: Env, admin: Address, target: Address) -> Result<(), NavinError> {
+    pub fn archive_shipment(env: Env, admin: Address, shipment_id: u64) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
         admin.require_auth();
 
         if storage::get_admin(&env) != admin {
             return Err(NavinError::Unauthorized);
         }
 
-        if admin == target {
-            return Err(NavinError::CannotSelfRevoke);
-        }
-
-        let current_role = storage::get_role(&env, &target).unwrap_or(Role::Unassigned);
+        let shipment = storage::get_persistent_shipment(&env, shipment_id)
+            .ok_or(NavinError::ShipmentNotFound)?;
 
-        if current_role == Role::Unassigned {
-            return Err(NavinError::Unauthorized);
+        // Only allow archiving terminal state shipments
+        if shipment.status != ShipmentStatus::Delivered
+            && shipment.status != ShipmentStatus::Cancelled
+        {
+            return Err(NavinError::InvalidStatus);
         }
 
-        // Mark as suspended in storage
-        storage::suspend_role(&env, &target, &current_role);
+        // Archive the shipment (move from persistent to temporary storage)
+        storage::archive_shipment(&env, shipment_id, &shipment);
 
-        // Emit role history event
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Suspended,
-            &admin,
-            &target,
-            &current_role,
-        );
+        let timestamp = env.ledger().timestamp();
+        events::emit_shipment_archived(&env, shipment_id, timestamp);
 
         Ok(())
     }
 
-    /// Reactivate a previously suspended role.
-    ///
-    /// Only the admin can reactivate roles. This restores the address's
-    /// ability to perform role-gated actions.
+    /// Permanently replace a settled shipment with a compact `ArchivedShipment`
+    /// record. Anyone may call this once the shipment is finalized and has not
+    /// been updated for `archive_retention_seconds`, and holds no unclaimed
+    /// insurance reserve or dispute bond. The full shipment record is removed,
+    /// so `get_shipment` reports `ShipmentNotFound` afterwards.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the reactivation.
-    /// * `target` - The address whose role is being reactivated.
+    /// * `shipment_id` - ID of the shipment to archive.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful reactivation.
+    /// * `Result<(), NavinError>` - Ok if the shipment was archived.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by a non-admin or target not suspended.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist or is already archived.
+    /// * `NavinError::InvalidStatus` - If the shipment is not finalized or still holds
+    ///   an insurance reserve or dispute bond.
+    /// * `NavinError::ArchiveRetentionActive` - If the retention period has not elapsed.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.reactivate_role(&env, &admin, &target_addr);
+    /// // contract.archive_settled_shipment(&env, 1);
     /// ```
-    pub fn reactivate_role(env: Env, admin: Address, target: Address) -> Result<(), NavinError> {
+    pub fn archive_settled_shipment(env: Env, shipment_id: u64) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
 
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
-        }
-
-        let current_role = storage::get_role(&env, &target).unwrap_or(Role::Unassigned);
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        if current_role == Role::Unassigned {
-            return Err(NavinError::Unauthorized);
+        if !shipment.finalized {
+            return Err(NavinError::InvalidStatus);
         }
 
-        // Reactivate the role
-        storage::reactivate_role(&env, &target, &current_role);
-
-        // Emit role history event
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Reactivated,
-            &admin,
-            &target,
-            &current_role,
-        );
-
-        Ok(())
-    }
-
-    /// Suspend a company from creating or updating shipments.
-    pub fn suspend_company(env: Env, admin: Address, company: Address) -> Result<(), NavinError> {
-        require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        require_admin_or_operator(&env, &admin)?;
+        // Archiving removes the shipment, so funds still owed on it would be stranded.
+        if storage::get_insurance_reserve(&env, shipment_id) > 0
+            || storage::get_dispute_bond(&env, shipment_id).is_some()
+        {
+            return Err(NavinError::InvalidStatus);
+        }
 
-        if !storage::has_company_role(&env, &company) {
-            return Err(NavinError::Unauthorized);
+        let now = env.ledger().timestamp();
+        let retention = config::get_config(&env).archive_retention_seconds;
+        if now < shipment.updated_at.saturating_add(retention) {
+            return Err(NavinError::ArchiveRetentionActive);
         }
 
-        storage::suspend_company(&env, &company);
+        let record = ArchivedShipment {
+            id: shipment_id,
+            status: shipment.status,
+            total_escrow: shipment.total_escrow,
+            final_escrow: shipment.escrow_amount,
+            data_hash: shipment.data_hash,
+            creation_hash: storage::get_creation_hash(&env, shipment_id),
+            archived_at: now,
+        };
+        storage::compact_shipment(&env, &record);
 
-        // Emit role history event (reusing Reactive/Suspended for audit)
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Suspended,
-            &admin,
-            &company,
-            &Role::Company,
-        );
+        events::emit_shipment_archived(&env, shipment_id, now);
 
         Ok(())
     }
 
-    /// Reactivate a suspended company.
-    pub fn reactivate_company(
+    /// Get the compact record of a shipment archived via `archive_settled_shipment`.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the archived shipment.
+    ///
+    /// # Returns
+    /// * `Result<ArchivedShipment, NavinError>` - The compact archive record.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If no archive record exists for the shipment.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let record = contract.get_archived_shipment(&env, 1);
+    /// ```
+    pub fn get_archived_shipment(
         env: Env,
-        admin: Address,
-        company: Address,
-    ) -> Result<(), NavinError> {
+        shipment_id: u64,
+    ) -> Result<ArchivedShipment, NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        admin.require_auth();
-
-        require_admin_or_operator(&env, &admin)?;
-
-        storage::reactivate_company(&env, &company);
-
-        // Emit role history event
-        events::emit_role_changed(
-            &env,
-            &RoleChangeAction::Reactivated,
-            &admin,
-            &company,
-            &Role::Company,
-        );
-
-        Ok(())
+        storage::get_archive_record(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)
     }
 
-    /// Create a shipment and emit the shipment_created event.
+    /// Confirm delivery of a shipment.
+    /// Only the designated receiver can call this function.
+    /// Shipment must be in InTransit or AtCheckpoint status.
+    /// Stores the confirmation_hash (hash of proof-of-delivery data) and
+    /// transitions the shipment status to Delivered. With a non-zero
+    /// `release_delay_seconds`, the escrow is withheld until `finalize_release`.
+    /// With `auto_whitelist_on_delivery`, the carrier joins the sender's whitelist.
+    /// Deliveries after the deadline pay `late_delivery_penalty_bps` of the
+    /// escrow to the receiver before the carrier is paid.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `sender` - Company address creating the shipment.
-    /// * `receiver` - Destination address for the shipment.
-    /// * `carrier` - Carrier address assigned to the shipment.
-    /// * `data_hash` - Off-chain data hash of shipment details.
-    /// * `payment_milestones` - Schedule for escrow releases based on checkpoints.
-    /// * `deadline` - Timestamp after which shipment is considered expired and can be auto-cancelled.
+    /// * `receiver` - Receiver address confirming the delivery.
+    /// * `shipment_id` - Identifier of delivered shipment.
+    /// * `confirmation_hash` - The proof-of-delivery hash.
     ///
     /// # Returns
-    /// * `Result<u64, NavinError>` - Newly created shipment ID.
+    /// * `Result<DeliveryReceipt, NavinError>` - Receipt with the escrow released to the carrier.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller isn't a Company.
-    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
-    /// * `NavinError::MilestoneSumInvalid` - If milestone percentages do not equal 100%.
-    /// * `NavinError::CounterOverflow` - If total shipment count overflows max u64.
-    /// * `NavinError::InvalidTimestamp` - If the deadline is not strictly in the future.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If called by an address other than the shipment receiver.
+    /// * `NavinError::InvalidHash` - If confirmation_hash is all zeros.
+    /// * `NavinError::InvalidStatus` - If shipment is not in a transitable status to Delivered.
     ///
     /// # Examples
-    ///
+    /// ```rust
     /// ```rust,no_run
     /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
     /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient};
+    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
     /// # let env = Env::default();
     /// # env.mock_all_auths();
     /// # let contract_id = env.register(NavinShipment, ());
     /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// let sender = admin.clone();
-    /// let receiver = Address::generate(&env);
-    /// let carrier = Address::generate(&env);
-    /// let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// let milestones: Vec<(Symbol, u32)> = Vec::new(&env); // no milestone splits
-    /// let deadline = env.ledger().timestamp() + 86_400; // 1 day from now
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// # let carrier = Address::generate(&env);
+    /// # client.add_carrier(&admin, &carrier);
+    /// # let receiver = Address::generate(&env);
+    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    /// # let deadline = env.ledger().timestamp() + 86_400;
+    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
+    /// # client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &BytesN::from_array(&env, &[2u8; 32]));
+    /// let pod_hash = BytesN::from_array(&env, &[3u8; 32]); // SHA-256 of proof-of-delivery doc
     ///
-    /// let shipment_id = client.create_shipment(
-    ///     &sender, &receiver, &carrier, &data_hash, &milestones, &deadline,
-    /// );
-    /// assert_eq!(shipment_id, 1);
+    /// // Receiver confirms delivery; escrow is automatically released to the carrier.
+    /// let receipt = client.confirm_delivery(&receiver, &shipment_id, &pod_hash);
+    /// assert_eq!(receipt.carrier, carrier);
     /// ```
-    pub fn create_shipment(
+    pub fn confirm_delivery(
         env: Env,
-        sender: Address,
         receiver: Address,
-        carrier: Address,
-        data_hash: BytesN<32>,
-        payment_milestones: Vec<(Symbol, u32)>,
-        deadline: u64,
-    ) -> Result<u64, NavinError> {
+        shipment_id: u64,
+        confirmation_hash: BytesN<32>,
+    ) -> Result<DeliveryReceipt, NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        sender.require_auth();
-        require_role(&env, &sender, Role::Company)?;
-        validate_milestones(&env, &payment_milestones)?;
-        validate_hash(&data_hash)?;
+        receiver.require_auth();
+        with_reentrancy_lock(&env, || {
+            // Validate hash before storage
+            validation::validate_hash(&confirmation_hash)?;
 
-        // Idempotency: reject duplicate (sender, data_hash) within the window.
-        let mut payload = soroban_sdk::Bytes::new(&env);
-        payload.append(&sender.clone().to_xdr(&env));
-        payload.append(&data_hash.clone().into());
-        check_idempotency(&env, payload)?;
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        let now = env.ledger().timestamp();
-        if deadline <= now {
-            return Err(NavinError::InvalidTimestamp);
-        }
+            // Only the designated receiver can confirm delivery
+            if shipment.receiver != receiver {
+                return Err(NavinError::Unauthorized);
+            }
+            require_not_finalized(&shipment)?;
 
-        // Check company active shipment limit
-        let current_active = storage::get_active_shipment_count(&env, &sender);
-        let limit = storage::get_effective_shipment_limit(&env, &sender);
-        if current_active >= limit {
-            return Err(NavinError::ShipmentLimitReached);
-        }
+            // Validate transition to Delivered
+            crate::validate_shipment_transition(&shipment.status, &ShipmentStatus::Delivered)?;
 
-        // Check per-company creation quota window (issue #296).
-        check_and_update_creation_quota(&env, &sender)?;
+            let now = env.ledger().timestamp();
+            let old_status = shipment.status.clone();
+            shipment.status = ShipmentStatus::Delivered;
+            shipment.updated_at = now;
 
-        let shipment_id = storage::get_shipment_counter(&env)
-            .checked_add(1)
-            .ok_or(NavinError::CounterOverflow)?;
+            storage::decrement_status_count(&env, &old_status);
+            storage::increment_status_count(&env, &ShipmentStatus::Delivered);
+            storage::push_status_history(&env, shipment_id, &ShipmentStatus::Delivered, &receiver);
+            storage::set_confirmation_hash(&env, shipment_id, &confirmation_hash);
+            storage::decrement_active_shipment_count(&env, &shipment.sender);
+            extend_shipment_ttl(&env, shipment_id);
 
-        let shipment = Shipment {
-            id: shipment_id,
-            sender: sender.clone(),
-            receiver: receiver.clone(),
-            carrier,
-            data_hash: data_hash.clone(),
-            status: ShipmentStatus::Created,
-            created_at: now,
-            updated_at: now,
-            escrow_amount: 0,
-            total_escrow: 0,
-            payment_milestones,
-            paid_milestones: Vec::new(&env),
-            milestones_completed: Vec::new(&env),
-            metadata: None,
-            deadline,
-            integration_nonce: 0,
-            finalized: false,
-        };
+            apply_late_delivery_penalty(&env, &mut shipment, now)?;
+            track_checkpoint_dwell(&env, &shipment, &old_status, now);
 
-        persist_shipment(&env, &shipment)?;
-        storage::set_shipment_counter(&env, shipment_id);
-        storage::increment_status_count(&env, &ShipmentStatus::Created);
-        storage::increment_active_shipment_count(&env, &sender);
-        extend_shipment_ttl(&env, shipment_id);
+            let remaining_escrow = shipment.escrow_amount;
+            let release_delay = config::get_config(&env).escrow_policy.release_delay_seconds;
+            if release_delay > 0 && remaining_escrow > 0 {
+                // Withhold the transfer until the cooling-off period elapses; see `finalize_release`.
+                storage::set_release_at(&env, shipment_id, now.saturating_add(release_delay));
+            } else {
+                internal_release_escrow(&env, &mut shipment, remaining_escrow)?;
+            }
+            let released_amount = checked_sub_i128(remaining_escrow, shipment.escrow_amount)?;
 
-        events::emit_shipment_created(&env, shipment_id, &sender, &receiver, &data_hash);
-        events::emit_notification(
-            &env,
-            &receiver,
-            NotificationType::ShipmentCreated,
-            shipment_id,
-            &data_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.carrier,
-            NotificationType::ShipmentCreated,
-            shipment_id,
-            &data_hash,
-        );
+            finalize_if_settled(&env, &mut shipment);
+            persist_shipment(&env, &shipment)?;
 
-        Ok(shipment_id)
+            events::emit_delivery_confirmed(&env, shipment_id, &receiver, &confirmation_hash);
+
+            let config = config::get_config(&env);
+            if config.carrier_policy.auto_whitelist_on_delivery
+                && !storage::is_carrier_whitelisted(&env, &shipment.sender, &shipment.carrier)
+                && storage::get_whitelist_size(&env, &shipment.sender) < config.max_whitelist_size
+            {
+                storage::add_carrier_to_whitelist(&env, &shipment.sender, &shipment.carrier);
+                env.events().publish(
+                    (symbol_short!("add_wl"),),
+                    (shipment.sender.clone(), shipment.carrier.clone()),
+                );
+            }
+
+            // Reputation: record successful delivery for the carrier
+            events::emit_delivery_success(&env, &shipment.carrier, shipment_id, now);
+
+            let total_milestones = shipment.payment_milestones.len();
+            let milestones_hit = shipment.paid_milestones.len();
+            events::emit_carrier_milestone_rate(
+                &env,
+                &shipment.carrier,
+                shipment_id,
+                milestones_hit,
+                total_milestones,
+            );
+
+            let on_time = now <= shipment.deadline;
+            storage::record_carrier_delivery(&env, &shipment.carrier, on_time);
+            if !on_time {
+                events::emit_carrier_late_delivery(
+                    &env,
+                    &shipment.carrier,
+                    shipment_id,
+                    shipment.deadline,
+                    now,
+                );
+            } else {
+                events::emit_carrier_on_time_delivery(&env, &shipment.carrier, shipment_id);
+            }
+
+            events::emit_notification(
+                &env,
+                &shipment.sender,
+                NotificationType::DeliveryConfirmed,
+                shipment_id,
+                &confirmation_hash,
+            );
+            notify_extra_recipients(
+                &env,
+                &shipment.sender,
+                NotificationType::DeliveryConfirmed,
+                shipment_id,
+                &confirmation_hash,
+            );
+            events::emit_notification(
+                &env,
+                &shipment.carrier,
+                NotificationType::DeliveryConfirmed,
+                shipment_id,
+                &confirmation_hash,
+            );
+
+            Ok(DeliveryReceipt {
+                shipment_id,
+                released_amount,
+                carrier: shipment.carrier,
+                timestamp: now,
+            })
+        })
     }
 
-    /// Create multiple shipments in a single atomic transaction.
-    /// Limit: 10 shipments per batch.
+    /// Confirm a partial delivery and release a bounded escrow percentage.
+    ///
+    /// The receiver can repeatedly confirm partial delivery slices while the
+    /// shipment is in transit/checkpoint/partial states. Each call releases
+    /// `release_percent` of `total_escrow`, and cumulative releases are bounded
+    /// so they never exceed the escrow initially deposited.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `sender` - Company address creating shipments.
-    /// * `shipments` - Vector of shipment inputs.
+    /// * `receiver` - Receiver address confirming the partial delivery.
+    /// * `shipment_id` - Identifier of the shipment.
+    /// * `confirmation_hash` - The proof-of-delivery hash for this partial confirmation.
+    /// * `release_percent` - Percentage of total escrow to release (1-100).
     ///
     /// # Returns
-    /// * `Result<Vec<u64>, NavinError>` - Vector of newly created shipment IDs.
+    /// * `Result<(), NavinError>` - Ok on successful partial confirmation.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller isn't a Company.
-    /// * `NavinError::BatchTooLarge` - If more than 10 shipments are submitted.
-    /// * `NavinError::InvalidShipmentInput` - If receiver matches carrier for any shipment.
-    /// * `NavinError::InvalidHash` - If any data_hash is all zeros.
-    /// * `NavinError::MilestoneSumInvalid` - If payment milestones are invalid per item.
-    /// * `NavinError::InvalidTimestamp` - If the deadline is not strictly in the future.
+    /// * `NavinError::InvalidHash` - If confirmation_hash is all zeros.
+    /// * `NavinError::InvalidAmount` - If release_percent is 0 or > 100.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If called by an address other than the shipment receiver.
+    /// * `NavinError::InvalidStatus` - If shipment is not in a valid state for partial delivery.
     ///
     /// # Examples
     /// ```rust
-    /// // let ids = contract.create_shipments_batch(&env, &sender, inputs_vec);
+    /// // contract.confirm_partial_delivery(&env, &receiver, 1, &hash, 50);
     /// ```
-    pub fn create_shipments_batch(
+    pub fn confirm_partial_delivery(
         env: Env,
-        sender: Address,
-        shipments: Vec<ShipmentInput>,
-    ) -> Result<Vec<u64>, NavinError> {
+        receiver: Address,
+        shipment_id: u64,
+        confirmation_hash: BytesN<32>,
+        release_percent: u32,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        sender.require_auth();
-        require_role(&env, &sender, Role::Company)?;
-
-        let config = config::get_config(&env);
-        if shipments.len() > config.batch_operation_limit {
-            return Err(NavinError::BatchTooLarge);
-        }
-
-        let mut ids = Vec::new(&env);
-        let now = env.ledger().timestamp();
-
-        // Check batch size against limit
-        let current_active = storage::get_active_shipment_count(&env, &sender);
-        let limit = storage::get_effective_shipment_limit(&env, &sender);
-        if current_active.saturating_add(shipments.len()) > limit {
-            return Err(NavinError::ShipmentLimitReached);
-        }
-
-        // Check per-company creation quota window for the entire batch (issue #296).
-        // We check once per item to correctly track the rolling count.
-        {
-            let cfg = config::get_config(&env);
-            if cfg.creation_quota_max > 0 {
-                let now_ts = env.ledger().timestamp();
-                let mut tracker = storage::get_creation_quota(&env, &sender).unwrap_or(
-                    crate::types::CreationQuotaTracker {
-                        count: 0,
-                        window_start: now_ts,
-                    },
-                );
-                if now_ts >= tracker.window_start + cfg.creation_quota_window_seconds {
-                    tracker.window_start = now_ts;
-                    tracker.count = 0;
-                }
-                let batch_len = shipments.len();
-                let new_count = tracker
-                    .count
-                    .checked_add(batch_len)
-                    .ok_or(NavinError::CounterOverflow)?;
-                if new_count > cfg.creation_quota_max {
-                    return Err(NavinError::CreationQuotaExceeded);
-                }
-                tracker.count = new_count;
-                storage::set_creation_quota(&env, &sender, &tracker);
-            }
-        }
+        receiver.require_auth();
+        with_reentrancy_lock(&env, || {
+            // Validate hash before storage
+            validation::validate_hash(&confirmation_hash)?;
 
-        for shipment_input in shipments.iter() {
-            if shipment_input.receiver == shipment_input.carrier {
-                return Err(NavinError::InvalidShipmentInput);
+            if release_percent == 0 || release_percent > 100 {
+                return Err(NavinError::InvalidAmount);
             }
-            validate_milestones(&env, &shipment_input.payment_milestones)?;
-            validate_hash(&shipment_input.data_hash)?;
 
-            if shipment_input.deadline <= now {
-                return Err(NavinError::InvalidTimestamp);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            if shipment.receiver != receiver {
+                return Err(NavinError::Unauthorized);
             }
+            require_not_finalized(&shipment)?;
 
-            let shipment_id = storage::get_shipment_counter(&env)
-                .checked_add(1)
-                .ok_or(NavinError::CounterOverflow)?;
-
-            let shipment = Shipment {
-                id: shipment_id,
-                sender: sender.clone(),
-                receiver: shipment_input.receiver.clone(),
-                carrier: shipment_input.carrier.clone(),
-                data_hash: shipment_input.data_hash.clone(),
-                status: ShipmentStatus::Created,
-                created_at: now,
-                updated_at: now,
-                escrow_amount: 0,
-                total_escrow: 0,
-                payment_milestones: shipment_input.payment_milestones,
-                paid_milestones: Vec::new(&env),
-                milestones_completed: Vec::new(&env),
-                metadata: None,
-                deadline: shipment_input.deadline,
-                integration_nonce: 0,
-                finalized: false,
+            if shipment.status != ShipmentStatus::InTransit
+                && shipment.status != ShipmentStatus::AtCheckpoint
+                && shipment.status != ShipmentStatus::PartiallyDelivered
+            {
+                return Err(NavinError::InvalidStatus);
+            }
+
+            let release_amount =
+                checked_mul_div_i128(shipment.total_escrow, release_percent as i128, 100)?;
+            if release_amount <= 0 {
+                return Err(NavinError::InvalidAmount);
+            }
+
+            let released_so_far = checked_sub_i128(shipment.total_escrow, shipment.escrow_amount)?;
+            let new_total_released = checked_add_i128(released_so_far, release_amount)?;
+            if new_total_released > shipment.total_escrow {
+                return Err(NavinError::InvalidAmount);
+            }
+
+            let old_status = shipment.status.clone();
+            shipment.status = if new_total_released == shipment.total_escrow {
+                ShipmentStatus::Delivered
+            } else {
+                ShipmentStatus::PartiallyDelivered
             };
+            shipment.updated_at = env.ledger().timestamp();
+
+            storage::decrement_status_count(&env, &old_status);
+            storage::increment_status_count(&env, &shipment.status);
+            storage::push_status_history(&env, shipment_id, &shipment.status, &receiver);
+            storage::set_confirmation_hash(&env, shipment_id, &confirmation_hash);
+            if shipment.status == ShipmentStatus::Delivered {
+                storage::decrement_active_shipment_count(&env, &shipment.sender);
+            }
 
+            internal_release_escrow(&env, &mut shipment, release_amount)?;
+            finalize_if_settled(&env, &mut shipment);
             persist_shipment(&env, &shipment)?;
-            storage::set_shipment_counter(&env, shipment_id);
-            storage::increment_status_count(&env, &ShipmentStatus::Created);
-            storage::increment_active_shipment_count(&env, &sender);
-            // Use the cached-config variant to avoid re-reading config from storage per item.
-            extend_shipment_ttl_cached(
-                &env,
-                shipment_id,
-                config.shipment_ttl_threshold,
-                config.shipment_ttl_extension,
-            );
+            extend_shipment_ttl(&env, shipment_id);
 
-            events::emit_shipment_created(
-                &env,
-                shipment_id,
-                &sender,
-                &shipment_input.receiver,
-                &shipment_input.data_hash,
-            );
-            events::emit_notification(
-                &env,
-                &shipment_input.receiver,
-                NotificationType::ShipmentCreated,
-                shipment_id,
-                &shipment_input.data_hash,
-            );
-            events::emit_notification(
+            events::emit_status_updated(
                 &env,
-                &shipment_input.carrier,
-                NotificationType::ShipmentCreated,
                 shipment_id,
-                &shipment_input.data_hash,
+                &old_status,
+                &shipment.status,
+                &confirmation_hash,
             );
-            ids.push_back(shipment_id);
-        }
 
-        Ok(ids)
+            Ok(())
+        })
     }
 
-    /// Retrieve shipment details by ID.
+    /// Report a geofence event for a shipment.
+    /// Only registered carriers can report geofence events.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - ID of the shipment to fetch.
+    /// * `carrier` - Carrier address reporting the event.
+    /// * `shipment_id` - ID of the tracked shipment.
+    /// * `zone_type` - Type of geofence event crossed.
+    /// * `data_hash` - Encrypted off-chain location data representation.
     ///
     /// # Returns
-    /// * `Result<Shipment, NavinError>` - Reconstructed shipment struct.
+    /// * `Result<(), NavinError>` - Ok on successful report tracking.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller isn't a Carrier role.
+    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If tracking context specifies an invalid shipment.
+    /// * `NavinError::RateLimitExceeded` - If a geofence event was reported within `min_report_interval` (unless Admin).
     ///
     /// # Examples
     /// ```rust
-    /// // let shipment = contract.get_shipment(&env, 1);
+    /// // contract.report_geofence_event(&env, &carrier, 1, GeofenceEvent::ZoneEntry, &hash);
     /// ```
-    pub fn get_shipment(env: Env, shipment_id: u64) -> Result<Shipment, NavinError> {
+    pub fn report_geofence_event(
+        env: Env,
+        carrier: Address,
+        shipment_id: u64,
+        zone_type: GeofenceEvent,
+        data_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)
+        carrier.require_auth();
+        require_role(&env, &carrier, Role::Carrier)?;
+        require_active_carrier(&env, &carrier)?;
+
+        // Verify shipment exists and carrier is assigned
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        require_not_finalized(&shipment)?;
+
+        // Validate hash before storage
+        validation::validate_hash(&data_hash)?;
+
+        if shipment.carrier != carrier {
+            return Err(NavinError::Unauthorized);
+        }
+
+        enforce_report_interval(&env, &carrier, shipment_id, symbol_short!("geofence"))?;
+
+        events::emit_geofence_event(&env, shipment_id, zone_type, &data_hash);
+
+        Ok(())
     }
 
-    /// Retrieve the immutable creator identity for a shipment.
+    /// Update ETA for a shipment.
+    /// Only the designated registered carrier can update ETA.
+    /// ETA must be strictly in the future.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - ID of the shipment.
+    /// * `carrier` - Active assigned carrier modifying ETA.
+    /// * `shipment_id` - Identifiable tracker mapping to shipment.
+    /// * `eta_timestamp` - The estimated timestamp prediction in the future.
+    /// * `data_hash` - The mapped hash associated with the update.
     ///
     /// # Returns
-    /// * `Result<Address, NavinError>` - Address that originally created the shipment.
+    /// * `Result<(), NavinError>` - Ok on successful ETA registry.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    pub fn get_shipment_creator(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
+    /// * `NavinError::Unauthorized` - If caller isn't the assigned carrier.
+    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment instance targets missing entry.
+    /// * `NavinError::InvalidTimestamp` - If provided ETA is strictly in the past or present.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.update_eta(&env, &carrier, 1, new_eta, &hash);
+    /// ```
+    pub fn update_eta(
+        env: Env,
+        carrier: Address,
+        shipment_id: u64,
+        eta_timestamp: u64,
+        data_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
+        carrier.require_auth();
+        require_role(&env, &carrier, Role::Carrier)?;
+
         let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.sender)
+
+        require_not_finalized(&shipment)?;
+
+        // Validate hash before storage
+        validation::validate_hash(&data_hash)?;
+
+        if shipment.carrier != carrier {
+            return Err(NavinError::Unauthorized);
+        }
+
+        if eta_timestamp <= env.ledger().timestamp() {
+            return Err(NavinError::InvalidTimestamp);
+        }
+
+        events::emit_eta_updated(&env, shipment_id, eta_timestamp, &data_hash);
+
+        Ok(())
     }
 
-    /// Retrieve the immutable receiver identity for a shipment.
+    /// Add carrier-reported (e.g. GPS-derived) distance to a shipment's running total.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - ID of the shipment.
+    /// * `carrier` - Assigned carrier reporting the distance.
+    /// * `shipment_id` - ID of the in-transit shipment.
+    /// * `meters` - Distance travelled since the last report, in meters.
     ///
     /// # Returns
-    /// * `Result<Address, NavinError>` - Address designated as shipment receiver at creation.
+    /// * `Result<u64, NavinError>` - The updated total distance in meters.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    pub fn get_shipment_receiver(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
+    /// * `NavinError::Unauthorized` - If caller is not the assigned carrier.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `InTransit`.
+    /// * `NavinError::ArithmeticError` - If the total would overflow `u64`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let total = contract.record_distance(&env, &carrier, shipment_id, 12_500);
+    /// ```
+    pub fn record_distance(
+        env: Env,
+        carrier: Address,
+        shipment_id: u64,
+        meters: u64,
+    ) -> Result<u64, NavinError> {
         require_initialized(&env)?;
+        require_not_paused(&env)?;
+        carrier.require_auth();
+        require_role(&env, &carrier, Role::Carrier)?;
+        require_active_carrier(&env, &carrier)?;
+
         let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.receiver)
+        require_not_finalized(&shipment)?;
+
+        if shipment.carrier != carrier {
+            return Err(NavinError::Unauthorized);
+        }
+        if shipment.status != ShipmentStatus::InTransit {
+            return Err(NavinError::InvalidStatus);
+        }
+
+        let total = storage::get_distance(&env, shipment_id)
+            .checked_add(meters)
+            .ok_or(NavinError::ArithmeticError)?;
+        storage::set_distance(&env, shipment_id, total);
+        extend_shipment_ttl(&env, shipment_id);
+
+        events::emit_distance_recorded(&env, shipment_id, &carrier, meters, total);
+
+        Ok(total)
     }
 
-    /// Retrieve the creation timestamp for a shipment.
-    /// Retrieve the immutable sender (creator) identity for a shipment.
+    /// Get the total carrier-reported distance for a shipment, in meters.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
     /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<u64, NavinError>` - Ledger timestamp of creation.
-    pub fn get_shipment_created_at(env: Env, shipment_id: u64) -> Result<u64, NavinError> {
-        require_initialized(&env)?;
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.created_at)
-    }
-
-    /// Retrieve the last update timestamp for a shipment.
-    /// * `Result<Address, NavinError>` - Address that originally created the shipment.
+    /// * `Result<u64, NavinError>` - Total distance in meters (0 if none recorded).
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    pub fn get_shipment_sender(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let meters = contract.get_distance(&env, shipment_id);
+    /// ```
+    pub fn get_distance(env: Env, shipment_id: u64) -> Result<u64, NavinError> {
         require_initialized(&env)?;
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.sender)
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_distance(&env, shipment_id))
     }
 
-    /// Retrieve the immutable carrier identity for a shipment.
+    /// Record a milestone for a shipment.
+    /// Only registered carriers can record milestones. Recording the configured
+    /// `departure_checkpoint` on a `Created` shipment first advances it to
+    /// `InTransit`.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - ID of the shipment.
+    /// * `carrier` - Assigned carrier address triggering the recording.
+    /// * `shipment_id` - ID of the tracked shipment.
+    /// * `checkpoint` - Representation of progress milestone achieved.
+    /// * `data_hash` - Integrity hash associated with offchain progress indicators.
     ///
     /// # Returns
-    /// * `Result<u64, NavinError>` - Ledger timestamp of the last update.
-    pub fn get_shipment_updated_at(env: Env, shipment_id: u64) -> Result<u64, NavinError> {
-        require_initialized(&env)?;
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.updated_at)
-    }
-
-    /// * `Result<Address, NavinError>` - Address designated as shipment carrier at creation.
+    /// * `Result<(), NavinError>` - Ok on successful tracking record update.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    pub fn get_shipment_carrier(env: Env, shipment_id: u64) -> Result<Address, NavinError> {
+    /// * `NavinError::Unauthorized` - If called by unassigned identity.
+    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
+    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
+    /// * `NavinError::ShipmentNotFound` - If shipment instance targets missing entry.
+    /// * `NavinError::InvalidStatus` - If tracked instance is not `InTransit`.
+    /// * `NavinError::CheckpointNotAcknowledged` - If receiver acknowledgement is required but missing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.record_milestone(&env, &carrier, 1, Symbol::new(&env, "warehouse"), &hash);
+    /// ```
+    pub fn record_milestone(
+        env: Env,
+        carrier: Address,
+        shipment_id: u64,
+        checkpoint: Symbol,
+        data_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        Ok(shipment.carrier)
+        require_not_paused(&env)?;
+        carrier.require_auth();
+        with_reentrancy_lock(&env, || {
+            record_milestone_for(&env, &carrier, shipment_id, &checkpoint, &data_hash)
+        })
     }
 
-    /// Return read-only diagnostics that help operators triage restore requirements.
+    /// Register the ed25519 public key a carrier signs milestone attestations
+    /// with. Calling again replaces the previous key.
     ///
-    /// This query does not mutate state. It classifies the shipment ID as active,
-    /// archived-expected, missing, or inconsistent (both active and archived present).
-    pub fn get_restore_diagnostics(
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Carrier registering its key.
+    /// * `public_key` - Raw 32-byte ed25519 public key.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok once the key is stored.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not a carrier.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.set_carrier_public_key(&env, &carrier, &public_key);
+    /// ```
+    pub fn set_carrier_public_key(
         env: Env,
-        shipment_id: u64,
-    ) -> Result<PersistentRestoreDiagnostics, NavinError> {
+        carrier: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
+        carrier.require_auth();
+        require_role(&env, &carrier, Role::Carrier)?;
 
-        let persistent_shipment_present = storage::has_persistent_shipment(&env, shipment_id);
-        let archived_shipment_present = storage::is_shipment_archived(&env, shipment_id);
-
-        let state = if persistent_shipment_present && archived_shipment_present {
-            StoragePresenceState::InconsistentDualPresence
-        } else if persistent_shipment_present {
-            StoragePresenceState::ActivePersistent
-        } else if archived_shipment_present {
-            StoragePresenceState::ArchivedExpected
-        } else {
-            StoragePresenceState::Missing
-        };
+        storage::set_carrier_public_key(&env, &carrier, &public_key);
+        Ok(())
+    }
 
-        Ok(PersistentRestoreDiagnostics {
-            shipment_id,
-            state,
-            persistent_shipment_present,
-            archived_shipment_present,
-            escrow_present: storage::has_escrow_entry(&env, shipment_id),
-            confirmation_hash_present: storage::has_confirmation_hash_entry(&env, shipment_id),
-            last_status_update_present: storage::has_last_status_update_entry(&env, shipment_id),
-            event_count_present: storage::has_event_count_entry(&env, shipment_id),
-        })
+    /// Get the ed25519 public key a carrier registered, if any.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Carrier to look up.
+    ///
+    /// # Returns
+    /// * `Option<BytesN<32>>` - The registered key, or `None`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let key = contract.get_carrier_public_key(&env, &carrier);
+    /// ```
+    pub fn get_carrier_public_key(env: Env, carrier: Address) -> Option<BytesN<32>> {
+        storage::get_carrier_public_key(&env, &carrier)
     }
 
-    /// Deposit escrow funds for a shipment.
-    /// Only a Company can deposit, and the shipment must be in Created status.
+    /// Record a milestone from an off-chain attestation signed by the assigned
+    /// carrier, so a relayer can submit it on the carrier's behalf.
+    ///
+    /// `signature` must be the carrier's ed25519 signature, under its
+    /// registered public key, over the XDR of `shipment_id`, `checkpoint` and
+    /// `data_hash` concatenated in that order. Once verified, the milestone is
+    /// recorded exactly as `record_milestone` would record it. An invalid
+    /// signature aborts the invocation.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `from` - Company address providing escrow.
-    /// * `shipment_id` - Target shipment.
-    /// * `amount` - Balance of tokens deposited into escrow.
+    /// * `shipment_id` - ID of the tracked shipment.
+    /// * `checkpoint` - Representation of progress milestone achieved.
+    /// * `data_hash` - Integrity hash associated with offchain progress indicators.
+    /// * `signature` - Carrier's ed25519 signature over the attestation.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful deposit.
+    /// * `Result<(), NavinError>` - Ok on successful tracking record update.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller isn't a Company.
-    /// * `NavinError::InvalidAmount` - If amount is zero, negative, or exceeds the maximum.
-    /// * `NavinError::ShipmentNotFound` - If shipment is untracked.
-    /// * `NavinError::InvalidStatus` - If shipment is not in `Created` status.
-    /// * `NavinError::EscrowLocked` - If escrow is already deposited for shipment.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::CarrierKeyNotRegistered` - If the assigned carrier has no public key.
+    /// * Any error `record_milestone` can return for the assigned carrier.
     ///
     /// # Examples
-    ///
-    /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
-    /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient};
-    /// # let env = Env::default();
-    /// # env.mock_all_auths();
-    /// # let contract_id = env.register(NavinShipment, ());
-    /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
-    /// # let deadline = env.ledger().timestamp() + 86_400;
-    /// # let receiver = Address::generate(&env);
-    /// # let carrier = Address::generate(&env);
-    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
-    /// // Deposit 5_000_000 stroops (0.5 tokens) into escrow for the shipment.
-    /// // The company must have pre-approved the token transfer allowance.
-    /// client.deposit_escrow(&admin, &shipment_id, &5_000_000_i128);
+    /// ```rust
+    /// // contract.record_milestone_signed(&env, 1, Symbol::new(&env, "warehouse"), &hash, &signature);
     /// ```
-    pub fn deposit_escrow(
+    pub fn record_milestone_signed(
         env: Env,
-        from: Address,
         shipment_id: u64,
-        amount: i128,
+        checkpoint: Symbol,
+        data_hash: BytesN<32>,
+        signature: BytesN<64>,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        from.require_auth();
-        require_role(&env, &from, Role::Company)?;
-
-        with_reentrancy_lock(&env, || {
-            validation::validate_positive_amount(amount)?;
-
-            let mut shipment =
-                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-            require_not_finalized(&shipment)?;
 
-            if shipment.status != ShipmentStatus::Created {
-                return Err(NavinError::InvalidStatus);
-            }
-
-            if shipment.escrow_amount > 0 {
-                return Err(NavinError::EscrowLocked);
-            }
-
-            // Get token contract address
-            let token_contract =
-                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
-
-            // Validate that the token uses 7 decimal places (Stellar standard).
-            // This prevents silent amount mismatches for non-standard tokens.
-            validate_token_decimals(&env, &token_contract)?;
-
-            // Create settlement record in Pending state
-            let contract_address = env.current_contract_address();
-            let settlement_id = create_settlement(
-                &env,
-                shipment_id,
-                SettlementOperation::Deposit,
-                amount,
-                &from,
-                &contract_address,
-            )?;
-
-            // Transfer tokens from user to this contract
-            let transfer_result =
-                invoke_token_transfer(&env, &token_contract, &from, &contract_address, amount);
-
-            match transfer_result {
-                Ok(()) => {
-                    complete_settlement(&env, settlement_id, shipment_id)?;
-
-                    let mut net_amount = amount;
-                    if let Some(fee_config) = storage::get_fee_config(&env) {
-                        if fee_config.fee_bps > 0 {
-                            let fee_amount =
-                                checked_mul_div_i128(amount, fee_config.fee_bps as i128, 10000)?;
-                            if fee_amount > 0 {
-                                // Transfer fee from this contract to treasury
-                                invoke_token_transfer(
-                                    &env,
-                                    &token_contract,
-                                    &contract_address,
-                                    &fee_config.treasury,
-                                    fee_amount,
-                                )?;
-                                net_amount = checked_sub_i128(amount, fee_amount)?;
-                                events::emit_platform_fee_collected(
-                                    &env,
-                                    shipment_id,
-                                    &fee_config.treasury,
-                                    fee_amount,
-                                );
-                            }
-                        }
-                    }
+        let carrier = storage::get_shipment(&env, shipment_id)
+            .ok_or(NavinError::ShipmentNotFound)?
+            .carrier;
+        let public_key = storage::get_carrier_public_key(&env, &carrier)
+            .ok_or(NavinError::CarrierKeyNotRegistered)?;
 
-                    shipment.escrow_amount = net_amount;
-                    shipment.total_escrow = net_amount;
-                    shipment.updated_at = env.ledger().timestamp();
-                    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-                    persist_shipment(&env, &shipment)?;
-                    storage::set_escrow(&env, shipment_id, net_amount);
-                    storage::add_total_escrow_volume(&env, amount)?;
-                    extend_shipment_ttl(&env, shipment_id);
+        let payload = milestone_attestation_payload(&env, shipment_id, &checkpoint, &data_hash);
+        env.crypto()
+            .ed25519_verify(&public_key, &payload, &signature);
 
-                    events::emit_escrow_deposited(&env, shipment_id, &from, net_amount);
-                }
-                Err(e) => {
-                    fail_settlement(&env, settlement_id, shipment_id, e as u32)?;
-                    return Err(e);
-                }
-            }
-
-            Ok(())
+        with_reentrancy_lock(&env, || {
+            record_milestone_for(&env, &carrier, shipment_id, &checkpoint, &data_hash)
         })
     }
 
-    /// Update shipment status with transition validation.
-    /// Only the carrier or admin can update the status.
+    /// Require the receiver to co-sign checkpoint arrivals before milestone
+    /// payments are released. Off by default.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `caller` - Carrier or admin address making the update.
-    /// * `shipment_id` - Current shipment identifier.
-    /// * `new_status` - The destination transitional status.
-    /// * `data_hash` - The off-chain data hash tracking context for update.
+    /// * `sender` - Company that created the shipment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `required` - Whether receiver acknowledgement is required.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on valid transition.
+    /// * `Result<(), NavinError>` - Ok on success.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If shipment doesn't exist.
-    /// * `NavinError::Unauthorized` - If caller is neither the carrier nor admin.
-    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
-    /// * `NavinError::CarrierSuspended` - If the assigned carrier is suspended.
-    /// * `NavinError::RateLimitExceeded` - If status was updated too recently (unless Admin).
-    /// * `NavinError::InvalidStatus` - If transitioning to an improperly sequenced state.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the shipment sender.
+    /// * `NavinError::ShipmentFinalized` - If the shipment is finalized.
     ///
     /// # Examples
     /// ```rust
-    /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
-    /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
-    /// # let env = Env::default();
-    /// # env.mock_all_auths();
-    /// # let contract_id = env.register(NavinShipment, ());
-    /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// # let carrier = Address::generate(&env);
-    /// # client.add_carrier(&admin, &carrier);
-    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
-    /// # let deadline = env.ledger().timestamp() + 86_400;
-    /// # let receiver = Address::generate(&env);
-    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
-    /// let transit_hash = BytesN::from_array(&env, &[2u8; 32]);
-    ///
-    /// // Carrier moves shipment from Created -> InTransit.
-    /// client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &transit_hash);
+    /// // contract.set_checkpoint_ack_required(&env, &company, &1, &true);
     /// ```
-    pub fn update_status(
+    pub fn set_checkpoint_ack_required(
         env: Env,
-        caller: Address,
+        sender: Address,
         shipment_id: u64,
-        new_status: ShipmentStatus,
-        data_hash: BytesN<32>,
+        required: bool,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        caller.require_auth();
-
-        // Validate hash before storage
-        validation::validate_hash(&data_hash)?;
+        sender.require_auth();
 
-        let admin = storage::get_admin(&env);
-        let mut shipment =
+        let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-        if caller != shipment.carrier && caller != admin {
+        if shipment.sender != sender {
             return Err(NavinError::Unauthorized);
         }
         require_not_finalized(&shipment)?;
-        if caller == shipment.carrier {
-            require_active_carrier(&env, &caller)?;
-        }
-
-        // Idempotency: reject duplicate (shipment_id, new_status, data_hash) within the window.
-        let mut payload = soroban_sdk::Bytes::new(&env);
-        payload.append(&soroban_sdk::Bytes::from_array(
-            &env,
-            &shipment_id.to_be_bytes(),
-        ));
-        payload.append(&new_status.clone().to_xdr(&env));
-        payload.append(&data_hash.clone().into());
-        check_idempotency(&env, payload)?;
-
-        // Rate-limit check: admin bypasses; all other callers must wait the minimum interval.
-        if caller != admin {
-            if let Some(last) = storage::get_last_status_update(&env, shipment_id) {
-                let now = env.ledger().timestamp();
-                let config = config::get_config(&env);
-                if now.saturating_sub(last) < config.min_status_update_interval {
-                    return Err(NavinError::RateLimitExceeded);
-                }
-            }
-        }
-
-        crate::validate_shipment_transition(&shipment.status, &new_status)?;
-
-        let old_status = shipment.status.clone();
-        shipment.status = new_status.clone();
-        shipment.data_hash = data_hash.clone();
-        shipment.updated_at = env.ledger().timestamp();
-        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &shipment.status);
-
-        finalize_if_settled(&env, &mut shipment);
-        persist_shipment(&env, &shipment)?;
-
-        if shipment.status == ShipmentStatus::Disputed {
-            storage::increment_total_disputes(&env);
-        }
 
-        storage::set_last_status_update(&env, shipment_id, env.ledger().timestamp());
+        storage::set_checkpoint_ack_required(&env, shipment_id, required);
         extend_shipment_ttl(&env, shipment_id);
-
-        // Store the data hash for this status transition (IoT verification)
-        storage::set_status_hash(&env, shipment_id, &new_status, &data_hash);
-
-        events::emit_status_updated(&env, shipment_id, &old_status, &new_status, &data_hash);
-        events::emit_notification(
-            &env,
-            &shipment.sender,
-            NotificationType::StatusChanged,
-            shipment_id,
-            &data_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.receiver,
-            NotificationType::StatusChanged,
-            shipment_id,
-            &data_hash,
-        );
-
         Ok(())
     }
 
-    /// Returns the current escrowed amount for a specific shipment.
-    /// Returns 0 if no escrow has been deposited.
-    /// Returns ShipmentNotFound if the shipment does not exist.
+    /// Denominate a shipment's milestone payments in absolute token amounts
+    /// instead of percentages of escrow.
+    ///
+    /// Each recorded milestone whose checkpoint matches an entry releases that
+    /// entry's amount to the carrier. Fixed milestones are mutually exclusive
+    /// with the percentage `payment_milestones` given at creation, must be set
+    /// before escrow is deposited, and `deposit_escrow`/`top_up_escrow` then
+    /// require the funded `total_escrow` to equal their sum.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `sender` - Company that created the shipment.
     /// * `shipment_id` - ID of the shipment.
+    /// * `milestones` - `(checkpoint, amount)` pairs; an empty vector clears them.
     ///
     /// # Returns
-    /// * `Result<i128, NavinError>` - Amount stored in escrow.
+    /// * `Result<(), NavinError>` - Ok on success.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the shipment sender.
+    /// * `NavinError::InvalidStatus` - If the shipment is no longer `Created`.
+    /// * `NavinError::EscrowLocked` - If escrow has already been deposited.
+    /// * `NavinError::InvalidPaymentMilestones` - If percentage milestones are already set.
+    /// * `NavinError::MilestoneLimitExceeded` - If more milestones than `max_milestones_per_shipment`.
+    /// * `NavinError::InvalidAmount` - If any amount is zero or negative.
+    /// * `NavinError::InvalidPaymentMilestoneName` - If a checkpoint name is invalid.
+    /// * `NavinError::DuplicatePaymentMilestone` - If a checkpoint appears twice.
     ///
     /// # Examples
     /// ```rust
-    /// // let balance = contract.get_escrow_balance(&env, 1);
+    /// // contract.set_fixed_payment_milestones(&env, &company, 1, milestones);
     /// ```
-    pub fn get_escrow_balance(env: Env, shipment_id: u64) -> Result<i128, NavinError> {
-        require_initialized(&env)?;
-        if storage::get_shipment(&env, shipment_id).is_none() {
-            return Err(NavinError::ShipmentNotFound);
-        }
-        Ok(storage::get_escrow_balance(&env, shipment_id))
-    }
-
-    /// Get the latest structured escrow freeze reason for a shipment, if present.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    /// * `shipment_id` - ID of the shipment.
-    ///
-    /// # Returns
-    /// * `Result<Option<EscrowFreezeReason>, NavinError>` - Latest freeze reason code.
-    pub fn get_escrow_freeze_reason(
+    pub fn set_fixed_payment_milestones(
         env: Env,
+        sender: Address,
         shipment_id: u64,
-    ) -> Result<Option<EscrowFreezeReason>, NavinError> {
+        milestones: Vec<(Symbol, i128)>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        if storage::get_shipment(&env, shipment_id).is_none() {
-            return Err(NavinError::ShipmentNotFound);
+        require_not_paused(&env)?;
+        sender.require_auth();
+
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        if shipment.sender != sender {
+            return Err(NavinError::Unauthorized);
+        }
+        if shipment.status != ShipmentStatus::Created {
+            return Err(NavinError::InvalidStatus);
+        }
+        if shipment.total_escrow > 0 {
+            return Err(NavinError::EscrowLocked);
         }
-        Ok(storage::get_escrow_freeze_reason(&env, shipment_id))
+        if !shipment.payment_milestones.is_empty() {
+            return Err(NavinError::InvalidPaymentMilestones);
+        }
+        if milestones.len() > config::get_config(&env).max_milestones_per_shipment {
+            return Err(NavinError::MilestoneLimitExceeded);
+        }
+
+        let mut names = Vec::new(&env);
+        for (name, amount) in milestones.iter() {
+            validation::validate_positive_amount(amount)?;
+            validation::validate_symbol(&env, &name)
+                .map_err(|_| NavinError::InvalidPaymentMilestoneName)?;
+            if names.contains(&name) {
+                return Err(NavinError::DuplicatePaymentMilestone);
+            }
+            names.push_back(name);
+        }
+
+        storage::set_fixed_payment_milestones(&env, shipment_id, &milestones);
+        extend_shipment_ttl(&env, shipment_id);
+        Ok(())
     }
 
-    /// Get a settlement record by ID.
+    /// Get the absolute-amount payment milestones configured for a shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `settlement_id` - The ID of the settlement.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<SettlementRecord, NavinError>` - The settlement record.
+    /// * `Result<Vec<(Symbol, i128)>, NavinError>` - Configured milestones; empty if none.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If settlement doesn't exist (reusing error).
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
     ///
     /// # Examples
     /// ```rust
-    /// // let settlement = contract.get_settlement(&env, 1);
+    /// // let fixed = contract.get_fixed_payment_milestones(&env, 1);
     /// ```
-    pub fn get_settlement(env: Env, settlement_id: u64) -> Result<SettlementRecord, NavinError> {
+    pub fn get_fixed_payment_milestones(
+        env: Env,
+        shipment_id: u64,
+    ) -> Result<Vec<(Symbol, i128)>, NavinError> {
         require_initialized(&env)?;
-        storage::get_settlement(&env, settlement_id).ok_or(NavinError::ShipmentNotFound)
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_fixed_payment_milestones(&env, shipment_id))
     }
 
-    /// Get the active settlement ID for a shipment.
+    /// Receiver acknowledges a checkpoint arrival. `ack_hash` must match the
+    /// hash the carrier supplied with the latest `AtCheckpoint` update.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - The ID of the shipment.
+    /// * `receiver` - Receiver of the shipment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `ack_hash` - Hash of the checkpoint arrival being acknowledged.
     ///
     /// # Returns
-    /// * `Result<Option<u64>, NavinError>` - The active settlement ID if one exists.
+    /// * `Result<(), NavinError>` - Ok on success.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If ack_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the shipment receiver.
+    /// * `NavinError::ShipmentFinalized` - If the shipment is finalized.
+    /// * `NavinError::CheckpointAckMismatch` - If no checkpoint arrival matches `ack_hash`.
     ///
     /// # Examples
     /// ```rust
-    /// // let active_id = contract.get_active_settlement(&env, 1);
+    /// // contract.ack_checkpoint(&env, &receiver, &1, &ack_hash);
     /// ```
-    pub fn get_active_settlement(env: Env, shipment_id: u64) -> Result<Option<u64>, NavinError> {
+    pub fn ack_checkpoint(
+        env: Env,
+        receiver: Address,
+        shipment_id: u64,
+        ack_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        Ok(storage::get_active_settlement(&env, shipment_id))
-    }
+        require_not_paused(&env)?;
+        receiver.require_auth();
+        validation::validate_hash(&ack_hash)?;
 
-    /// Get the total number of settlements created.
-    ///
-    /// # Arguments
-    /// * `env` - Execution environment.
-    ///
-    /// # Returns
-    /// * `u64` - The total settlement count.
-    ///
-    /// # Examples
-    /// ```rust
-    /// // let count = contract.get_settlement_count(&env);
-    /// ```
-    pub fn get_settlement_count(env: Env) -> u64 {
-        storage::get_settlement_counter(&env)
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        if shipment.receiver != receiver {
+            return Err(NavinError::Unauthorized);
+        }
+        require_not_finalized(&shipment)?;
+
+        let arrival_hash =
+            storage::get_status_hash(&env, shipment_id, &ShipmentStatus::AtCheckpoint);
+        if arrival_hash != Some(ack_hash.clone()) {
+            return Err(NavinError::CheckpointAckMismatch);
+        }
+
+        storage::set_checkpoint_ack(&env, shipment_id, &ack_hash);
+        extend_shipment_ttl(&env, shipment_id);
+        events::emit_checkpoint_acknowledged(&env, shipment_id, &receiver, &ack_hash);
+        Ok(())
     }
 
-    /// Returns the total number of shipments created on the platform.
-    /// Returns 0 if the contract has not been initialized.
+    /// Record multiple milestones for a shipment in a single atomic transaction.
+    /// Allows a carrier to record multiple checkpoints at once, reducing gas costs.
+    /// Limit: 10 milestones per batch.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `carrier` - Assigned carrier address triggering the recording.
+    /// * `shipment_id` - ID of the tracked shipment.
+    /// * `milestones` - Vector of (checkpoint, data_hash) tuples.
     ///
     /// # Returns
-    /// * `u64` - Overall total shipments registered.
+    /// * `Result<(), NavinError>` - Ok on successful batch recording.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If called by unassigned identity.
+    /// * `NavinError::InvalidHash` - If any data_hash is all zeros.
+    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
+    /// * `NavinError::ShipmentNotFound` - If shipment instance targets missing entry.
+    /// * `NavinError::InvalidStatus` - If tracked instance is not `InTransit`.
+    /// * `NavinError::BatchTooLarge` - If more than 10 milestones are submitted.
     ///
     /// # Examples
     /// ```rust
-    /// // let total = contract.get_shipment_count(&env);
+    /// // let milestones = vec![
+    /// //     (Symbol::new(&env, "warehouse"), hash1),
+    /// //     (Symbol::new(&env, "port"), hash2),
+    /// // ];
+    /// // contract.record_milestones_batch(&env, &carrier, 1, milestones);
     /// ```
-    pub fn get_shipment_count(env: Env) -> u64 {
-        storage::get_shipment_counter(&env)
-    }
-
-    /// Fetch multiple shipments in one call while preserving input order.
-    ///
-    /// Returns `None` for unknown IDs instead of failing the entire request.
-    pub fn get_shipments_batch(
+    pub fn record_milestones_batch(
         env: Env,
-        shipment_ids: Vec<u64>,
-    ) -> Result<Vec<Option<Shipment>>, NavinError> {
+        carrier: Address,
+        shipment_id: u64,
+        milestones: Vec<(Symbol, BytesN<32>)>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
+        require_not_paused(&env)?;
+        carrier.require_auth();
+        with_reentrancy_lock(&env, || {
+            require_role(&env, &carrier, Role::Carrier)?;
+            require_active_carrier(&env, &carrier)?;
 
-        let max_batch = effective_batch_query_limit(&env);
-        if shipment_ids.len() > max_batch {
-            return Err(NavinError::BatchTooLarge);
-        }
+            // Validate batch size
+            let config = config::get_config(&env);
+            if milestones.len() > config.batch_operation_limit {
+                return Err(NavinError::BatchTooLarge);
+            }
 
-        let mut results = Vec::new(&env);
-        for shipment_id in shipment_ids.iter() {
-            results.push_back(storage::get_shipment(&env, shipment_id));
-        }
+            // Validate all hashes in milestones
+            for (_, hash) in &milestones {
+                validation::validate_hash(&hash)?;
+            }
 
-        Ok(results)
-    }
+            // Verify shipment exists, carrier is assigned, and status
+            let shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-    /// Filter shipments by sender with optional offset pagination.
-    pub fn get_shipments_by_sender(
-        env: Env,
-        sender: Address,
-        limit: u32,
-    ) -> Result<Vec<Shipment>, NavinError> {
-        Self::get_shipments_by_sender_page(env, sender, 0, limit)
-    }
+            require_not_finalized(&shipment)?;
 
-    /// Filter shipments by sender with offset pagination.
-    pub fn get_shipments_by_sender_page(
-        env: Env,
-        sender: Address,
-        offset: u32,
-        limit: u32,
-    ) -> Result<Vec<Shipment>, NavinError> {
-        require_initialized(&env)?;
-        let max_batch = effective_batch_query_limit(&env);
-        if limit == 0 || limit > max_batch {
-            return Err(NavinError::InvalidConfig);
-        }
+            if shipment.carrier != carrier {
+                return Err(NavinError::Unauthorized);
+            }
 
-        let mut matched = Vec::new(&env);
-        let mut skipped = 0_u32;
-        let mut collected = 0_u32;
-        let total_shipments = storage::get_shipment_counter(&env);
+            if shipment.status != ShipmentStatus::InTransit {
+                return Err(NavinError::InvalidStatus);
+            }
 
-        for shipment_id in 1..=total_shipments {
-            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
-                if shipment.sender != sender {
-                    continue;
-                }
-                if skipped < offset {
-                    skipped = skipped.saturating_add(1);
-                    continue;
+            // Validate all milestones before committing any (atomic operation)
+            // This ensures that if any milestone is invalid, none are committed
+            for milestone_tuple in milestones.iter() {
+                let data_hash = &milestone_tuple.1;
+
+                // Validate hash
+                validation::validate_hash(data_hash)?;
+            }
+
+            // Enforce milestone event payload size guard
+            let config = config::get_config(&env);
+            let current_milestone_count = storage::get_milestone_event_count(&env, shipment_id);
+            let new_milestones = milestones.len();
+            if current_milestone_count
+                .checked_add(new_milestones)
+                .ok_or(NavinError::ArithmeticError)?
+                > config.max_milestones_per_shipment
+            {
+                return Err(NavinError::MilestoneLimitExceeded);
+            }
+
+            // All validations passed, now process each milestone
+            let timestamp = env.ledger().timestamp();
+            let mut mut_shipment = shipment;
+
+            for milestone_tuple in milestones.iter() {
+                let checkpoint = milestone_tuple.0.clone();
+                let data_hash = milestone_tuple.1.clone();
+
+                let _milestone = Milestone {
+                    shipment_id,
+                    checkpoint: checkpoint.clone(),
+                    data_hash: data_hash.clone(),
+                    timestamp,
+                    reporter: carrier.clone(),
+                };
+
+                // Emit one event per milestone (Hash-and-Emit pattern)
+                events::emit_milestone_recorded(
+                    &env,
+                    shipment_id,
+                    &checkpoint,
+                    &data_hash,
+                    &carrier,
+                );
+
+                // Check for milestone-based payments
+                let mut found_index = None;
+                for (i, payment_milestone) in mut_shipment.payment_milestones.iter().enumerate() {
+                    if payment_milestone.0 == checkpoint {
+                        found_index = Some(i);
+                        break;
+                    }
                 }
-                matched.push_back(shipment);
-                collected = collected.saturating_add(1);
-                if collected >= limit {
-                    break;
+
+                let payout_pct = found_index
+                    .map(|idx| mut_shipment.payment_milestones.get(idx as u32).unwrap().1);
+                let fixed_amount = match payout_pct {
+                    Some(_) => None,
+                    None => fixed_milestone_amount(&env, shipment_id, &checkpoint),
+                };
+
+                if payout_pct.is_some() || fixed_amount.is_some() {
+                    let mut already_paid = false;
+                    for paid_symbol in mut_shipment.paid_milestones.iter() {
+                        if paid_symbol == checkpoint {
+                            already_paid = true;
+                            break;
+                        }
+                    }
+
+                    if !already_paid {
+                        mut_shipment
+                            .milestones_completed
+                            .push_back(checkpoint.clone());
+                        if !mut_shipment.paid_milestones.iter().any(|m| m == checkpoint) {
+                            mut_shipment.paid_milestones.push_back(checkpoint.clone());
+                        }
+
+                        let release_amount = match payout_pct {
+                            Some(pct) => milestone_payout(&env, &mut_shipment, pct)?,
+                            None => {
+                                fixed_milestone_payout(&mut_shipment, fixed_amount.unwrap_or(0))
+                            }
+                        };
+
+                        events::emit_milestone_payment_released(
+                            &env,
+                            shipment_id,
+                            &checkpoint,
+                            release_amount,
+                            &mut_shipment.carrier,
+                        );
+                        internal_release_escrow(&env, &mut mut_shipment, release_amount)?;
+                    }
                 }
             }
-        }
 
-        Ok(matched)
-    }
+            finalize_if_settled(&env, &mut mut_shipment);
+            storage::set_shipment(&env, &mut_shipment);
 
-    /// Filter shipments by carrier with optional offset pagination.
-    pub fn get_shipments_by_carrier(
-        env: Env,
-        carrier: Address,
-        limit: u32,
-    ) -> Result<Vec<Shipment>, NavinError> {
-        Self::get_shipments_by_carrier_page(env, carrier, 0, limit)
+            Ok(())
+        })
     }
 
-    /// Filter shipments by carrier with offset pagination.
-    pub fn get_shipments_by_carrier_page(
+    /// Explicitly release a partial escrow payment for a specific milestone.
+    /// Only Carrier or Admin can call this.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `caller` - Identity triggering the release (Carrier or Admin).
+    /// * `shipment_id` - ID of the target shipment.
+    /// * `milestone_name` - symbolic name of the milestone to pay.
+    pub fn release_milestone_payment(
         env: Env,
-        carrier: Address,
-        offset: u32,
-        limit: u32,
-    ) -> Result<Vec<Shipment>, NavinError> {
+        caller: Address,
+        shipment_id: u64,
+        milestone_name: Symbol,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        let max_batch = effective_batch_query_limit(&env);
-        if limit == 0 || limit > max_batch {
-            return Err(NavinError::InvalidConfig);
-        }
+        require_not_paused(&env)?;
+        caller.require_auth();
+        with_reentrancy_lock(&env, || {
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            require_not_finalized(&shipment)?;
 
-        let mut matched = Vec::new(&env);
-        let mut skipped = 0_u32;
-        let mut collected = 0_u32;
-        let total_shipments = storage::get_shipment_counter(&env);
+            let admin = storage::get_admin(&env);
+            if caller != shipment.carrier && caller != admin {
+                return Err(NavinError::Unauthorized);
+            }
 
-        for shipment_id in 1..=total_shipments {
-            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
-                if shipment.carrier != carrier {
-                    continue;
-                }
-                if skipped < offset {
-                    skipped = skipped.saturating_add(1);
-                    continue;
+            if caller == shipment.carrier {
+                require_active_carrier(&env, &caller)?;
+            }
+
+            // Check if milestone exists in payment_milestones
+            let mut milestone_idx = None;
+            for (i, ms) in shipment.payment_milestones.iter().enumerate() {
+                if ms.0 == milestone_name {
+                    milestone_idx = Some(i);
+                    break;
                 }
-                matched.push_back(shipment);
-                collected = collected.saturating_add(1);
-                if collected >= limit {
+            }
+
+            let idx = milestone_idx.ok_or(NavinError::InvalidShipmentInput)?;
+
+            // Check if already in milestones_completed
+            let mut already_completed = false;
+            for ms in shipment.milestones_completed.iter() {
+                if ms == milestone_name {
+                    already_completed = true;
                     break;
                 }
             }
-        }
-
-        Ok(matched)
-    }
 
-    /// Filter shipments by status with optional offset pagination.
-    pub fn get_shipments_by_status(
-        env: Env,
-        status: ShipmentStatus,
-        limit: u32,
-    ) -> Result<Vec<Shipment>, NavinError> {
-        Self::get_shipments_by_status_page(env, status, 0, limit)
-    }
+            if already_completed {
+                return Err(NavinError::MilestoneAlreadyPaid);
+            }
+
+            // Enforce sequential ordering: all prior milestones must be paid first.
+            if idx > 0 {
+                let completed_count = shipment.milestones_completed.len() as usize;
+                if completed_count < idx {
+                    return Err(NavinError::InvalidStatus);
+                }
+            }
+
+            let ms_config = shipment.payment_milestones.get(idx as u32).unwrap();
 
-    /// Filter shipments by status with offset pagination.
-    pub fn get_shipments_by_status_page(
-        env: Env,
-        status: ShipmentStatus,
-        offset: u32,
-        limit: u32,
-    ) -> Result<Vec<Shipment>, NavinError> {
-        require_initialized(&env)?;
-        let max_batch = effective_batch_query_limit(&env);
-        if limit == 0 || limit > max_batch {
-            return Err(NavinError::InvalidConfig);
-        }
+            // Calculate total percentage paid including this one to handle rounding on last milestone
+            let mut total_pct_paid = 0;
+            for (m_sym, m_pct) in shipment.payment_milestones.iter() {
+                if shipment.milestones_completed.iter().any(|p| p == m_sym)
+                    || m_sym == milestone_name
+                {
+                    total_pct_paid += m_pct;
+                }
+            }
 
-        let mut matched = Vec::new(&env);
-        let mut skipped = 0_u32;
-        let mut collected = 0_u32;
-        let total_shipments = storage::get_shipment_counter(&env);
+            let release_amount = if total_pct_paid == 100 {
+                shipment.escrow_amount
+            } else {
+                milestone_release_amount(&env, shipment.total_escrow, ms_config.1)?
+            };
 
-        for shipment_id in 1..=total_shipments {
-            if let Some(shipment) = storage::get_shipment(&env, shipment_id) {
-                if shipment.status != status {
-                    continue;
-                }
-                if skipped < offset {
-                    skipped = skipped.saturating_add(1);
-                    continue;
+            if release_amount > 0 {
+                shipment
+                    .milestones_completed
+                    .push_back(milestone_name.clone());
+                // Keep paid_milestones in sync for backward compatibility
+                let mut in_paid = false;
+                for ms in shipment.paid_milestones.iter() {
+                    if ms == milestone_name {
+                        in_paid = true;
+                        break;
+                    }
                 }
-                matched.push_back(shipment);
-                collected = collected.saturating_add(1);
-                if collected >= limit {
-                    break;
+                if !in_paid {
+                    shipment.paid_milestones.push_back(milestone_name.clone());
                 }
+
+                events::emit_milestone_payment_released(
+                    &env,
+                    shipment_id,
+                    &milestone_name,
+                    release_amount,
+                    &shipment.carrier,
+                );
+                internal_release_escrow(&env, &mut shipment, release_amount)?;
             }
-        }
 
-        Ok(matched)
+            finalize_if_settled(&env, &mut shipment);
+            storage::set_shipment(&env, &shipment);
+
+            Ok(())
+        })
     }
 
-    /// Cursor-based search for shipment IDs by status.
+    /// Extend the TTL of a shipment's persistent storage entries.
+    /// Delivered and cancelled shipments are extended by the shorter
+    /// `settled_ttl_extension`.
     ///
-    /// Results are returned in ascending shipment ID order for deterministic pagination.
-    /// `cursor` is the last seen shipment ID from a previous page.
-    pub fn search_shipments_by_status(
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - Shipment ID to renew TTL.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on success.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.extend_shipment_ttl(env, 1);
+    /// ```
+    pub fn extend_shipment_ttl(env: Env, shipment_id: u64) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        extend_shipment_ttl(&env, shipment_id);
+        Ok(())
+    }
+
+    /// Cancel a shipment before it is delivered.
+    /// Only the Company (sender) or Admin can cancel.
+    /// Shipment must not be Delivered or Disputed.
+    /// If escrow exists, triggers automatic refund to the Company.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `caller` - Executing Company or Admin address.
+    /// * `shipment_id` - ID specifying cancelled shipment instance.
+    /// * `reason_hash` - The mapped hash associated to the cancellation context.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on cancellation.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If tracking context is invalid list element.
+    /// * `NavinError::Unauthorized` - If called by unauthorized accounts.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If tracking context specified reached terminal states.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.cancel_shipment(&env, &admin, 1, &hash);
+    /// ```
+    pub fn cancel_shipment(
         env: Env,
-        status: ShipmentStatus,
-        cursor: Option<u64>,
-        page_size: u32,
-    ) -> Result<ShipmentStatusCursorPage, NavinError> {
+        caller: Address,
+        shipment_id: u64,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
+        require_not_paused(&env)?;
+        caller.require_auth();
 
-        let config = config::get_config(&env);
-        if page_size == 0 || page_size > config.batch_operation_limit {
-            return Err(NavinError::InvalidConfig);
-        }
+        // Validate hash before storage
+        validation::validate_hash(&reason_hash)?;
 
-        let mut shipment_ids = Vec::new(&env);
-        let mut current_id = cursor.unwrap_or(0);
-        let total_shipments = storage::get_shipment_counter(&env);
-        let mut next_cursor = None;
+        with_reentrancy_lock(&env, || {
+            let admin = storage::get_admin(&env);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        while current_id < total_shipments {
-            current_id = current_id.saturating_add(1);
+            require_not_finalized(&shipment)?;
 
-            if let Some(shipment) = storage::get_shipment(&env, current_id) {
-                if shipment.status == status {
-                    shipment_ids.push_back(current_id);
-                    if shipment_ids.len() == page_size {
-                        if current_id < total_shipments {
-                            next_cursor = Some(current_id);
-                        }
-                        break;
-                    }
+            if caller != shipment.sender && caller != admin {
+                return Err(NavinError::Unauthorized);
+            }
+
+            // Check for suspension if caller is the sender (company)
+            if caller == shipment.sender {
+                require_active_company(&env, &caller)?;
+            }
+
+            match shipment.status {
+                ShipmentStatus::Delivered | ShipmentStatus::Disputed => {
+                    return Err(NavinError::ShipmentAlreadyCompleted);
                 }
+                _ => {}
             }
-        }
 
-        Ok(ShipmentStatusCursorPage {
-            shipment_ids,
-            next_cursor,
+            apply_cancellation(&env, &caller, &mut shipment, &reason_hash)
         })
     }
 
-    /// Get the event count for a shipment.
-    /// Returns the number of events emitted for this shipment.
-    /// Returns 0 for brand-new shipments or shipments with no events yet.
+    /// Check whether `caller` could cancel a shipment right now.
+    ///
+    /// Mirrors the checks performed by `cancel_shipment`: the contract must
+    /// not be paused, the caller must be the sender (not suspended) or the
+    /// admin, and the shipment must not be finalized, delivered, disputed or
+    /// already cancelled. Lets clients decide whether to offer cancellation
+    /// without simulating the call.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
     /// * `shipment_id` - ID of the shipment.
+    /// * `caller` - Address that would request the cancellation.
     ///
     /// # Returns
-    /// * `Result<u32, NavinError>` - The number of events emitted for this shipment.
+    /// * `Result<bool, NavinError>` - `true` if `cancel_shipment` would be accepted.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
@@ -2959,1718 +6774,1759 @@ impl NavinShipment {
     ///
     /// # Examples
     /// ```rust
-    /// // let event_count = contract.get_event_count(&env, 1);
+    /// // let allowed = contract.can_cancel(&env, 1, &company);
     /// ```
-    pub fn get_event_count(env: Env, shipment_id: u64) -> Result<u32, NavinError> {
+    pub fn can_cancel(env: Env, shipment_id: u64, caller: Address) -> Result<bool, NavinError> {
         require_initialized(&env)?;
-        // Verify shipment exists
-        if storage::get_shipment(&env, shipment_id).is_none() {
-            return Err(NavinError::ShipmentNotFound);
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        if storage::is_paused(&env) || shipment.finalized {
+            return Ok(false);
         }
-        Ok(storage::get_event_count(&env, shipment_id))
+
+        let admin = storage::get_admin(&env);
+        if caller != shipment.sender && caller != admin {
+            return Ok(false);
+        }
+        if caller == shipment.sender && require_active_company(&env, &caller).is_err() {
+            return Ok(false);
+        }
+
+        Ok(!matches!(
+            shipment.status,
+            ShipmentStatus::Delivered | ShipmentStatus::Disputed | ShipmentStatus::Cancelled
+        ))
     }
 
-    /// Archive a shipment by moving it from persistent to temporary storage.
-    /// This reduces state rent costs for completed shipments.
-    /// Only admin can archive, and shipment must be in a terminal state (Delivered or Cancelled).
+    /// Cancel a shipment while compensating the carrier from escrow.
+    /// Only the Company (sender) or Admin can call.
+    ///
+    /// Pays `carrier_amount` (capped at the held escrow) to the carrier,
+    /// refunds the remainder to the company, and cancels the shipment.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Admin address performing the archival.
-    /// * `shipment_id` - ID of the shipment to archive.
+    /// * `company` - Shipment sender or admin.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `carrier_amount` - Compensation for the carrier.
+    /// * `reason_hash` - Hash of the cancellation reason.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully archived.
+    /// * `Result<(), NavinError>` - Ok on successful cancellation.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller is not the admin.
+    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::InvalidAmount` - If `carrier_amount` is negative.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    /// * `NavinError::InvalidStatus` - If shipment is not in a terminal state (Delivered or Cancelled).
+    /// * `NavinError::Unauthorized` - If caller is neither the sender nor the admin.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is delivered, disputed, or cancelled.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.archive_shipment(&env, &admin, 1);
+    /// // contract.cancel_with_compensation(&env, &company, &1, &300, &reason_hash);
     /// ```
-    pub fn archive_shipment(env: Env, admin: Address, shipment_id: u64) -> Result<(), NavinError> {
+    pub fn cancel_with_compensation(
+        env: Env,
+        company: Address,
+        shipment_id: u64,
+        carrier_amount: i128,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        company.require_auth();
+        with_reentrancy_lock(&env, || {
+            validation::validate_hash(&reason_hash)?;
+            if carrier_amount < 0 {
+                return Err(NavinError::InvalidAmount);
+            }
+
+            let admin = storage::get_admin(&env);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+            require_not_finalized(&shipment)?;
+
+            if company != shipment.sender && company != admin {
+                return Err(NavinError::Unauthorized);
+            }
+
+            if company == shipment.sender {
+                require_active_company(&env, &company)?;
+            }
+
+            match shipment.status {
+                ShipmentStatus::Delivered
+                | ShipmentStatus::Disputed
+                | ShipmentStatus::Cancelled => {
+                    return Err(NavinError::ShipmentAlreadyCompleted);
+                }
+                _ => {}
+            }
+
+            let escrow_amount = shipment.escrow_amount;
+            let compensation = carrier_amount.min(escrow_amount);
+            let refund = checked_sub_i128(escrow_amount, compensation)?;
+
+            if escrow_amount > 0 {
+                let token_contract =
+                    storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+                let contract_address = env.current_contract_address();
+                if compensation > 0 {
+                    invoke_token_transfer(
+                        &env,
+                        &token_contract,
+                        &contract_address,
+                        &shipment.carrier,
+                        compensation,
+                    )?;
+                    storage::record_escrow_released(&env, compensation);
+                    events::emit_escrow_released(
+                        &env,
+                        shipment_id,
+                        &shipment.carrier,
+                        compensation,
+                    );
+                }
+                if refund > 0 {
+                    invoke_token_transfer(
+                        &env,
+                        &token_contract,
+                        &contract_address,
+                        &shipment.sender,
+                        refund,
+                    )?;
+                    storage::record_escrow_refunded(&env, refund);
+                    events::emit_escrow_refunded(&env, shipment_id, &shipment.sender, refund);
+                }
+                // Escrow is settled above; apply_cancellation only updates status.
+                shipment.escrow_amount = 0;
+                storage::remove_escrow_balance(&env, shipment_id);
+            }
+
+            apply_cancellation(&env, &company, &mut shipment, &reason_hash)
+        })
+    }
+
+    /// Cancel several shipments in one call, e.g. when a company winds down.
+    /// Only the Company (sender) of every listed shipment, or Admin, can call.
+    ///
+    /// Shipments that are already finalized or in a non-cancellable status
+    /// (`Delivered`, `Disputed`, `Cancelled`) are skipped rather than failing
+    /// the whole batch. Held escrow is refunded as in `cancel_shipment`.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `caller` - Executing Company or Admin address.
+    /// * `shipment_ids` - IDs of the shipments to cancel.
+    /// * `reason_hash` - Hash of the off-chain cancellation reason, applied to every shipment.
+    ///
+    /// # Returns
+    /// * `Result<Vec<u64>, NavinError>` - IDs that were actually cancelled.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::BatchTooLarge` - If more IDs than `batch_operation_limit` are given.
+    /// * `NavinError::ShipmentNotFound` - If any ID does not exist.
+    /// * `NavinError::Unauthorized` - If caller is neither the sender of a listed shipment nor Admin.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let cancelled = contract.cancel_shipments_batch(&env, &company, &ids, &hash);
+    /// ```
+    pub fn cancel_shipments_batch(
+        env: Env,
+        caller: Address,
+        shipment_ids: Vec<u64>,
+        reason_hash: BytesN<32>,
+    ) -> Result<Vec<u64>, NavinError> {
         require_initialized(&env)?;
-        admin.require_auth();
+        require_not_paused(&env)?;
+        caller.require_auth();
+        validation::validate_hash(&reason_hash)?;
 
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
+        let config = config::get_config(&env);
+        if shipment_ids.len() > config.batch_operation_limit {
+            return Err(NavinError::BatchTooLarge);
         }
 
-        let shipment = storage::get_persistent_shipment(&env, shipment_id)
-            .ok_or(NavinError::ShipmentNotFound)?;
-
-        // Only allow archiving terminal state shipments
-        if shipment.status != ShipmentStatus::Delivered
-            && shipment.status != ShipmentStatus::Cancelled
-        {
-            return Err(NavinError::InvalidStatus);
+        let is_admin = caller == storage::get_admin(&env);
+        if !is_admin {
+            require_active_company(&env, &caller)?;
         }
 
-        // Archive the shipment (move from persistent to temporary storage)
-        storage::archive_shipment(&env, shipment_id, &shipment);
+        with_reentrancy_lock(&env, || {
+            let mut cancelled = Vec::new(&env);
+            for shipment_id in shipment_ids.iter() {
+                let mut shipment =
+                    storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        let timestamp = env.ledger().timestamp();
-        events::emit_shipment_archived(&env, shipment_id, timestamp);
+                if !is_admin && caller != shipment.sender {
+                    return Err(NavinError::Unauthorized);
+                }
 
-        Ok(())
+                if shipment.finalized
+                    || matches!(
+                        shipment.status,
+                        ShipmentStatus::Delivered
+                            | ShipmentStatus::Disputed
+                            | ShipmentStatus::Cancelled
+                    )
+                {
+                    continue;
+                }
+
+                apply_cancellation(&env, &caller, &mut shipment, &reason_hash)?;
+                cancelled.push_back(shipment_id);
+            }
+
+            Ok(cancelled)
+        })
     }
 
-    /// Confirm delivery of a shipment.
-    /// Only the designated receiver can call this function.
-    /// Shipment must be in InTransit or AtCheckpoint status.
-    /// Stores the confirmation_hash (hash of proof-of-delivery data) and
-    /// transitions the shipment status to Delivered.
+    /// Emergency admin-only force-cancel for a shipment.
+    ///
+    /// This is a privileged override that bypasses the normal cancellation rules.
+    /// It can cancel a shipment in **any non-terminal state** (including Disputed),
+    /// and it requires a mandatory, non-zero `reason_hash` to ensure an immutable
+    /// audit trail is always present.
+    ///
+    /// Escrow behaviour is deterministic:
+    /// - If escrow is held, the full remaining balance is refunded to the company
+    ///   via the token contract before the shipment is marked Cancelled.
+    /// - If no escrow is held, the shipment is cancelled with no token transfer.
+    ///
+    /// Only the single admin or a multi-sig admin (via `propose_action` /
+    /// `approve_action`) may call this function directly. Regular companies and
+    /// carriers are rejected with `Unauthorized`.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `receiver` - Receiver address confirming the delivery.
-    /// * `shipment_id` - Identifier of delivered shipment.
-    /// * `confirmation_hash` - The proof-of-delivery hash.
+    /// * `admin` - Admin address executing the force-cancel.
+    /// * `shipment_id` - ID of the shipment to force-cancel.
+    /// * `reason_hash` - Mandatory SHA-256 hash of the off-chain reason document.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful confirmation.
+    /// * `Result<(), NavinError>` - Ok on success.
     ///
     /// # Errors
-    /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    /// * `NavinError::Unauthorized` - If called by an address other than the shipment receiver.
-    /// * `NavinError::InvalidHash` - If confirmation_hash is all zeros.
-    /// * `NavinError::InvalidStatus` - If shipment is not in a transitable status to Delivered.
+    /// * `NavinError::NotInitialized` - Contract not initialized.
+    /// * `NavinError::Unauthorized` - Caller is not the admin.
+    /// * `NavinError::ShipmentNotFound` - Shipment does not exist.
+    /// * `NavinError::InvalidHash` - `reason_hash` is all zeros.
+    /// * `NavinError::ShipmentAlreadyCompleted` - Shipment is already Delivered or Cancelled.
     ///
     /// # Examples
     /// ```rust
-    /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
-    /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
-    /// # let env = Env::default();
-    /// # env.mock_all_auths();
-    /// # let contract_id = env.register(NavinShipment, ());
-    /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// # let carrier = Address::generate(&env);
-    /// # client.add_carrier(&admin, &carrier);
-    /// # let receiver = Address::generate(&env);
-    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
-    /// # let deadline = env.ledger().timestamp() + 86_400;
-    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
-    /// # client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &BytesN::from_array(&env, &[2u8; 32]));
-    /// let pod_hash = BytesN::from_array(&env, &[3u8; 32]); // SHA-256 of proof-of-delivery doc
-    ///
-    /// // Receiver confirms delivery; escrow is automatically released to the carrier.
-    /// client.confirm_delivery(&receiver, &shipment_id, &pod_hash);
+    /// // contract.force_cancel_shipment(&env, &admin, 1, &reason_hash);
     /// ```
-    pub fn confirm_delivery(
+    pub fn force_cancel_shipment(
         env: Env,
-        receiver: Address,
+        admin: Address,
         shipment_id: u64,
-        confirmation_hash: BytesN<32>,
+        reason_hash: BytesN<32>,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        receiver.require_auth();
+        admin.require_auth();
+        with_reentrancy_lock(&env, || {
+            // Strict admin-only gate — no company/carrier bypass.
+            if storage::get_admin(&env) != admin {
+                return Err(NavinError::Unauthorized);
+            }
 
-        // Validate hash before storage
-        validation::validate_hash(&confirmation_hash)?;
+            // Reason hash is mandatory and must be non-zero.
+            validation::validate_hash(&reason_hash)?;
 
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        // Only the designated receiver can confirm delivery
-        if shipment.receiver != receiver {
-            return Err(NavinError::Unauthorized);
-        }
-        require_not_finalized(&shipment)?;
+            require_not_finalized(&shipment)?;
 
-        // Validate transition to Delivered
-        crate::validate_shipment_transition(&shipment.status, &ShipmentStatus::Delivered)?;
+            // Terminal states cannot be force-cancelled.
+            match shipment.status {
+                ShipmentStatus::Delivered | ShipmentStatus::Cancelled => {
+                    return Err(NavinError::ShipmentAlreadyCompleted);
+                }
+                _ => {}
+            }
 
-        let now = env.ledger().timestamp();
-        let old_status = shipment.status.clone();
-        shipment.status = ShipmentStatus::Delivered;
-        shipment.updated_at = now;
+            let old_status = shipment.status.clone();
+            let escrow_amount = shipment.escrow_amount;
+            if old_status == ShipmentStatus::Disputed {
+                storage::remove_disputed_escrow(&env, escrow_amount)?;
+            }
 
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &ShipmentStatus::Delivered);
-        storage::set_confirmation_hash(&env, shipment_id, &confirmation_hash);
-        storage::decrement_active_shipment_count(&env, &shipment.sender);
-        extend_shipment_ttl(&env, shipment_id);
+            // Deterministic escrow refund: always refund to company if escrow is held.
+            if escrow_amount > 0 {
+                let token_contract =
+                    storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+                let contract_address = env.current_contract_address();
+                invoke_token_transfer(
+                    &env,
+                    &token_contract,
+                    &contract_address,
+                    &shipment.sender,
+                    escrow_amount,
+                )?;
+
+                shipment.escrow_amount = 0;
+                storage::record_escrow_refunded(&env, escrow_amount);
+                events::emit_escrow_refunded(&env, shipment_id, &shipment.sender, escrow_amount);
+            }
+            settle_dispute_bond(&env, &shipment, false)?;
+            settle_insurance_reserve(&env, &shipment, false)?;
+
+            shipment.status = ShipmentStatus::Cancelled;
+            shipment.updated_at = env.ledger().timestamp();
+            shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
 
-        let remaining_escrow = shipment.escrow_amount;
-        internal_release_escrow(&env, &mut shipment, remaining_escrow)?;
+            storage::decrement_status_count(&env, &old_status);
+            storage::increment_status_count(&env, &ShipmentStatus::Cancelled);
+            storage::push_status_history(&env, shipment_id, &ShipmentStatus::Cancelled, &admin);
 
-        finalize_if_settled(&env, &mut shipment);
-        persist_shipment(&env, &shipment)?;
+            // Decrement active count only if the shipment was not already in a
+            // non-active state (Cancelled is the only non-active non-terminal state
+            // that can't reach here, so this is always safe).
+            storage::decrement_active_shipment_count(&env, &shipment.sender);
 
-        events::emit_delivery_confirmed(&env, shipment_id, &receiver, &confirmation_hash);
+            finalize_if_settled(&env, &mut shipment);
+            persist_shipment(&env, &shipment)?;
 
-        // Reputation: record successful delivery for the carrier
-        events::emit_delivery_success(&env, &shipment.carrier, shipment_id, now);
+            extend_shipment_ttl(&env, shipment_id);
 
-        let total_milestones = shipment.payment_milestones.len();
-        let milestones_hit = shipment.paid_milestones.len();
-        events::emit_carrier_milestone_rate(
-            &env,
-            &shipment.carrier,
-            shipment_id,
-            milestones_hit,
-            total_milestones,
-        );
+            // Emit the dedicated force-cancel event — distinct from shipment_cancelled.
+            events::emit_force_cancelled(&env, shipment_id, &admin, &reason_hash, escrow_amount);
 
-        if now > shipment.deadline {
-            events::emit_carrier_late_delivery(
-                &env,
-                &shipment.carrier,
-                shipment_id,
-                shipment.deadline,
-                now,
-            );
-        } else {
-            events::emit_carrier_on_time_delivery(&env, &shipment.carrier, shipment_id);
-        }
+            Ok(())
+        })
+    }
 
-        events::emit_notification(
-            &env,
-            &shipment.sender,
-            NotificationType::DeliveryConfirmed,
-            shipment_id,
-            &confirmation_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.carrier,
-            NotificationType::DeliveryConfirmed,
-            shipment_id,
-            &confirmation_hash,
-        );
+    /// Approve a WASM hash as an upgrade target. Both `upgrade` and the
+    /// multisig `Upgrade` action reject hashes that were not approved here.
+    /// Only the admin can call this.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin approving the hash.
+    /// * `wasm_hash` - Hash of the uploaded WASM to allow.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok once the hash is allowlisted.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If wasm_hash is all zeros.
+    /// * `NavinError::Unauthorized` - If caller is not the admin.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.allow_wasm_hash(&env, &admin, &wasm_hash);
+    /// ```
+    pub fn allow_wasm_hash(
+        env: Env,
+        admin: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        admin.require_auth();
+        validation::validate_hash(&wasm_hash)?;
+        require_admin(&env, &admin)?;
 
+        storage::allow_wasm_hash(&env, &wasm_hash);
+        events::emit_wasm_hash_allowed(&env, &admin, &wasm_hash);
         Ok(())
     }
 
-    /// Confirm a partial delivery and release a bounded escrow percentage.
+    /// Check whether a WASM hash is allowlisted as an upgrade target.
     ///
-    /// The receiver can repeatedly confirm partial delivery slices while the
-    /// shipment is in transit/checkpoint/partial states. Each call releases
-    /// `release_percent` of `total_escrow`, and cumulative releases are bounded
-    /// so they never exceed the escrow initially deposited.
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `wasm_hash` - Hash to look up.
+    ///
+    /// # Returns
+    /// * `bool` - `true` once `allow_wasm_hash` has approved the hash.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let allowed = contract.is_wasm_hash_allowed(&env, &wasm_hash);
+    /// ```
+    pub fn is_wasm_hash_allowed(env: Env, wasm_hash: BytesN<32>) -> bool {
+        storage::is_wasm_hash_allowed(&env, &wasm_hash)
+    }
+
+    /// Upgrade the contract to a new WASM implementation.
+    /// Only the admin can trigger upgrades, and only to an allowlisted hash.
+    /// State is preserved.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `receiver` - Receiver address confirming the partial delivery.
-    /// * `shipment_id` - Identifier of the shipment.
-    /// * `confirmation_hash` - The proof-of-delivery hash for this partial confirmation.
-    /// * `release_percent` - Percentage of total escrow to release (1-100).
+    /// * `admin` - Contract admin executing the upgrade.
+    /// * `new_wasm_hash` - Hash pointer to the new WASM instance loaded on network.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful partial confirmation.
+    /// * `Result<(), NavinError>` - Ok on successful deployment upgrade instance.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::InvalidHash` - If confirmation_hash is all zeros.
-    /// * `NavinError::InvalidAmount` - If release_percent is 0 or > 100.
-    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    /// * `NavinError::Unauthorized` - If called by an address other than the shipment receiver.
-    /// * `NavinError::InvalidStatus` - If shipment is not in a valid state for partial delivery.
+    /// * `NavinError::Unauthorized` - If caller isn't contract admin instance.
+    /// * `NavinError::InvalidHash` - If new_wasm_hash is all zeros.
+    /// * `NavinError::CounterOverflow` - If total tracking version identifier pointer triggers overflow.
+    /// * `NavinError::WasmNotAllowlisted` - If new_wasm_hash was not approved via `allow_wasm_hash`.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.confirm_partial_delivery(&env, &receiver, 1, &hash, 50);
+    /// // contract.upgrade(env, admin, new_wasm_hash);
     /// ```
-    pub fn confirm_partial_delivery(
+    pub fn upgrade(
         env: Env,
-        receiver: Address,
-        shipment_id: u64,
-        confirmation_hash: BytesN<32>,
-        release_percent: u32,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+        target_version: u32,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        require_not_paused(&env)?;
-        receiver.require_auth();
+        admin.require_auth();
 
         // Validate hash before storage
-        validation::validate_hash(&confirmation_hash)?;
-
-        if release_percent == 0 || release_percent > 100 {
-            return Err(NavinError::InvalidAmount);
-        }
+        validation::validate_hash(&new_wasm_hash)?;
 
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        if shipment.receiver != receiver {
+        if storage::get_admin(&env) != admin {
             return Err(NavinError::Unauthorized);
         }
-        require_not_finalized(&shipment)?;
 
-        if shipment.status != ShipmentStatus::InTransit
-            && shipment.status != ShipmentStatus::AtCheckpoint
-            && shipment.status != ShipmentStatus::PartiallyDelivered
-        {
-            return Err(NavinError::InvalidStatus);
-        }
+        let current_version = storage::get_version(&env);
 
-        let release_amount =
-            checked_mul_div_i128(shipment.total_escrow, release_percent as i128, 100)?;
-        if release_amount <= 0 {
-            return Err(NavinError::InvalidAmount);
+        // Enforce one-way migration guardrails and allowed edges
+        if !is_allowed_migration(current_version, target_version) {
+            return Err(NavinError::InvalidMigrationEdge);
         }
-
-        let released_so_far = checked_sub_i128(shipment.total_escrow, shipment.escrow_amount)?;
-        let new_total_released = checked_add_i128(released_so_far, release_amount)?;
-        if new_total_released > shipment.total_escrow {
-            return Err(NavinError::InvalidAmount);
+        if !storage::is_wasm_hash_allowed(&env, &new_wasm_hash) {
+            return Err(NavinError::WasmNotAllowlisted);
         }
 
-        let old_status = shipment.status.clone();
-        shipment.status = if new_total_released == shipment.total_escrow {
-            ShipmentStatus::Delivered
-        } else {
-            ShipmentStatus::PartiallyDelivered
-        };
-        shipment.updated_at = env.ledger().timestamp();
+        let shipment_count = storage::get_shipment_counter(&env);
 
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &shipment.status);
-        storage::set_confirmation_hash(&env, shipment_id, &confirmation_hash);
-        if shipment.status == ShipmentStatus::Delivered {
-            storage::decrement_active_shipment_count(&env, &shipment.sender);
-        }
+        let report = MigrationReport {
+            current_version,
+            target_version,
+            affected_shipments: shipment_count,
+        };
 
-        internal_release_escrow(&env, &mut shipment, release_amount)?;
-        finalize_if_settled(&env, &mut shipment);
-        persist_shipment(&env, &shipment)?;
-        extend_shipment_ttl(&env, shipment_id);
+        storage::set_version(&env, target_version);
+        events::emit_contract_upgraded(&env, &admin, &new_wasm_hash, target_version);
+        events::emit_migration_report(&env, &report);
 
-        events::emit_status_updated(
-            &env,
-            shipment_id,
-            &old_status,
-            &shipment.status,
-            &confirmation_hash,
-        );
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
 
         Ok(())
     }
 
-    /// Report a geofence event for a shipment.
-    /// Only registered carriers can report geofence events.
+    /// Read-only dry-run for a proposed migration to estimate impact and validate edges.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `carrier` - Carrier address reporting the event.
-    /// * `shipment_id` - ID of the tracked shipment.
-    /// * `zone_type` - Type of geofence event crossed.
-    /// * `data_hash` - Encrypted off-chain location data representation.
+    /// * `target_version` - The version to simulate migrating to.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful report tracking.
-    ///
-    /// # Errors
-    /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller isn't a Carrier role.
-    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
-    /// * `NavinError::ShipmentNotFound` - If tracking context specifies an invalid shipment.
-    ///
-    /// # Examples
-    /// ```rust
-    /// // contract.report_geofence_event(&env, &carrier, 1, GeofenceEvent::ZoneEntry, &hash);
-    /// ```
-    pub fn report_geofence_event(
-        env: Env,
-        carrier: Address,
-        shipment_id: u64,
-        zone_type: GeofenceEvent,
-        data_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
+    /// * `Result<MigrationReport, NavinError>` - Summary of the migration impact.
+    pub fn dry_run_migration(env: Env, target_version: u32) -> Result<MigrationReport, NavinError> {
         require_initialized(&env)?;
-        carrier.require_auth();
-        require_role(&env, &carrier, Role::Carrier)?;
-        require_active_carrier(&env, &carrier)?;
-
-        // Verify shipment exists and carrier is assigned
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-        require_not_finalized(&shipment)?;
 
-        // Validate hash before storage
-        validation::validate_hash(&data_hash)?;
+        let current_version = storage::get_version(&env);
 
-        if shipment.carrier != carrier {
-            return Err(NavinError::Unauthorized);
+        if !is_allowed_migration(current_version, target_version) {
+            return Err(NavinError::InvalidMigrationEdge);
         }
 
-        events::emit_geofence_event(&env, shipment_id, zone_type, &data_hash);
+        let shipment_count = storage::get_shipment_counter(&env);
 
-        Ok(())
+        Ok(MigrationReport {
+            current_version,
+            target_version,
+            affected_shipments: shipment_count,
+        })
     }
 
-    /// Update ETA for a shipment.
-    /// Only the designated registered carrier can update ETA.
-    /// ETA must be strictly in the future.
+    /// Release escrowed funds to the carrier after delivery confirmation.
+    /// Only the receiver or admin can trigger release.
+    /// Shipment must be in Delivered status.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `carrier` - Active assigned carrier modifying ETA.
-    /// * `shipment_id` - Identifiable tracker mapping to shipment.
-    /// * `eta_timestamp` - The estimated timestamp prediction in the future.
-    /// * `data_hash` - The mapped hash associated with the update.
+    /// * `caller` - Originating user triggering escrow delivery (receiver/admin).
+    /// * `shipment_id` - Tracking assignment associated with delivery payload instances.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful ETA registry.
+    /// * `Result<(), NavinError>` - Ok on successful asset delivery.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller isn't the assigned carrier.
-    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
-    /// * `NavinError::ShipmentNotFound` - If shipment instance targets missing entry.
-    /// * `NavinError::InvalidTimestamp` - If provided ETA is strictly in the past or present.
+    /// * `NavinError::ShipmentNotFound` - If tracking context specifies an invalid shipment.
+    /// * `NavinError::Unauthorized` - If caller isn't receiver or admin.
+    /// * `NavinError::InvalidStatus` - If contract expects specific lifecycle constraint and differs.
+    /// * `NavinError::NotFunded` - If escrow was never deposited for the shipment.
+    /// * `NavinError::ShipmentFinalized` - If the escrow was already fully released.
+    /// * `NavinError::InsufficientFunds` - If no escrow remains on a delivered shipment that is not yet finalized.
+    /// * `NavinError::ReleaseDelayActive` - If the post-delivery cooling-off period has not elapsed.
+    /// * `NavinError::ContractPaused` - If the contract is paused.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.update_eta(&env, &carrier, 1, new_eta, &hash);
+    /// ```rust,no_run
+    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
+    /// # use soroban_sdk::testutils::Address as _;
+    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
+    /// # let env = Env::default();
+    /// # env.mock_all_auths();
+    /// # let contract_id = env.register(NavinShipment, ());
+    /// # let client = NavinShipmentClient::new(&env, &contract_id);
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// # let carrier = Address::generate(&env);
+    /// # client.add_carrier(&admin, &carrier);
+    /// # let receiver = Address::generate(&env);
+    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    /// # let deadline = env.ledger().timestamp() + 86_400;
+    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
+    /// # client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &BytesN::from_array(&env, &[2u8; 32]));
+    /// # client.confirm_delivery(&receiver, &shipment_id, &BytesN::from_array(&env, &[3u8; 32]));
+    /// // Manually release any remaining escrow to the carrier after delivery is confirmed.
+    /// client.release_escrow(&receiver, &shipment_id);
     /// ```
-    pub fn update_eta(
-        env: Env,
-        carrier: Address,
-        shipment_id: u64,
-        eta_timestamp: u64,
-        data_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
+    pub fn release_escrow(env: Env, caller: Address, shipment_id: u64) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        carrier.require_auth();
-        require_role(&env, &carrier, Role::Carrier)?;
+        require_not_paused(&env)?;
+        caller.require_auth();
 
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        with_reentrancy_lock(&env, || {
+            let admin = storage::get_admin(&env);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        require_not_finalized(&shipment)?;
+            if caller != shipment.receiver && caller != admin {
+                return Err(NavinError::Unauthorized);
+            }
 
-        // Validate hash before storage
-        validation::validate_hash(&data_hash)?;
+            // Checked before finalization: an unfunded shipment finalizes on
+            // delivery, which would otherwise hide why there is nothing to release.
+            if shipment.total_escrow == 0 {
+                return Err(NavinError::NotFunded);
+            }
 
-        if shipment.carrier != carrier {
-            return Err(NavinError::Unauthorized);
-        }
+            require_not_finalized(&shipment)?;
 
-        if eta_timestamp <= env.ledger().timestamp() {
-            return Err(NavinError::InvalidTimestamp);
-        }
+            if shipment.status != ShipmentStatus::Delivered {
+                return Err(NavinError::InvalidStatus);
+            }
+            require_release_delay_elapsed(&env, shipment_id)?;
 
-        events::emit_eta_updated(&env, shipment_id, eta_timestamp, &data_hash);
+            let escrow_amount = shipment.escrow_amount;
+            if escrow_amount == 0 {
+                return Err(NavinError::InsufficientFunds);
+            }
 
-        Ok(())
+            internal_release_escrow(&env, &mut shipment, escrow_amount)?;
+            storage::remove_release_at(&env, shipment_id);
+            finalize_if_settled(&env, &mut shipment);
+            persist_shipment(&env, &shipment)?;
+            events::emit_notification(
+                &env,
+                &shipment.sender,
+                NotificationType::EscrowReleased,
+                shipment_id,
+                &BytesN::from_array(&env, &[0u8; 32]),
+            );
+            notify_extra_recipients(
+                &env,
+                &shipment.sender,
+                NotificationType::EscrowReleased,
+                shipment_id,
+                &BytesN::from_array(&env, &[0u8; 32]),
+            );
+            events::emit_notification(
+                &env,
+                &shipment.carrier,
+                NotificationType::EscrowReleased,
+                shipment_id,
+                &BytesN::from_array(&env, &[0u8; 32]),
+            );
+
+            Ok(())
+        })
     }
 
-    /// Record a milestone for a shipment.
-    /// Only registered carriers can record milestones.
+    /// Release escrow withheld by `release_delay_seconds` once the cooling-off
+    /// period after delivery has elapsed. Anyone may call this.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `carrier` - Assigned carrier address triggering the recording.
-    /// * `shipment_id` - ID of the tracked shipment.
-    /// * `checkpoint` - Representation of progress milestone achieved.
-    /// * `data_hash` - Integrity hash associated with offchain progress indicators.
+    /// * `shipment_id` - ID of the delivered shipment.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful tracking record update.
+    /// * `Result<(), NavinError>` - Ok once the escrow is released to the carrier.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by unassigned identity.
-    /// * `NavinError::InvalidHash` - If data_hash is all zeros.
-    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
-    /// * `NavinError::ShipmentNotFound` - If shipment instance targets missing entry.
-    /// * `NavinError::InvalidStatus` - If tracked instance is not `InTransit`.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidStatus` - If the shipment has no withheld release.
+    /// * `NavinError::ReleaseDelayActive` - If the cooling-off period has not elapsed.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.record_milestone(&env, &carrier, 1, Symbol::new(&env, "warehouse"), &hash);
+    /// // contract.finalize_release(&env, 1);
     /// ```
-    pub fn record_milestone(
-        env: Env,
-        carrier: Address,
-        shipment_id: u64,
-        checkpoint: Symbol,
-        data_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
+    pub fn finalize_release(env: Env, shipment_id: u64) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        carrier.require_auth();
-        require_role(&env, &carrier, Role::Carrier)?;
-        require_active_carrier(&env, &carrier)?;
-
-        // Verify shipment exists, carrier is assigned, and status
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-        require_not_finalized(&shipment)?;
 
-        // Validate checkpoint symbol
-        validation::validate_checkpoint_symbol(&env, &checkpoint)?;
-
-        // Validate hash before storage
-        validation::validate_hash(&data_hash)?;
+        with_reentrancy_lock(&env, || {
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        if shipment.carrier != carrier {
-            return Err(NavinError::Unauthorized);
-        }
+            require_not_finalized(&shipment)?;
 
-        if shipment.status != ShipmentStatus::InTransit {
-            return Err(NavinError::InvalidStatus);
-        }
+            if shipment.status != ShipmentStatus::Delivered
+                || storage::get_release_at(&env, shipment_id).is_none()
+            {
+                return Err(NavinError::InvalidStatus);
+            }
+            require_release_delay_elapsed(&env, shipment_id)?;
 
-        // Enforce milestone event payload size guard
-        let config = config::get_config(&env);
-        let current_milestone_count = storage::get_milestone_event_count(&env, shipment_id);
-        if current_milestone_count >= config.max_milestones_per_shipment {
-            return Err(NavinError::MilestoneLimitExceeded);
-        }
+            let escrow_amount = shipment.escrow_amount;
+            internal_release_escrow(&env, &mut shipment, escrow_amount)?;
+            storage::remove_release_at(&env, shipment_id);
+            finalize_if_settled(&env, &mut shipment);
+            persist_shipment(&env, &shipment)?;
+            extend_shipment_ttl(&env, shipment_id);
 
-        let timestamp = env.ledger().timestamp();
+            Ok(())
+        })
+    }
 
-        let _milestone = Milestone {
-            shipment_id,
-            checkpoint: checkpoint.clone(),
-            data_hash: data_hash.clone(),
-            timestamp,
-            reporter: carrier.clone(),
-        };
+    /// Refund escrowed funds to the company if shipment is cancelled.
+    /// Only the sender (Company) or admin can trigger refund.
+    /// Shipment must be in Created or Cancelled status.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `caller` - Reference mapping handler execution triggers for scope access control checks.
+    /// * `shipment_id` - Identification marker mapping.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful refund sequence generation.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If valid identifiers track undefined mappings instances.
+    /// * `NavinError::Unauthorized` - If execution identity doesn't resolve matching configurations contexts mappings.
+    /// * `NavinError::InvalidStatus` - If mapping resolves illegal flow mappings configuration combinations triggers.
+    /// * `NavinError::InsufficientFunds` - If token escrow state points map uninitialized quantities values scope checks.
+    /// * `NavinError::ContractPaused` - If the contract is paused.
+    ///
+    /// # Examples
+    /// ```rust
+    /// ```rust,no_run
+    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
+    /// # use soroban_sdk::testutils::Address as _;
+    /// # use shipment::{NavinShipment, NavinShipmentClient};
+    /// # let env = Env::default();
+    /// # env.mock_all_auths();
+    /// # let contract_id = env.register(NavinShipment, ());
+    /// # let client = NavinShipmentClient::new(&env, &contract_id);
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// # let carrier = Address::generate(&env);
+    /// # client.add_carrier(&admin, &carrier);
+    /// # let receiver = Address::generate(&env);
+    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    /// # let deadline = env.ledger().timestamp() + 86_400;
+    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
+    /// // Refund escrow back to the company when the shipment is in Created or Cancelled state.
+    /// client.refund_escrow(&admin, &shipment_id);
+    /// ```
+    pub fn refund_escrow(env: Env, caller: Address, shipment_id: u64) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        caller.require_auth();
 
-        // Do NOT store the milestone on-chain
-        // Emit the milestone_recorded event (Hash-and-Emit pattern)
-        events::emit_milestone_recorded(&env, shipment_id, &checkpoint, &data_hash, &carrier);
+        with_reentrancy_lock(&env, || {
+            let admin = storage::get_admin(&env);
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        // Check for milestone-based payments
-        let mut mut_shipment = shipment;
-        let mut found_index = None;
-        for (i, milestone) in mut_shipment.payment_milestones.iter().enumerate() {
-            if milestone.0 == checkpoint {
-                found_index = Some(i);
-                break;
+            if caller != shipment.sender && caller != admin {
+                return Err(NavinError::Unauthorized);
             }
-        }
 
-        if let Some(idx) = found_index {
-            let mut already_paid = false;
-            for paid_symbol in mut_shipment.paid_milestones.iter() {
-                if paid_symbol == checkpoint {
-                    already_paid = true;
-                    break;
-                }
-            }
+            require_not_finalized(&shipment)?;
 
-            if already_paid {
-                return Err(NavinError::MilestoneAlreadyPaid);
+            // Check for suspension if caller is the sender (company)
+            if caller == shipment.sender {
+                require_active_company(&env, &caller)?;
             }
 
-            let milestone = mut_shipment.payment_milestones.get(idx as u32).unwrap();
-
-            mut_shipment
-                .milestones_completed
-                .push_back(checkpoint.clone());
-            if !mut_shipment.paid_milestones.iter().any(|m| m == checkpoint) {
-                mut_shipment.paid_milestones.push_back(checkpoint.clone());
+            if shipment.status != ShipmentStatus::Created
+                && shipment.status != ShipmentStatus::Cancelled
+            {
+                return Err(NavinError::InvalidStatus);
             }
 
-            // Calculate total percentage paid including this one
-            let mut total_pct_paid = 0;
-            for (m_sym, m_pct) in mut_shipment.payment_milestones.iter() {
-                if mut_shipment.paid_milestones.iter().any(|p| p == m_sym) {
-                    total_pct_paid += m_pct;
-                }
+            if shipment.escrow_amount == 0 {
+                return Err(NavinError::InsufficientFunds);
             }
 
-            let release_amount = if total_pct_paid == 100 {
-                mut_shipment.escrow_amount
-            } else {
-                checked_mul_div_i128(mut_shipment.total_escrow, milestone.1 as i128, 100)?
-            };
-
-            events::emit_milestone_payment_released(
-                &env,
-                shipment_id,
-                &checkpoint,
-                release_amount,
-                &mut_shipment.carrier,
-            );
-            internal_release_escrow(&env, &mut mut_shipment, release_amount)?;
-        }
-
-        finalize_if_settled(&env, &mut mut_shipment);
-        storage::set_shipment(&env, &mut_shipment);
+            refund_and_cancel(&env, &mut shipment, &caller)?;
 
-        Ok(())
+            Ok(())
+        })
     }
 
-    /// Record multiple milestones for a shipment in a single atomic transaction.
-    /// Allows a carrier to record multiple checkpoints at once, reducing gas costs.
-    /// Limit: 10 milestones per batch.
+    /// Raise a dispute for a shipment.
+    /// Only the sender, receiver, or carrier can raise a dispute.
+    /// Shipment must not be Cancelled or already Disputed.
     ///
     /// # Arguments
-    /// * `env` - Execution environment.
-    /// * `carrier` - Assigned carrier address triggering the recording.
-    /// * `shipment_id` - ID of the tracked shipment.
-    /// * `milestones` - Vector of (checkpoint, data_hash) tuples.
+    /// * `env` - Execution environment tracking context.
+    /// * `caller` - Identity specifying resolution event raising instances configuration contexts.
+    /// * `shipment_id` - Object tracker index identifying execution scope handlers.
+    /// * `reason_hash` - Encoded offchain metadata representation parameter validation identifier limits strings pointers.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful batch recording.
+    /// * `Result<(), NavinError>` - Ok on successful dispute registry logging.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If called by unassigned identity.
-    /// * `NavinError::InvalidHash` - If any data_hash is all zeros.
-    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
-    /// * `NavinError::ShipmentNotFound` - If shipment instance targets missing entry.
-    /// * `NavinError::InvalidStatus` - If tracked instance is not `InTransit`.
-    /// * `NavinError::BatchTooLarge` - If more than 10 milestones are submitted.
+    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not involved in the shipment.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is already completed.
+    /// * `NavinError::DisputeLimitReached` - If the shipment has reached `max_disputes_per_shipment`.
     ///
     /// # Examples
     /// ```rust
-    /// // let milestones = vec![
-    /// //     (Symbol::new(&env, "warehouse"), hash1),
-    /// //     (Symbol::new(&env, "port"), hash2),
-    /// // ];
-    /// // contract.record_milestones_batch(&env, &carrier, 1, milestones);
+    /// ```rust,no_run
+    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
+    /// # use soroban_sdk::testutils::Address as _;
+    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
+    /// # let env = Env::default();
+    /// # env.mock_all_auths();
+    /// # let contract_id = env.register(NavinShipment, ());
+    /// # let client = NavinShipmentClient::new(&env, &contract_id);
+    /// # let admin = Address::generate(&env);
+    /// # let token = Address::generate(&env);
+    /// # client.initialize(&admin, &token);
+    /// # client.add_company(&admin, &admin);
+    /// # let carrier = Address::generate(&env);
+    /// # client.add_carrier(&admin, &carrier);
+    /// # let receiver = Address::generate(&env);
+    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    /// # let deadline = env.ledger().timestamp() + 86_400;
+    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
+    /// # client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &BytesN::from_array(&env, &[2u8; 32]));
+    /// let reason_hash = BytesN::from_array(&env, &[4u8; 32]); // SHA-256 of dispute reason doc
+    ///
+    /// // Receiver raises a dispute; escrow is frozen until admin resolves it.
+    /// client.raise_dispute(&receiver, &shipment_id, &reason_hash);
     /// ```
-    pub fn record_milestones_batch(
+    pub fn raise_dispute(
         env: Env,
-        carrier: Address,
+        caller: Address,
         shipment_id: u64,
-        milestones: Vec<(Symbol, BytesN<32>)>,
+        reason_hash: BytesN<32>,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        carrier.require_auth();
-        require_role(&env, &carrier, Role::Carrier)?;
-        require_active_carrier(&env, &carrier)?;
-
-        // Validate batch size
-        let config = config::get_config(&env);
-        if milestones.len() > config.batch_operation_limit {
-            return Err(NavinError::BatchTooLarge);
-        }
-
-        // Validate all hashes in milestones
-        for (_, hash) in &milestones {
-            validation::validate_hash(&hash)?;
-        }
-
-        // Verify shipment exists, carrier is assigned, and status
-        let shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-        require_not_finalized(&shipment)?;
-
-        if shipment.carrier != carrier {
-            return Err(NavinError::Unauthorized);
-        }
-
-        if shipment.status != ShipmentStatus::InTransit {
-            return Err(NavinError::InvalidStatus);
-        }
-
-        // Validate all milestones before committing any (atomic operation)
-        // This ensures that if any milestone is invalid, none are committed
-        for milestone_tuple in milestones.iter() {
-            let data_hash = &milestone_tuple.1;
-
-            // Validate hash
-            validation::validate_hash(data_hash)?;
-        }
-
-        // Enforce milestone event payload size guard
-        let config = config::get_config(&env);
-        let current_milestone_count = storage::get_milestone_event_count(&env, shipment_id);
-        let new_milestones = milestones.len();
-        if current_milestone_count
-            .checked_add(new_milestones)
-            .ok_or(NavinError::ArithmeticError)?
-            > config.max_milestones_per_shipment
-        {
-            return Err(NavinError::MilestoneLimitExceeded);
-        }
-
-        // All validations passed, now process each milestone
-        let timestamp = env.ledger().timestamp();
-        let mut mut_shipment = shipment;
-
-        for milestone_tuple in milestones.iter() {
-            let checkpoint = milestone_tuple.0.clone();
-            let data_hash = milestone_tuple.1.clone();
-
-            let _milestone = Milestone {
-                shipment_id,
-                checkpoint: checkpoint.clone(),
-                data_hash: data_hash.clone(),
-                timestamp,
-                reporter: carrier.clone(),
-            };
-
-            // Emit one event per milestone (Hash-and-Emit pattern)
-            events::emit_milestone_recorded(&env, shipment_id, &checkpoint, &data_hash, &carrier);
-
-            // Check for milestone-based payments
-            let mut found_index = None;
-            for (i, payment_milestone) in mut_shipment.payment_milestones.iter().enumerate() {
-                if payment_milestone.0 == checkpoint {
-                    found_index = Some(i);
-                    break;
-                }
-            }
-
-            if let Some(idx) = found_index {
-                let mut already_paid = false;
-                for paid_symbol in mut_shipment.paid_milestones.iter() {
-                    if paid_symbol == checkpoint {
-                        already_paid = true;
-                        break;
-                    }
-                }
-
-                if !already_paid {
-                    let payment_milestone =
-                        mut_shipment.payment_milestones.get(idx as u32).unwrap();
-                    let release_amount = checked_mul_div_i128(
-                        mut_shipment.total_escrow,
-                        payment_milestone.1 as i128,
-                        100,
-                    )?;
-
-                    mut_shipment
-                        .milestones_completed
-                        .push_back(checkpoint.clone());
-                    if !mut_shipment.paid_milestones.iter().any(|m| m == checkpoint) {
-                        mut_shipment.paid_milestones.push_back(checkpoint.clone());
-                    }
-
-                    events::emit_milestone_payment_released(
-                        &env,
-                        shipment_id,
-                        &checkpoint,
-                        release_amount,
-                        &mut_shipment.carrier,
-                    );
-                    internal_release_escrow(&env, &mut mut_shipment, release_amount)?;
-                }
-            }
-        }
-
-        finalize_if_settled(&env, &mut mut_shipment);
-        storage::set_shipment(&env, &mut_shipment);
+        caller.require_auth();
 
-        Ok(())
+        apply_dispute(&env, &caller, shipment_id, &reason_hash)
     }
 
-    /// Explicitly release a partial escrow payment for a specific milestone.
-    /// Only Carrier or Admin can call this.
+    /// Raise a dispute and lock a bond to deter frivolous disputes.
+    ///
+    /// The bond is pulled from `caller` and held until the dispute is
+    /// resolved. It is returned if the outcome favours the disputer and
+    /// forfeited to the winning party otherwise. A carrier wins on release;
+    /// the sender and receiver win on refund.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `caller` - Identity triggering the release (Carrier or Admin).
-    /// * `shipment_id` - ID of the target shipment.
-    /// * `milestone_name` - symbolic name of the milestone to pay.
-    pub fn release_milestone_payment(
+    /// * `caller` - Sender, receiver, or carrier raising the dispute.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `reason_hash` - Hash of the off-chain dispute reason.
+    /// * `bond` - Token amount to lock.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok once the dispute is raised and the bond locked.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidAmount` - If `bond` is not positive.
+    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not involved in the shipment.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is cancelled or already disputed.
+    /// * `NavinError::InsufficientFunds` - If the shipment holds no escrow.
+    /// * `NavinError::DisputeLimitReached` - If the shipment has reached `max_disputes_per_shipment`.
+    /// * `NavinError::TokenTransferFailed` - If the bond cannot be pulled from the caller.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.raise_dispute_with_bond(&env, &receiver, &shipment_id, &reason_hash, &100);
+    /// ```
+    pub fn raise_dispute_with_bond(
         env: Env,
         caller: Address,
         shipment_id: u64,
-        milestone_name: Symbol,
+        reason_hash: BytesN<32>,
+        bond: i128,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
         caller.require_auth();
-
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-        require_not_finalized(&shipment)?;
-
-        let admin = storage::get_admin(&env);
-        if caller != shipment.carrier && caller != admin {
-            return Err(NavinError::Unauthorized);
-        }
-
-        if caller == shipment.carrier {
-            require_active_carrier(&env, &caller)?;
-        }
-
-        // Check if milestone exists in payment_milestones
-        let mut milestone_idx = None;
-        for (i, ms) in shipment.payment_milestones.iter().enumerate() {
-            if ms.0 == milestone_name {
-                milestone_idx = Some(i);
-                break;
-            }
-        }
-
-        let idx = milestone_idx.ok_or(NavinError::InvalidShipmentInput)?;
-
-        // Check if already in milestones_completed
-        let mut already_completed = false;
-        for ms in shipment.milestones_completed.iter() {
-            if ms == milestone_name {
-                already_completed = true;
-                break;
-            }
-        }
-
-        if already_completed {
-            return Err(NavinError::MilestoneAlreadyPaid);
-        }
-
-        // Enforce sequential ordering: all prior milestones must be paid first.
-        if idx > 0 {
-            let completed_count = shipment.milestones_completed.len() as usize;
-            if completed_count < idx {
-                return Err(NavinError::InvalidStatus);
-            }
-        }
-
-        let ms_config = shipment.payment_milestones.get(idx as u32).unwrap();
-
-        // Calculate total percentage paid including this one to handle rounding on last milestone
-        let mut total_pct_paid = 0;
-        for (m_sym, m_pct) in shipment.payment_milestones.iter() {
-            if shipment.milestones_completed.iter().any(|p| p == m_sym) || m_sym == milestone_name {
-                total_pct_paid += m_pct;
+        with_reentrancy_lock(&env, || {
+            if bond <= 0 {
+                return Err(NavinError::InvalidAmount);
             }
-        }
-
-        let release_amount = if total_pct_paid == 100 {
-            shipment.escrow_amount
-        } else {
-            checked_mul_div_i128(shipment.total_escrow, ms_config.1 as i128, 100)?
-        };
 
-        if release_amount > 0 {
-            shipment
-                .milestones_completed
-                .push_back(milestone_name.clone());
-            // Keep paid_milestones in sync for backward compatibility
-            let mut in_paid = false;
-            for ms in shipment.paid_milestones.iter() {
-                if ms == milestone_name {
-                    in_paid = true;
-                    break;
-                }
-            }
-            if !in_paid {
-                shipment.paid_milestones.push_back(milestone_name.clone());
+            // An unfunded dispute can never be settled, which would lock the bond.
+            let shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            if shipment.escrow_amount == 0 {
+                return Err(NavinError::InsufficientFunds);
             }
 
-            events::emit_milestone_payment_released(
-                &env,
-                shipment_id,
-                &milestone_name,
-                release_amount,
-                &shipment.carrier,
-            );
-            internal_release_escrow(&env, &mut shipment, release_amount)?;
-        }
+            apply_dispute(&env, &caller, shipment_id, &reason_hash)?;
 
-        finalize_if_settled(&env, &mut shipment);
-        storage::set_shipment(&env, &shipment);
+            let token_contract =
+                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+            let contract_address = env.current_contract_address();
+            invoke_token_transfer(&env, &token_contract, &caller, &contract_address, bond)?;
+            storage::set_dispute_bond(&env, shipment_id, &caller, bond);
 
-        Ok(())
+            events::emit_dispute_bond_posted(&env, shipment_id, &caller, bond);
+            Ok(())
+        })
     }
 
-    /// Extend the TTL of a shipment's persistent storage entries.
+    /// Preview the transfer `resolve_dispute` would make, without mutating state.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - Shipment ID to renew TTL.
+    /// * `shipment_id` - ID of the disputed shipment.
+    /// * `resolution` - Resolution to simulate.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on success.
+    /// * `Result<(Address, i128), NavinError>` - Recipient and amount that would be paid.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Disputed`.
+    /// * `NavinError::InsufficientFunds` - If no escrow is held.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.extend_shipment_ttl(env, 1);
+    /// // let (to, amount) = contract.simulate_resolution(&env, 1, DisputeResolution::ReleaseToCarrier);
     /// ```
-    pub fn extend_shipment_ttl(env: Env, shipment_id: u64) -> Result<(), NavinError> {
+    pub fn simulate_resolution(
+        env: Env,
+        shipment_id: u64,
+        resolution: DisputeResolution,
+    ) -> Result<(Address, i128), NavinError> {
         require_initialized(&env)?;
-        extend_shipment_ttl(&env, shipment_id);
-        Ok(())
+        let shipment = load_resolvable_dispute(&env, shipment_id)?;
+
+        let recipient = match resolution {
+            DisputeResolution::ReleaseToCarrier => shipment.carrier,
+            DisputeResolution::RefundToCompany => shipment.sender,
+        };
+        Ok((recipient, shipment.escrow_amount))
     }
 
-    /// Cancel a shipment before it is delivered.
-    /// Only the Company (sender) or Admin can cancel.
-    /// Shipment must not be Delivered or Disputed.
-    /// If escrow exists, triggers automatic refund to the Company.
+    /// Preview the transfers `resolve_dispute_split` would make, without mutating state.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `caller` - Executing Company or Admin address.
-    /// * `shipment_id` - ID specifying cancelled shipment instance.
-    /// * `reason_hash` - The mapped hash associated to the cancellation context.
+    /// * `shipment_id` - ID of the disputed shipment.
+    /// * `carrier_bps` - Carrier share in basis points (0–10000).
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on cancellation.
+    /// * `Result<(i128, i128), NavinError>` - `(carrier_amount, company_amount)`.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
-    /// * `NavinError::ShipmentNotFound` - If tracking context is invalid list element.
-    /// * `NavinError::Unauthorized` - If called by unauthorized accounts.
-    /// * `NavinError::ShipmentAlreadyCompleted` - If tracking context specified reached terminal states.
+    /// * `NavinError::InvalidAmount` - If `carrier_bps` exceeds 10000.
+    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Disputed`.
+    /// * `NavinError::InsufficientFunds` - If no escrow is held.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.cancel_shipment(&env, &admin, 1, &hash);
+    /// // let (to_carrier, to_company) = contract.simulate_resolution_split(&env, 1, 7_000);
     /// ```
-    pub fn cancel_shipment(
+    pub fn simulate_resolution_split(
         env: Env,
-        caller: Address,
         shipment_id: u64,
-        reason_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
+        carrier_bps: u32,
+    ) -> Result<(i128, i128), NavinError> {
+        require_initialized(&env)?;
+        if carrier_bps > 10_000 {
+            return Err(NavinError::InvalidAmount);
+        }
+        let shipment = load_resolvable_dispute(&env, shipment_id)?;
+
+        let carrier_amount =
+            checked_mul_div_i128(shipment.escrow_amount, carrier_bps as i128, 10_000)?;
+        let company_amount = checked_sub_i128(shipment.escrow_amount, carrier_amount)?;
+        Ok((carrier_amount, company_amount))
+    }
+
+    /// Escalate an open dispute so that it requires multisig resolution.
+    ///
+    /// Once escalated, `resolve_dispute` and `resolve_dispute_split` reject the
+    /// dispute; it can only be settled by an executed
+    /// `AdminAction::ResolveDispute` proposal.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `caller` - Sender, receiver, or carrier of the shipment.
+    /// * `shipment_id` - ID of the disputed shipment.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if the dispute was escalated.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not a party to the shipment.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Disputed`.
+    /// * `NavinError::DisputeEscalated` - If the dispute is already escalated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.escalate_dispute(&env, &receiver, shipment_id);
+    /// ```
+    pub fn escalate_dispute(env: Env, caller: Address, shipment_id: u64) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
         caller.require_auth();
 
-        // Validate hash before storage
-        validation::validate_hash(&reason_hash)?;
-
-        let admin = storage::get_admin(&env);
-        let mut shipment =
+        let shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        require_not_finalized(&shipment)?;
-
-        if caller != shipment.sender && caller != admin {
+        if caller != shipment.sender && caller != shipment.receiver && caller != shipment.carrier {
             return Err(NavinError::Unauthorized);
         }
+        require_not_finalized(&shipment)?;
 
-        // Check for suspension if caller is the sender (company)
-        if caller == shipment.sender {
-            require_active_company(&env, &caller)?;
-        }
-
-        match shipment.status {
-            ShipmentStatus::Delivered | ShipmentStatus::Disputed => {
-                return Err(NavinError::ShipmentAlreadyCompleted);
-            }
-            _ => {}
+        if shipment.status != ShipmentStatus::Disputed {
+            return Err(NavinError::InvalidStatus);
         }
-
-        let escrow_amount = shipment.escrow_amount;
-        let old_status = shipment.status.clone();
-        shipment.status = ShipmentStatus::Cancelled;
-        shipment.escrow_amount = 0;
-        shipment.updated_at = env.ledger().timestamp();
-        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-
-        persist_shipment(&env, &shipment)?;
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &ShipmentStatus::Cancelled);
-
-        // Decrement active shipment count if it was not already cancelled
-        if old_status != ShipmentStatus::Cancelled {
-            storage::decrement_active_shipment_count(&env, &shipment.sender);
+        if storage::is_dispute_escalated(&env, shipment_id) {
+            return Err(NavinError::DisputeEscalated);
         }
 
-        if escrow_amount > 0 {
-            storage::remove_escrow_balance(&env, shipment_id);
-            events::emit_escrow_released(&env, shipment_id, &shipment.sender, escrow_amount);
-        }
-        finalize_if_settled(&env, &mut shipment);
-        persist_shipment(&env, &shipment)?;
-        storage::remove_escrow_balance(&env, shipment_id);
+        storage::set_dispute_escalated(&env, shipment_id);
         extend_shipment_ttl(&env, shipment_id);
 
-        events::emit_shipment_cancelled(&env, shipment_id, &caller, &reason_hash);
+        events::emit_dispute_escalated(&env, shipment_id, &caller);
 
         Ok(())
     }
 
-    /// Emergency admin-only force-cancel for a shipment.
-    ///
-    /// This is a privileged override that bypasses the normal cancellation rules.
-    /// It can cancel a shipment in **any non-terminal state** (including Disputed),
-    /// and it requires a mandatory, non-zero `reason_hash` to ensure an immutable
-    /// audit trail is always present.
-    ///
-    /// Escrow behaviour is deterministic:
-    /// - If escrow is held, the full remaining balance is refunded to the company
-    ///   via the token contract before the shipment is marked Cancelled.
-    /// - If no escrow is held, the shipment is cancelled with no token transfer.
-    ///
-    /// Only the single admin or a multi-sig admin (via `propose_action` /
-    /// `approve_action`) may call this function directly. Regular companies and
-    /// carriers are rejected with `Unauthorized`.
+    /// Resolve a shipment dispute. Callable by the admin, a guardian or an arbitrator.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Admin address executing the force-cancel.
-    /// * `shipment_id` - ID of the shipment to force-cancel.
-    /// * `reason_hash` - Mandatory SHA-256 hash of the off-chain reason document.
+    /// * `admin` - Admin, guardian or arbitrator resolving the dispute.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `resolution` - Target resolution (Release to Carrier or Refund to Company).
+    /// * `reason_hash` - SHA-256 hash of the off-chain justification document.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on success.
+    /// * `Result<(), NavinError>` - Ok if successfully resolved.
     ///
     /// # Errors
-    /// * `NavinError::NotInitialized` - Contract not initialized.
-    /// * `NavinError::Unauthorized` - Caller is not the admin.
-    /// * `NavinError::ShipmentNotFound` - Shipment does not exist.
-    /// * `NavinError::InvalidHash` - `reason_hash` is all zeros.
-    /// * `NavinError::ShipmentAlreadyCompleted` - Shipment is already Delivered or Cancelled.
-    ///
-    /// # Examples
-    /// ```rust
-    /// // contract.force_cancel_shipment(&env, &admin, 1, &reason_hash);
-    /// ```
-    pub fn force_cancel_shipment(
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
+    /// * `NavinError::Unauthorized` - If caller is not the admin, a guardian or an arbitrator.
+    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::DisputeEscalated` - If the dispute was escalated to the multisig.
+    pub fn resolve_dispute(
         env: Env,
         admin: Address,
         shipment_id: u64,
+        resolution: DisputeResolution,
         reason_hash: BytesN<32>,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
         admin.require_auth();
+        with_reentrancy_lock(&env, || {
+            require_dispute_resolver(&env, &admin)?;
 
-        // Strict admin-only gate — no company/carrier bypass.
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
-        }
-
-        // Reason hash is mandatory and must be non-zero.
-        validation::validate_hash(&reason_hash)?;
-
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-        require_not_finalized(&shipment)?;
-
-        // Terminal states cannot be force-cancelled.
-        match shipment.status {
-            ShipmentStatus::Delivered | ShipmentStatus::Cancelled => {
-                return Err(NavinError::ShipmentAlreadyCompleted);
+            // Reason hash is mandatory; use a specific error rather than the generic InvalidHash.
+            if reason_hash.to_array().iter().all(|&b| b == 0) {
+                return Err(NavinError::DisputeReasonHashMissing);
             }
-            _ => {}
-        }
 
-        let old_status = shipment.status.clone();
-        let escrow_amount = shipment.escrow_amount;
+            // Escalated disputes can only be settled through a multisig proposal.
+            if storage::is_dispute_escalated(&env, shipment_id) {
+                return Err(NavinError::DisputeEscalated);
+            }
 
-        // Deterministic escrow refund: always refund to company if escrow is held.
-        if escrow_amount > 0 {
-            let token_contract =
-                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
-            let contract_address = env.current_contract_address();
-            invoke_token_transfer(
+            // Idempotency: reject duplicate (shipment_id, resolution, reason_hash) within the window.
+            let mut payload = soroban_sdk::Bytes::new(&env);
+            payload.append(&soroban_sdk::Bytes::from_array(
                 &env,
-                &token_contract,
-                &contract_address,
-                &shipment.sender,
-                escrow_amount,
-            )?;
-
-            shipment.escrow_amount = 0;
-            events::emit_escrow_refunded(&env, shipment_id, &shipment.sender, escrow_amount);
-        }
-
-        shipment.status = ShipmentStatus::Cancelled;
-        shipment.updated_at = env.ledger().timestamp();
-        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &ShipmentStatus::Cancelled);
-
-        // Decrement active count only if the shipment was not already in a
-        // non-active state (Cancelled is the only non-active non-terminal state
-        // that can't reach here, so this is always safe).
-        storage::decrement_active_shipment_count(&env, &shipment.sender);
-
-        finalize_if_settled(&env, &mut shipment);
-        persist_shipment(&env, &shipment)?;
-
-        extend_shipment_ttl(&env, shipment_id);
-
-        // Emit the dedicated force-cancel event — distinct from shipment_cancelled.
-        events::emit_force_cancelled(&env, shipment_id, &admin, &reason_hash, escrow_amount);
+                &shipment_id.to_be_bytes(),
+            ));
+            payload.append(&resolution.clone().to_xdr(&env));
+            payload.append(&reason_hash.clone().into());
+            check_idempotency(&env, payload)?;
 
-        Ok(())
+            settle_dispute(
+                &env,
+                &admin,
+                shipment_id,
+                resolution_carrier_bps(&resolution),
+                &reason_hash,
+            )
+        })
     }
 
-    /// Upgrade the contract to a new WASM implementation.
-    /// Only the admin can trigger upgrades. State is preserved.
+    /// Refund the company for a dispute left open past `dispute_window_seconds`.
+    ///
+    /// Permissionless, so disputes cannot stall forever waiting on the admin.
+    /// Settles exactly like `resolve_dispute` with `RefundToCompany`, using a
+    /// fixed reason hash (`sha256("auto_resolve_dispute")`) and the contract
+    /// itself as the resolver. Escalated disputes still require multisig.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin executing the upgrade.
-    /// * `new_wasm_hash` - Hash pointer to the new WASM instance loaded on network.
+    /// * `shipment_id` - ID of the disputed shipment.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful deployment upgrade instance.
+    /// * `Result<(), NavinError>` - Ok once the escrow has been refunded.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::Unauthorized` - If caller isn't contract admin instance.
-    /// * `NavinError::InvalidHash` - If new_wasm_hash is all zeros.
-    /// * `NavinError::CounterOverflow` - If total tracking version identifier pointer triggers overflow.
+    /// * `NavinError::ContractPaused` - If the contract is paused.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Disputed`.
+    /// * `NavinError::DisputeEscalated` - If the dispute was escalated to multisig.
+    /// * `NavinError::NotExpired` - If the window has not elapsed or auto-resolution is disabled.
+    /// * `NavinError::InsufficientFunds` - If the disputed shipment holds no escrow.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.upgrade(env, admin, new_wasm_hash);
+    /// // contract.auto_resolve_dispute(&env, 1);
     /// ```
-    pub fn upgrade(
-        env: Env,
-        admin: Address,
-        new_wasm_hash: BytesN<32>,
-        target_version: u32,
-    ) -> Result<(), NavinError> {
+    pub fn auto_resolve_dispute(env: Env, shipment_id: u64) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        admin.require_auth();
-
-        // Validate hash before storage
-        validation::validate_hash(&new_wasm_hash)?;
-
-        if storage::get_admin(&env) != admin {
-            return Err(NavinError::Unauthorized);
-        }
-
-        let current_version = storage::get_version(&env);
-
-        // Enforce one-way migration guardrails and allowed edges
-        if !is_allowed_migration(current_version, target_version) {
-            return Err(NavinError::InvalidMigrationEdge);
-        }
-
-        let shipment_count = storage::get_shipment_counter(&env);
-
-        let report = MigrationReport {
-            current_version,
-            target_version,
-            affected_shipments: shipment_count,
-        };
-
-        storage::set_version(&env, target_version);
-        events::emit_contract_upgraded(&env, &admin, &new_wasm_hash, target_version);
-        events::emit_migration_report(&env, &report);
+        require_not_paused(&env)?;
+        with_reentrancy_lock(&env, || {
+            let shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            if shipment.status != ShipmentStatus::Disputed {
+                return Err(NavinError::InvalidStatus);
+            }
+            if storage::is_dispute_escalated(&env, shipment_id) {
+                return Err(NavinError::DisputeEscalated);
+            }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+            let window = config::get_config(&env)
+                .dispute_policy
+                .dispute_window_seconds;
+            let raised_at =
+                storage::get_dispute_raised_at(&env, shipment_id).unwrap_or(shipment.updated_at);
+            if window == 0 || env.ledger().timestamp() < raised_at.saturating_add(window) {
+                return Err(NavinError::NotExpired);
+            }
 
-        Ok(())
+            let reason_hash: BytesN<32> = env
+                .crypto()
+                .sha256(&soroban_sdk::Bytes::from_slice(
+                    &env,
+                    b"auto_resolve_dispute",
+                ))
+                .into();
+            settle_dispute(
+                &env,
+                &env.current_contract_address(),
+                shipment_id,
+                resolution_carrier_bps(&DisputeResolution::RefundToCompany),
+                &reason_hash,
+            )
+        })
     }
 
-    /// Read-only dry-run for a proposed migration to estimate impact and validate edges.
+    /// Get the rationale hash committed when a shipment's dispute was resolved.
+    ///
+    /// The note is the `reason_hash` supplied to `resolve_dispute` (or the
+    /// multisig proposal that settled the dispute) and is also carried in the
+    /// `dispute_resolved` event.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `target_version` - The version to simulate migrating to.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<MigrationReport, NavinError>` - Summary of the migration impact.
-    pub fn dry_run_migration(env: Env, target_version: u32) -> Result<MigrationReport, NavinError> {
+    /// * `Result<Option<BytesN<32>>, NavinError>` - The note hash, or `None` if unresolved.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let note = contract.get_resolution_note(&env, shipment_id);
+    /// ```
+    pub fn get_resolution_note(
+        env: Env,
+        shipment_id: u64,
+    ) -> Result<Option<BytesN<32>>, NavinError> {
         require_initialized(&env)?;
-
-        let current_version = storage::get_version(&env);
-
-        if !is_allowed_migration(current_version, target_version) {
-            return Err(NavinError::InvalidMigrationEdge);
-        }
-
-        let shipment_count = storage::get_shipment_counter(&env);
-
-        Ok(MigrationReport {
-            current_version,
-            target_version,
-            affected_shipments: shipment_count,
-        })
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+        Ok(storage::get_resolution_note(&env, shipment_id))
     }
 
-    /// Release escrowed funds to the carrier after delivery confirmation.
-    /// Only the receiver or admin can trigger release.
-    /// Shipment must be in Delivered status.
+    /// Pay a shipment's insurance reserve to its sender after the carrier lost a dispute.
+    ///
+    /// The reserve is funded at deposit time from `insurance_bps` of the escrow.
+    /// It becomes claimable only when the carrier loses a dispute; on every
+    /// other terminal path the reserve is paid out alongside the escrow.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `caller` - Originating user triggering escrow delivery (receiver/admin).
-    /// * `shipment_id` - Tracking assignment associated with delivery payload instances.
+    /// * `sender` - Company that created the shipment.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful asset delivery.
+    /// * `Result<i128, NavinError>` - Amount paid out.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If tracking context specifies an invalid shipment.
-    /// * `NavinError::Unauthorized` - If caller isn't receiver or admin.
-    /// * `NavinError::InvalidStatus` - If contract expects specific lifecycle constraint and differs.
-    /// * `NavinError::InsufficientFunds` - If payload is fully released and balances are zeroed out.
+    /// * `NavinError::ContractPaused` - If the contract is paused.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the shipment's sender.
+    /// * `NavinError::InvalidStatus` - If the carrier has not lost a dispute on this shipment.
+    /// * `NavinError::InsufficientFunds` - If no reserve is held for the shipment.
     ///
     /// # Examples
     /// ```rust
-    /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
-    /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
-    /// # let env = Env::default();
-    /// # env.mock_all_auths();
-    /// # let contract_id = env.register(NavinShipment, ());
-    /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// # let carrier = Address::generate(&env);
-    /// # client.add_carrier(&admin, &carrier);
-    /// # let receiver = Address::generate(&env);
-    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
-    /// # let deadline = env.ledger().timestamp() + 86_400;
-    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
-    /// # client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &BytesN::from_array(&env, &[2u8; 32]));
-    /// # client.confirm_delivery(&receiver, &shipment_id, &BytesN::from_array(&env, &[3u8; 32]));
-    /// // Manually release any remaining escrow to the carrier after delivery is confirmed.
-    /// client.release_escrow(&receiver, &shipment_id);
+    /// // let paid = contract.claim_shipment_insurance(&env, &company, shipment_id);
     /// ```
-    pub fn release_escrow(env: Env, caller: Address, shipment_id: u64) -> Result<(), NavinError> {
+    pub fn claim_shipment_insurance(
+        env: Env,
+        sender: Address,
+        shipment_id: u64,
+    ) -> Result<i128, NavinError> {
         require_initialized(&env)?;
-        caller.require_auth();
+        require_not_paused(&env)?;
+        sender.require_auth();
 
         with_reentrancy_lock(&env, || {
-            let admin = storage::get_admin(&env);
-            let mut shipment =
+            let shipment =
                 storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-            require_not_finalized(&shipment)?;
-
-            if caller != shipment.receiver && caller != admin {
+            if shipment.sender != sender {
                 return Err(NavinError::Unauthorized);
             }
-
-            if shipment.status != ShipmentStatus::Delivered {
+            if !storage::is_insurance_claimable(&env, shipment_id) {
                 return Err(NavinError::InvalidStatus);
             }
 
-            let escrow_amount = shipment.escrow_amount;
-            if escrow_amount == 0 {
+            let reserve = storage::get_insurance_reserve(&env, shipment_id);
+            if reserve <= 0 {
                 return Err(NavinError::InsufficientFunds);
             }
+            storage::remove_insurance_reserve(&env, shipment_id);
 
-            internal_release_escrow(&env, &mut shipment, escrow_amount)?;
-            finalize_if_settled(&env, &mut shipment);
-            persist_shipment(&env, &shipment)?;
-            events::emit_notification(
-                &env,
-                &shipment.sender,
-                NotificationType::EscrowReleased,
-                shipment_id,
-                &BytesN::from_array(&env, &[0u8; 32]),
-            );
-            events::emit_notification(
+            let token_contract =
+                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+            invoke_token_transfer(
                 &env,
-                &shipment.carrier,
-                NotificationType::EscrowReleased,
-                shipment_id,
-                &BytesN::from_array(&env, &[0u8; 32]),
-            );
+                &token_contract,
+                &env.current_contract_address(),
+                &sender,
+                reserve,
+            )?;
 
-            Ok(())
+            storage::record_escrow_refunded(&env, reserve);
+            extend_shipment_ttl(&env, shipment_id);
+            events::emit_insurance_claimed(&env, shipment_id, &sender, reserve);
+
+            Ok(reserve)
         })
     }
 
-    /// Refund escrowed funds to the company if shipment is cancelled.
-    /// Only the sender (Company) or admin can trigger refund.
-    /// Shipment must be in Created or Cancelled status.
+    /// Resolve a dispute by splitting the escrow between carrier and company.
+    ///
+    /// Releases `escrow * carrier_bps / 10_000` to the carrier and refunds the
+    /// remainder to the sender. The shipment ends in `Delivered` when the
+    /// carrier receives the larger share and `Cancelled` otherwise (an even
+    /// split is treated as a neutral cancellation). Settles through the same
+    /// path as `resolve_dispute`, so the resolution note, notifications and
+    /// insurance handling are identical.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `caller` - Reference mapping handler execution triggers for scope access control checks.
-    /// * `shipment_id` - Identification marker mapping.
+    /// * `admin` - Admin, guardian or arbitrator resolving the dispute.
+    /// * `shipment_id` - ID of the disputed shipment.
+    /// * `carrier_bps` - Carrier share in basis points (0–10 000).
+    /// * `reason_hash` - Hash of the off-chain resolution rationale.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful refund sequence generation.
+    /// * `Result<(), NavinError>` - Ok if both legs of the split settled.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If valid identifiers track undefined mappings instances.
-    /// * `NavinError::Unauthorized` - If execution identity doesn't resolve matching configurations contexts mappings.
-    /// * `NavinError::InvalidStatus` - If mapping resolves illegal flow mappings configuration combinations triggers.
-    /// * `NavinError::InsufficientFunds` - If token escrow state points map uninitialized quantities values scope checks.
+    /// * `NavinError::Unauthorized` - If caller is not the admin, a guardian or an arbitrator.
+    /// * `NavinError::InvalidAmount` - If `carrier_bps` exceeds 10 000.
+    /// * `NavinError::DisputeReasonHashMissing` - If `reason_hash` is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidStatus` - If shipment is not `Disputed`.
+    /// * `NavinError::InsufficientFunds` - If there is no escrow to split.
+    /// * `NavinError::DisputeEscalated` - If the dispute was escalated to the multisig.
     ///
     /// # Examples
     /// ```rust
-    /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
-    /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient};
-    /// # let env = Env::default();
-    /// # env.mock_all_auths();
-    /// # let contract_id = env.register(NavinShipment, ());
-    /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// # let carrier = Address::generate(&env);
-    /// # client.add_carrier(&admin, &carrier);
-    /// # let receiver = Address::generate(&env);
-    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
-    /// # let deadline = env.ledger().timestamp() + 86_400;
-    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
-    /// // Refund escrow back to the company when the shipment is in Created or Cancelled state.
-    /// client.refund_escrow(&admin, &shipment_id);
+    /// // 70 % to the carrier, 30 % back to the company
+    /// // contract.resolve_dispute_split(&env, &admin, shipment_id, 7_000, &reason_hash);
     /// ```
-    pub fn refund_escrow(env: Env, caller: Address, shipment_id: u64) -> Result<(), NavinError> {
+    pub fn resolve_dispute_split(
+        env: Env,
+        admin: Address,
+        shipment_id: u64,
+        carrier_bps: u32,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        caller.require_auth();
-
+        require_not_paused(&env)?;
+        admin.require_auth();
         with_reentrancy_lock(&env, || {
-            let admin = storage::get_admin(&env);
-            let mut shipment =
-                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            require_dispute_resolver(&env, &admin)?;
 
-            if caller != shipment.sender && caller != admin {
-                return Err(NavinError::Unauthorized);
+            if carrier_bps > 10_000 {
+                return Err(NavinError::InvalidAmount);
             }
-
-            require_not_finalized(&shipment)?;
-
-            // Check for suspension if caller is the sender (company)
-            if caller == shipment.sender {
-                require_active_company(&env, &caller)?;
+            if reason_hash.to_array().iter().all(|&b| b == 0) {
+                return Err(NavinError::DisputeReasonHashMissing);
             }
-
-            if shipment.status != ShipmentStatus::Created
-                && shipment.status != ShipmentStatus::Cancelled
-            {
-                return Err(NavinError::InvalidStatus);
+            if storage::is_dispute_escalated(&env, shipment_id) {
+                return Err(NavinError::DisputeEscalated);
             }
 
-            let escrow_amount = shipment.escrow_amount;
-            if escrow_amount == 0 {
-                return Err(NavinError::InsufficientFunds);
-            }
+            settle_dispute(&env, &admin, shipment_id, carrier_bps, &reason_hash)
+        })
+    }
 
-            // Get token contract address
-            let token_contract =
-                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+    /// Handoff a shipment from current carrier to a new carrier.
+    /// Only the current assigned carrier can initiate the handoff.
+    /// New carrier must have Carrier role.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `current_carrier` - Current assigned carrier address.
+    /// * `new_carrier` - New carrier address to assign.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `handoff_hash` - Hash of the handoff documentation.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful handoff.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If handoff_hash is all zeros.
+    /// * `NavinError::Unauthorized` - If current_carrier is not the assigned carrier.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is already completed.
+    /// * `NavinError::InvalidShipmentInput` - If new_carrier is the shipment's sender or receiver.
+    /// * `NavinError::HandoffCooldown` - If the previous handoff was less than
+    ///   `handoff_cooldown_seconds` ago.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.handoff_shipment(env, old, new_carrier, 1, hash);
+    /// ```
+    pub fn handoff_shipment(
+        env: Env,
+        current_carrier: Address,
+        new_carrier: Address,
+        shipment_id: u64,
+        handoff_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        current_carrier.require_auth();
+        require_role(&env, &current_carrier, Role::Carrier)?;
+        require_role(&env, &new_carrier, Role::Carrier)?;
 
-            // Transfer tokens from this contract to company
-            let contract_address = env.current_contract_address();
+        let mut shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-            // Create settlement record in Pending state
-            let settlement_id = create_settlement(
-                &env,
-                shipment_id,
-                SettlementOperation::Refund,
-                escrow_amount,
-                &contract_address,
-                &shipment.sender,
-            )?;
+        require_not_finalized(&shipment)?;
 
-            // Transfer tokens
-            invoke_token_transfer(
-                &env,
-                &token_contract,
-                &contract_address,
-                &shipment.sender,
-                escrow_amount,
-            )?;
+        // Validate hash before storage
+        validation::validate_hash(&handoff_hash)?;
 
-            // Mark settlement as completed
-            complete_settlement(&env, settlement_id, shipment_id)?;
+        // Verify current carrier is the assigned carrier
+        if shipment.carrier != current_carrier {
+            return Err(NavinError::Unauthorized);
+        }
 
-            shipment.escrow_amount = 0;
-            let old_status = shipment.status.clone();
-            shipment.status = ShipmentStatus::Cancelled;
-            shipment.updated_at = env.ledger().timestamp();
-            shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+        // Prevent handoff from completed shipments
+        match shipment.status {
+            ShipmentStatus::Delivered | ShipmentStatus::Cancelled => {
+                return Err(NavinError::ShipmentAlreadyCompleted);
+            }
+            _ => {}
+        }
 
-            finalize_if_settled(&env, &mut shipment);
-            persist_shipment(&env, &shipment)?;
-            storage::decrement_status_count(&env, &old_status);
-            storage::increment_status_count(&env, &ShipmentStatus::Cancelled);
+        // The new carrier must not collapse roles with the shipment's parties.
+        if new_carrier == shipment.receiver || new_carrier == shipment.sender {
+            return Err(NavinError::InvalidShipmentInput);
+        }
 
-            // Decrement active shipment count if it was not already cancelled
-            if old_status != ShipmentStatus::Cancelled {
-                storage::decrement_active_shipment_count(&env, &shipment.sender);
+        // Throttle back-to-back handoffs of the same shipment.
+        let now = env.ledger().timestamp();
+        let cooldown = config::get_config(&env)
+            .carrier_policy
+            .handoff_cooldown_seconds;
+        if let Some(last) = storage::get_last_handoff_at(&env, shipment_id) {
+            if now.saturating_sub(last) < cooldown {
+                return Err(NavinError::HandoffCooldown);
             }
+        }
 
-            extend_shipment_ttl(&env, shipment_id);
-            extend_shipment_ttl(&env, shipment_id);
+        // Update carrier address on the shipment
+        let old_carrier = shipment.carrier.clone();
+        shipment.carrier = new_carrier.clone();
+        shipment.updated_at = now;
+        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
 
-            events::emit_escrow_refunded(&env, shipment_id, &shipment.sender, escrow_amount);
+        persist_shipment(&env, &shipment)?;
+        storage::set_last_handoff_at(&env, shipment_id, now);
+        storage::remove_carrier_shipment(&env, &old_carrier, shipment_id);
+        storage::push_carrier_shipment(&env, &new_carrier, shipment_id);
+        extend_shipment_ttl(&env, shipment_id);
 
-            Ok(())
-        })
+        // Emit carrier_handoff event
+        events::emit_carrier_handoff(&env, shipment_id, &old_carrier, &new_carrier, &handoff_hash);
+
+        // Emit carrier_handoff_completed event
+        events::emit_carrier_handoff_completed(&env, &old_carrier, &new_carrier, shipment_id);
+
+        // Record a milestone for the handoff
+        events::emit_milestone_recorded(
+            &env,
+            shipment_id,
+            &symbol_short!("handoff"),
+            &handoff_hash,
+            &current_carrier,
+        );
+
+        Ok(())
     }
 
-    /// Raise a dispute for a shipment.
-    /// Only the sender, receiver, or carrier can raise a dispute.
-    /// Shipment must not be Cancelled or already Disputed.
+    /// Replace a shipment's carrier before pickup, e.g. after the carrier
+    /// declined or went unresponsive. Callable by the sender or the admin
+    /// while the shipment is `Created`. Any acceptance by the previous carrier
+    /// is cleared.
     ///
     /// # Arguments
-    /// * `env` - Execution environment tracking context.
-    /// * `caller` - Identity specifying resolution event raising instances configuration contexts.
-    /// * `shipment_id` - Object tracker index identifying execution scope handlers.
-    /// * `reason_hash` - Encoded offchain metadata representation parameter validation identifier limits strings pointers.
+    /// * `env` - Execution environment.
+    /// * `caller` - Sender of the shipment, or the admin.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `new_carrier` - Carrier taking over the shipment.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful dispute registry logging.
+    /// * `Result<(), NavinError>` - Ok on successful reassignment.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
+    /// * `NavinError::Unauthorized` - If caller is neither the sender nor the admin,
+    ///   or `new_carrier` does not hold the Carrier role.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    /// * `NavinError::Unauthorized` - If caller is not involved in the shipment.
-    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is already completed.
+    /// * `NavinError::InvalidStatus` - If the shipment is no longer `Created`.
+    /// * `NavinError::InvalidShipmentInput` - If `new_carrier` is already the carrier
+    ///   or is the shipment's sender or receiver.
+    /// * `NavinError::CarrierNotWhitelisted` - If receiver approval is required and missing.
     ///
     /// # Examples
     /// ```rust
-    /// ```rust,no_run
-    /// # use soroban_sdk::{Env, Address, BytesN, Vec, Symbol};
-    /// # use soroban_sdk::testutils::Address as _;
-    /// # use shipment::{NavinShipment, NavinShipmentClient, ShipmentStatus};
-    /// # let env = Env::default();
-    /// # env.mock_all_auths();
-    /// # let contract_id = env.register(NavinShipment, ());
-    /// # let client = NavinShipmentClient::new(&env, &contract_id);
-    /// # let admin = Address::generate(&env);
-    /// # let token = Address::generate(&env);
-    /// # client.initialize(&admin, &token);
-    /// # client.add_company(&admin, &admin);
-    /// # let carrier = Address::generate(&env);
-    /// # client.add_carrier(&admin, &carrier);
-    /// # let receiver = Address::generate(&env);
-    /// # let data_hash = BytesN::from_array(&env, &[1u8; 32]);
-    /// # let milestones: Vec<(Symbol, u32)> = Vec::new(&env);
-    /// # let deadline = env.ledger().timestamp() + 86_400;
-    /// # let shipment_id = client.create_shipment(&admin, &receiver, &carrier, &data_hash, &milestones, &deadline);
-    /// # client.update_status(&carrier, &shipment_id, &ShipmentStatus::InTransit, &BytesN::from_array(&env, &[2u8; 32]));
-    /// let reason_hash = BytesN::from_array(&env, &[4u8; 32]); // SHA-256 of dispute reason doc
-    ///
-    /// // Receiver raises a dispute; escrow is frozen until admin resolves it.
-    /// client.raise_dispute(&receiver, &shipment_id, &reason_hash);
+    /// // contract.reassign_carrier(&env, &company, 1, &new_carrier);
     /// ```
-    pub fn raise_dispute(
+    pub fn reassign_carrier(
         env: Env,
         caller: Address,
         shipment_id: u64,
-        reason_hash: BytesN<32>,
+        new_carrier: Address,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
         caller.require_auth();
-
-        // Validate hash before storage
-        validation::validate_hash(&reason_hash)?;
+        require_role(&env, &new_carrier, Role::Carrier)?;
 
         let mut shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        require_not_finalized(&shipment)?;
-
-        if caller != shipment.sender && caller != shipment.receiver && caller != shipment.carrier {
+        if shipment.sender != caller && storage::get_admin(&env) != caller {
             return Err(NavinError::Unauthorized);
         }
-
-        // Check for suspension if caller is the sender (company)
-        if caller == shipment.sender {
-            require_active_company(&env, &caller)?;
+        if shipment.status != ShipmentStatus::Created {
+            return Err(NavinError::InvalidStatus);
         }
-
-        if shipment.status == ShipmentStatus::Cancelled
-            || shipment.status == ShipmentStatus::Disputed
+        if new_carrier == shipment.carrier
+            || new_carrier == shipment.sender
+            || new_carrier == shipment.receiver
         {
-            return Err(NavinError::ShipmentAlreadyCompleted);
+            return Err(NavinError::InvalidShipmentInput);
         }
+        require_receiver_approved_carrier(&env, &shipment.receiver, &new_carrier)?;
 
-        let old_status = shipment.status.clone();
-        shipment.status = ShipmentStatus::Disputed;
+        let old_carrier = shipment.carrier.clone();
+        shipment.carrier = new_carrier.clone();
         shipment.updated_at = env.ledger().timestamp();
         shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
 
         persist_shipment(&env, &shipment)?;
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &ShipmentStatus::Disputed);
-        storage::increment_total_disputes(&env);
-        storage::set_escrow_freeze_reason(
-            &env,
-            shipment_id,
-            &crate::types::EscrowFreezeReason::DisputeRaised,
-        );
-
+        storage::remove_assignment_accepted(&env, shipment_id);
+        storage::set_assigned_at(&env, shipment_id, env.ledger().timestamp());
+        storage::remove_carrier_shipment(&env, &old_carrier, shipment_id);
+        storage::push_carrier_shipment(&env, &new_carrier, shipment_id);
         extend_shipment_ttl(&env, shipment_id);
 
-        events::emit_dispute_raised(&env, shipment_id, &caller, &reason_hash);
-        // Emit a structured freeze reason so indexers can classify the escrow block.
-        events::emit_escrow_frozen(
-            &env,
-            shipment_id,
-            crate::types::EscrowFreezeReason::DisputeRaised,
-            &caller,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.sender,
-            NotificationType::DisputeRaised,
-            shipment_id,
-            &reason_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.receiver,
-            NotificationType::DisputeRaised,
-            shipment_id,
-            &reason_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.carrier,
-            NotificationType::DisputeRaised,
-            shipment_id,
-            &reason_hash,
-        );
+        events::emit_carrier_reassigned(&env, shipment_id, &old_carrier, &new_carrier);
 
         Ok(())
     }
 
-    /// Resolve a shipment dispute. Only the admin can call this.
+    /// Accept a shipment assignment. Only the assigned carrier can call this,
+    /// while the shipment is still `Created`. With `require_carrier_acceptance`
+    /// enabled, the shipment cannot move to `InTransit` until accepted.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `admin` - Contract admin address.
+    /// * `carrier` - Assigned carrier accepting the shipment.
     /// * `shipment_id` - ID of the shipment.
-    /// * `resolution` - Target resolution (Release to Carrier or Refund to Company).
-    /// * `reason_hash` - SHA-256 hash of the off-chain justification document.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully resolved.
+    /// * `Result<(), NavinError>` - Ok once the assignment is accepted.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ShipmentNotFound` - If the shipment doesn't exist.
-    /// * `NavinError::Unauthorized` - If called by a non-admin.
-    /// * `NavinError::InvalidHash` - If reason_hash is all zeros.
-    pub fn resolve_dispute(
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the assigned carrier.
+    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Created`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.accept_assignment(&env, &carrier, 1);
+    /// ```
+    pub fn accept_assignment(
         env: Env,
-        admin: Address,
+        carrier: Address,
         shipment_id: u64,
-        resolution: DisputeResolution,
-        reason_hash: BytesN<32>,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
         require_not_paused(&env)?;
-        admin.require_auth();
-
-        require_admin_or_guardian(&env, &admin)?;
+        carrier.require_auth();
 
-        // Reason hash is mandatory; use a specific error rather than the generic InvalidHash.
-        if reason_hash.to_array().iter().all(|&b| b == 0) {
-            return Err(NavinError::DisputeReasonHashMissing);
-        }
+        let shipment = pending_assignment(&env, &carrier, shipment_id)?;
 
-        // Idempotency: reject duplicate (shipment_id, resolution, reason_hash) within the window.
-        let mut payload = soroban_sdk::Bytes::new(&env);
-        payload.append(&soroban_sdk::Bytes::from_array(
-            &env,
-            &shipment_id.to_be_bytes(),
-        ));
-        payload.append(&resolution.clone().to_xdr(&env));
-        payload.append(&reason_hash.clone().into());
-        check_idempotency(&env, payload)?;
+        storage::set_assignment_accepted(&env, shipment.id);
+        events::emit_assignment_accepted(&env, shipment.id, &carrier);
+        Ok(())
+    }
 
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+    /// Decline a shipment assignment. Only the assigned carrier can call this,
+    /// while the shipment is still `Created`. The shipment stays `Created` and
+    /// an `assignment_declined` event tells the sender to reassign it. When
+    /// `acceptance_timeout_seconds` is non-zero, any escrow is instead refunded
+    /// to the sender and the shipment is cancelled.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Assigned carrier declining the shipment.
+    /// * `shipment_id` - ID of the shipment.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok once the decline is recorded.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the assigned carrier.
+    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Created`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.decline_assignment(&env, &carrier, 1);
+    /// ```
+    pub fn decline_assignment(
+        env: Env,
+        carrier: Address,
+        shipment_id: u64,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        carrier.require_auth();
 
-        require_not_finalized(&shipment)?;
+        with_reentrancy_lock(&env, || {
+            let mut shipment = pending_assignment(&env, &carrier, shipment_id)?;
 
-        if shipment.status != ShipmentStatus::Disputed {
-            return Err(NavinError::InvalidStatus);
-        }
+            storage::remove_assignment_accepted(&env, shipment.id);
+            events::emit_assignment_declined(&env, shipment.id, &carrier, &shipment.sender);
 
-        let escrow_amount = shipment.escrow_amount;
-        if escrow_amount == 0 {
-            return Err(NavinError::InsufficientFunds);
-        }
+            let timeout = config::get_config(&env)
+                .carrier_policy
+                .acceptance_timeout_seconds;
+            if timeout > 0 && shipment.escrow_amount > 0 {
+                refund_and_cancel(&env, &mut shipment, &carrier)?;
+            }
+            Ok(())
+        })
+    }
 
-        shipment.escrow_amount = 0;
-        shipment.updated_at = env.ledger().timestamp();
-        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+    /// Refund the company when the assigned carrier never accepted a shipment.
+    ///
+    /// Permissionless, so escrow is not stranded on abandoned assignments.
+    /// Requires `require_carrier_acceptance` and a non-zero
+    /// `acceptance_timeout_seconds`; the timeout runs from the latest
+    /// `reassign_carrier` call, or from creation if the carrier never changed.
+    /// The escrow is refunded to the sender and the shipment is cancelled.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the unaccepted shipment.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok once the escrow has been refunded.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ContractPaused` - If the contract is paused.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::ShipmentFinalized` - If the shipment is finalized.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Created` or was accepted.
+    /// * `NavinError::NotExpired` - If the timeout has not elapsed or auto-refund is disabled.
+    /// * `NavinError::InsufficientFunds` - If the shipment holds no escrow.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.expire_assignment(&env, 1);
+    /// ```
+    pub fn expire_assignment(env: Env, shipment_id: u64) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        with_reentrancy_lock(&env, || {
+            let mut shipment =
+                storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+            require_not_finalized(&shipment)?;
+            if shipment.status != ShipmentStatus::Created
+                || storage::is_assignment_accepted(&env, shipment_id)
+            {
+                return Err(NavinError::InvalidStatus);
+            }
 
-        let recipient = match resolution {
-            DisputeResolution::ReleaseToCarrier => {
-                shipment.status = ShipmentStatus::Delivered;
-                shipment.carrier.clone()
+            let config = config::get_config(&env);
+            let timeout = config.carrier_policy.acceptance_timeout_seconds;
+            let assigned_at =
+                storage::get_assigned_at(&env, shipment_id).unwrap_or(shipment.created_at);
+            if !config.carrier_policy.require_carrier_acceptance
+                || timeout == 0
+                || env.ledger().timestamp() < assigned_at.saturating_add(timeout)
+            {
+                return Err(NavinError::NotExpired);
             }
-            DisputeResolution::RefundToCompany => {
-                shipment.status = ShipmentStatus::Cancelled;
-                shipment.sender.clone()
+            if shipment.escrow_amount == 0 {
+                return Err(NavinError::InsufficientFunds);
             }
-        };
 
-        // Transfer tokens from this contract to recipient
-        let token_contract = storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
-        let contract_address = env.current_contract_address();
+            refund_and_cancel(&env, &mut shipment, &env.current_contract_address())
+        })
+    }
 
-        // Create settlement record in Pending state
-        let operation = match resolution {
-            DisputeResolution::ReleaseToCarrier => SettlementOperation::Release,
-            DisputeResolution::RefundToCompany => SettlementOperation::Refund,
-        };
-        let settlement_id = create_settlement(
-            &env,
-            shipment_id,
-            operation,
-            escrow_amount,
-            &contract_address,
-            &recipient,
-        )?;
+    /// Check whether the assigned carrier has accepted a shipment.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    ///
+    /// # Returns
+    /// * `bool` - `true` once `accept_assignment` has been called.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let accepted = contract.is_assignment_accepted(&env, 1);
+    /// ```
+    pub fn is_assignment_accepted(env: Env, shipment_id: u64) -> bool {
+        storage::is_assignment_accepted(&env, shipment_id)
+    }
 
-        // Transfer tokens
-        invoke_token_transfer(
-            &env,
-            &token_contract,
-            &contract_address,
-            &recipient,
-            escrow_amount,
-        )?;
+    /// Transfer a shipment's ownership (sender) to another company.
+    /// Callable by the current sender or the admin while the shipment is not
+    /// in a terminal state. Active shipment counts move with the shipment.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `current_sender` - Current sender of the shipment, or the admin.
+    /// * `new_company` - Company that takes over the shipment.
+    /// * `shipment_id` - ID of the shipment.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on successful transfer.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is neither the sender nor the admin,
+    ///   or `new_company` does not hold an active Company role.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::ShipmentFinalized` - If the shipment is finalized.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is delivered or cancelled.
+    /// * `NavinError::InvalidShipmentInput` - If `new_company` is already the sender
+    ///   or is the shipment's receiver or carrier.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.transfer_shipment_ownership(env, sender, new_company, 1);
+    /// ```
+    pub fn transfer_shipment_ownership(
+        env: Env,
+        current_sender: Address,
+        new_company: Address,
+        shipment_id: u64,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        current_sender.require_auth();
+        require_role(&env, &new_company, Role::Company)?;
 
-        // Mark settlement as completed
-        complete_settlement(&env, settlement_id, shipment_id)?;
+        let mut shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        storage::decrement_status_count(&env, &ShipmentStatus::Disputed);
-        storage::increment_status_count(&env, &shipment.status);
-        storage::decrement_active_shipment_count(&env, &shipment.sender);
+        require_not_finalized(&shipment)?;
 
-        finalize_if_settled(&env, &mut shipment);
-        persist_shipment(&env, &shipment)?;
-        storage::remove_escrow_balance(&env, shipment_id);
-        extend_shipment_ttl(&env, shipment_id);
+        if shipment.sender != current_sender && storage::get_admin(&env) != current_sender {
+            return Err(NavinError::Unauthorized);
+        }
 
-        match resolution {
-            DisputeResolution::ReleaseToCarrier => {
-                events::emit_escrow_released(&env, shipment_id, &recipient, escrow_amount);
-            }
-            DisputeResolution::RefundToCompany => {
-                events::emit_escrow_refunded(&env, shipment_id, &recipient, escrow_amount);
-                // Reputation: carrier lost this dispute
-                events::emit_carrier_dispute_loss(&env, &shipment.carrier, shipment_id);
+        match shipment.status {
+            ShipmentStatus::Delivered | ShipmentStatus::Cancelled => {
+                return Err(NavinError::ShipmentAlreadyCompleted);
             }
+            _ => {}
         }
 
-        // Emit specialized resolution event with context
-        events::emit_dispute_resolved(&env, shipment_id, &resolution, &reason_hash, &admin);
+        if new_company == shipment.sender
+            || new_company == shipment.receiver
+            || new_company == shipment.carrier
+        {
+            return Err(NavinError::InvalidShipmentInput);
+        }
 
-        events::emit_notification(
-            &env,
-            &shipment.sender,
-            NotificationType::DisputeResolved,
-            shipment_id,
-            &reason_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.receiver,
-            NotificationType::DisputeResolved,
-            shipment_id,
-            &reason_hash,
-        );
-        events::emit_notification(
-            &env,
-            &shipment.carrier,
-            NotificationType::DisputeResolved,
-            shipment_id,
-            &reason_hash,
-        );
+        let old_sender = shipment.sender.clone();
+        shipment.sender = new_company.clone();
+        shipment.updated_at = env.ledger().timestamp();
+        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+        persist_shipment(&env, &shipment)?;
+        storage::decrement_active_shipment_count(&env, &old_sender);
+        storage::increment_active_shipment_count(&env, &new_company);
+        if config::get_config(&env).index_company_shipments {
+            storage::push_company_shipment(&env, &new_company, shipment_id);
+        }
+        extend_shipment_ttl(&env, shipment_id);
+
+        events::emit_ownership_transferred(&env, shipment_id, &old_sender, &new_company);
 
         Ok(())
     }
 
-    /// Handoff a shipment from current carrier to a new carrier.
-    /// Only the current assigned carrier can initiate the handoff.
-    /// New carrier must have Carrier role.
+    /// Push back a shipment's delivery deadline, keeping its escrow in place.
+    /// Callable by the sender (Company) or the admin while the shipment is not
+    /// in a terminal state.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `current_carrier` - Current assigned carrier address.
-    /// * `new_carrier` - New carrier address to assign.
+    /// * `caller` - Sender of the shipment, or the admin.
     /// * `shipment_id` - ID of the shipment.
-    /// * `handoff_hash` - Hash of the handoff documentation.
+    /// * `new_deadline` - New deadline; must be later than both the current deadline and now.
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok on successful handoff.
+    /// * `Result<(), NavinError>` - Ok once the deadline is extended.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::InvalidHash` - If handoff_hash is all zeros.
-    /// * `NavinError::Unauthorized` - If current_carrier is not the assigned carrier.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
-    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is already completed.
+    /// * `NavinError::Unauthorized` - If caller is neither the sender nor the admin.
+    /// * `NavinError::CompanySuspended` - If the sender is suspended.
+    /// * `NavinError::ShipmentFinalized` - If the shipment is finalized.
+    /// * `NavinError::ShipmentAlreadyCompleted` - If shipment is delivered or cancelled.
+    /// * `NavinError::InvalidTimestamp` - If `new_deadline` does not move the deadline
+    ///   later or is not in the future.
     ///
     /// # Examples
     /// ```rust
-    /// // contract.handoff_shipment(env, old, new_carrier, 1, hash);
+    /// // contract.extend_deadline(&env, &company, 1, new_deadline);
     /// ```
-    pub fn handoff_shipment(
+    pub fn extend_deadline(
         env: Env,
-        current_carrier: Address,
-        new_carrier: Address,
+        caller: Address,
         shipment_id: u64,
-        handoff_hash: BytesN<32>,
+        new_deadline: u64,
     ) -> Result<(), NavinError> {
         require_initialized(&env)?;
-        current_carrier.require_auth();
-        require_role(&env, &current_carrier, Role::Carrier)?;
-        require_role(&env, &new_carrier, Role::Carrier)?;
+        require_not_paused(&env)?;
+        caller.require_auth();
 
         let mut shipment =
             storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
 
-        require_not_finalized(&shipment)?;
-
-        // Validate hash before storage
-        validation::validate_hash(&handoff_hash)?;
-
-        // Verify current carrier is the assigned carrier
-        if shipment.carrier != current_carrier {
+        if caller != shipment.sender && caller != storage::get_admin(&env) {
             return Err(NavinError::Unauthorized);
         }
+        if caller == shipment.sender {
+            require_active_company(&env, &caller)?;
+        }
 
-        // Prevent handoff from completed shipments
         match shipment.status {
             ShipmentStatus::Delivered | ShipmentStatus::Cancelled => {
                 return Err(NavinError::ShipmentAlreadyCompleted);
             }
             _ => {}
         }
+        require_not_finalized(&shipment)?;
 
-        // Update carrier address on the shipment
-        let old_carrier = shipment.carrier.clone();
-        shipment.carrier = new_carrier.clone();
-        shipment.updated_at = env.ledger().timestamp();
+        let now = env.ledger().timestamp();
+        if new_deadline <= shipment.deadline || new_deadline <= now {
+            return Err(NavinError::InvalidTimestamp);
+        }
+
+        let old_deadline = shipment.deadline;
+        shipment.deadline = new_deadline;
+        shipment.updated_at = now;
         shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
 
         persist_shipment(&env, &shipment)?;
         extend_shipment_ttl(&env, shipment_id);
 
-        // Emit carrier_handoff event
-        events::emit_carrier_handoff(&env, shipment_id, &old_carrier, &new_carrier, &handoff_hash);
-
-        // Emit carrier_handoff_completed event
-        events::emit_carrier_handoff_completed(&env, &old_carrier, &new_carrier, shipment_id);
-
-        // Record a milestone for the handoff
-        events::emit_milestone_recorded(
-            &env,
-            shipment_id,
-            &symbol_short!("handoff"),
-            &handoff_hash,
-            &current_carrier,
-        );
+        events::emit_deadline_extended(&env, shipment_id, old_deadline, new_deadline, &caller);
 
         Ok(())
     }
@@ -4697,6 +8553,7 @@ impl NavinShipment {
     /// * `NavinError::InvalidHash` - If data_hash is all zeros.
     /// * `NavinError::Unauthorized` - If caller is not the assigned carrier.
     /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::RateLimitExceeded` - If a breach was reported within `min_report_interval` (unless Admin).
     ///
     /// # Examples
     /// ```rust
@@ -4734,6 +8591,8 @@ impl NavinShipment {
             return Err(NavinError::BreachLimitExceeded);
         }
 
+        enforce_report_interval(&env, &carrier, shipment_id, symbol_short!("breach"))?;
+
         events::emit_condition_breach(
             &env,
             shipment_id,
@@ -4743,19 +8602,27 @@ impl NavinShipment {
             &data_hash,
         );
 
-        // Reputation: record breach against carrier
+        // Reputation: record breach against carrier, weighted by breach type
+        storage::add_carrier_breach_penalty(
+            &env,
+            &carrier,
+            config.breach_weights.weight_for(&breach_type),
+        );
+        storage::increment_carrier_breaches(&env, &carrier);
         events::emit_carrier_breach(&env, &carrier, shipment_id, &breach_type, &severity);
 
         // Increment breach event count
         storage::increment_breach_event_count(&env, shipment_id);
 
         // Auto-open dispute on Critical breaches when the config toggle is enabled.
-        // Skips silently if the shipment is already Disputed or Cancelled.
+        // Skips silently if the shipment is already Disputed or Cancelled, or has
+        // reached `max_disputes_per_shipment`.
         let cfg = config::get_config(&env);
         if cfg.auto_dispute_breach
             && severity == Severity::Critical
             && shipment.status != ShipmentStatus::Cancelled
             && shipment.status != ShipmentStatus::Disputed
+            && !dispute_limit_reached(&env, shipment_id)
         {
             let old_status = shipment.status.clone();
             let mut s = shipment;
@@ -4767,7 +8634,8 @@ impl NavinShipment {
             storage::set_shipment(&env, &s);
             storage::decrement_status_count(&env, &old_status);
             storage::increment_status_count(&env, &ShipmentStatus::Disputed);
-            storage::increment_total_disputes(&env);
+            storage::push_status_history(&env, shipment_id, &ShipmentStatus::Disputed, &carrier);
+            record_dispute(&env, shipment_id, s.escrow_amount)?;
             extend_shipment_ttl(&env, shipment_id);
             // Use the breach data hash as the dispute reason so indexers can correlate
             events::emit_dispute_raised(&env, shipment_id, &carrier, &data_hash);
@@ -4778,6 +8646,13 @@ impl NavinShipment {
                 shipment_id,
                 &data_hash,
             );
+            notify_extra_recipients(
+                &env,
+                &sender,
+                NotificationType::DisputeRaised,
+                shipment_id,
+                &data_hash,
+            );
             events::emit_notification(
                 &env,
                 &receiver,
@@ -4797,6 +8672,100 @@ impl NavinShipment {
         Ok(())
     }
 
+    /// Record proof of pickup for a shipment, moving it from `Created` to
+    /// `InTransit`. Only the assigned carrier can call this. The pickup hash
+    /// is stored for later verification with `verify_pickup_proof`.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Assigned carrier picking up the shipment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `pickup_hash` - Hash of the off-chain proof-of-pickup data.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok once pickup is recorded.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If pickup_hash is all zeros.
+    /// * `NavinError::Unauthorized` - If caller is not the assigned carrier.
+    /// * `NavinError::CarrierSuspended` - If the carrier is suspended.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Created`.
+    /// * `NavinError::AssignmentNotAccepted` - If acceptance is required and missing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.record_pickup(&env, &carrier, 1, &pickup_hash);
+    /// ```
+    pub fn record_pickup(
+        env: Env,
+        carrier: Address,
+        shipment_id: u64,
+        pickup_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        carrier.require_auth();
+        require_role(&env, &carrier, Role::Carrier)?;
+        require_active_carrier(&env, &carrier)?;
+        validation::validate_hash(&pickup_hash)?;
+
+        let mut shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        require_not_finalized(&shipment)?;
+        if shipment.carrier != carrier {
+            return Err(NavinError::Unauthorized);
+        }
+        if shipment.status != ShipmentStatus::Created {
+            return Err(NavinError::InvalidStatus);
+        }
+
+        advance_to_in_transit(&env, &mut shipment, &carrier, &pickup_hash)?;
+        persist_shipment(&env, &shipment)?;
+        storage::set_pickup_hash(&env, shipment_id, &pickup_hash);
+        extend_shipment_ttl(&env, shipment_id);
+
+        events::emit_pickup_recorded(&env, shipment_id, &carrier, &pickup_hash);
+
+        Ok(())
+    }
+
+    /// Verify a proof-of-pickup hash against the hash stored by `record_pickup`.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `proof_hash` - Hash to verify against the stored pickup hash.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - True if hashes match, false otherwise.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidHash` - If proof_hash is all zeros.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let is_valid = contract.verify_pickup_proof(&env, 1, hash);
+    /// ```
+    pub fn verify_pickup_proof(
+        env: Env,
+        shipment_id: u64,
+        proof_hash: BytesN<32>,
+    ) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+        validation::validate_hash(&proof_hash)?;
+
+        if storage::get_shipment(&env, shipment_id).is_none() {
+            return Err(NavinError::ShipmentNotFound);
+        }
+
+        Ok(storage::get_pickup_hash(&env, shipment_id) == Some(proof_hash))
+    }
+
     /// Verify a proof-of-delivery hash against the stored confirmation hash.
     ///
     /// Returns `true` if `proof_hash` matches the hash stored during delivery confirmation,
@@ -4859,6 +8828,8 @@ impl NavinShipment {
     }
 
     /// Accept the admin role transfer. Only the proposed admin can call this.
+    /// The new admin is granted the Company role; with
+    /// `revoke_old_admin_company_role` enabled the outgoing admin loses theirs.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
@@ -4881,6 +8852,15 @@ impl NavinShipment {
         // Also update the role for the new admin if it's not already set
         storage::set_company_role(&env, &new_admin);
 
+        // Optionally drop the Company role the outgoing admin was granted.
+        if old_admin != new_admin
+            && config::get_config(&env).revoke_old_admin_company_role
+            && storage::has_company_role(&env, &old_admin)
+        {
+            storage::revoke_role(&env, &old_admin, &Role::Company);
+            events::emit_role_revoked(&env, &new_admin, &old_admin, &Role::Company);
+        }
+
         events::emit_admin_transferred(&env, &old_admin, &new_admin);
 
         Ok(())
@@ -5062,6 +9042,89 @@ impl NavinShipment {
         require_initialized(&env)?;
         approver.require_auth();
 
+        Self::record_approval(env, approver, proposal_id)
+    }
+
+    /// Authorize a delegate, such as an organization's signing service, to
+    /// approve proposals on the signer's behalf. Pass `None` to revoke.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `signer` - Multisig admin granting the delegation.
+    /// * `delegate` - Delegate address, or `None` to revoke.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on success.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::NotAnAdmin` - If signer is not in the admin list.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.set_signer_delegate(&env, &admin2, &Some(signing_service));
+    /// ```
+    pub fn set_signer_delegate(
+        env: Env,
+        signer: Address,
+        delegate: Option<Address>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        signer.require_auth();
+
+        if !storage::is_admin(&env, &signer) {
+            return Err(NavinError::NotAnAdmin);
+        }
+
+        match delegate {
+            Some(delegate) => storage::set_signer_delegate(&env, &signer, &delegate),
+            None => storage::remove_signer_delegate(&env, &signer),
+        }
+        Ok(())
+    }
+
+    /// Approve a pending proposal as a delegate. The approval is recorded as
+    /// `on_behalf_of`'s, so the signer cannot approve the same proposal again.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `delegate` - Delegate authorized via `set_signer_delegate`.
+    /// * `on_behalf_of` - Multisig admin the delegate approves for.
+    /// * `proposal_id` - ID of the proposal to approve.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if approved successfully.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If `delegate` is not `on_behalf_of`'s delegate.
+    /// * `NavinError::NotAnAdmin` - If `on_behalf_of` is not in the admin list.
+    /// * `NavinError::ProposalNotFound` - If proposal doesn't exist.
+    /// * `NavinError::ProposalExpired` - If proposal has expired.
+    /// * `NavinError::ProposalAlreadyExecuted` - If proposal was already executed.
+    /// * `NavinError::AlreadyApproved` - If the signer already approved this proposal.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.approve_as_delegate(&env, &signing_service, &admin2, 1);
+    /// ```
+    pub fn approve_as_delegate(
+        env: Env,
+        delegate: Address,
+        on_behalf_of: Address,
+        proposal_id: u64,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        delegate.require_auth();
+
+        if storage::get_signer_delegate(&env, &on_behalf_of) != Some(delegate) {
+            return Err(NavinError::Unauthorized);
+        }
+
+        Self::record_approval(env, on_behalf_of, proposal_id)
+    }
+
+    fn record_approval(env: Env, approver: Address, proposal_id: u64) -> Result<(), NavinError> {
         // Check if approver is in admin list
         if !storage::is_admin(&env, &approver) {
             return Err(NavinError::NotAnAdmin);
@@ -5122,6 +9185,7 @@ impl NavinShipment {
     /// * `NavinError::ProposalExpired` - If proposal has expired.
     /// * `NavinError::ProposalAlreadyExecuted` - If proposal was already executed.
     /// * `NavinError::InsufficientApprovals` - If not enough approvals.
+    /// * `NavinError::WasmNotAllowlisted` - If an `Upgrade` targets a hash not approved via `allow_wasm_hash`.
     ///
     /// # Examples
     /// ```rust
@@ -5154,6 +9218,12 @@ impl NavinShipment {
             return Err(NavinError::InsufficientApprovals);
         }
 
+        if let crate::types::AdminAction::Upgrade(wasm_hash) = &proposal.action {
+            if !storage::is_wasm_hash_allowed(&env, wasm_hash) {
+                return Err(NavinError::WasmNotAllowlisted);
+            }
+        }
+
         // Mark as executed
         proposal.executed = true;
         storage::set_proposal(&env, &proposal);
@@ -5177,97 +9247,272 @@ impl NavinShipment {
                 events::emit_admin_transferred(&env, &old_admin, &new_admin);
             }
             crate::types::AdminAction::ForceRelease(shipment_id) => {
-                let mut shipment =
-                    storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+                with_reentrancy_lock(&env, || {
+                    let mut shipment = storage::get_shipment(&env, shipment_id)
+                        .ok_or(NavinError::ShipmentNotFound)?;
+
+                    let escrow_amount = shipment.escrow_amount;
+                    if escrow_amount > 0 {
+                        // Get token contract address
+                        if let Some(token_contract) = storage::get_token_contract(&env) {
+                            // Transfer tokens from this contract to carrier
+                            let contract_address = env.current_contract_address();
+                            invoke_token_transfer(
+                                &env,
+                                &token_contract,
+                                &contract_address,
+                                &shipment.carrier,
+                                escrow_amount,
+                            )?;
+                        }
 
-                let escrow_amount = shipment.escrow_amount;
-                if escrow_amount > 0 {
-                    // Get token contract address
-                    if let Some(token_contract) = storage::get_token_contract(&env) {
-                        // Transfer tokens from this contract to carrier
-                        let contract_address = env.current_contract_address();
-                        invoke_token_transfer(
+                        shipment.escrow_amount = 0;
+                        shipment.updated_at = env.ledger().timestamp();
+                        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+                        persist_shipment(&env, &shipment)?;
+
+                        storage::record_escrow_released(&env, escrow_amount);
+                        events::emit_escrow_released(
                             &env,
-                            &token_contract,
-                            &contract_address,
+                            shipment_id,
                             &shipment.carrier,
                             escrow_amount,
-                        )?;
+                        );
+                    }
+                    settle_dispute_bond(&env, &shipment, true)?;
+                    settle_insurance_reserve(&env, &shipment, true)
+                })?
+            }
+            crate::types::AdminAction::ForceRefund(shipment_id) => {
+                with_reentrancy_lock(&env, || {
+                    let mut shipment = storage::get_shipment(&env, shipment_id)
+                        .ok_or(NavinError::ShipmentNotFound)?;
+
+                    let escrow_amount = shipment.escrow_amount;
+                    if escrow_amount > 0 {
+                        // Get token contract address
+                        if let Some(token_contract) = storage::get_token_contract(&env) {
+                            // Transfer tokens from this contract to company
+                            let contract_address = env.current_contract_address();
+                            invoke_token_transfer(
+                                &env,
+                                &token_contract,
+                                &contract_address,
+                                &shipment.sender,
+                                escrow_amount,
+                            )?;
+                        }
+
+                        shipment.escrow_amount = 0;
+                        shipment.updated_at = env.ledger().timestamp();
+                        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+                        persist_shipment(&env, &shipment)?;
+
+                        storage::record_escrow_refunded(&env, escrow_amount);
+                        events::emit_escrow_refunded(
+                            &env,
+                            shipment_id,
+                            &shipment.sender,
+                            escrow_amount,
+                        );
                     }
+                    settle_dispute_bond(&env, &shipment, false)?;
+                    settle_insurance_reserve(&env, &shipment, false)
+                })?
+            }
+            crate::types::AdminAction::ResolveDispute(shipment_id, resolution, reason_hash) => {
+                with_reentrancy_lock(&env, || {
+                    if reason_hash.to_array().iter().all(|&b| b == 0) {
+                        return Err(NavinError::DisputeReasonHashMissing);
+                    }
+                    settle_dispute(
+                        &env,
+                        &proposal.proposer,
+                        shipment_id,
+                        resolution_carrier_bps(&resolution),
+                        &reason_hash,
+                    )
+                })?
+            }
+            crate::types::AdminAction::Reinitialize(token_contract) => {
+                // Escrow may have been deposited while the proposal was pending.
+                require_no_locked_escrow(&env)?;
+
+                storage::set_token_contract(&env, &token_contract);
+                config::set_config(&env, &ContractConfig::default())
+                    .map_err(|_| NavinError::InvalidConfig)?;
+
+                events::emit_contract_initialized(&env, &storage::get_admin(&env), &token_contract);
+            }
+        }
+
+        env.events()
+            .publish((symbol_short!("executed"),), (proposal_id, proposal.action));
 
-                    shipment.escrow_amount = 0;
-                    shipment.updated_at = env.ledger().timestamp();
-                    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-                    persist_shipment(&env, &shipment)?;
+        Ok(())
+    }
+
+    /// Get a proposal by ID.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `proposal_id` - ID of the proposal.
+    ///
+    /// # Returns
+    /// * `Result<Proposal, NavinError>` - The proposal data.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ProposalNotFound` - If proposal doesn't exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let proposal = contract.get_proposal(&env, 1);
+    /// ```
+    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<crate::types::Proposal, NavinError> {
+        require_initialized(&env)?;
+        storage::get_proposal(&env, proposal_id).ok_or(NavinError::ProposalNotFound)
+    }
+
+    /// Get a proposal together with its computed status, approval count,
+    /// threshold, and seconds remaining until expiry.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `proposal_id` - ID of the proposal.
+    ///
+    /// # Returns
+    /// * `Result<ProposalDetail, NavinError>` - The proposal and derived fields.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ProposalNotFound` - If proposal doesn't exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let detail = contract.get_proposal_detail(&env, 1);
+    /// ```
+    pub fn get_proposal_detail(env: Env, proposal_id: u64) -> Result<ProposalDetail, NavinError> {
+        require_initialized(&env)?;
+        let proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(NavinError::ProposalNotFound)?;
 
-                    events::emit_escrow_released(
-                        &env,
-                        shipment_id,
-                        &shipment.carrier,
-                        escrow_amount,
-                    );
-                }
-            }
-            crate::types::AdminAction::ForceRefund(shipment_id) => {
-                let mut shipment =
-                    storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        let now = env.ledger().timestamp();
+        let approvals = proposal.approvals.len();
+        let threshold = storage::get_multisig_threshold(&env).unwrap_or(2);
+        let status = if proposal.executed {
+            ProposalStatus::Executed
+        } else if now > proposal.expires_at {
+            ProposalStatus::Expired
+        } else if approvals >= threshold {
+            ProposalStatus::Approved
+        } else {
+            ProposalStatus::Pending
+        };
+        let seconds_to_expiry = proposal.expires_at.saturating_sub(now);
 
-                let escrow_amount = shipment.escrow_amount;
-                if escrow_amount > 0 {
-                    // Get token contract address
-                    if let Some(token_contract) = storage::get_token_contract(&env) {
-                        // Transfer tokens from this contract to company
-                        let contract_address = env.current_contract_address();
-                        invoke_token_transfer(
-                            &env,
-                            &token_contract,
-                            &contract_address,
-                            &shipment.sender,
-                            escrow_amount,
-                        )?;
-                    }
+        Ok(ProposalDetail {
+            proposal,
+            status,
+            approvals,
+            threshold,
+            seconds_to_expiry,
+        })
+    }
 
-                    shipment.escrow_amount = 0;
-                    shipment.updated_at = env.ledger().timestamp();
-                    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
-                    persist_shipment(&env, &shipment)?;
+    /// Check whether a signer's approval is still needed on a proposal.
+    ///
+    /// Returns `true` only when the signer is in the multisig admin list, has
+    /// not yet approved, and the proposal is unexecuted, unexpired, and below
+    /// the approval threshold.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `proposal_id` - ID of the proposal.
+    /// * `signer` - Address of the prospective approver.
+    ///
+    /// # Returns
+    /// * `Result<bool, NavinError>` - True if the signer's approval is needed.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ProposalNotFound` - If proposal doesn't exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let pending = contract.needs_approval_from(&env, 1, &signer);
+    /// ```
+    pub fn needs_approval_from(
+        env: Env,
+        proposal_id: u64,
+        signer: Address,
+    ) -> Result<bool, NavinError> {
+        require_initialized(&env)?;
+        let proposal =
+            storage::get_proposal(&env, proposal_id).ok_or(NavinError::ProposalNotFound)?;
 
-                    events::emit_escrow_refunded(
-                        &env,
-                        shipment_id,
-                        &shipment.sender,
-                        escrow_amount,
-                    );
-                }
-            }
+        if !storage::is_admin(&env, &signer)
+            || proposal.executed
+            || env.ledger().timestamp() > proposal.expires_at
+        {
+            return Ok(false);
         }
 
-        env.events()
-            .publish((symbol_short!("executed"),), (proposal_id, proposal.action));
+        let threshold = storage::get_multisig_threshold(&env).unwrap_or(2);
+        if proposal.approvals.len() >= threshold {
+            return Ok(false);
+        }
 
-        Ok(())
+        Ok(!proposal.approvals.contains(&signer))
     }
 
-    /// Get a proposal by ID.
+    /// Get a snapshot of pending governance changes.
+    ///
+    /// Bundles the admin successor proposed via `transfer_admin` with the
+    /// current multisig membership and any open (unexecuted, unexpired)
+    /// `TransferAdmin` proposals. Multisig membership itself only changes
+    /// through `init_multisig`, so there are no pending signer proposals to
+    /// report. Only the most recent `MAX_BATCH_QUERY_SIZE` proposals are
+    /// scanned to keep the call bounded.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `proposal_id` - ID of the proposal.
     ///
     /// # Returns
-    /// * `Result<Proposal, NavinError>` - The proposal data.
+    /// * `Result<PendingGovernance, NavinError>` - Pending control changes.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ProposalNotFound` - If proposal doesn't exist.
     ///
     /// # Examples
     /// ```rust
-    /// // let proposal = contract.get_proposal(&env, 1);
+    /// // let pending = contract.get_pending_governance(&env);
     /// ```
-    pub fn get_proposal(env: Env, proposal_id: u64) -> Result<crate::types::Proposal, NavinError> {
+    pub fn get_pending_governance(env: Env) -> Result<PendingGovernance, NavinError> {
         require_initialized(&env)?;
-        storage::get_proposal(&env, proposal_id).ok_or(NavinError::ProposalNotFound)
+
+        let now = env.ledger().timestamp();
+        let counter = storage::get_proposal_counter(&env);
+        let oldest = counter.saturating_sub(effective_batch_query_limit(&env) as u64);
+        let mut pending_admin_transfers = Vec::new(&env);
+        for proposal_id in (oldest + 1)..=counter {
+            if let Some(proposal) = storage::get_proposal(&env, proposal_id) {
+                if proposal.executed || now > proposal.expires_at {
+                    continue;
+                }
+                if let crate::types::AdminAction::TransferAdmin(new_admin) = proposal.action {
+                    pending_admin_transfers.push_back((proposal_id, new_admin));
+                }
+            }
+        }
+
+        Ok(PendingGovernance {
+            admin: storage::get_admin(&env),
+            proposed_admin: storage::get_proposed_admin(&env),
+            signers: storage::get_admin_list(&env).unwrap_or(Vec::new(&env)),
+            threshold: storage::get_multisig_threshold(&env).unwrap_or(0),
+            pending_admin_transfers,
+        })
     }
 
     /// Get the multi-sig configuration.
@@ -5374,93 +9619,441 @@ impl NavinShipment {
         Ok(())
     }
 
-    /// Add a new carrier to the contract.
+    /// Sweep dust escrow residue from completed shipments to a treasury.
+    /// Only Admin can execute.
+    ///
+    /// A shipment is eligible when it is `Delivered` or `Cancelled`, holds a
+    /// non-zero escrow strictly below `ContractConfig.dust_threshold` and has
+    /// no settlement in flight: shipments with a delayed release still awaiting
+    /// `finalize_release` or with an unfinished settlement are skipped, as are
+    /// ineligible or unknown IDs, rather than failing the batch.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `admin` - Contract admin executing the sweep.
+    /// * `shipment_ids` - Candidate shipment IDs.
+    /// * `to` - Treasury address receiving the swept residue.
+    ///
+    /// # Returns
+    /// * `Result<i128, NavinError>` - Total amount swept.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller is not the admin.
+    /// * `NavinError::BatchTooLarge` - If more IDs than `batch_operation_limit` are given.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let swept = contract.sweep_dust(&env, &admin, &ids, &treasury);
+    /// ```
+    pub fn sweep_dust(
+        env: Env,
+        admin: Address,
+        shipment_ids: Vec<u64>,
+        to: Address,
+    ) -> Result<i128, NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        admin.require_auth();
+        with_reentrancy_lock(&env, || {
+            require_admin(&env, &admin)?;
+
+            let config = config::get_config(&env);
+            if shipment_ids.len() > config.batch_operation_limit {
+                return Err(NavinError::BatchTooLarge);
+            }
+
+            let token_contract =
+                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
+            let contract_address = env.current_contract_address();
+            let now = env.ledger().timestamp();
+            let mut total: i128 = 0;
+
+            for shipment_id in shipment_ids.iter() {
+                let mut shipment = match storage::get_shipment(&env, shipment_id) {
+                    Some(shipment) => shipment,
+                    None => continue,
+                };
+                let completed = shipment.status == ShipmentStatus::Delivered
+                    || shipment.status == ShipmentStatus::Cancelled;
+                let amount = shipment.escrow_amount;
+                if !completed || amount <= 0 || amount >= config.dust_threshold {
+                    continue;
+                }
+                if storage::get_release_at(&env, shipment_id).is_some()
+                    || storage::get_active_settlement(&env, shipment_id).is_some()
+                {
+                    continue;
+                }
+
+                invoke_token_transfer(&env, &token_contract, &contract_address, &to, amount)?;
+
+                shipment.escrow_amount = 0;
+                shipment.updated_at = now;
+                shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+                finalize_if_settled(&env, &mut shipment);
+                persist_shipment(&env, &shipment)?;
+                storage::remove_escrow_balance(&env, shipment_id);
+                extend_shipment_ttl(&env, shipment_id);
+
+                storage::record_escrow_released(&env, amount);
+                events::emit_dust_swept(&env, shipment_id, &to, amount);
+                settle_insurance_reserve(
+                    &env,
+                    &shipment,
+                    shipment.status == ShipmentStatus::Delivered,
+                )?;
+                total = checked_add_i128(total, amount)?;
+            }
+
+            Ok(total)
+        })
+    }
+
+    /// Add a new carrier to the contract.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<ContractConfig, NavinError>` - The current configuration.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let config = contract.get_config(&env);
+    /// ```
+    pub fn get_contract_config(env: Env) -> Result<ContractConfig, NavinError> {
+        require_initialized(&env)?;
+        Ok(config::get_config(&env))
+    }
+
+    /// Get the set of optional features currently active on the contract.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    ///
+    /// # Returns
+    /// * `Result<FeatureFlags, NavinError>` - Enabled/disabled state of each feature.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let flags = contract.get_feature_flags(&env);
+    /// // if flags.release_delay { /* expect finalize_release */ }
+    /// ```
+    pub fn get_feature_flags(env: Env) -> Result<FeatureFlags, NavinError> {
+        require_initialized(&env)?;
+        let config = config::get_config(&env);
+        Ok(FeatureFlags {
+            paused: storage::is_paused(&env),
+            auto_paused: storage::is_auto_paused(&env),
+            platform_fee: storage::get_fee_config(&env).is_some_and(|fee| fee.fee_bps > 0),
+            min_escrow_value: config.price_oracle.is_some() && config.min_escrow_usd > 0,
+            release_delay: config.escrow_policy.release_delay_seconds > 0,
+            dispute_spike_guard: config.dispute_policy.dispute_spike_threshold > 0,
+            auto_dispute_breach: config.auto_dispute_breach,
+            rounding_favors_carrier: config.rounding_favors_carrier,
+            require_registered_receiver: config.require_registered_receiver,
+            require_receiver_approval: config.require_receiver_approval,
+            penalize_deadline_miss: config.carrier_policy.penalize_deadline_miss,
+            index_company_shipments: config.index_company_shipments,
+            revoke_old_admin_company_role: config.revoke_old_admin_company_role,
+            allow_third_party_escrow: config.escrow_policy.allow_third_party_escrow,
+            allow_milestone_holdback: config.escrow_policy.allow_milestone_holdback,
+            auto_whitelist_on_delivery: config.carrier_policy.auto_whitelist_on_delivery,
+            require_carrier_acceptance: config.carrier_policy.require_carrier_acceptance,
+        })
+    }
+
+    /// Cancel a shipment and auto-refund escrow if its delivery deadline has passed.
+    /// Permissionless design — can be triggered by any caller (e.g., automated cron/crank).
+    /// Shipments in carrier custody (`InTransit`, `AtCheckpoint`,
+    /// `PartiallyDelivered`) expire `deadline_grace_seconds` past the deadline,
+    /// giving carriers a buffer; all other shipments expire at the deadline.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_id` - ID of the target shipment.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok if successfully cancelled and escrow refunded.
+    ///
+    /// # Errors
+    /// * `NavinError::NotExpired` - If the ledger time hasn't reached the deadline (plus grace in transit).
+    /// * `NavinError::ShipmentAlreadyCompleted` - If the shipment is already in a terminal state.
+    pub fn check_deadline(env: Env, shipment_id: u64) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        with_reentrancy_lock(&env, || expire_shipment(&env, shipment_id))
+    }
+
+    /// Expire many shipments in one call, for cranks clearing a backlog.
+    /// Shipments that are missing, not yet past their deadline, or already
+    /// terminal are skipped instead of failing the batch.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `shipment_ids` - Candidate shipment IDs.
+    ///
+    /// # Returns
+    /// * `Result<Vec<u64>, NavinError>` - IDs that were cancelled and refunded.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::BatchTooLarge` - If more IDs than `batch_operation_limit` are given.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let expired = contract.check_deadlines_batch(&env, ids);
+    /// ```
+    pub fn check_deadlines_batch(env: Env, shipment_ids: Vec<u64>) -> Result<Vec<u64>, NavinError> {
+        require_initialized(&env)?;
+        if shipment_ids.len() > config::get_config(&env).batch_operation_limit {
+            return Err(NavinError::BatchTooLarge);
+        }
+
+        with_reentrancy_lock(&env, || {
+            let mut expired = Vec::new(&env);
+            for shipment_id in shipment_ids.iter() {
+                match expire_shipment(&env, shipment_id) {
+                    Ok(()) => expired.push_back(shipment_id),
+                    Err(NavinError::ShipmentNotFound)
+                    | Err(NavinError::NotExpired)
+                    | Err(NavinError::ShipmentAlreadyCompleted) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(expired)
+        })
+    }
+
+    /// Get how many carrier-held shipments expired past their deadline.
+    /// Only counted while `ContractConfig.penalize_deadline_miss` is enabled.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Carrier address.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - Number of recorded deadline misses.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let misses = contract.get_carrier_deadline_misses(&env, &carrier);
+    /// ```
+    pub fn get_carrier_deadline_misses(env: Env, carrier: Address) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_carrier_deadline_misses(&env, &carrier))
+    }
+
+    /// Get how many times a carrier kept a shipment at a checkpoint longer
+    /// than `ContractConfig.max_checkpoint_dwell`.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Carrier address.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - Number of recorded checkpoint overstays.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let overstays = contract.get_carrier_checkpoint_overstays(&env, &carrier);
+    /// ```
+    pub fn get_carrier_checkpoint_overstays(env: Env, carrier: Address) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_carrier_checkpoint_overstays(&env, &carrier))
+    }
+
+    /// Get a carrier's accumulated breach penalty.
+    ///
+    /// Each `report_condition_breach` adds the configured
+    /// `ContractConfig.breach_weights` entry for its breach type, so a higher
+    /// value means a worse reputation.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Carrier address.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - Accumulated weighted breach penalty.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let penalty = contract.get_carrier_breach_penalty(&env, &carrier);
+    /// ```
+    pub fn get_carrier_breach_penalty(env: Env, carrier: Address) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_carrier_breach_penalty(&env, &carrier))
+    }
+
+    /// Get the share of a carrier's confirmed deliveries that arrived on or
+    /// before the deadline, as a percentage (0-100). Returns 0 for carriers
+    /// with no confirmed deliveries.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carrier` - Carrier address.
+    ///
+    /// # Returns
+    /// * `Result<u32, NavinError>` - On-time delivery rate scaled by 100.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let rate = contract.get_carrier_on_time_rate(&env, &carrier);
+    /// ```
+    pub fn get_carrier_on_time_rate(env: Env, carrier: Address) -> Result<u32, NavinError> {
+        require_initialized(&env)?;
+        let total = storage::get_carrier_deliveries(&env, &carrier);
+        if total == 0 {
+            return Ok(0);
+        }
+        let on_time = storage::get_carrier_on_time_deliveries(&env, &carrier);
+        Ok(((on_time as u64 * 100) / total as u64) as u32)
+    }
+
+    /// Fetch all on-chain reputation counters for a carrier.
+    ///
+    /// Counters are updated alongside the `delivery_success`,
+    /// `carrier_dispute_loss` and `carrier_breach` events, so clients can read
+    /// a carrier's track record without indexing the event stream. Unknown
+    /// carriers are returned with all counters set to zero.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
+    /// * `carrier` - Carrier address.
     ///
     /// # Returns
-    /// * `Result<ContractConfig, NavinError>` - The current configuration.
+    /// * `Result<CarrierReputation, NavinError>` - The carrier's reputation counters.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
     ///
     /// # Examples
     /// ```rust
-    /// // let config = contract.get_config(&env);
+    /// // let rep = contract.get_carrier_reputation(&env, carrier);
     /// ```
-    pub fn get_contract_config(env: Env) -> Result<ContractConfig, NavinError> {
+    pub fn get_carrier_reputation(
+        env: Env,
+        carrier: Address,
+    ) -> Result<CarrierReputation, NavinError> {
         require_initialized(&env)?;
-        Ok(config::get_config(&env))
+        Ok(carrier_reputation(&env, carrier))
     }
 
-    /// Cancel a shipment and auto-refund escrow if its delivery deadline has passed.
-    /// Permissionless design — can be triggered by any caller (e.g., automated cron/crank).
+    /// Rate the carrier of a delivered shipment on a 1-5 scale.
+    ///
+    /// Only the shipment's receiver may rate, and only once per shipment. The
+    /// score is folded into the carrier's running average reported by
+    /// `get_carrier_reputation`.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `shipment_id` - ID of the target shipment.
+    /// * `receiver` - Receiver of the shipment.
+    /// * `shipment_id` - ID of the delivered shipment.
+    /// * `score` - Rating from 1 (worst) to 5 (best).
     ///
     /// # Returns
-    /// * `Result<(), NavinError>` - Ok if successfully cancelled and escrow refunded.
+    /// * `Result<(), NavinError>` - Ok once the rating is recorded.
     ///
     /// # Errors
-    /// * `NavinError::NotExpired` - If the current ledger time hasn't passed the deadline.
-    /// * `NavinError::ShipmentAlreadyCompleted` - If the shipment is already in a terminal state.
-    pub fn check_deadline(env: Env, shipment_id: u64) -> Result<(), NavinError> {
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::InvalidRating` - If `score` is outside 1..=5.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::Unauthorized` - If caller is not the shipment's receiver.
+    /// * `NavinError::InvalidStatus` - If the shipment is not `Delivered`.
+    /// * `NavinError::AlreadyRated` - If the shipment has already been rated.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // contract.rate_carrier(&env, &receiver, 1, 5);
+    /// ```
+    pub fn rate_carrier(
+        env: Env,
+        receiver: Address,
+        shipment_id: u64,
+        score: u32,
+    ) -> Result<(), NavinError> {
         require_initialized(&env)?;
+        require_not_paused(&env)?;
+        receiver.require_auth();
 
-        let mut shipment =
-            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
-
-        let config = config::get_config(&env);
-        let expiry_threshold = shipment
-            .deadline
-            .saturating_add(config.deadline_grace_seconds);
-
-        if env.ledger().timestamp() < expiry_threshold {
-            return Err(NavinError::NotExpired);
+        if !(1..=5).contains(&score) {
+            return Err(NavinError::InvalidRating);
         }
 
-        match shipment.status {
-            ShipmentStatus::Delivered | ShipmentStatus::Disputed | ShipmentStatus::Cancelled => {
-                return Err(NavinError::ShipmentAlreadyCompleted);
-            }
-            _ => {}
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+        if shipment.receiver != receiver {
+            return Err(NavinError::Unauthorized);
+        }
+        if shipment.status != ShipmentStatus::Delivered {
+            return Err(NavinError::InvalidStatus);
+        }
+        if storage::is_rated(&env, shipment_id) {
+            return Err(NavinError::AlreadyRated);
         }
 
-        let escrow_amount = shipment.escrow_amount;
-        let old_status = shipment.status.clone();
-        shipment.status = ShipmentStatus::Cancelled;
-        shipment.escrow_amount = 0;
-        shipment.updated_at = env.ledger().timestamp();
-        shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+        storage::set_rated(&env, shipment_id);
+        storage::add_carrier_rating(&env, &shipment.carrier, score);
+        events::emit_carrier_rated(&env, &shipment.carrier, shipment_id, score);
 
-        persist_shipment(&env, &shipment)?;
-        storage::decrement_status_count(&env, &old_status);
-        storage::increment_status_count(&env, &ShipmentStatus::Cancelled);
-        storage::decrement_active_shipment_count(&env, &shipment.sender);
+        Ok(())
+    }
 
-        if escrow_amount > 0 {
-            storage::remove_escrow_balance(&env, shipment_id);
+    /// Fetch reputation counters for multiple carriers in one call.
+    ///
+    /// Results preserve input order; unknown carriers are returned with all
+    /// counters set to zero.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `carriers` - Carrier addresses to look up.
+    ///
+    /// # Returns
+    /// * `Result<Vec<CarrierReputation>, NavinError>` - One entry per requested carrier.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::BatchTooLarge` - If more carriers than the batch query limit are requested.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let reps = contract.get_reputations_batch(&env, carriers);
+    /// ```
+    pub fn get_reputations_batch(
+        env: Env,
+        carriers: Vec<Address>,
+    ) -> Result<Vec<CarrierReputation>, NavinError> {
+        require_initialized(&env)?;
 
-            let token_contract =
-                storage::get_token_contract(&env).ok_or(NavinError::NotInitialized)?;
-            let contract_address = env.current_contract_address();
-            invoke_token_transfer(
-                &env,
-                &token_contract,
-                &contract_address,
-                &shipment.sender,
-                escrow_amount,
-            )?;
-            events::emit_escrow_refunded(&env, shipment_id, &shipment.sender, escrow_amount);
+        let max_batch = effective_batch_query_limit(&env);
+        if carriers.len() > max_batch {
+            return Err(NavinError::BatchTooLarge);
         }
 
-        extend_shipment_ttl(&env, shipment_id);
-        events::emit_shipment_expired(&env, shipment_id);
+        let mut results = Vec::new(&env);
+        for carrier in carriers.iter() {
+            results.push_back(carrier_reputation(&env, carrier));
+        }
 
-        Ok(())
+        Ok(results)
     }
 
     /// Generate a deterministic shipment reference string for cross-system interoperability.
@@ -5529,7 +10122,8 @@ impl NavinShipment {
     }
 
     /// Unpause the contract, re-enabling state-changing operations.
-    /// Only the admin can unpause the contract.
+    /// Only the admin or a guardian can unpause the contract; a pause triggered
+    /// by dispute spike detection can only be lifted by the admin.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
@@ -5551,6 +10145,11 @@ impl NavinShipment {
         admin.require_auth();
 
         require_admin_or_guardian(&env, &admin)?;
+        // Guardians cannot lift a pause triggered by dispute spike detection.
+        if storage::is_auto_paused(&env) {
+            require_admin(&env, &admin)?;
+            storage::set_auto_paused(&env, false);
+        }
 
         storage::set_paused(&env, false);
         events::emit_contract_unpaused(&env, &admin);
@@ -5940,110 +10539,827 @@ impl NavinShipment {
     }
 
     // =========================================================================
-    // Issue #297 — Multi-sig Proposal Action Hash and Digest Query
+    // Issue #297 — Multi-sig Proposal Action Hash and Digest Query
+    // =========================================================================
+
+    /// Retrieve the stored action digest for a proposal.
+    ///
+    /// The digest is computed and stored when `propose_action` is called.
+    /// Off-chain signers can recompute `sha256(proposal_id_be_u64 || action_xdr)`
+    /// to verify the exact payload before approving.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `proposal_id` - The proposal whose digest to retrieve.
+    ///
+    /// # Returns
+    /// * `Result<ProposalActionDigest, NavinError>` - The stored digest record.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ProposalNotFound` - If the proposal or its digest does not exist.
+    pub fn get_proposal_action_digest(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<ProposalActionDigest, NavinError> {
+        require_initialized(&env)?;
+        storage::get_proposal_digest(&env, proposal_id).ok_or(NavinError::ProposalNotFound)
+    }
+
+    /// Compute the action digest for an `AdminAction` without storing it.
+    ///
+    /// Pure helper for off-chain tooling: returns the same digest that
+    /// `propose_action` would store, so callers can verify before submitting.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `proposal_id` - The proposal ID to bind into the digest.
+    /// * `action` - The action to hash.
+    ///
+    /// # Returns
+    /// * `BytesN<32>` - The SHA-256 digest.
+    pub fn compute_proposal_digest(
+        env: Env,
+        proposal_id: u64,
+        action: crate::types::AdminAction,
+    ) -> BytesN<32> {
+        compute_action_digest(&env, proposal_id, &action)
+    }
+
+    /// Compute a deterministic SHA-256 hash of an ordered list of values.
+    ///
+    /// Each element is XDR-serialized in order and the concatenated bytes are
+    /// hashed. Useful for off-chain verification of on-chain data payloads.
+    pub fn get_canonical_hash(env: Env, fields: soroban_sdk::Vec<soroban_sdk::Val>) -> BytesN<32> {
+        use soroban_sdk::xdr::ToXdr;
+        let xdr_bytes = fields.to_xdr(&env);
+        env.crypto().sha256(&xdr_bytes).into()
+    }
+
+    // =========================================================================
+    // Recovery Operations
+    // =========================================================================
+
+    pub fn recover_shipment(
+        env: Env,
+        admin: Address,
+        shipment_id: u64,
+        target_status: ShipmentStatus,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        recovery::recover_shipment(&env, &admin, shipment_id, target_status, &reason_hash)
+    }
+
+    pub fn unlock_escrow(
+        env: Env,
+        admin: Address,
+        shipment_id: u64,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        recovery::unlock_escrow(&env, &admin, shipment_id, &reason_hash)
+    }
+
+    pub fn clear_finalization(
+        env: Env,
+        admin: Address,
+        shipment_id: u64,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        recovery::clear_finalization(&env, &admin, shipment_id, &reason_hash)
+    }
+
+    pub fn rollback_on_external_failure(
+        env: Env,
+        admin: Address,
+        shipment_id: u64,
+        previous_status: ShipmentStatus,
+        reason_hash: BytesN<32>,
+    ) -> Result<(), NavinError> {
+        recovery::rollback_on_external_failure(
+            &env,
+            &admin,
+            shipment_id,
+            previous_status,
+            &reason_hash,
+        )
+    }
+
+    // =========================================================================
+    // Notification Inbox
+    // =========================================================================
+
+    /// Opt an address into (or out of) the on-chain notification inbox.
+    ///
+    /// Notifications are always emitted as events; opted-in addresses also
+    /// get each notification appended to a bounded inbox so that a newly
+    /// connecting client can fetch what it missed. Opting out leaves any
+    /// existing entries in place until `clear_inbox` is called.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `address` - Address changing its inbox preference (must authorize).
+    /// * `enabled` - `true` to start recording notifications, `false` to stop.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on success.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn set_inbox_opt_in(env: Env, address: Address, enabled: bool) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        address.require_auth();
+
+        storage::set_inbox_enabled(&env, &address, enabled);
+        Ok(())
+    }
+
+    /// Return the notifications recorded in an address's inbox, oldest first.
+    ///
+    /// Each entry is `(notification_type, shipment_id, timestamp)`. At most
+    /// `MAX_INBOX_ENTRIES` are retained; older entries are dropped first.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `address` - Address whose inbox to read.
+    ///
+    /// # Returns
+    /// * `Result<Vec<(NotificationType, u64, u64)>, NavinError>` - Inbox entries.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn get_inbox(
+        env: Env,
+        address: Address,
+    ) -> Result<Vec<(NotificationType, u64, u64)>, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_inbox(&env, &address))
+    }
+
+    /// Remove every entry from the caller's notification inbox.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `address` - Inbox owner (must authorize).
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on success.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn clear_inbox(env: Env, address: Address) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        address.require_auth();
+
+        storage::clear_inbox(&env, &address);
+        Ok(())
+    }
+
+    /// Register extra addresses, such as operators, to be notified of the
+    /// company's shipment lifecycle actions alongside the usual parties.
+    /// Replaces any previously registered list.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `company` - Company registering the recipients (must authorize).
+    /// * `recipients` - Extra recipients; an empty list clears them.
+    ///
+    /// # Returns
+    /// * `Result<(), NavinError>` - Ok on success.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::Unauthorized` - If caller does not hold the Company role.
+    /// * `NavinError::TooManyNotificationRecipients` - If the three shipment
+    ///   parties plus `recipients` exceed `max_notification_recipients`.
+    pub fn set_notification_recipients(
+        env: Env,
+        company: Address,
+        recipients: Vec<Address>,
+    ) -> Result<(), NavinError> {
+        require_initialized(&env)?;
+        require_not_paused(&env)?;
+        company.require_auth();
+        require_role(&env, &company, Role::Company)?;
+
+        let config = config::get_config(&env);
+        if recipients.len().saturating_add(3) > config.max_notification_recipients {
+            return Err(NavinError::TooManyNotificationRecipients);
+        }
+
+        storage::set_notification_recipients(&env, &company, &recipients);
+        Ok(())
+    }
+
+    /// Return the extra notification recipients registered by a company.
+    ///
+    /// # Arguments
+    /// * `env` - Execution environment.
+    /// * `company` - Company address.
+    ///
+    /// # Returns
+    /// * `Result<Vec<Address>, NavinError>` - Registered extra recipients.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    pub fn get_notification_recipients(
+        env: Env,
+        company: Address,
+    ) -> Result<Vec<Address>, NavinError> {
+        require_initialized(&env)?;
+        Ok(storage::get_notification_recipients(&env, &company))
+    }
+
+    // =========================================================================
+    // Allowed Actions Query
     // =========================================================================
 
-    /// Retrieve the stored action digest for a proposal.
+    /// Return the entry points `caller` may currently invoke on a shipment.
     ///
-    /// The digest is computed and stored when `propose_action` is called.
-    /// Off-chain signers can recompute `sha256(proposal_id_be_u64 || action_xdr)`
-    /// to verify the exact payload before approving.
+    /// Mirrors the role, relationship and status checks of the individual
+    /// handlers so front-ends can render action buttons without duplicating
+    /// contract logic. The list is advisory: argument-level checks (amounts,
+    /// hashes, rate limits, idempotency) are still enforced by each handler.
+    /// A paused contract yields an empty list; a finalized shipment only offers
+    /// the post-settlement actions (`rate_carrier`, `claim_shipment_insurance`).
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `proposal_id` - The proposal whose digest to retrieve.
+    /// * `shipment_id` - ID of the shipment.
+    /// * `caller` - Address whose permitted actions to compute.
     ///
     /// # Returns
-    /// * `Result<ProposalActionDigest, NavinError>` - The stored digest record.
+    /// * `Result<Vec<Symbol>, NavinError>` - Function names the caller may invoke.
     ///
     /// # Errors
     /// * `NavinError::NotInitialized` - If contract is not initialized.
-    /// * `NavinError::ProposalNotFound` - If the proposal or its digest does not exist.
-    pub fn get_proposal_action_digest(
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let actions = contract.allowed_actions(&env, shipment_id, &carrier);
+    /// ```
+    pub fn allowed_actions(
         env: Env,
-        proposal_id: u64,
-    ) -> Result<ProposalActionDigest, NavinError> {
+        shipment_id: u64,
+        caller: Address,
+    ) -> Result<Vec<Symbol>, NavinError> {
         require_initialized(&env)?;
-        storage::get_proposal_digest(&env, proposal_id).ok_or(NavinError::ProposalNotFound)
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        let mut actions = Vec::new(&env);
+        if storage::is_paused(&env) {
+            return Ok(actions);
+        }
+
+        let status = shipment.status.clone();
+        let is_admin = storage::get_admin(&env) == caller;
+        let is_sender = shipment.sender == caller && require_active_company(&env, &caller).is_ok();
+        let is_receiver = shipment.receiver == caller;
+        let is_carrier = shipment.carrier == caller
+            && storage::has_carrier_role(&env, &caller)
+            && require_active_carrier(&env, &caller).is_ok();
+        let is_assigned_carrier =
+            shipment.carrier == caller && require_active_carrier(&env, &caller).is_ok();
+        let is_party =
+            shipment.sender == caller || shipment.receiver == caller || shipment.carrier == caller;
+        let is_arbiter = require_dispute_resolver(&env, &caller).is_ok();
+        let is_escalated = storage::is_dispute_escalated(&env, shipment_id);
+        let is_open = !matches!(
+            status,
+            ShipmentStatus::Delivered
+                | ShipmentStatus::Cancelled
+                | ShipmentStatus::PartiallyRefunded
+        );
+
+        let mut allow = |name: &str, permitted: bool| {
+            if permitted {
+                actions.push_back(Symbol::new(&env, name));
+            }
+        };
+
+        if !shipment.finalized {
+            allow(
+                "deposit_escrow",
+                is_sender && status == ShipmentStatus::Created && shipment.escrow_amount == 0,
+            );
+            allow(
+                "top_up_escrow",
+                is_sender && status == ShipmentStatus::Created && shipment.escrow_amount > 0,
+            );
+            allow(
+                "accept_assignment",
+                is_assigned_carrier && status == ShipmentStatus::Created,
+            );
+            allow(
+                "decline_assignment",
+                is_assigned_carrier && status == ShipmentStatus::Created,
+            );
+            allow(
+                "record_pickup",
+                is_carrier && status == ShipmentStatus::Created,
+            );
+            allow("update_status", (is_carrier || is_admin) && is_open);
+            allow(
+                "record_milestone",
+                is_carrier && status == ShipmentStatus::InTransit,
+            );
+            allow("update_eta", is_carrier);
+            allow("report_geofence_event", is_carrier);
+            allow("report_condition_breach", is_carrier);
+            allow("handoff_shipment", is_carrier && is_open);
+            allow(
+                "confirm_delivery",
+                is_receiver && status.is_valid_transition(&ShipmentStatus::Delivered),
+            );
+            allow(
+                "confirm_partial_delivery",
+                is_receiver
+                    && shipment.escrow_amount > 0
+                    && matches!(
+                        status,
+                        ShipmentStatus::InTransit
+                            | ShipmentStatus::AtCheckpoint
+                            | ShipmentStatus::PartiallyDelivered
+                    ),
+            );
+            allow(
+                "release_escrow",
+                (is_receiver || is_admin)
+                    && status == ShipmentStatus::Delivered
+                    && shipment.escrow_amount > 0,
+            );
+            allow(
+                "refund_escrow",
+                (is_sender || is_admin)
+                    && matches!(status, ShipmentStatus::Created | ShipmentStatus::Cancelled)
+                    && shipment.escrow_amount > 0,
+            );
+            let can_cancel =
+                (is_sender || is_admin) && is_open && status != ShipmentStatus::Disputed;
+            allow("cancel_shipment", can_cancel);
+            allow("cancel_with_compensation", can_cancel);
+            allow(
+                "extend_deadline",
+                (is_sender || is_admin)
+                    && !matches!(
+                        status,
+                        ShipmentStatus::Delivered | ShipmentStatus::Cancelled
+                    ),
+            );
+            let can_dispute = (is_sender || is_receiver || shipment.carrier == caller)
+                && status != ShipmentStatus::Disputed
+                && status.is_valid_transition(&ShipmentStatus::Disputed);
+            allow("raise_dispute", can_dispute);
+            allow("raise_dispute_with_bond", can_dispute);
+            allow(
+                "escalate_dispute",
+                is_party && status == ShipmentStatus::Disputed && !is_escalated,
+            );
+            let can_resolve = is_arbiter
+                && status == ShipmentStatus::Disputed
+                && shipment.escrow_amount > 0
+                && !is_escalated;
+            allow("resolve_dispute", can_resolve);
+            allow("resolve_dispute_split", can_resolve);
+        }
+
+        // Post-settlement actions remain available on finalized shipments.
+        allow(
+            "rate_carrier",
+            is_receiver
+                && status == ShipmentStatus::Delivered
+                && !storage::is_rated(&env, shipment_id),
+        );
+        allow(
+            "claim_shipment_insurance",
+            shipment.sender == caller
+                && storage::is_insurance_claimable(&env, shipment_id)
+                && storage::get_insurance_reserve(&env, shipment_id) > 0,
+        );
+
+        Ok(actions)
     }
 
-    /// Compute the action digest for an `AdminAction` without storing it.
+    /// Return the address whose signature a shipment entry point requires.
     ///
-    /// Pure helper for off-chain tooling: returns the same digest that
-    /// `propose_action` would store, so callers can verify before submitting.
+    /// Lets wallets pre-build auth entries for the right party. Only entry
+    /// points with a single fixed signer per shipment are supported; functions
+    /// any participant may call (such as `raise_dispute`) are rejected.
+    /// Admin-override paths (e.g. admin calling `update_status`) are not
+    /// reflected — the shipment's own party is returned.
     ///
     /// # Arguments
     /// * `env` - Execution environment.
-    /// * `proposal_id` - The proposal ID to bind into the digest.
-    /// * `action` - The action to hash.
+    /// * `function` - Name of the entry point.
+    /// * `shipment_id` - ID of the shipment.
     ///
     /// # Returns
-    /// * `BytesN<32>` - The SHA-256 digest.
-    pub fn compute_proposal_digest(
+    /// * `Result<Address, NavinError>` - Address that must authorize the call.
+    ///
+    /// # Errors
+    /// * `NavinError::NotInitialized` - If contract is not initialized.
+    /// * `NavinError::ShipmentNotFound` - If shipment does not exist.
+    /// * `NavinError::InvalidSymbol` - If `function` has no single fixed signer.
+    ///
+    /// # Examples
+    /// ```rust
+    /// // let signer = contract.required_auth(&env, &Symbol::new(&env, "confirm_delivery"), shipment_id);
+    /// ```
+    pub fn required_auth(
         env: Env,
-        proposal_id: u64,
-        action: crate::types::AdminAction,
-    ) -> BytesN<32> {
-        compute_action_digest(&env, proposal_id, &action)
+        function: Symbol,
+        shipment_id: u64,
+    ) -> Result<Address, NavinError> {
+        require_initialized(&env)?;
+        let shipment =
+            storage::get_shipment(&env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+        let is = |name: &str| function == Symbol::new(&env, name);
+
+        if is("deposit_escrow")
+            || is("refund_escrow")
+            || is("cancel_shipment")
+            || is("set_checkpoint_ack_required")
+            || is("transfer_shipment_ownership")
+            || is("reassign_carrier")
+            || is("extend_deadline")
+        {
+            Ok(shipment.sender)
+        } else if is("update_status")
+            || is("record_milestone")
+            || is("update_eta")
+            || is("record_distance")
+            || is("report_geofence_event")
+            || is("report_condition_breach")
+            || is("handoff_shipment")
+            || is("accept_assignment")
+            || is("record_pickup")
+            || is("decline_assignment")
+        {
+            Ok(shipment.carrier)
+        } else if is("confirm_delivery")
+            || is("confirm_partial_delivery")
+            || is("release_escrow")
+            || is("ack_checkpoint")
+            || is("rate_carrier")
+        {
+            Ok(shipment.receiver)
+        } else if is("resolve_dispute")
+            || is("resolve_dispute_split")
+            || is("force_cancel_shipment")
+        {
+            Ok(storage::get_admin(&env))
+        } else {
+            Err(NavinError::InvalidSymbol)
+        }
     }
+}
 
-    /// Compute a deterministic SHA-256 hash of an ordered list of values.
-    ///
-    /// Each element is XDR-serialized in order and the concatenated bytes are
-    /// hashed. Useful for off-chain verification of on-chain data payloads.
-    pub fn get_canonical_hash(env: Env, fields: soroban_sdk::Vec<soroban_sdk::Val>) -> BytesN<32> {
-        use soroban_sdk::xdr::ToXdr;
-        let xdr_bytes = fields.to_xdr(&env);
-        env.crypto().sha256(&xdr_bytes).into()
+/// Move a shipment into `Disputed` on behalf of `caller`.
+///
+/// Shared by `raise_dispute` and `raise_dispute_with_bond`; callers must
+/// perform authentication first.
+fn apply_dispute(
+    env: &Env,
+    caller: &Address,
+    shipment_id: u64,
+    reason_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    // Validate hash before storage
+    validation::validate_hash(reason_hash)?;
+
+    let mut shipment =
+        storage::get_shipment(env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+    require_not_finalized(&shipment)?;
+
+    if *caller != shipment.sender && *caller != shipment.receiver && *caller != shipment.carrier {
+        return Err(NavinError::Unauthorized);
     }
 
-    // =========================================================================
-    // Recovery Operations
-    // =========================================================================
+    // Check for suspension if caller is the sender (company)
+    if *caller == shipment.sender {
+        require_active_company(env, caller)?;
+    }
 
-    pub fn recover_shipment(
-        env: Env,
-        admin: Address,
-        shipment_id: u64,
-        target_status: ShipmentStatus,
-        reason_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
-        recovery::recover_shipment(&env, &admin, shipment_id, target_status, &reason_hash)
+    if shipment.status == ShipmentStatus::Cancelled || shipment.status == ShipmentStatus::Disputed {
+        return Err(NavinError::ShipmentAlreadyCompleted);
     }
 
-    pub fn unlock_escrow(
-        env: Env,
-        admin: Address,
-        shipment_id: u64,
-        reason_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
-        recovery::unlock_escrow(&env, &admin, shipment_id, &reason_hash)
+    if dispute_limit_reached(env, shipment_id) {
+        return Err(NavinError::DisputeLimitReached);
     }
 
-    pub fn clear_finalization(
-        env: Env,
-        admin: Address,
-        shipment_id: u64,
-        reason_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
-        recovery::clear_finalization(&env, &admin, shipment_id, &reason_hash)
+    let old_status = shipment.status.clone();
+    shipment.status = ShipmentStatus::Disputed;
+    shipment.updated_at = env.ledger().timestamp();
+    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+    persist_shipment(env, &shipment)?;
+    storage::decrement_status_count(env, &old_status);
+    storage::increment_status_count(env, &ShipmentStatus::Disputed);
+    storage::push_status_history(env, shipment_id, &ShipmentStatus::Disputed, caller);
+    record_dispute(env, shipment.id, shipment.escrow_amount)?;
+    storage::set_escrow_freeze_reason(
+        env,
+        shipment_id,
+        &crate::types::EscrowFreezeReason::DisputeRaised,
+    );
+
+    extend_shipment_ttl(env, shipment_id);
+
+    events::emit_dispute_raised(env, shipment_id, caller, reason_hash);
+    // Emit a structured freeze reason so indexers can classify the escrow block.
+    events::emit_escrow_frozen(
+        env,
+        shipment_id,
+        crate::types::EscrowFreezeReason::DisputeRaised,
+        caller,
+    );
+    events::emit_notification(
+        env,
+        &shipment.sender,
+        NotificationType::DisputeRaised,
+        shipment_id,
+        reason_hash,
+    );
+    notify_extra_recipients(
+        env,
+        &shipment.sender,
+        NotificationType::DisputeRaised,
+        shipment_id,
+        reason_hash,
+    );
+    events::emit_notification(
+        env,
+        &shipment.receiver,
+        NotificationType::DisputeRaised,
+        shipment_id,
+        reason_hash,
+    );
+    events::emit_notification(
+        env,
+        &shipment.carrier,
+        NotificationType::DisputeRaised,
+        shipment_id,
+        reason_hash,
+    );
+
+    Ok(())
+}
+
+/// Pay out a shipment's insurance reserve, if one is held.
+///
+/// The reserve follows the escrow: it goes to the carrier when the shipment
+/// was paid out (`to_carrier`) and back to the sender otherwise. A dispute the
+/// carrier lost instead leaves it for `claim_shipment_insurance`. Callers must
+/// hold the reentrancy lock.
+fn settle_insurance_reserve(
+    env: &Env,
+    shipment: &Shipment,
+    to_carrier: bool,
+) -> Result<(), NavinError> {
+    let reserve = storage::get_insurance_reserve(env, shipment.id);
+    if reserve <= 0 {
+        return Ok(());
     }
+    storage::remove_insurance_reserve(env, shipment.id);
 
-    pub fn rollback_on_external_failure(
-        env: Env,
-        admin: Address,
-        shipment_id: u64,
-        previous_status: ShipmentStatus,
-        reason_hash: BytesN<32>,
-    ) -> Result<(), NavinError> {
-        recovery::rollback_on_external_failure(
-            &env,
-            &admin,
+    let recipient = if to_carrier {
+        &shipment.carrier
+    } else {
+        &shipment.sender
+    };
+    let token_contract = storage::get_token_contract(env).ok_or(NavinError::NotInitialized)?;
+    invoke_token_transfer(
+        env,
+        &token_contract,
+        &env.current_contract_address(),
+        recipient,
+        reserve,
+    )?;
+
+    if to_carrier {
+        storage::record_escrow_released(env, reserve);
+        events::emit_escrow_released(env, shipment.id, recipient, reserve);
+    } else {
+        storage::record_escrow_refunded(env, reserve);
+        events::emit_escrow_refunded(env, shipment.id, recipient, reserve);
+    }
+    Ok(())
+}
+
+/// Pay out a shipment's dispute bond, if one was posted.
+///
+/// The carrier wins when `carrier_won`; the sender and receiver win otherwise.
+/// A winning disputer gets the bond back, while a losing disputer forfeits it
+/// to the winning party (the carrier or the sender).
+fn settle_dispute_bond(
+    env: &Env,
+    shipment: &Shipment,
+    carrier_won: bool,
+) -> Result<(), NavinError> {
+    let (disputer, bond) = match storage::get_dispute_bond(env, shipment.id) {
+        Some(entry) => entry,
+        None => return Ok(()),
+    };
+    let disputer_won = (disputer == shipment.carrier) == carrier_won;
+    let recipient = if disputer_won {
+        disputer
+    } else if carrier_won {
+        shipment.carrier.clone()
+    } else {
+        shipment.sender.clone()
+    };
+
+    storage::remove_dispute_bond(env, shipment.id);
+    let token_contract = storage::get_token_contract(env).ok_or(NavinError::NotInitialized)?;
+    invoke_token_transfer(
+        env,
+        &token_contract,
+        &env.current_contract_address(),
+        &recipient,
+        bond,
+    )?;
+
+    events::emit_dispute_bond_settled(env, shipment.id, &recipient, bond, disputer_won);
+    Ok(())
+}
+
+/// Carrier share, in basis points, implied by a full dispute resolution.
+fn resolution_carrier_bps(resolution: &DisputeResolution) -> u32 {
+    match resolution {
+        DisputeResolution::ReleaseToCarrier => 10_000,
+        DisputeResolution::RefundToCompany => 0,
+    }
+}
+
+/// Settle a disputed shipment, paying `carrier_bps` of the escrow to the
+/// carrier and refunding the remainder to the sender.
+///
+/// Shared by `resolve_dispute`, `resolve_dispute_split`, `auto_resolve_dispute`
+/// and the multisig `AdminAction::ResolveDispute` path; callers perform their
+/// own authorization, bps validation and idempotency checks. The shipment ends
+/// `Delivered` when the carrier receives more than half of the escrow and
+/// `Cancelled` otherwise, in which case the carrier is charged with the loss.
+/// Full settlements (0 or 10 000 bps) emit `dispute_resolved`; partial ones
+/// emit `dispute_split_resolved`.
+fn settle_dispute(
+    env: &Env,
+    resolver: &Address,
+    shipment_id: u64,
+    carrier_bps: u32,
+    reason_hash: &BytesN<32>,
+) -> Result<(), NavinError> {
+    let mut shipment =
+        storage::get_shipment(env, shipment_id).ok_or(NavinError::ShipmentNotFound)?;
+
+    require_not_finalized(&shipment)?;
+
+    if shipment.status != ShipmentStatus::Disputed {
+        return Err(NavinError::InvalidStatus);
+    }
+
+    let escrow_amount = shipment.escrow_amount;
+    if escrow_amount == 0 {
+        return Err(NavinError::InsufficientFunds);
+    }
+
+    let carrier_amount = checked_mul_div_i128(escrow_amount, carrier_bps as i128, 10_000)?;
+    let company_amount = checked_sub_i128(escrow_amount, carrier_amount)?;
+    let carrier_won = carrier_bps > 5_000;
+    storage::remove_disputed_escrow(env, escrow_amount)?;
+
+    shipment.escrow_amount = 0;
+    shipment.status = if carrier_won {
+        ShipmentStatus::Delivered
+    } else {
+        ShipmentStatus::Cancelled
+    };
+    shipment.updated_at = env.ledger().timestamp();
+    shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
+
+    let token_contract = storage::get_token_contract(env).ok_or(NavinError::NotInitialized)?;
+    let contract_address = env.current_contract_address();
+
+    // Each non-empty leg gets its own settlement record and transfer.
+    for (operation, recipient, amount) in [
+        (
+            SettlementOperation::Release,
+            &shipment.carrier,
+            carrier_amount,
+        ),
+        (
+            SettlementOperation::Refund,
+            &shipment.sender,
+            company_amount,
+        ),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        let settlement_id = create_settlement(
+            env,
             shipment_id,
-            previous_status,
-            &reason_hash,
-        )
+            operation,
+            amount,
+            &contract_address,
+            recipient,
+        )?;
+        invoke_token_transfer(env, &token_contract, &contract_address, recipient, amount)?;
+        complete_settlement(env, settlement_id, shipment_id)?;
     }
+
+    storage::decrement_status_count(env, &ShipmentStatus::Disputed);
+    storage::increment_status_count(env, &shipment.status);
+    storage::push_status_history(env, shipment_id, &shipment.status, resolver);
+    storage::decrement_active_shipment_count(env, &shipment.sender);
+
+    finalize_if_settled(env, &mut shipment);
+    persist_shipment(env, &shipment)?;
+    storage::remove_escrow_balance(env, shipment_id);
+    storage::clear_dispute_escalation(env, shipment_id);
+    storage::set_resolution_note(env, shipment_id, reason_hash);
+    extend_shipment_ttl(env, shipment_id);
+
+    if carrier_amount > 0 {
+        storage::record_escrow_released(env, carrier_amount);
+        events::emit_escrow_released(env, shipment_id, &shipment.carrier, carrier_amount);
+    }
+    if company_amount > 0 {
+        storage::record_escrow_refunded(env, company_amount);
+        events::emit_escrow_refunded(env, shipment_id, &shipment.sender, company_amount);
+    }
+    if carrier_won {
+        settle_insurance_reserve(env, &shipment, true)?;
+    } else {
+        // Reputation: carrier lost this dispute
+        storage::increment_carrier_dispute_losses(env, &shipment.carrier);
+        events::emit_carrier_dispute_loss(env, &shipment.carrier, shipment_id);
+        if storage::get_insurance_reserve(env, shipment_id) > 0 {
+            storage::set_insurance_claimable(env, shipment_id);
+        }
+    }
+
+    settle_dispute_bond(env, &shipment, carrier_won)?;
+
+    // Emit specialized resolution event with context
+    match carrier_bps {
+        10_000 => events::emit_dispute_resolved(
+            env,
+            shipment_id,
+            &DisputeResolution::ReleaseToCarrier,
+            reason_hash,
+            resolver,
+        ),
+        0 => events::emit_dispute_resolved(
+            env,
+            shipment_id,
+            &DisputeResolution::RefundToCompany,
+            reason_hash,
+            resolver,
+        ),
+        _ => events::emit_dispute_split_resolved(
+            env,
+            shipment_id,
+            carrier_bps,
+            carrier_amount,
+            company_amount,
+            resolver,
+        ),
+    }
+
+    events::emit_notification(
+        env,
+        &shipment.sender,
+        NotificationType::DisputeResolved,
+        shipment_id,
+        reason_hash,
+    );
+    notify_extra_recipients(
+        env,
+        &shipment.sender,
+        NotificationType::DisputeResolved,
+        shipment_id,
+        reason_hash,
+    );
+    events::emit_notification(
+        env,
+        &shipment.receiver,
+        NotificationType::DisputeResolved,
+        shipment_id,
+        reason_hash,
+    );
+    events::emit_notification(
+        env,
+        &shipment.carrier,
+        NotificationType::DisputeResolved,
+        shipment_id,
+        reason_hash,
+    );
+
+    Ok(())
 }
 
 /// Validates whether a version transition is permitted.
@@ -101,6 +101,7 @@ pub fn recover_shipment(
 
     // Persist updated shipment
     storage::set_shipment(env, &shipment);
+    storage::push_status_history(env, shipment_id, &shipment.status, admin);
     crate::extend_shipment_ttl(env, shipment_id);
 
     // Emit recovery event
@@ -167,6 +168,8 @@ pub fn unlock_escrow(
 
     // Persist updated shipment
     storage::set_shipment(env, &shipment);
+    // Unlocked escrow is returned to the company's control off-chain.
+    storage::record_escrow_refunded(env, old_escrow);
     crate::extend_shipment_ttl(env, shipment_id);
 
     // Emit unlock event
@@ -319,6 +322,7 @@ pub fn rollback_on_external_failure(
     shipment.integration_nonce = shipment.integration_nonce.saturating_add(1);
 
     storage::set_shipment(env, &shipment);
+    storage::push_status_history(env, shipment_id, &shipment.status, admin);
     crate::extend_shipment_ttl(env, shipment_id);
 
     events::emit_recovery_event(
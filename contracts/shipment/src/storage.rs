@@ -1,5 +1,5 @@
 use crate::{errors::NavinError, types::*};
-use soroban_sdk::{Address, BytesN, Env};
+use soroban_sdk::{Address, BytesN, Env, Symbol};
 
 /// Check if the contract has been initialized (admin set).
 ///
@@ -236,6 +236,10 @@ pub fn increment_shipment_count(env: &Env) -> u64 {
 /// // storage::add_carrier_to_whitelist(&env, &company_addr, &carrier_addr);
 /// ```
 pub fn add_carrier_to_whitelist(env: &Env, company: &Address, carrier: &Address) {
+    if !is_carrier_whitelisted(env, company, carrier) {
+        let size = get_whitelist_size(env, company).saturating_add(1);
+        set_whitelist_size(env, company, size);
+    }
     let key = DataKey::CarrierWhitelist(company.clone(), carrier.clone());
     env.storage().instance().set(&key, &true);
 }
@@ -255,10 +259,29 @@ pub fn add_carrier_to_whitelist(env: &Env, company: &Address, carrier: &Address)
 /// // storage::remove_carrier_from_whitelist(&env, &company_addr, &carrier_addr);
 /// ```
 pub fn remove_carrier_from_whitelist(env: &Env, company: &Address, carrier: &Address) {
+    if is_carrier_whitelisted(env, company, carrier) {
+        let size = get_whitelist_size(env, company).saturating_sub(1);
+        set_whitelist_size(env, company, size);
+    }
     let key = DataKey::CarrierWhitelist(company.clone(), carrier.clone());
     env.storage().instance().remove(&key);
 }
 
+/// Get the number of carriers on a company's whitelist.
+pub fn get_whitelist_size(env: &Env, company: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::WhitelistSize(company.clone()))
+        .unwrap_or(0)
+}
+
+/// Set the number of carriers on a company's whitelist.
+fn set_whitelist_size(env: &Env, company: &Address, size: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::WhitelistSize(company.clone()), &size);
+}
+
 /// Check whether a carrier is whitelisted for a given company.
 ///
 /// # Arguments
@@ -278,10 +301,107 @@ pub fn is_carrier_whitelisted(env: &Env, company: &Address, carrier: &Address) -
     env.storage().instance().get(&key).unwrap_or(false)
 }
 
+/// Add a carrier to a receiver's whitelist in persistent storage.
+///
+/// # Arguments
+/// * `env` - The execution environment.
+/// * `receiver` - The receiver's address.
+/// * `carrier` - The carrier's address.
+///
+/// # Returns
+/// No return value.
+///
+/// # Examples
+/// ```rust
+/// // storage::add_receiver_whitelisted_carrier(&env, &receiver, &carrier);
+/// ```
+pub fn add_receiver_whitelisted_carrier(env: &Env, receiver: &Address, carrier: &Address) {
+    let key = DataKey::ReceiverCarrierWhitelist(receiver.clone(), carrier.clone());
+    env.storage().persistent().set(&key, &true);
+}
+
+/// Remove a carrier from a receiver's whitelist in persistent storage.
+///
+/// # Arguments
+/// * `env` - The execution environment.
+/// * `receiver` - The receiver's address.
+/// * `carrier` - The carrier's address.
+///
+/// # Returns
+/// No return value.
+///
+/// # Examples
+/// ```rust
+/// // storage::remove_receiver_whitelisted_carrier(&env, &receiver, &carrier);
+/// ```
+pub fn remove_receiver_whitelisted_carrier(env: &Env, receiver: &Address, carrier: &Address) {
+    let key = DataKey::ReceiverCarrierWhitelist(receiver.clone(), carrier.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Check whether a carrier is whitelisted by a given receiver.
+///
+/// # Arguments
+/// * `env` - The execution environment.
+/// * `receiver` - The receiver's address.
+/// * `carrier` - The carrier's address.
+///
+/// # Returns
+/// * `bool` - True if the receiver has approved the carrier.
+///
+/// # Examples
+/// ```rust
+/// // let approved = storage::is_receiver_whitelisted(&env, &receiver, &carrier);
+/// ```
+pub fn is_receiver_whitelisted(env: &Env, receiver: &Address, carrier: &Address) -> bool {
+    let key = DataKey::ReceiverCarrierWhitelist(receiver.clone(), carrier.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Mark a receiver address as registered in instance storage.
+///
+/// # Arguments
+/// * `env` - The execution environment.
+/// * `receiver` - The receiver's address.
+///
+/// # Returns
+/// No return value.
+///
+/// # Examples
+/// ```rust
+/// // storage::add_receiver(&env, &receiver);
+/// ```
+pub fn add_receiver(env: &Env, receiver: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RegisteredReceiver(receiver.clone()), &true);
+}
+
+/// Check whether a receiver address is registered.
+///
+/// # Arguments
+/// * `env` - The execution environment.
+/// * `receiver` - The receiver's address.
+///
+/// # Returns
+/// * `bool` - True if the receiver has been registered.
+///
+/// # Examples
+/// ```rust
+/// // let registered = storage::is_receiver(&env, &receiver);
+/// ```
+pub fn is_receiver(env: &Env, receiver: &Address) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RegisteredReceiver(receiver.clone()))
+        .unwrap_or(false)
+}
+
 /// Assign a role to an address in instance storage.
 ///
 /// Supports multiple roles per address via `UserRole(address, role)` keys
 /// and also sets the legacy `Role(address)` key for backward compatibility.
+/// Company and Carrier grants bump the matching role counter.
 ///
 /// # Arguments
 /// * `env` - The execution environment.
@@ -293,6 +413,9 @@ pub fn is_carrier_whitelisted(env: &Env, company: &Address, carrier: &Address) -
 /// // storage::set_role(&env, &user_addr, &Role::Company);
 /// ```
 pub fn set_role(env: &Env, address: &Address, role: &Role) {
+    if !has_role(env, address, role) {
+        adjust_role_count(env, role, true);
+    }
     let key = DataKey::UserRole(address.clone(), role.clone());
     env.storage().instance().set(&key, &true);
     // also set legacy single-role slot for compatibility for the primary role
@@ -372,7 +495,8 @@ pub fn set_carrier_role(env: &Env, carrier: &Address) {
 /// Revoke a role from an address in instance storage.
 ///
 /// Removes the `UserRole(address, role)` key and resets the legacy
-/// `Role(address)` key to `Unassigned`.
+/// `Role(address)` key to `Unassigned`. Company and Carrier revocations
+/// decrement the matching role counter.
 ///
 /// # Arguments
 /// * `env` - The execution environment.
@@ -384,6 +508,9 @@ pub fn set_carrier_role(env: &Env, carrier: &Address) {
 /// // storage::revoke_role(&env, &user_addr, &Role::Company);
 /// ```
 pub fn revoke_role(env: &Env, address: &Address, role: &Role) {
+    if has_role(env, address, role) {
+        adjust_role_count(env, role, false);
+    }
     let key = DataKey::UserRole(address.clone(), role.clone());
     env.storage().instance().remove(&key);
     // Reset legacy single-role slot to Unassigned
@@ -1965,116 +2092,1069 @@ pub fn get_proposal_digest(
         .get(&DataKey::ProposalDigest(proposal_id))
 }
 
-#[cfg(test)]
-#[allow(clippy::items_after_test_module)]
-mod tests {
-    use super::*;
-    use crate::test_utils;
-    use soroban_sdk::testutils::Address as _;
+// ============= Settlement State Storage Functions =============
 
-    fn with_contract_env() -> (Env, Address) {
-        let (env, _) = test_utils::setup_env();
-        let contract_id = env.register(crate::NavinShipment, ());
-        (env, contract_id)
-    }
+// ============= Notification Inbox Storage Functions =============
 
-    #[test]
-    fn carrier_whitelist_tuple_key_round_trip_and_order_regression() {
-        let (env, contract_id) = with_contract_env();
-        let company = Address::generate(&env);
-        let carrier = Address::generate(&env);
+/// Returns true if the address has opted into the on-chain notification inbox.
+pub fn is_inbox_enabled(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InboxOptIn(address.clone()))
+        .unwrap_or(false)
+}
 
-        env.as_contract(&contract_id, || {
-            add_carrier_to_whitelist(&env, &company, &carrier);
+/// Opt an address into or out of the on-chain notification inbox.
+pub fn set_inbox_enabled(env: &Env, address: &Address, enabled: bool) {
+    let key = DataKey::InboxOptIn(address.clone());
+    if enabled {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
 
-            assert!(is_carrier_whitelisted(&env, &company, &carrier));
-            assert!(!is_carrier_whitelisted(&env, &carrier, &company));
+/// Get the notification inbox for an address (oldest first).
+pub fn get_inbox(env: &Env, address: &Address) -> soroban_sdk::Vec<(NotificationType, u64, u64)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Inbox(address.clone()))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
 
-            let canonical_key = DataKey::CarrierWhitelist(company.clone(), carrier.clone());
-            let reversed_key = DataKey::CarrierWhitelist(carrier.clone(), company.clone());
-            assert!(env.storage().instance().has(&canonical_key));
-            assert!(!env.storage().instance().has(&reversed_key));
-        });
+/// Append a notification to an address's inbox, dropping the oldest entry
+/// once `MAX_INBOX_ENTRIES` is reached.
+pub fn push_inbox_entry(env: &Env, address: &Address, entry: (NotificationType, u64, u64)) {
+    let mut inbox = get_inbox(env, address);
+    while inbox.len() >= MAX_INBOX_ENTRIES {
+        inbox.pop_front();
     }
+    inbox.push_back(entry);
+    env.storage()
+        .persistent()
+        .set(&DataKey::Inbox(address.clone()), &inbox);
+}
 
-    #[test]
-    fn user_role_tuple_key_round_trip() {
-        let (env, contract_id) = with_contract_env();
-        let user = Address::generate(&env);
+/// Remove every entry from an address's notification inbox.
+pub fn clear_inbox(env: &Env, address: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Inbox(address.clone()));
+}
 
-        env.as_contract(&contract_id, || {
-            set_role(&env, &user, &Role::Carrier);
+// ============= Dispute Escalation Storage Functions =============
 
-            assert!(has_role(&env, &user, &Role::Carrier));
-            assert!(!has_role(&env, &user, &Role::Company));
-        });
-    }
+/// Returns true if the shipment's dispute has been escalated to the multisig.
+pub fn is_dispute_escalated(env: &Env, shipment_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeEscalated(shipment_id))
+        .unwrap_or(false)
+}
 
-    #[test]
-    fn role_suspended_tuple_key_round_trip() {
-        let (env, contract_id) = with_contract_env();
-        let user = Address::generate(&env);
+/// Mark a shipment's dispute as escalated.
+pub fn set_dispute_escalated(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisputeEscalated(shipment_id), &true);
+}
 
-        env.as_contract(&contract_id, || {
-            suspend_role(&env, &user, &Role::Company);
-            assert!(is_role_suspended(&env, &user, &Role::Company));
+/// Clear the escalation flag once the dispute is settled.
+pub fn clear_dispute_escalation(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DisputeEscalated(shipment_id));
+}
 
-            reactivate_role(&env, &user, &Role::Company);
-            assert!(!is_role_suspended(&env, &user, &Role::Company));
-        });
-    }
+// ============= Distance Storage Functions =============
 
-    #[test]
-    fn shipment_note_tuple_key_round_trip_and_component_regression() {
-        let (env, contract_id) = with_contract_env();
-        let shipment_id = 77_u64;
-        let note_idx_0 = 0_u32;
-        let note_idx_1 = 1_u32;
-        let note_0 = BytesN::from_array(&env, &[0x11; 32]);
-        let note_1 = BytesN::from_array(&env, &[0x22; 32]);
+/// Get the accumulated distance in meters recorded for a shipment.
+pub fn get_distance(env: &Env, shipment_id: u64) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Distance(shipment_id))
+        .unwrap_or(0)
+}
 
-        env.as_contract(&contract_id, || {
-            set_note_hash(&env, shipment_id, note_idx_0, &note_0);
-            set_note_hash(&env, shipment_id, note_idx_1, &note_1);
+/// Set the accumulated distance in meters recorded for a shipment.
+pub fn set_distance(env: &Env, shipment_id: u64, meters: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Distance(shipment_id), &meters);
+}
 
-            assert_eq!(get_note_hash(&env, shipment_id, note_idx_0), Some(note_0));
-            assert_eq!(get_note_hash(&env, shipment_id, note_idx_1), Some(note_1));
-            assert_eq!(get_note_hash(&env, shipment_id + 1, note_idx_0), None);
-            assert_eq!(get_note_hash(&env, shipment_id, note_idx_1 + 1), None);
-        });
+// ============= Status History Storage Functions =============
+
+/// Get the recorded `(status, timestamp, actor)` transitions for a shipment,
+/// oldest first.
+pub fn get_status_history(
+    env: &Env,
+    shipment_id: u64,
+) -> soroban_sdk::Vec<(ShipmentStatus, u64, Address)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::StatusHistory(shipment_id))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Append a status transition to a shipment's history, dropping the oldest
+/// entry once `MAX_STATUS_HISTORY` is reached.
+pub fn push_status_history(env: &Env, shipment_id: u64, status: &ShipmentStatus, actor: &Address) {
+    let mut history = get_status_history(env, shipment_id);
+    while history.len() >= MAX_STATUS_HISTORY {
+        history.pop_front();
     }
+    history.push_back((status.clone(), env.ledger().timestamp(), actor.clone()));
+    env.storage()
+        .persistent()
+        .set(&DataKey::StatusHistory(shipment_id), &history);
+}
 
-    #[test]
-    fn dispute_evidence_tuple_key_round_trip_and_component_regression() {
-        let (env, contract_id) = with_contract_env();
-        let shipment_id = 900_u64;
-        let evidence_idx_0 = 0_u32;
-        let evidence_idx_1 = 1_u32;
-        let evidence_0 = BytesN::from_array(&env, &[0x33; 32]);
-        let evidence_1 = BytesN::from_array(&env, &[0x44; 32]);
+// ============= Carrier Deadline Miss Storage Functions =============
 
-        env.as_contract(&contract_id, || {
-            set_evidence_hash(&env, shipment_id, evidence_idx_0, &evidence_0);
-            set_evidence_hash(&env, shipment_id, evidence_idx_1, &evidence_1);
+/// Get how many carrier-held shipments have expired past their deadline.
+pub fn get_carrier_deadline_misses(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierDeadlineMisses(carrier.clone()))
+        .unwrap_or(0)
+}
 
-            assert_eq!(
-                get_evidence_hash(&env, shipment_id, evidence_idx_0),
-                Some(evidence_0)
-            );
-            assert_eq!(
-                get_evidence_hash(&env, shipment_id, evidence_idx_1),
-                Some(evidence_1)
-            );
-            assert_eq!(
-                get_evidence_hash(&env, shipment_id + 1, evidence_idx_0),
-                None
-            );
-            assert_eq!(
-                get_evidence_hash(&env, shipment_id, evidence_idx_1 + 1),
-                None
-            );
-        });
-    }
+/// Increment a carrier's deadline-miss counter.
+pub fn increment_carrier_deadline_misses(env: &Env, carrier: &Address) {
+    let count = get_carrier_deadline_misses(env, carrier).saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierDeadlineMisses(carrier.clone()), &count);
 }
 
-// ============= Settlement State Storage Functions =============
+// ============= Role Change Cooldown Storage Functions =============
+
+/// Get the ledger timestamp of the last role change for an address, if any.
+pub fn get_role_changed_at(env: &Env, address: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RoleChangedAt(address.clone()))
+}
+
+/// Record the ledger timestamp of a role change for an address.
+pub fn set_role_changed_at(env: &Env, address: &Address, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RoleChangedAt(address.clone()), &timestamp);
+}
+
+// ============= Company Shipment Index Storage Functions =============
+
+/// Get the ids of every shipment indexed for a company.
+pub fn get_company_shipments(env: &Env, company: &Address) -> soroban_sdk::Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CompanyShipments(company.clone()))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Append a shipment id to a company's shipment index.
+pub fn push_company_shipment(env: &Env, company: &Address, shipment_id: u64) {
+    let mut ids = get_company_shipments(env, company);
+    ids.push_back(shipment_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CompanyShipments(company.clone()), &ids);
+}
+
+// ============= Escrow Breakdown Storage Functions =============
+
+/// Get the escrow currently locked across all shipments.
+pub fn get_total_escrow_locked(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalEscrowLocked)
+        .unwrap_or(0)
+}
+
+/// Get the cumulative escrow released out of the contract.
+pub fn get_total_escrow_released(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalEscrowReleased)
+        .unwrap_or(0)
+}
+
+/// Get the cumulative escrow refunded to companies.
+pub fn get_total_escrow_refunded(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalEscrowRefunded)
+        .unwrap_or(0)
+}
+
+/// Record newly locked escrow.
+pub fn record_escrow_locked(env: &Env, amount: i128) {
+    let locked = get_total_escrow_locked(env).saturating_add(amount);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalEscrowLocked, &locked);
+}
+
+/// Move `amount` from the locked total to the released total.
+pub fn record_escrow_released(env: &Env, amount: i128) {
+    let locked = get_total_escrow_locked(env).saturating_sub(amount);
+    let released = get_total_escrow_released(env).saturating_add(amount);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalEscrowLocked, &locked);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalEscrowReleased, &released);
+}
+
+/// Move `amount` from the locked total to the refunded total.
+pub fn record_escrow_refunded(env: &Env, amount: i128) {
+    let locked = get_total_escrow_locked(env).saturating_sub(amount);
+    let refunded = get_total_escrow_refunded(env).saturating_add(amount);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalEscrowLocked, &locked);
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalEscrowRefunded, &refunded);
+}
+
+// ============= Carrier Breach Penalty Storage Functions =============
+
+/// Get a carrier's accumulated weighted breach penalty.
+pub fn get_carrier_breach_penalty(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierBreachPenalty(carrier.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `weight` to a carrier's accumulated breach penalty.
+pub fn add_carrier_breach_penalty(env: &Env, carrier: &Address, weight: u32) {
+    let penalty = get_carrier_breach_penalty(env, carrier).saturating_add(weight);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierBreachPenalty(carrier.clone()), &penalty);
+}
+
+// ============= Dispute Bond Storage Functions =============
+
+/// Get the disputer and amount of a shipment's dispute bond, if any.
+pub fn get_dispute_bond(env: &Env, shipment_id: u64) -> Option<(Address, i128)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeBond(shipment_id))
+}
+
+/// Record the bond locked by the party that raised a dispute.
+pub fn set_dispute_bond(env: &Env, shipment_id: u64, disputer: &Address, amount: i128) {
+    let previous = get_dispute_bond(env, shipment_id).map_or(0, |(_, held)| held);
+    set_total_dispute_bonds(
+        env,
+        get_total_dispute_bonds(env)
+            .saturating_sub(previous)
+            .saturating_add(amount),
+    );
+    env.storage().persistent().set(
+        &DataKey::DisputeBond(shipment_id),
+        &(disputer.clone(), amount),
+    );
+}
+
+/// Remove a shipment's dispute bond once it has been settled.
+pub fn remove_dispute_bond(env: &Env, shipment_id: u64) {
+    if let Some((_, held)) = get_dispute_bond(env, shipment_id) {
+        set_total_dispute_bonds(env, get_total_dispute_bonds(env).saturating_sub(held));
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DisputeBond(shipment_id));
+}
+
+/// Get the sum of dispute bonds currently held by the contract.
+pub fn get_total_dispute_bonds(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalDisputeBonds)
+        .unwrap_or(0)
+}
+
+/// Set the total dispute bonds currently held by the contract.
+fn set_total_dispute_bonds(env: &Env, total: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalDisputeBonds, &total);
+}
+
+// ============= Checkpoint Acknowledgement Storage Functions =============
+
+/// Check whether checkpoint arrivals need the receiver's acknowledgement.
+pub fn is_checkpoint_ack_required(env: &Env, shipment_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CheckpointAckRequired(shipment_id))
+        .unwrap_or(false)
+}
+
+/// Enable or disable the receiver acknowledgement requirement.
+pub fn set_checkpoint_ack_required(env: &Env, shipment_id: u64, required: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CheckpointAckRequired(shipment_id), &required);
+}
+
+/// Get the checkpoint hash acknowledged by the receiver, if any.
+pub fn get_checkpoint_ack(env: &Env, shipment_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CheckpointAck(shipment_id))
+}
+
+/// Record the checkpoint hash acknowledged by the receiver.
+pub fn set_checkpoint_ack(env: &Env, shipment_id: u64, ack_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CheckpointAck(shipment_id), ack_hash);
+}
+
+/// Clear the receiver acknowledgement once consumed or superseded.
+pub fn remove_checkpoint_ack(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CheckpointAck(shipment_id));
+}
+
+// ============= Carrier Delivery Rate Storage Functions =============
+
+/// Get how many deliveries have been confirmed for a carrier.
+pub fn get_carrier_deliveries(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierDeliveries(carrier.clone()))
+        .unwrap_or(0)
+}
+
+/// Get how many of a carrier's deliveries were confirmed by the deadline.
+pub fn get_carrier_on_time_deliveries(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierOnTimeDeliveries(carrier.clone()))
+        .unwrap_or(0)
+}
+
+/// Record a confirmed delivery for a carrier, counting it as on time if
+/// `on_time` is set.
+pub fn record_carrier_delivery(env: &Env, carrier: &Address, on_time: bool) {
+    let total = get_carrier_deliveries(env, carrier).saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierDeliveries(carrier.clone()), &total);
+    if on_time {
+        let on_time_count = get_carrier_on_time_deliveries(env, carrier).saturating_add(1);
+        env.storage().persistent().set(
+            &DataKey::CarrierOnTimeDeliveries(carrier.clone()),
+            &on_time_count,
+        );
+    }
+}
+
+// ============= Dispute Spike Storage Functions =============
+
+/// Record a dispute at `now` and return how many disputes fall inside the
+/// rolling `window` (in seconds). Older timestamps are pruned, and at most
+/// `cap` entries are kept so the list stays bounded.
+pub fn record_recent_dispute(env: &Env, now: u64, window: u64, cap: u32) -> u32 {
+    let recent: soroban_sdk::Vec<u64> = env
+        .storage()
+        .instance()
+        .get(&DataKey::RecentDisputes)
+        .unwrap_or(soroban_sdk::Vec::new(env));
+    let mut kept = soroban_sdk::Vec::new(env);
+    for ts in recent.iter() {
+        if now.saturating_sub(ts) < window {
+            kept.push_back(ts);
+        }
+    }
+    kept.push_back(now);
+    while kept.len() > cap {
+        kept.pop_front();
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::RecentDisputes, &kept);
+    kept.len()
+}
+
+/// Check whether the contract was paused by dispute spike detection.
+pub fn is_auto_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AutoPaused)
+        .unwrap_or(false)
+}
+
+/// Set or clear the auto-pause marker.
+pub fn set_auto_paused(env: &Env, auto_paused: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AutoPaused, &auto_paused);
+}
+
+// ============= Signer Delegate Storage Functions =============
+
+/// Get the delegate authorized to approve proposals for `signer`, if any.
+pub fn get_signer_delegate(env: &Env, signer: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SignerDelegate(signer.clone()))
+}
+
+/// Authorize `delegate` to approve proposals on behalf of `signer`.
+pub fn set_signer_delegate(env: &Env, signer: &Address, delegate: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::SignerDelegate(signer.clone()), delegate);
+}
+
+/// Revoke `signer`'s delegate.
+pub fn remove_signer_delegate(env: &Env, signer: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::SignerDelegate(signer.clone()));
+}
+
+// ============= Notification Recipient Storage Functions =============
+
+/// Get the extra notification recipients registered by a company.
+pub fn get_notification_recipients(env: &Env, company: &Address) -> soroban_sdk::Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::NotificationRecipients(company.clone()))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Replace the extra notification recipients registered by a company.
+pub fn set_notification_recipients(
+    env: &Env,
+    company: &Address,
+    recipients: &soroban_sdk::Vec<Address>,
+) {
+    env.storage().persistent().set(
+        &DataKey::NotificationRecipients(company.clone()),
+        recipients,
+    );
+}
+
+// ============= Disputed Escrow Storage Functions =============
+
+/// Get the escrow currently held by disputed shipments.
+pub fn get_disputed_escrow_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DisputedEscrowTotal)
+        .unwrap_or(0)
+}
+
+/// Add a newly disputed shipment's escrow to the running total.
+pub fn add_disputed_escrow(env: &Env, amount: i128) -> Result<(), NavinError> {
+    let total = crate::checked_add_i128(get_disputed_escrow_total(env), amount)?;
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputedEscrowTotal, &total);
+    Ok(())
+}
+
+/// Remove a shipment's escrow from the running total once its dispute ends.
+/// Clamped at zero so disputes raised before tracking began cannot drive the
+/// total negative.
+pub fn remove_disputed_escrow(env: &Env, amount: i128) -> Result<(), NavinError> {
+    let total = crate::checked_sub_i128(get_disputed_escrow_total(env), amount)?.max(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::DisputedEscrowTotal, &total);
+    Ok(())
+}
+
+// ============= Release Delay Storage Functions =============
+
+/// Get when a delivered shipment's withheld escrow becomes releasable, if any.
+pub fn get_release_at(env: &Env, shipment_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReleaseAt(shipment_id))
+}
+
+/// Record when a delivered shipment's withheld escrow becomes releasable.
+pub fn set_release_at(env: &Env, shipment_id: u64, release_at: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ReleaseAt(shipment_id), &release_at);
+}
+
+/// Clear the pending release once the escrow has been paid out.
+pub fn remove_release_at(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ReleaseAt(shipment_id));
+}
+
+// ============= Resolution Note Storage Functions =============
+
+/// Get the rationale hash recorded when a shipment's dispute was resolved.
+pub fn get_resolution_note(env: &Env, shipment_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ResolutionNote(shipment_id))
+}
+
+/// Record the rationale hash for a shipment's dispute resolution.
+pub fn set_resolution_note(env: &Env, shipment_id: u64, note_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ResolutionNote(shipment_id), note_hash);
+}
+
+// ============= Report Rate Limit Storage Functions =============
+
+/// Get when a carrier report of `kind` was last accepted for a shipment.
+pub fn get_last_report(env: &Env, shipment_id: u64, kind: &Symbol) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastReport(shipment_id, kind.clone()))
+}
+
+/// Record when a carrier report of `kind` was accepted for a shipment.
+pub fn set_last_report(env: &Env, shipment_id: u64, kind: &Symbol, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastReport(shipment_id, kind.clone()), &timestamp);
+}
+
+// ============= Creation Hash Storage Functions =============
+
+/// Get the hash of the parameters a shipment was created with.
+pub fn get_creation_hash(env: &Env, shipment_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreationHash(shipment_id))
+}
+
+/// Record the hash of the parameters a shipment was created with.
+pub fn set_creation_hash(env: &Env, shipment_id: u64, hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreationHash(shipment_id), hash);
+}
+
+// ============= Insurance Reserve Storage Functions =============
+
+/// Get the insurance reserve held for a shipment.
+pub fn get_insurance_reserve(env: &Env, shipment_id: u64) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsuranceReserve(shipment_id))
+        .unwrap_or(0)
+}
+
+/// Set the insurance reserve held for a shipment.
+pub fn set_insurance_reserve(env: &Env, shipment_id: u64, amount: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsuranceReserve(shipment_id), &amount);
+}
+
+/// Remove a shipment's insurance reserve once it has been paid out.
+pub fn remove_insurance_reserve(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InsuranceReserve(shipment_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::InsuranceClaimable(shipment_id));
+}
+
+/// Check whether a shipment's insurance reserve may be claimed by its sender.
+pub fn is_insurance_claimable(env: &Env, shipment_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InsuranceClaimable(shipment_id))
+        .unwrap_or(false)
+}
+
+/// Mark a shipment's insurance reserve as claimable after the carrier lost a dispute.
+pub fn set_insurance_claimable(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::InsuranceClaimable(shipment_id), &true);
+}
+
+// ============= Handoff Cooldown Storage Functions =============
+
+/// Get the timestamp of a shipment's most recent carrier handoff.
+pub fn get_last_handoff_at(env: &Env, shipment_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastHandoffAt(shipment_id))
+}
+
+/// Record the timestamp of a shipment's carrier handoff.
+pub fn set_last_handoff_at(env: &Env, shipment_id: u64, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastHandoffAt(shipment_id), &timestamp);
+}
+
+// ============= Role Count Storage Functions =============
+
+/// Storage key of the counter for `role`, if that role is counted.
+fn role_count_key(role: &Role) -> Option<DataKey> {
+    match role {
+        Role::Company => Some(DataKey::CompanyCount),
+        Role::Carrier => Some(DataKey::CarrierCount),
+        _ => None,
+    }
+}
+
+/// Get the number of addresses currently holding `role`.
+/// Only Company and Carrier are counted; other roles return 0.
+pub fn get_role_count(env: &Env, role: &Role) -> u64 {
+    match role_count_key(role) {
+        Some(key) => env.storage().instance().get(&key).unwrap_or(0),
+        None => 0,
+    }
+}
+
+/// Increment or decrement the holder count for `role`, saturating at the bounds.
+fn adjust_role_count(env: &Env, role: &Role, granted: bool) {
+    if let Some(key) = role_count_key(role) {
+        let current: u64 = env.storage().instance().get(&key).unwrap_or(0);
+        let next = if granted {
+            current.saturating_add(1)
+        } else {
+            current.saturating_sub(1)
+        };
+        env.storage().instance().set(&key, &next);
+    }
+}
+
+// ============= Archive Record Storage Functions =============
+
+/// Get the compact record of an archived shipment.
+pub fn get_archive_record(env: &Env, shipment_id: u64) -> Option<ArchivedShipment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ShipmentArchiveRecord(shipment_id))
+}
+
+/// Replace a shipment's full record with its compact archive record.
+/// Removes the shipment from both persistent and temporary storage.
+pub fn compact_shipment(env: &Env, record: &ArchivedShipment) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ShipmentArchiveRecord(record.id), record);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Shipment(record.id));
+    env.storage()
+        .temporary()
+        .remove(&DataKey::ArchivedShipment(record.id));
+}
+
+// ============= Carrier Shipment Index Storage Functions =============
+
+/// Get the ids of every shipment currently assigned to a carrier.
+pub fn get_carrier_shipments(env: &Env, carrier: &Address) -> soroban_sdk::Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierShipments(carrier.clone()))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Append a shipment id to a carrier's shipment index.
+pub fn push_carrier_shipment(env: &Env, carrier: &Address, shipment_id: u64) {
+    let mut ids = get_carrier_shipments(env, carrier);
+    ids.push_back(shipment_id);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierShipments(carrier.clone()), &ids);
+}
+
+/// Remove a shipment id from a carrier's shipment index, if present.
+pub fn remove_carrier_shipment(env: &Env, carrier: &Address, shipment_id: u64) {
+    let mut ids = get_carrier_shipments(env, carrier);
+    if let Some(index) = ids.first_index_of(shipment_id) {
+        ids.remove(index);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CarrierShipments(carrier.clone()), &ids);
+    }
+}
+
+// ============= Carrier Public Key Storage Functions =============
+
+/// Get the ed25519 public key a carrier registered for milestone attestations.
+pub fn get_carrier_public_key(env: &Env, carrier: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierPublicKey(carrier.clone()))
+}
+
+/// Register or replace a carrier's ed25519 public key.
+pub fn set_carrier_public_key(env: &Env, carrier: &Address, public_key: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierPublicKey(carrier.clone()), public_key);
+}
+
+// ============= Carrier Assignment Acceptance Storage Functions =============
+
+/// Check whether the assigned carrier has accepted a shipment.
+pub fn is_assignment_accepted(env: &Env, shipment_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssignmentAccepted(shipment_id))
+        .unwrap_or(false)
+}
+
+/// Mark a shipment as accepted by its assigned carrier.
+pub fn set_assignment_accepted(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AssignmentAccepted(shipment_id), &true);
+}
+
+/// Clear a shipment's acceptance, e.g. after its carrier declines.
+pub fn remove_assignment_accepted(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::AssignmentAccepted(shipment_id));
+}
+
+// ============= Proof-of-Pickup Storage Functions =============
+
+/// Get the proof-of-pickup hash recorded for a shipment.
+pub fn get_pickup_hash(env: &Env, shipment_id: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PickupHash(shipment_id))
+}
+
+/// Store the proof-of-pickup hash for a shipment.
+pub fn set_pickup_hash(env: &Env, shipment_id: u64, hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::PickupHash(shipment_id), hash);
+}
+
+// ============= Checkpoint Dwell Storage Functions =============
+
+/// Get the timestamp at which a shipment last entered `AtCheckpoint`.
+pub fn get_checkpoint_entered_at(env: &Env, shipment_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CheckpointEnteredAt(shipment_id))
+}
+
+/// Record the timestamp at which a shipment entered `AtCheckpoint`.
+pub fn set_checkpoint_entered_at(env: &Env, shipment_id: u64, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::CheckpointEnteredAt(shipment_id), &timestamp);
+}
+
+/// Clear the checkpoint entry timestamp once the shipment moves on.
+pub fn remove_checkpoint_entered_at(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::CheckpointEnteredAt(shipment_id));
+}
+
+/// Get how many times a carrier has overstayed `max_checkpoint_dwell`.
+pub fn get_carrier_checkpoint_overstays(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierCheckpointOverstays(carrier.clone()))
+        .unwrap_or(0)
+}
+
+/// Increment a carrier's checkpoint-overstay counter.
+pub fn increment_carrier_checkpoint_overstays(env: &Env, carrier: &Address) {
+    let count = get_carrier_checkpoint_overstays(env, carrier).saturating_add(1);
+    env.storage().persistent().set(
+        &DataKey::CarrierCheckpointOverstays(carrier.clone()),
+        &count,
+    );
+}
+
+// ============= Carrier Reputation Storage Functions =============
+
+/// Get how many disputes have been resolved against a carrier.
+pub fn get_carrier_dispute_losses(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierDisputeLosses(carrier.clone()))
+        .unwrap_or(0)
+}
+
+/// Increment a carrier's dispute-loss counter.
+pub fn increment_carrier_dispute_losses(env: &Env, carrier: &Address) {
+    let count = get_carrier_dispute_losses(env, carrier).saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierDisputeLosses(carrier.clone()), &count);
+}
+
+/// Get how many condition breaches have been recorded against a carrier.
+pub fn get_carrier_breaches(env: &Env, carrier: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierBreaches(carrier.clone()))
+        .unwrap_or(0)
+}
+
+/// Increment a carrier's condition-breach counter.
+pub fn increment_carrier_breaches(env: &Env, carrier: &Address) {
+    let count = get_carrier_breaches(env, carrier).saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CarrierBreaches(carrier.clone()), &count);
+}
+
+// ============= Carrier Rating Storage Functions =============
+
+/// Check whether the receiver has already rated the carrier for a shipment.
+pub fn is_rated(env: &Env, shipment_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Rated(shipment_id))
+        .unwrap_or(false)
+}
+
+/// Mark a shipment as rated.
+pub fn set_rated(env: &Env, shipment_id: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Rated(shipment_id), &true);
+}
+
+/// Get a carrier's receiver ratings as `(count, sum of scores)`.
+pub fn get_carrier_ratings(env: &Env, carrier: &Address) -> (u32, u64) {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CarrierRatings(carrier.clone()))
+        .unwrap_or((0, 0))
+}
+
+/// Fold a new rating into a carrier's running totals.
+pub fn add_carrier_rating(env: &Env, carrier: &Address, score: u32) {
+    let (count, sum) = get_carrier_ratings(env, carrier);
+    env.storage().persistent().set(
+        &DataKey::CarrierRatings(carrier.clone()),
+        &(count.saturating_add(1), sum.saturating_add(score as u64)),
+    );
+}
+
+// ============= Dispute Window Storage Functions =============
+
+/// Get the timestamp at which a shipment's current dispute was raised.
+pub fn get_dispute_raised_at(env: &Env, shipment_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeRaisedAt(shipment_id))
+}
+
+/// Record the timestamp at which a shipment entered `Disputed`.
+pub fn set_dispute_raised_at(env: &Env, shipment_id: u64, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisputeRaisedAt(shipment_id), &timestamp);
+}
+
+// ============= Fixed Payment Milestone Storage Functions =============
+
+/// Get the absolute-amount payment milestones configured for a shipment.
+/// Returns an empty vector when the shipment uses percentage milestones.
+pub fn get_fixed_payment_milestones(
+    env: &Env,
+    shipment_id: u64,
+) -> soroban_sdk::Vec<(Symbol, i128)> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FixedPaymentMilestones(shipment_id))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Store the absolute-amount payment milestones for a shipment.
+pub fn set_fixed_payment_milestones(
+    env: &Env,
+    shipment_id: u64,
+    milestones: &soroban_sdk::Vec<(Symbol, i128)>,
+) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::FixedPaymentMilestones(shipment_id), milestones);
+}
+
+// ============= Dispute Count Storage Functions =============
+
+/// Get how many disputes have been raised on a shipment.
+pub fn get_dispute_count(env: &Env, shipment_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DisputeCount(shipment_id))
+        .unwrap_or(0)
+}
+
+/// Increment a shipment's dispute counter.
+pub fn increment_dispute_count(env: &Env, shipment_id: u64) {
+    let count = get_dispute_count(env, shipment_id).saturating_add(1);
+    env.storage()
+        .persistent()
+        .set(&DataKey::DisputeCount(shipment_id), &count);
+}
+
+// ============= Assignment Time Storage Functions =============
+
+/// Get when the current carrier was assigned through `reassign_carrier`, if ever.
+pub fn get_assigned_at(env: &Env, shipment_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AssignedAt(shipment_id))
+}
+
+/// Record when a new carrier was assigned to a shipment.
+pub fn set_assigned_at(env: &Env, shipment_id: u64, timestamp: u64) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AssignedAt(shipment_id), &timestamp);
+}
+
+// ============= Upgrade Allowlist Storage Functions =============
+
+/// Check whether a WASM hash has been approved as an upgrade target.
+pub fn is_wasm_hash_allowed(env: &Env, wasm_hash: &BytesN<32>) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AllowedWasmHash(wasm_hash.clone()))
+        .unwrap_or(false)
+}
+
+/// Approve a WASM hash as an upgrade target.
+pub fn allow_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::AllowedWasmHash(wasm_hash.clone()), &true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use soroban_sdk::testutils::Address as _;
+
+    fn with_contract_env() -> (Env, Address) {
+        let (env, _) = test_utils::setup_env();
+        let contract_id = env.register(crate::NavinShipment, ());
+        (env, contract_id)
+    }
+
+    #[test]
+    fn carrier_whitelist_tuple_key_round_trip_and_order_regression() {
+        let (env, contract_id) = with_contract_env();
+        let company = Address::generate(&env);
+        let carrier = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            add_carrier_to_whitelist(&env, &company, &carrier);
+
+            assert!(is_carrier_whitelisted(&env, &company, &carrier));
+            assert!(!is_carrier_whitelisted(&env, &carrier, &company));
+
+            let canonical_key = DataKey::CarrierWhitelist(company.clone(), carrier.clone());
+            let reversed_key = DataKey::CarrierWhitelist(carrier.clone(), company.clone());
+            assert!(env.storage().instance().has(&canonical_key));
+            assert!(!env.storage().instance().has(&reversed_key));
+        });
+    }
+
+    #[test]
+    fn user_role_tuple_key_round_trip() {
+        let (env, contract_id) = with_contract_env();
+        let user = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            set_role(&env, &user, &Role::Carrier);
+
+            assert!(has_role(&env, &user, &Role::Carrier));
+            assert!(!has_role(&env, &user, &Role::Company));
+        });
+    }
+
+    #[test]
+    fn role_suspended_tuple_key_round_trip() {
+        let (env, contract_id) = with_contract_env();
+        let user = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            suspend_role(&env, &user, &Role::Company);
+            assert!(is_role_suspended(&env, &user, &Role::Company));
+
+            reactivate_role(&env, &user, &Role::Company);
+            assert!(!is_role_suspended(&env, &user, &Role::Company));
+        });
+    }
+
+    #[test]
+    fn shipment_note_tuple_key_round_trip_and_component_regression() {
+        let (env, contract_id) = with_contract_env();
+        let shipment_id = 77_u64;
+        let note_idx_0 = 0_u32;
+        let note_idx_1 = 1_u32;
+        let note_0 = BytesN::from_array(&env, &[0x11; 32]);
+        let note_1 = BytesN::from_array(&env, &[0x22; 32]);
+
+        env.as_contract(&contract_id, || {
+            set_note_hash(&env, shipment_id, note_idx_0, &note_0);
+            set_note_hash(&env, shipment_id, note_idx_1, &note_1);
+
+            assert_eq!(get_note_hash(&env, shipment_id, note_idx_0), Some(note_0));
+            assert_eq!(get_note_hash(&env, shipment_id, note_idx_1), Some(note_1));
+            assert_eq!(get_note_hash(&env, shipment_id + 1, note_idx_0), None);
+            assert_eq!(get_note_hash(&env, shipment_id, note_idx_1 + 1), None);
+        });
+    }
+
+    #[test]
+    fn dispute_evidence_tuple_key_round_trip_and_component_regression() {
+        let (env, contract_id) = with_contract_env();
+        let shipment_id = 900_u64;
+        let evidence_idx_0 = 0_u32;
+        let evidence_idx_1 = 1_u32;
+        let evidence_0 = BytesN::from_array(&env, &[0x33; 32]);
+        let evidence_1 = BytesN::from_array(&env, &[0x44; 32]);
+
+        env.as_contract(&contract_id, || {
+            set_evidence_hash(&env, shipment_id, evidence_idx_0, &evidence_0);
+            set_evidence_hash(&env, shipment_id, evidence_idx_1, &evidence_1);
+
+            assert_eq!(
+                get_evidence_hash(&env, shipment_id, evidence_idx_0),
+                Some(evidence_0)
+            );
+            assert_eq!(
+                get_evidence_hash(&env, shipment_id, evidence_idx_1),
+                Some(evidence_1)
+            );
+            assert_eq!(
+                get_evidence_hash(&env, shipment_id + 1, evidence_idx_0),
+                None
+            );
+            assert_eq!(
+                get_evidence_hash(&env, shipment_id, evidence_idx_1 + 1),
+                None
+            );
+        });
+    }
+}
@@ -2456,7 +2456,7 @@ fn test_record_milestone_success() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")]
+#[should_panic(expected = "Error(Contract, #14)")]
 fn test_deposit_escrow_invalid_amount() {
     let (env, client, admin, token_contract) = setup_shipment_env();
     let company = Address::generate(&env);
@@ -2478,7 +2478,7 @@ fn test_deposit_escrow_invalid_amount() {
     );
     let invalid_escrow_amount: i128 = 0;
 
-    // Should panic with error code 8 for invalid amount
+    // Should panic with error code 14 for invalid amount
     client.deposit_escrow(&company, &shipment_id, &invalid_escrow_amount);
 }
 
@@ -3574,6 +3574,7 @@ fn test_upgrade_success() {
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
     client.initialize(&admin, &token_contract);
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
     assert_eq!(client.get_version(), 1);
 
     // Drain events emitted by initialize so we can assert only on upgrade events
@@ -3687,6 +3688,7 @@ fn test_get_version_after_upgrade() {
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
     client.initialize(&admin, &token_contract);
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
     assert_eq!(client.get_version(), 1);
 
     client.upgrade(&admin, &new_wasm_hash, &2);
@@ -3708,6 +3710,7 @@ fn test_get_contract_metadata_after_upgrade() {
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
     client.initialize(&admin, &token_contract);
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
 
     let meta_before = client.get_contract_metadata();
     assert_eq!(meta_before.version, 1);
@@ -4026,6 +4029,64 @@ fn test_handoff_nonexistent_shipment() {
     );
 }
 
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_handoff_to_receiver_rejected() {
+    let (env, client, admin, token_contract) = setup_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let current_carrier = Address::generate(&env);
+    let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let handoff_hash = BytesN::from_array(&env, &[2u8; 32]);
+    let deadline = env.ledger().timestamp() + 3600;
+
+    client.initialize(&admin, &token_contract);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &current_carrier);
+    // Receiver also holds the Carrier role, so only the role-collapse check applies.
+    client.add_carrier(&admin, &receiver);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &current_carrier,
+        &data_hash,
+        &soroban_sdk::Vec::new(&env),
+        &deadline,
+    );
+
+    client.handoff_shipment(&current_carrier, &receiver, &shipment_id, &handoff_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_handoff_to_sender_rejected() {
+    let (env, client, admin, token_contract) = setup_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let current_carrier = Address::generate(&env);
+    let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let handoff_hash = BytesN::from_array(&env, &[2u8; 32]);
+    let deadline = env.ledger().timestamp() + 3600;
+
+    client.initialize(&admin, &token_contract);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &current_carrier);
+    // Sender also holds the Carrier role, so only the role-collapse check applies.
+    client.add_carrier(&admin, &company);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &current_carrier,
+        &data_hash,
+        &soroban_sdk::Vec::new(&env),
+        &deadline,
+    );
+
+    client.handoff_shipment(&current_carrier, &company, &shipment_id, &handoff_hash);
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")]
 fn test_create_shipment_fails_before_initialization() {
@@ -4957,6 +5018,7 @@ fn test_execute_proposal_auto_on_threshold() {
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
     client.initialize(&admin, &token_contract);
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
 
     let admin1 = Address::generate(&env);
     let admin2 = Address::generate(&env);
@@ -5178,6 +5240,7 @@ fn test_three_of_five_multisig() {
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
     client.initialize(&admin, &token_contract);
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
 
     let admin1 = Address::generate(&env);
     let admin2 = Address::generate(&env);
@@ -5265,7 +5328,7 @@ fn test_check_deadline_success_auto_cancels_and_refunds() {
     client.add_company(&admin, &company);
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
 
     let shipment_id = client.create_shipment(
         &company,
@@ -5279,7 +5342,7 @@ fn test_check_deadline_success_auto_cancels_and_refunds() {
     client.deposit_escrow(&company, &shipment_id, &escrow_amount);
 
     // Advance ledger time past the deadline threshold
-    super::test_utils::advance_ledger_time(&env, 1001);
+    super::test_utils::advance_ledger_time(&env, 3601);
 
     // Execute the deadline checker
     client.check_deadline(&shipment_id);
@@ -5303,7 +5366,7 @@ fn test_check_deadline_fails_if_not_expired() {
     client.add_company(&admin, &company);
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
 
     let shipment_id = client.create_shipment(
         &company,
@@ -5333,7 +5396,7 @@ fn test_delivery_before_deadline() {
     client.add_carrier(&admin, &carrier);
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
 
     let shipment_id = client.create_shipment(
         &company,
@@ -5356,7 +5419,7 @@ fn test_delivery_before_deadline() {
     assert_eq!(shipment.status, ShipmentStatus::Delivered);
 
     // Fast-forward past the deadline point
-    super::test_utils::advance_ledger_time(&env, 1001);
+    super::test_utils::advance_ledger_time(&env, 3601);
 
     // Attempting to crank check_deadline on a safely completed shipment errors appropriately (Error 9)
     let res = client.try_check_deadline(&shipment_id);
@@ -7103,7 +7166,7 @@ fn test_create_shipment_returns_counter_overflow() {
 // ============= Error #14: InvalidAmount Tests =============
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")]
+#[should_panic(expected = "Error(Contract, #14)")]
 fn test_deposit_escrow_returns_invalid_amount_zero() {
     let (env, client, admin, token_contract) = setup_shipment_env();
     let company = Address::generate(&env);
@@ -7128,7 +7191,7 @@ fn test_deposit_escrow_returns_invalid_amount_zero() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")]
+#[should_panic(expected = "Error(Contract, #14)")]
 fn test_deposit_escrow_returns_invalid_amount_negative() {
     let (env, client, admin, token_contract) = setup_shipment_env();
     let company = Address::generate(&env);
@@ -9594,8 +9657,9 @@ fn test_get_shipment_reference_collision_free() {
 
 // ============= Deadline Grace Period Tests =============
 
-/// Helper: initialize the contract, register a company, and create a shipment with the given
-/// deadline. Returns the shipment ID.
+/// Helper: initialize the contract, register a company and carrier, create a shipment with the
+/// given deadline and move it into `InTransit` (grace only covers shipments in carrier custody).
+/// Returns the shipment ID.
 fn setup_shipment_with_deadline(
     env: &Env,
     client: &NavinShipmentClient,
@@ -9610,15 +9674,23 @@ fn setup_shipment_with_deadline(
 
     client.initialize(admin, token_contract);
     client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
 
-    client.create_shipment(
+    let shipment_id = client.create_shipment(
         &company,
         &receiver,
         &carrier,
         &data_hash,
         &soroban_sdk::Vec::new(env),
         &deadline,
-    )
+    );
+    client.update_status(
+        &carrier,
+        &shipment_id,
+        &crate::ShipmentStatus::InTransit,
+        &BytesN::from_array(env, &[43u8; 32]),
+    );
+    shipment_id
 }
 
 /// Within the grace window: deadline has passed but grace has not — must return NotExpired.
@@ -9628,7 +9700,7 @@ fn test_check_deadline_within_grace_period_returns_not_expired() {
     let (env, client, admin, token_contract) = setup_shipment_env();
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
     let grace = 300u64;
 
     let shipment_id =
@@ -9652,7 +9724,7 @@ fn test_check_deadline_at_grace_boundary_succeeds() {
     let (env, client, admin, token_contract) = setup_shipment_env();
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
     let grace = 300u64;
 
     let shipment_id =
@@ -9677,7 +9749,7 @@ fn test_check_deadline_after_grace_period_cancels_shipment() {
     let (env, client, admin, token_contract) = setup_shipment_env();
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
     let grace = 300u64;
 
     let shipment_id =
@@ -9703,7 +9775,7 @@ fn test_check_deadline_zero_grace_expires_immediately() {
     let (env, client, admin, token_contract) = setup_shipment_env();
 
     let now = env.ledger().timestamp();
-    let deadline = now + 1000;
+    let deadline = now + 3600;
 
     let shipment_id =
         setup_shipment_with_deadline(&env, &client, &admin, &token_contract, deadline);
@@ -0,0 +1,147 @@
+//! Tests for escrow auto-refund on declined or never-accepted assignments
+//! (`acceptance_timeout_seconds` and `expire_assignment`).
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, dummy_hash, future_deadline};
+use crate::{CarrierPolicy, ContractConfig, NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, Symbol, TryFromVal, Vec};
+
+const TIMEOUT: u64 = 3_600;
+
+fn funded_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    carrier: &Address,
+    acceptance_timeout_seconds: u64,
+) -> u64 {
+    client.update_config(
+        admin,
+        &ContractConfig {
+            carrier_policy: CarrierPolicy {
+                require_carrier_acceptance: true,
+                acceptance_timeout_seconds,
+                ..CarrierPolicy::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    client.deposit_escrow(&company, &id, &5_000);
+    id
+}
+
+fn emitted(env: &Env, topic: &str) -> bool {
+    let topic = Symbol::new(env, topic);
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(env, &t).ok())
+            .is_some_and(|t| t == topic)
+    })
+}
+
+#[test]
+fn test_decline_refunds_escrow_when_enabled() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = funded_shipment(&env, &client, &admin, &carrier, TIMEOUT);
+
+    client.decline_assignment(&carrier, &id);
+    assert!(emitted(&env, "escrow_refunded"));
+
+    let shipment = client.get_shipment(&id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.escrow_amount, 0);
+}
+
+#[test]
+fn test_decline_keeps_escrow_when_disabled() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = funded_shipment(&env, &client, &admin, &carrier, 0);
+
+    client.decline_assignment(&carrier, &id);
+
+    let shipment = client.get_shipment(&id);
+    assert_eq!(shipment.status, ShipmentStatus::Created);
+    assert_eq!(shipment.escrow_amount, 5_000);
+}
+
+#[test]
+fn test_expire_assignment_refunds_after_timeout() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = funded_shipment(&env, &client, &admin, &carrier, TIMEOUT);
+
+    assert_eq!(
+        client.try_expire_assignment(&id),
+        Err(Ok(NavinError::NotExpired))
+    );
+
+    advance_ledger_time(&env, TIMEOUT);
+    client.expire_assignment(&id);
+    assert!(emitted(&env, "escrow_refunded"));
+
+    let shipment = client.get_shipment(&id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.escrow_amount, 0);
+}
+
+#[test]
+fn test_expire_assignment_rejected_once_accepted() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = funded_shipment(&env, &client, &admin, &carrier, TIMEOUT);
+
+    client.accept_assignment(&carrier, &id);
+    advance_ledger_time(&env, TIMEOUT);
+
+    assert_eq!(
+        client.try_expire_assignment(&id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_expire_assignment_restarts_after_reassignment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = funded_shipment(&env, &client, &admin, &carrier, TIMEOUT);
+    let sender = client.get_shipment(&id).sender;
+
+    advance_ledger_time(&env, TIMEOUT - 1);
+    let new_carrier = Address::generate(&env);
+    client.add_carrier(&admin, &new_carrier);
+    client.reassign_carrier(&sender, &id, &new_carrier);
+
+    advance_ledger_time(&env, 1);
+    assert_eq!(
+        client.try_expire_assignment(&id),
+        Err(Ok(NavinError::NotExpired))
+    );
+}
+
+#[test]
+fn test_expire_assignment_disabled_by_default() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = funded_shipment(&env, &client, &admin, &carrier, 0);
+
+    advance_ledger_time(&env, TIMEOUT);
+    assert_eq!(
+        client.try_expire_assignment(&id),
+        Err(Ok(NavinError::NotExpired))
+    );
+}
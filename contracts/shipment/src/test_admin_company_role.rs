@@ -0,0 +1,46 @@
+//! Tests for revoking the outgoing admin's Company role on admin transfer.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient, Role};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn transfer_admin(revoke: bool) -> (Env, NavinShipmentClient<'static>, Address, Address) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+
+    if revoke {
+        let mut cfg = client.get_contract_config();
+        cfg.revoke_old_admin_company_role = true;
+        client.update_config(&admin, &cfg);
+    }
+
+    let new_admin = Address::generate(&env);
+    client.transfer_admin(&admin, &new_admin);
+    client.accept_admin_transfer(&new_admin);
+    (env, client, admin, new_admin)
+}
+
+#[test]
+fn test_old_admin_company_role_revoked_when_enabled() {
+    let (env, client, old_admin, new_admin) = transfer_admin(true);
+
+    assert_eq!(client.get_role(&old_admin), Role::Unassigned);
+    assert_eq!(client.get_role(&new_admin), Role::Company);
+
+    let result = client.try_create_shipment(
+        &old_admin,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_old_admin_keeps_company_role_by_default() {
+    let (_env, client, old_admin, _new_admin) = transfer_admin(false);
+
+    assert_eq!(client.get_role(&old_admin), Role::Company);
+}
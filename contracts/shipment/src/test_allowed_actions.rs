@@ -0,0 +1,333 @@
+//! Tests for the `allowed_actions` read API.
+
+use crate::test::*;
+use crate::test_utils::{advance_past_rate_limit, dummy_hash};
+use crate::types::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+fn created_shipment(
+    env: &Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+) -> (u64, Address, Address, Address) {
+    let company = Address::generate(env);
+    let receiver = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &(env.ledger().timestamp() + 3600),
+    );
+    (shipment_id, company, receiver, carrier)
+}
+
+fn in_transit_shipment(
+    env: &Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+) -> (u64, Address, Address, Address) {
+    let (shipment_id, company, receiver, carrier) = created_shipment(env, client, admin);
+    advance_past_rate_limit(env);
+    client.update_status(
+        &carrier,
+        &shipment_id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(env, &[2u8; 32]),
+    );
+    (shipment_id, company, receiver, carrier)
+}
+
+fn has(env: &Env, actions: &Vec<Symbol>, name: &str) -> bool {
+    actions.contains(Symbol::new(env, name))
+}
+
+fn disputed_shipment(
+    env: &Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+) -> (u64, Address, Address, Address) {
+    let (id, company, receiver, carrier) = in_transit_shipment(env, client, admin);
+    client.raise_dispute(&receiver, &id, &BytesN::from_array(env, &[3u8; 32]));
+    (id, company, receiver, carrier)
+}
+
+#[test]
+fn test_allowed_actions_for_carrier_in_transit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, _receiver, carrier) = in_transit_shipment(&env, &client, &admin);
+
+    let actions = client.allowed_actions(&id, &carrier);
+    assert!(has(&env, &actions, "update_status"));
+    assert!(has(&env, &actions, "record_milestone"));
+    assert!(has(&env, &actions, "handoff_shipment"));
+    assert!(has(&env, &actions, "raise_dispute"));
+    assert!(!has(&env, &actions, "confirm_delivery"));
+    assert!(!has(&env, &actions, "cancel_shipment"));
+}
+
+#[test]
+fn test_allowed_actions_for_receiver_in_transit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, receiver, _carrier) = in_transit_shipment(&env, &client, &admin);
+
+    let actions = client.allowed_actions(&id, &receiver);
+    assert!(has(&env, &actions, "confirm_delivery"));
+    assert!(has(&env, &actions, "raise_dispute"));
+    assert!(!has(&env, &actions, "update_status"));
+    assert!(!has(&env, &actions, "release_escrow"));
+}
+
+#[test]
+fn test_allowed_actions_for_outsider_is_empty() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, _receiver, _carrier) = in_transit_shipment(&env, &client, &admin);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(client.allowed_actions(&id, &outsider).len(), 0);
+}
+
+#[test]
+fn test_allowed_actions_empty_when_paused() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, _receiver, carrier) = in_transit_shipment(&env, &client, &admin);
+
+    client.pause(&admin);
+    assert_eq!(client.allowed_actions(&id, &carrier).len(), 0);
+}
+
+#[test]
+fn test_allowed_actions_accept_assignment_while_created() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, _receiver, carrier) = created_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "accept_assignment"
+    ));
+
+    let (id, _company, _receiver, carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "accept_assignment"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_decline_assignment_while_created() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, receiver, carrier) = created_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "decline_assignment"
+    ));
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "decline_assignment"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_record_pickup_while_created() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, _receiver, carrier) = created_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "record_pickup"
+    ));
+
+    let (id, _company, _receiver, carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "record_pickup"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_top_up_escrow_after_deposit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, company, _receiver, _carrier) = created_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "top_up_escrow"
+    ));
+
+    client.deposit_escrow(&company, &id, &1_000);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "top_up_escrow"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_cancel_with_compensation_for_sender() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, company, _receiver, _carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "cancel_with_compensation"
+    ));
+
+    let (id, company, _receiver, _carrier) = disputed_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "cancel_with_compensation"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_extend_deadline_for_sender() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, company, _receiver, carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "extend_deadline"
+    ));
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "extend_deadline"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_raise_dispute_with_bond_in_transit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, receiver, _carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "raise_dispute_with_bond"
+    ));
+
+    let (id, _company, receiver, _carrier) = disputed_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "raise_dispute_with_bond"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_escalate_dispute_until_escalated() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, receiver, carrier) = disputed_shipment(&env, &client, &admin);
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "escalate_dispute"
+    ));
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &carrier),
+        "escalate_dispute"
+    ));
+
+    client.escalate_dispute(&receiver, &id);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "escalate_dispute"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_resolve_dispute_split_for_arbiter() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, company, receiver, carrier) = created_shipment(&env, &client, &admin);
+    client.deposit_escrow(&company, &id, &1_000);
+    advance_past_rate_limit(&env);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+    client.raise_dispute(&receiver, &id, &BytesN::from_array(&env, &[3u8; 32]));
+
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &admin),
+        "resolve_dispute_split"
+    ));
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "resolve_dispute_split"
+    ));
+
+    // Escalated disputes can only be settled through the multisig.
+    client.escalate_dispute(&receiver, &id);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &admin),
+        "resolve_dispute_split"
+    ));
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &admin),
+        "resolve_dispute"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_rate_carrier_after_delivery() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _company, receiver, _carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "rate_carrier"
+    ));
+
+    client.confirm_delivery(&receiver, &id, &BytesN::from_array(&env, &[4u8; 32]));
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "rate_carrier"
+    ));
+
+    client.rate_carrier(&receiver, &id, &5);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &receiver),
+        "rate_carrier"
+    ));
+}
+
+#[test]
+fn test_allowed_actions_claim_shipment_insurance_when_claimable() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, company, _receiver, _carrier) = in_transit_shipment(&env, &client, &admin);
+    assert!(!has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "claim_shipment_insurance"
+    ));
+
+    env.as_contract(&client.address, || {
+        crate::storage::set_insurance_reserve(&env, id, 100);
+        crate::storage::set_insurance_claimable(&env, id);
+    });
+    assert!(has(
+        &env,
+        &client.allowed_actions(&id, &company),
+        "claim_shipment_insurance"
+    ));
+}
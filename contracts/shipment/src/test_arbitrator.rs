@@ -0,0 +1,146 @@
+//! Tests for the Arbitrator role.
+//!
+//! Arbitrators may resolve disputed shipments, either outright or by split,
+//! but hold no other admin privileges and lose access once removed.
+
+use crate::{
+    test_utils, DisputeResolution, NavinError, NavinShipment, NavinShipmentClient, Role,
+    ShipmentStatus,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{token::StellarAssetClient, Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    carrier: Address,
+    arbitrator: Address,
+    token: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let contract_id = env.register(NavinShipment, ());
+    let client = NavinShipmentClient::new(&env, &contract_id);
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let arbitrator = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    client.add_arbitrator(&admin, &arbitrator);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        carrier,
+        arbitrator,
+        token,
+    }
+}
+
+fn disputed_shipment(ctx: &Ctx, amount: i128) -> u64 {
+    StellarAssetClient::new(&ctx.env, &ctx.token).mint(&ctx.company, &amount);
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &Vec::new(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, 3600),
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &amount);
+    ctx.client
+        .raise_dispute(&ctx.company, &id, &BytesN::from_array(&ctx.env, &[9u8; 32]));
+    id
+}
+
+#[test]
+fn add_arbitrator_assigns_role() {
+    let ctx = setup();
+    assert_eq!(ctx.client.get_role(&ctx.arbitrator), Role::Arbitrator);
+}
+
+#[test]
+fn arbitrator_can_resolve_dispute() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 1_000);
+
+    ctx.client.resolve_dispute(
+        &ctx.arbitrator,
+        &id,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&ctx.env, &[7u8; 32]),
+    );
+
+    let shipment = ctx.client.get_shipment(&id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.escrow_amount, 0);
+}
+
+#[test]
+fn arbitrator_can_split_dispute() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 1_000);
+
+    ctx.client.resolve_dispute_split(
+        &ctx.arbitrator,
+        &id,
+        &6_000,
+        &BytesN::from_array(&ctx.env, &[7u8; 32]),
+    );
+
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 0);
+}
+
+#[test]
+fn arbitrator_has_no_admin_privileges() {
+    let ctx = setup();
+    let outsider = Address::generate(&ctx.env);
+
+    assert_eq!(
+        ctx.client.try_add_carrier(&ctx.arbitrator, &outsider),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(
+        ctx.client.try_add_arbitrator(&ctx.arbitrator, &outsider),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn removed_arbitrator_cannot_resolve() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 1_000);
+
+    ctx.client.remove_arbitrator(&ctx.admin, &ctx.arbitrator);
+
+    assert_eq!(ctx.client.get_role(&ctx.arbitrator), Role::Unassigned);
+    assert_eq!(
+        ctx.client.try_resolve_dispute(
+            &ctx.arbitrator,
+            &id,
+            &DisputeResolution::ReleaseToCarrier,
+            &BytesN::from_array(&ctx.env, &[7u8; 32]),
+        ),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn remove_arbitrator_rejects_non_arbitrator() {
+    let ctx = setup();
+
+    assert_eq!(
+        ctx.client.try_remove_arbitrator(&ctx.admin, &ctx.carrier),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
@@ -0,0 +1,141 @@
+//! Tests for permissionless compaction of settled shipments.
+
+use crate::test::*;
+use crate::{
+    test_utils, DisputeResolution, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const RETENTION: u64 = 2_592_000;
+
+fn setup_delivered() -> (Env, NavinShipmentClient<'static>, u64) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &data_hash,
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    test_utils::advance_past_rate_limit(&env);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &data_hash);
+    client.confirm_delivery(&receiver, &id, &data_hash);
+    (env, client, id)
+}
+
+#[test]
+fn test_archive_settled_shipment_keeps_compact_record() {
+    let (env, client, id) = setup_delivered();
+    let creation_hash = client.get_creation_hash(&id);
+
+    test_utils::advance_ledger_time(&env, RETENTION);
+    client.archive_settled_shipment(&id);
+
+    assert_eq!(
+        client.try_get_shipment(&id),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+
+    let record = client.get_archived_shipment(&id);
+    assert_eq!(record.id, id);
+    assert_eq!(record.status, ShipmentStatus::Delivered);
+    assert_eq!(record.final_escrow, 0);
+    assert_eq!(record.data_hash, BytesN::from_array(&env, &[1u8; 32]));
+    assert!(creation_hash.is_some());
+    assert_eq!(record.creation_hash, creation_hash);
+    assert_eq!(record.archived_at, env.ledger().timestamp());
+}
+
+#[test]
+fn test_archive_settled_shipment_respects_retention() {
+    let (env, client, id) = setup_delivered();
+
+    test_utils::advance_ledger_time(&env, RETENTION - 1);
+    assert_eq!(
+        client.try_archive_settled_shipment(&id),
+        Err(Ok(NavinError::ArchiveRetentionActive))
+    );
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Delivered);
+}
+
+#[test]
+fn test_archive_settled_shipment_requires_finalized() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+
+    test_utils::advance_ledger_time(&env, RETENTION);
+    assert_eq!(
+        client.try_archive_settled_shipment(&id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+    assert_eq!(
+        client.try_get_archived_shipment(&id),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
+
+#[test]
+fn test_archive_settled_shipment_refuses_unclaimed_insurance() {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let mut cfg = client.get_contract_config();
+    cfg.escrow_policy.insurance_bps = 500;
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &10_000);
+    client.deposit_escrow(&company, &id, &10_000);
+    client.raise_dispute(&company, &id, &BytesN::from_array(&env, &[9u8; 32]));
+    client.resolve_dispute(
+        &admin,
+        &id,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+    assert!(client.get_shipment(&id).finalized);
+
+    test_utils::advance_ledger_time(&env, RETENTION);
+    assert_eq!(
+        client.try_archive_settled_shipment(&id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+
+    assert_eq!(client.claim_shipment_insurance(&company, &id), 500);
+    client.archive_settled_shipment(&id);
+    assert_eq!(client.get_archived_shipment(&id).id, id);
+}
@@ -0,0 +1,119 @@
+//! Tests for `accept_assignment`, `decline_assignment` and the
+//! `require_carrier_acceptance` configuration flag.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{CarrierPolicy, ContractConfig, NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, Symbol, TryFromVal, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    carrier: &Address,
+) -> u64 {
+    client.update_config(
+        admin,
+        &ContractConfig {
+            carrier_policy: CarrierPolicy {
+                require_carrier_acceptance: true,
+                ..CarrierPolicy::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, carrier);
+    client.create_shipment(
+        &company,
+        &Address::generate(env),
+        carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    )
+}
+
+fn last_call_emitted(env: &Env, topic: &str) -> bool {
+    let topic = Symbol::new(env, topic);
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(env, &t).ok())
+            .is_some_and(|t| t == topic)
+    })
+}
+
+#[test]
+fn test_accept_then_transit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier);
+
+    assert!(!client.is_assignment_accepted(&id));
+    client.accept_assignment(&carrier, &id);
+    assert!(last_call_emitted(&env, "assignment_accepted"));
+    assert!(client.is_assignment_accepted(&id));
+
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+}
+
+#[test]
+fn test_transit_before_accept_fails() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier);
+
+    let result =
+        client.try_update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(result, Err(Ok(NavinError::AssignmentNotAccepted)));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+}
+
+#[test]
+fn test_decline_keeps_shipment_created() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier);
+
+    client.accept_assignment(&carrier, &id);
+    client.decline_assignment(&carrier, &id);
+    assert!(last_call_emitted(&env, "assignment_declined"));
+    assert!(!client.is_assignment_accepted(&id));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+
+    let result =
+        client.try_update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(result, Err(Ok(NavinError::AssignmentNotAccepted)));
+}
+
+#[test]
+fn test_only_assigned_carrier_can_accept() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier);
+
+    let other = Address::generate(&env);
+    client.add_carrier(&admin, &other);
+    assert_eq!(
+        client.try_accept_assignment(&other, &id),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_accept_rejected_after_departure() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier);
+
+    client.accept_assignment(&carrier, &id);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(
+        client.try_decline_assignment(&carrier, &id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
@@ -0,0 +1,44 @@
+//! Tests for `auto_whitelist_on_delivery`.
+
+use crate::test::*;
+use crate::{test_utils, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn deliver(enabled: bool) -> (Env, NavinShipmentClient<'static>, Address, Address) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut cfg = client.get_contract_config();
+    cfg.carrier_policy.auto_whitelist_on_delivery = enabled;
+    client.update_config(&admin, &cfg);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &hash,
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &hash);
+    client.confirm_delivery(&receiver, &id, &hash);
+    (env, client, company, carrier)
+}
+
+#[test]
+fn test_delivery_whitelists_carrier_when_enabled() {
+    let (_env, client, company, carrier) = deliver(true);
+    assert!(client.is_carrier_whitelisted(&company, &carrier));
+}
+
+#[test]
+fn test_delivery_leaves_whitelist_alone_when_disabled() {
+    let (_env, client, company, carrier) = deliver(false);
+    assert!(!client.is_carrier_whitelisted(&company, &carrier));
+}
@@ -303,3 +303,44 @@ fn test_batch_all_present_matches_individual_reads_field_by_field() {
         assert_eq!(batch_item.finalized, single.finalized);
     }
 }
+
+#[test]
+fn test_get_escrow_balances_aligns_with_input() {
+    let (env, client, admin, token_contract) = setup_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+
+    client.initialize(&admin, &token_contract);
+    client.add_company(&admin, &company);
+
+    let funded = create_shipment_for(&client, &env, &company, &receiver, &carrier, 1);
+    let unfunded = create_shipment_for(&client, &env, &company, &receiver, &carrier, 2);
+    client.deposit_escrow(&company, &funded, &5_000);
+
+    let mut ids = Vec::new(&env);
+    ids.push_back(unfunded);
+    ids.push_back(9999);
+    ids.push_back(funded);
+
+    let balances = client.get_escrow_balances(&ids);
+    assert_eq!(balances.len(), 3);
+    assert_eq!(balances.get(0).unwrap(), 0);
+    assert_eq!(balances.get(1).unwrap(), 0);
+    assert_eq!(balances.get(2).unwrap(), client.get_escrow_balance(&funded));
+    assert_eq!(balances.get(2).unwrap(), 5_000);
+}
+
+#[test]
+fn test_get_escrow_balances_rejects_requests_over_hard_limit() {
+    let (env, client, admin, token_contract) = setup_shipment_env();
+    client.initialize(&admin, &token_contract);
+
+    let mut ids = Vec::new(&env);
+    for i in 0..51_u64 {
+        ids.push_back(i + 1);
+    }
+
+    let result = client.try_get_escrow_balances(&ids);
+    assert!(matches!(result, Err(Ok(NavinError::BatchTooLarge))));
+}
@@ -0,0 +1,96 @@
+//! Tests for per-breach-type reputation weighting via `breach_weights`.
+
+use crate::test::*;
+use crate::test_utils::future_deadline;
+use crate::{BreachType, BreachWeights, ContractConfig, NavinShipmentClient, Severity};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+/// Creates a shipment for a fresh carrier and reports one breach of `breach_type`.
+fn report(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    breach_type: BreachType,
+) -> Address {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &BytesN::from_array(env, &[1u8; 32]),
+        &Vec::new(env),
+        &future_deadline(env, 3600),
+    );
+    client.report_condition_breach(
+        &carrier,
+        &id,
+        &breach_type,
+        &Severity::Medium,
+        &BytesN::from_array(env, &[2u8; 32]),
+    );
+    carrier
+}
+
+#[test]
+fn test_tamper_breach_penalized_more_than_temperature() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+
+    let tampered = report(&env, &client, &admin, BreachType::TamperDetected);
+    let warm = report(&env, &client, &admin, BreachType::TemperatureHigh);
+
+    let defaults = BreachWeights::default();
+    assert_eq!(
+        client.get_carrier_breach_penalty(&tampered),
+        defaults.tamper_detected
+    );
+    assert_eq!(
+        client.get_carrier_breach_penalty(&warm),
+        defaults.temperature_high
+    );
+    assert!(
+        client.get_carrier_breach_penalty(&tampered) > client.get_carrier_breach_penalty(&warm)
+    );
+}
+
+#[test]
+fn test_custom_breach_weights_applied() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            breach_weights: BreachWeights {
+                temperature_high: 0,
+                temperature_low: 0,
+                humidity_high: 0,
+                impact: 40,
+                tamper_detected: 100,
+            },
+            ..ContractConfig::default()
+        },
+    );
+
+    let impacted = report(&env, &client, &admin, BreachType::Impact);
+    let humid = report(&env, &client, &admin, BreachType::HumidityHigh);
+    assert_eq!(client.get_carrier_breach_penalty(&impacted), 40);
+    assert_eq!(client.get_carrier_breach_penalty(&humid), 0);
+}
+
+#[test]
+fn test_breach_weight_above_cap_rejected() {
+    let (_env, client, admin, _token) = setup_initialized_shipment_env();
+    let result = client.try_update_config(
+        &admin,
+        &ContractConfig {
+            breach_weights: BreachWeights {
+                tamper_detected: 101,
+                ..BreachWeights::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,71 @@
+//! Tests for `can_cancel`.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    let company = Address::generate(env);
+    let receiver = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    (company, receiver, carrier, id)
+}
+
+#[test]
+fn test_sender_and_admin_can_cancel_created_shipment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, _receiver, _carrier, id) = setup_shipment(&env, &client, &admin);
+
+    assert!(client.can_cancel(&id, &company));
+    assert!(client.can_cancel(&id, &admin));
+}
+
+#[test]
+fn test_outsider_and_carrier_cannot_cancel() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, receiver, carrier, id) = setup_shipment(&env, &client, &admin);
+
+    assert!(!client.can_cancel(&id, &Address::generate(&env)));
+    assert!(!client.can_cancel(&id, &carrier));
+    assert!(!client.can_cancel(&id, &receiver));
+}
+
+#[test]
+fn test_cannot_cancel_delivered_or_cancelled_shipment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, receiver, carrier, id) = setup_shipment(&env, &client, &admin);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert!(client.can_cancel(&id, &company));
+    client.confirm_delivery(&receiver, &id, &BytesN::from_array(&env, &[3u8; 32]));
+    assert!(!client.can_cancel(&id, &company));
+
+    let (company, _receiver, _carrier, id) = setup_shipment(&env, &client, &admin);
+    client.cancel_shipment(&company, &id, &BytesN::from_array(&env, &[4u8; 32]));
+    assert!(!client.can_cancel(&id, &company));
+}
+
+#[test]
+fn test_can_cancel_unknown_shipment() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+
+    assert_eq!(
+        client.try_can_cancel(&999, &Address::generate(&env)),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
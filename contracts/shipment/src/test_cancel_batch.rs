@@ -0,0 +1,168 @@
+//! Tests for `cancel_shipments_batch`.
+
+use crate::test::*;
+use crate::test_utils::{self, future_deadline};
+use crate::{NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn create(env: &Env, client: &NavinShipmentClient, company: &Address, seed: u8) -> u64 {
+    client.create_shipment(
+        company,
+        &Address::generate(env),
+        &Address::generate(env),
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &future_deadline(env, 3600),
+    )
+}
+
+/// Initialized contract backed by a real token, with `company` funded.
+fn setup_with_token() -> (Env, NavinShipmentClient<'static>, Address, Address, Address) {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    (env, client, admin, token, company)
+}
+
+fn set_status(env: &Env, client: &NavinShipmentClient, id: u64, status: ShipmentStatus) {
+    env.as_contract(&client.address, || {
+        let mut shipment = crate::storage::get_shipment(env, id).unwrap();
+        shipment.status = status;
+        crate::storage::set_shipment(env, &shipment);
+    });
+}
+
+fn ids(env: &Env, list: &[u64]) -> Vec<u64> {
+    let mut v = Vec::new(env);
+    for id in list {
+        v.push_back(*id);
+    }
+    v
+}
+
+#[test]
+fn test_cancel_batch_skips_terminal_shipments() {
+    let (env, client, _admin, token, company) = setup_with_token();
+    let token_client = TokenClient::new(&env, &token);
+
+    let created = create(&env, &client, &company, 1);
+    let funded = create(&env, &client, &company, 2);
+    client.deposit_escrow(&company, &funded, &500);
+    let delivered = create(&env, &client, &company, 3);
+    set_status(&env, &client, delivered, ShipmentStatus::Delivered);
+    assert_eq!(token_client.balance(&company), 500);
+
+    let reason = BytesN::from_array(&env, &[9u8; 32]);
+    let cancelled =
+        client.cancel_shipments_batch(&company, &ids(&env, &[created, funded, delivered]), &reason);
+
+    assert_eq!(cancelled, ids(&env, &[created, funded]));
+    for id in [created, funded] {
+        let shipment = client.get_shipment(&id);
+        assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+        assert_eq!(shipment.escrow_amount, 0);
+    }
+    assert_eq!(
+        client.get_shipment(&delivered).status,
+        ShipmentStatus::Delivered
+    );
+    assert_eq!(token_client.balance(&company), 1_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_cancel_batch_refunds_every_funded_shipment() {
+    let (env, client, admin, token, company) = setup_with_token();
+    let token_client = TokenClient::new(&env, &token);
+
+    let first = create(&env, &client, &company, 1);
+    let second = create(&env, &client, &company, 2);
+    client.deposit_escrow(&company, &first, &300);
+    client.deposit_escrow(&company, &second, &200);
+    assert_eq!(token_client.balance(&company), 500);
+
+    client.cancel_shipments_batch(
+        &admin,
+        &ids(&env, &[first, second]),
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+
+    assert_eq!(token_client.balance(&company), 1_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    for id in [first, second] {
+        assert!(client.get_shipment(&id).finalized);
+        assert_eq!(client.get_escrow_balance(&id), 0);
+    }
+}
+
+#[test]
+fn test_cancel_shipment_refunds_sender() {
+    let (env, client, _admin, token, company) = setup_with_token();
+    let token_client = TokenClient::new(&env, &token);
+
+    let id = create(&env, &client, &company, 1);
+    client.deposit_escrow(&company, &id, &400);
+    assert_eq!(token_client.balance(&company), 600);
+
+    client.cancel_shipment(&company, &id, &BytesN::from_array(&env, &[9u8; 32]));
+
+    assert_eq!(token_client.balance(&company), 1_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Cancelled);
+}
+
+#[test]
+fn test_cancel_batch_by_admin() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let id = create(&env, &client, &company, 1);
+
+    let cancelled = client.cancel_shipments_batch(
+        &admin,
+        &ids(&env, &[id]),
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+    assert_eq!(cancelled, ids(&env, &[id]));
+}
+
+#[test]
+fn test_cancel_batch_rejects_foreign_shipment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_company(&admin, &other);
+    let own = create(&env, &client, &company, 1);
+    let foreign = create(&env, &client, &other, 2);
+
+    let result = client.try_cancel_shipments_batch(
+        &company,
+        &ids(&env, &[own, foreign]),
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+    assert_eq!(client.get_shipment(&own).status, ShipmentStatus::Created);
+}
+
+#[test]
+fn test_cancel_batch_enforces_batch_limit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let limit = client.get_contract_config().batch_operation_limit;
+
+    let mut list = Vec::new(&env);
+    for id in 1..=(limit as u64 + 1) {
+        list.push_back(id);
+    }
+    let result =
+        client.try_cancel_shipments_batch(&admin, &list, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(result, Err(Ok(NavinError::BatchTooLarge)));
+}
@@ -0,0 +1,117 @@
+//! Tests for `cancel_with_compensation`.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const ESCROW: i128 = 1_000;
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    carrier: Address,
+    token: Address,
+    shipment_id: u64,
+}
+
+fn setup_in_transit() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    StellarAssetClient::new(&env, &token).mint(&company, &ESCROW);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 3600),
+    );
+    client.deposit_escrow(&company, &shipment_id, &ESCROW);
+    client.update_status(
+        &carrier,
+        &shipment_id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        carrier,
+        token,
+        shipment_id,
+    }
+}
+
+fn balance(ctx: &Ctx, who: &Address) -> i128 {
+    TokenClient::new(&ctx.env, &ctx.token).balance(who)
+}
+
+fn reason(ctx: &Ctx) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[7u8; 32])
+}
+
+#[test]
+fn test_compensated_in_transit_cancellation_splits_escrow() {
+    let ctx = setup_in_transit();
+
+    ctx.client
+        .cancel_with_compensation(&ctx.company, &ctx.shipment_id, &300, &reason(&ctx));
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 300);
+    assert_eq!(balance(&ctx, &ctx.company), 700);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+
+    let shipment = ctx.client.get_shipment(&ctx.shipment_id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.escrow_amount, 0);
+}
+
+#[test]
+fn test_compensation_capped_at_escrow() {
+    let ctx = setup_in_transit();
+
+    ctx.client
+        .cancel_with_compensation(&ctx.admin, &ctx.shipment_id, &(ESCROW * 5), &reason(&ctx));
+
+    assert_eq!(balance(&ctx, &ctx.carrier), ESCROW);
+    assert_eq!(balance(&ctx, &ctx.company), 0);
+}
+
+#[test]
+fn test_compensation_rejects_non_sender() {
+    let ctx = setup_in_transit();
+
+    let result = ctx.client.try_cancel_with_compensation(
+        &ctx.carrier,
+        &ctx.shipment_id,
+        &300,
+        &reason(&ctx),
+    );
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_compensation_rejects_negative_amount() {
+    let ctx = setup_in_transit();
+
+    let result =
+        ctx.client
+            .try_cancel_with_compensation(&ctx.company, &ctx.shipment_id, &-1, &reason(&ctx));
+    assert_eq!(result, Err(Ok(NavinError::InvalidAmount)));
+}
@@ -0,0 +1,66 @@
+//! Tests for `get_carrier_disputes`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn create(
+    env: &Env,
+    client: &NavinShipmentClient,
+    company: &Address,
+    carrier: &Address,
+    seed: u8,
+) -> u64 {
+    client.create_shipment(
+        company,
+        &Address::generate(env),
+        carrier,
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &test_utils::future_deadline(env, 86_400),
+    )
+}
+
+fn dispute(env: &Env, client: &NavinShipmentClient, company: &Address, id: u64) {
+    client.raise_dispute(company, &id, &BytesN::from_array(env, &[99u8; 32]));
+}
+
+#[test]
+fn test_carrier_disputes_filters_by_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier_a = Address::generate(&env);
+    let carrier_b = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier_a);
+    client.add_carrier(&admin, &carrier_b);
+
+    let a1 = create(&env, &client, &company, &carrier_a, 1);
+    let b1 = create(&env, &client, &company, &carrier_b, 2);
+    let _a_open = create(&env, &client, &company, &carrier_a, 3);
+    let a2 = create(&env, &client, &company, &carrier_a, 4);
+    dispute(&env, &client, &company, a1);
+    dispute(&env, &client, &company, b1);
+    dispute(&env, &client, &company, a2);
+
+    let disputes = client.get_carrier_disputes(&carrier_a, &0, &10);
+    assert_eq!(disputes, Vec::from_array(&env, [a1, a2]));
+
+    let page = client.get_carrier_disputes(&carrier_a, &1, &10);
+    assert_eq!(page, Vec::from_array(&env, [a2]));
+
+    let other = client.get_carrier_disputes(&carrier_b, &0, &10);
+    assert_eq!(other, Vec::from_array(&env, [b1]));
+}
+
+#[test]
+fn test_carrier_disputes_rejects_invalid_limit() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+
+    assert_eq!(
+        client.try_get_carrier_disputes(&carrier, &0, &0),
+        Err(Ok(NavinError::InvalidConfig))
+    );
+}
@@ -0,0 +1,160 @@
+//! Tests for `get_carrier_reputation` and `rate_carrier`.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{
+    BreachType, DisputeResolution, NavinError, NavinShipmentClient, Severity, ShipmentStatus,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    let company = Address::generate(env);
+    let receiver = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    (company, receiver, carrier, id)
+}
+
+#[test]
+fn test_unknown_carrier_reputation_is_zero() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+
+    let rep = client.get_carrier_reputation(&carrier);
+    assert_eq!(rep.carrier, carrier);
+    assert_eq!(rep.deliveries, 0);
+    assert_eq!(rep.dispute_losses, 0);
+    assert_eq!(rep.breaches, 0);
+}
+
+#[test]
+fn test_delivery_increments_deliveries() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, receiver, carrier, id) = setup_shipment(&env, &client, &admin);
+
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    client.confirm_delivery(&receiver, &id, &BytesN::from_array(&env, &[3u8; 32]));
+
+    let rep = client.get_carrier_reputation(&carrier);
+    assert_eq!(rep.deliveries, 1);
+    assert_eq!(rep.dispute_losses, 0);
+}
+
+#[test]
+fn test_dispute_lost_increments_dispute_losses() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, _receiver, carrier, id) = setup_shipment(&env, &client, &admin);
+
+    client.deposit_escrow(&company, &id, &5_000);
+    client.raise_dispute(&company, &id, &BytesN::from_array(&env, &[99u8; 32]));
+    client.resolve_dispute(
+        &admin,
+        &id,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+
+    let rep = client.get_carrier_reputation(&carrier);
+    assert_eq!(rep.dispute_losses, 1);
+    assert_eq!(rep.deliveries, 0);
+}
+
+#[test]
+fn test_breach_increments_breaches() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, _receiver, carrier, id) = setup_shipment(&env, &client, &admin);
+
+    client.report_condition_breach(
+        &carrier,
+        &id,
+        &BreachType::TemperatureHigh,
+        &Severity::Low,
+        &BytesN::from_array(&env, &[5u8; 32]),
+    );
+
+    let rep = client.get_carrier_reputation(&carrier);
+    assert_eq!(rep.breaches, 1);
+    assert!(rep.breach_penalty > 0);
+}
+
+fn delivered_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    let (_company, receiver, carrier, id) = setup_shipment(env, client, admin);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(env));
+    client.confirm_delivery(&receiver, &id, &BytesN::from_array(env, &[3u8; 32]));
+    (receiver, carrier, id)
+}
+
+#[test]
+fn test_receiver_rating_updates_average() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (receiver, carrier, id) = delivered_shipment(&env, &client, &admin);
+
+    client.rate_carrier(&receiver, &id, &4);
+
+    let rep = client.get_carrier_reputation(&carrier);
+    assert_eq!(rep.ratings, 1);
+    assert_eq!(rep.average_rating, 400);
+}
+
+#[test]
+fn test_double_rating_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (receiver, carrier, id) = delivered_shipment(&env, &client, &admin);
+
+    client.rate_carrier(&receiver, &id, &5);
+    assert_eq!(
+        client.try_rate_carrier(&receiver, &id, &1),
+        Err(Ok(NavinError::AlreadyRated))
+    );
+    assert_eq!(client.get_carrier_reputation(&carrier).average_rating, 500);
+}
+
+#[test]
+fn test_rating_by_non_receiver_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_receiver, carrier, id) = delivered_shipment(&env, &client, &admin);
+
+    assert_eq!(
+        client.try_rate_carrier(&Address::generate(&env), &id, &3),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(client.get_carrier_reputation(&carrier).ratings, 0);
+}
+
+#[test]
+fn test_rating_out_of_range_or_undelivered_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (receiver, _carrier, id) = delivered_shipment(&env, &client, &admin);
+    assert_eq!(
+        client.try_rate_carrier(&receiver, &id, &0),
+        Err(Ok(NavinError::InvalidRating))
+    );
+    assert_eq!(
+        client.try_rate_carrier(&receiver, &id, &6),
+        Err(Ok(NavinError::InvalidRating))
+    );
+
+    let (_company, receiver, _carrier, id) = setup_shipment(&env, &client, &admin);
+    assert_eq!(
+        client.try_rate_carrier(&receiver, &id, &3),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
@@ -0,0 +1,86 @@
+//! Tests for the per-carrier shipment index read by `get_carrier_shipment_ids`.
+
+use crate::test::*;
+use crate::test_utils;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Vec};
+
+#[test]
+fn test_handoff_moves_shipment_between_carrier_indexes() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier_a = Address::generate(&env);
+    let carrier_b = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier_a);
+    client.add_carrier(&admin, &carrier_b);
+
+    let mut ids = Vec::new(&env);
+    for seed in 1..=3u8 {
+        ids.push_back(client.create_shipment(
+            &company,
+            &Address::generate(&env),
+            &carrier_a,
+            &BytesN::from_array(&env, &[seed; 32]),
+            &Vec::new(&env),
+            &test_utils::future_deadline(&env, 86_400),
+        ));
+    }
+    let (first, second, third) = (
+        ids.get_unchecked(0),
+        ids.get_unchecked(1),
+        ids.get_unchecked(2),
+    );
+    assert_eq!(client.get_carrier_shipment_ids(&carrier_a, &0, &10), ids);
+    assert_eq!(
+        client.get_carrier_shipment_ids(&carrier_b, &0, &10).len(),
+        0
+    );
+
+    client.handoff_shipment(
+        &carrier_a,
+        &carrier_b,
+        &second,
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+
+    assert_eq!(
+        client.get_carrier_shipment_ids(&carrier_a, &0, &10),
+        vec![&env, first, third]
+    );
+    assert_eq!(
+        client.get_carrier_shipment_ids(&carrier_b, &0, &10),
+        vec![&env, second]
+    );
+}
+
+#[test]
+fn test_carrier_index_paginates() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut ids = Vec::new(&env);
+    for seed in 1..=3u8 {
+        ids.push_back(client.create_shipment(
+            &company,
+            &Address::generate(&env),
+            &carrier,
+            &BytesN::from_array(&env, &[seed; 32]),
+            &Vec::new(&env),
+            &test_utils::future_deadline(&env, 86_400),
+        ));
+    }
+
+    assert_eq!(
+        client.get_carrier_shipment_ids(&carrier, &0, &2),
+        ids.slice(0..2)
+    );
+    assert_eq!(
+        client.get_carrier_shipment_ids(&carrier, &2, &2),
+        ids.slice(2..3)
+    );
+    assert_eq!(client.get_carrier_shipment_ids(&carrier, &5, &2).len(), 0);
+}
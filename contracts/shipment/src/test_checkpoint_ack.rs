@@ -0,0 +1,171 @@
+//! Tests for receiver co-signed checkpoint arrivals.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    token: Address,
+    company: Address,
+    carrier: Address,
+    receiver: Address,
+    id: u64,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut milestones = Vec::new(&env);
+    milestones.push_back((symbol_short!("port"), 40));
+    milestones.push_back((symbol_short!("dropoff"), 60));
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+
+    Ctx {
+        env,
+        client,
+        token,
+        company,
+        carrier,
+        receiver,
+        id,
+    }
+}
+
+fn advance(env: &Env) {
+    env.ledger().with_mut(|l| l.timestamp += 61);
+}
+
+/// Move the shipment InTransit -> AtCheckpoint (with `ack_hash`) -> InTransit.
+fn arrive_at_checkpoint(ctx: &Ctx, ack_hash: &BytesN<32>) {
+    ctx.client.update_status(
+        &ctx.carrier,
+        &ctx.id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+    );
+    advance(&ctx.env);
+    ctx.client.update_status(
+        &ctx.carrier,
+        &ctx.id,
+        &ShipmentStatus::AtCheckpoint,
+        ack_hash,
+    );
+    advance(&ctx.env);
+    ctx.client.update_status(
+        &ctx.carrier,
+        &ctx.id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&ctx.env, &[4u8; 32]),
+    );
+}
+
+#[test]
+fn test_co_signed_checkpoint_releases_payment() {
+    let ctx = setup();
+    ctx.client
+        .set_checkpoint_ack_required(&ctx.company, &ctx.id, &true);
+
+    let ack_hash = BytesN::from_array(&ctx.env, &[3u8; 32]);
+    arrive_at_checkpoint(&ctx, &ack_hash);
+    ctx.client.ack_checkpoint(&ctx.receiver, &ctx.id, &ack_hash);
+
+    ctx.client.record_milestone(
+        &ctx.carrier,
+        &ctx.id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&ctx.env, &[5u8; 32]),
+    );
+
+    assert!(ctx
+        .client
+        .is_milestone_paid(&ctx.id, &symbol_short!("port")));
+    assert_eq!(
+        TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.carrier),
+        400
+    );
+}
+
+#[test]
+fn test_milestone_rejected_without_ack() {
+    let ctx = setup();
+    ctx.client
+        .set_checkpoint_ack_required(&ctx.company, &ctx.id, &true);
+
+    arrive_at_checkpoint(&ctx, &BytesN::from_array(&ctx.env, &[3u8; 32]));
+
+    let result = ctx.client.try_record_milestone(
+        &ctx.carrier,
+        &ctx.id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&ctx.env, &[5u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(NavinError::CheckpointNotAcknowledged)));
+    assert_eq!(ctx.client.get_shipment(&ctx.id).escrow_amount, 1_000);
+}
+
+#[test]
+fn test_ack_with_wrong_hash_rejected() {
+    let ctx = setup();
+    ctx.client
+        .set_checkpoint_ack_required(&ctx.company, &ctx.id, &true);
+
+    arrive_at_checkpoint(&ctx, &BytesN::from_array(&ctx.env, &[3u8; 32]));
+
+    let result = ctx.client.try_ack_checkpoint(
+        &ctx.receiver,
+        &ctx.id,
+        &BytesN::from_array(&ctx.env, &[9u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(NavinError::CheckpointAckMismatch)));
+}
+
+#[test]
+fn test_ack_by_non_receiver_rejected() {
+    let ctx = setup();
+    let ack_hash = BytesN::from_array(&ctx.env, &[3u8; 32]);
+    arrive_at_checkpoint(&ctx, &ack_hash);
+
+    let result = ctx
+        .client
+        .try_ack_checkpoint(&ctx.carrier, &ctx.id, &ack_hash);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_ack_not_required_by_default() {
+    let ctx = setup();
+    arrive_at_checkpoint(&ctx, &BytesN::from_array(&ctx.env, &[3u8; 32]));
+
+    ctx.client.record_milestone(
+        &ctx.carrier,
+        &ctx.id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&ctx.env, &[5u8; 32]),
+    );
+    assert!(ctx
+        .client
+        .is_milestone_paid(&ctx.id, &symbol_short!("port")));
+}
@@ -0,0 +1,94 @@
+//! Tests for the `max_checkpoint_dwell` carrier reputation penalty.
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, dummy_hash, future_deadline};
+use crate::{CarrierPolicy, ContractConfig, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup_at_checkpoint(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    max_checkpoint_dwell: u64,
+) -> (Address, u64) {
+    client.update_config(
+        admin,
+        &ContractConfig {
+            carrier_policy: CarrierPolicy {
+                max_checkpoint_dwell,
+                ..CarrierPolicy::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(env));
+    advance_ledger_time(env, 120);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::AtCheckpoint,
+        &BytesN::from_array(env, &[2u8; 32]),
+    );
+    (carrier, id)
+}
+
+#[test]
+fn test_short_checkpoint_dwell_not_penalized() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = setup_at_checkpoint(&env, &client, &admin, 3_600);
+
+    advance_ledger_time(&env, 600);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    assert_eq!(client.get_carrier_checkpoint_overstays(&carrier), 0);
+}
+
+#[test]
+fn test_long_checkpoint_dwell_penalizes_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = setup_at_checkpoint(&env, &client, &admin, 3_600);
+
+    advance_ledger_time(&env, 3_601);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    assert_eq!(client.get_carrier_checkpoint_overstays(&carrier), 1);
+}
+
+#[test]
+fn test_checkpoint_dwell_ignored_when_disabled() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = setup_at_checkpoint(&env, &client, &admin, 0);
+
+    advance_ledger_time(&env, 86_000);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    assert_eq!(client.get_carrier_checkpoint_overstays(&carrier), 0);
+}
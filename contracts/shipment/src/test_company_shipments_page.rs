@@ -0,0 +1,86 @@
+//! Tests for paging a company's shipments via `get_shipments_by_company`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+
+fn create(
+    env: &Env,
+    client: &NavinShipmentClient,
+    company: &Address,
+    carrier: &Address,
+    seed: u8,
+) -> u64 {
+    client.create_shipment(
+        company,
+        &Address::generate(env),
+        carrier,
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &test_utils::future_deadline(env, 86_400),
+    )
+}
+
+#[test]
+fn test_paginate_shipments_per_company() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let mut cfg = client.get_contract_config();
+    cfg.index_company_shipments = true;
+    client.update_config(&admin, &cfg);
+
+    let company_a = Address::generate(&env);
+    let company_b = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company_a);
+    client.add_company(&admin, &company_b);
+    client.add_carrier(&admin, &carrier);
+
+    let a1 = create(&env, &client, &company_a, &carrier, 1);
+    let b1 = create(&env, &client, &company_b, &carrier, 2);
+    let a2 = create(&env, &client, &company_a, &carrier, 3);
+    let a3 = create(&env, &client, &company_a, &carrier, 4);
+    let b2 = create(&env, &client, &company_b, &carrier, 5);
+
+    assert_eq!(
+        client.get_shipments_by_company(&company_a, &0, &2),
+        vec![&env, a1, a2]
+    );
+    assert_eq!(
+        client.get_shipments_by_company(&company_a, &2, &2),
+        vec![&env, a3]
+    );
+    assert_eq!(client.get_shipments_by_company(&company_a, &3, &2).len(), 0);
+    assert_eq!(
+        client.get_shipments_by_company(&company_b, &0, &10),
+        vec![&env, b1, b2]
+    );
+}
+
+#[test]
+fn test_company_page_capped_at_batch_limit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let mut cfg = client.get_contract_config();
+    cfg.index_company_shipments = true;
+    cfg.batch_operation_limit = 2;
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    for seed in 1..=3 {
+        create(&env, &client, &company, &carrier, seed);
+    }
+
+    assert_eq!(client.get_shipments_by_company(&company, &0, &50).len(), 2);
+}
+
+#[test]
+fn test_company_page_requires_index() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    assert_eq!(
+        client.try_get_shipments_by_company(&Address::generate(&env), &0, &10),
+        Err(Ok(NavinError::CompanyIndexDisabled))
+    );
+}
@@ -1238,6 +1238,7 @@ fn test_upgrade_preserves_analytics_counters() {
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
 
     let contract_id = client.address.clone();
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
     client.upgrade(&admin, &new_wasm_hash, &target_version);
 
     env.as_contract(&contract_id, || {
@@ -1292,6 +1293,7 @@ fn test_get_version_increments_match_target_after_upgrade() {
     let wasm: &[u8] = include_bytes!("../test_wasms/upgrade_test.wasm");
     let new_wasm_hash = env.deployer().upload_contract_wasm(wasm);
     let contract_id = client.address.clone();
+    client.allow_wasm_hash(&admin, &new_wasm_hash);
     client.upgrade(&admin, &new_wasm_hash, &target);
 
     env.as_contract(&contract_id, || {
@@ -0,0 +1,52 @@
+//! Tests for `get_creation_hash`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Symbol, Vec};
+
+#[test]
+fn test_creation_hash_matches_recomputed_inputs() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let data_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let mut milestones: Vec<(Symbol, u32)> = Vec::new(&env);
+    milestones.push_back((Symbol::new(&env, "pickup"), 40));
+    milestones.push_back((Symbol::new(&env, "dropoff"), 60));
+    let deadline = test_utils::future_deadline(&env, 86_400);
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &data_hash,
+        &milestones,
+        &deadline,
+    );
+
+    let mut payload = Bytes::new(&env);
+    payload.append(&company.to_xdr(&env));
+    payload.append(&receiver.to_xdr(&env));
+    payload.append(&carrier.to_xdr(&env));
+    payload.append(&data_hash.to_xdr(&env));
+    payload.append(&milestones.to_xdr(&env));
+    payload.append(&deadline.to_xdr(&env));
+    let expected: BytesN<32> = env.crypto().sha256(&payload).into();
+
+    assert_eq!(client.get_creation_hash(&id), Some(expected));
+}
+
+#[test]
+fn test_creation_hash_unknown_shipment() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+    assert_eq!(
+        client.try_get_creation_hash(&42),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
@@ -0,0 +1,99 @@
+//! Tests for the `check_deadlines_batch` expiry crank.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    token: Address,
+    company: Address,
+    carrier: Address,
+    receiver: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    StellarAssetClient::new(&env, &token).mint(&company, &300);
+
+    Ctx {
+        receiver: Address::generate(&env),
+        env,
+        client,
+        token,
+        company,
+        carrier,
+    }
+}
+
+fn funded_shipment(ctx: &Ctx, seed: u8, deadline_in: u64) -> u64 {
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &ctx.receiver,
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[seed; 32]),
+        &Vec::new(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, deadline_in),
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &100);
+    id
+}
+
+#[test]
+fn test_batch_expires_only_eligible_shipments() {
+    let ctx = setup();
+    let expiring = funded_shipment(&ctx, 1, 3_600);
+    let pending = funded_shipment(&ctx, 2, 86_400);
+    let delivered = funded_shipment(&ctx, 3, 3_600);
+
+    let hash = BytesN::from_array(&ctx.env, &[9u8; 32]);
+    ctx.client
+        .update_status(&ctx.carrier, &delivered, &ShipmentStatus::InTransit, &hash);
+    ctx.client
+        .confirm_delivery(&ctx.receiver, &delivered, &hash);
+
+    test_utils::advance_ledger_time(&ctx.env, 3_601);
+    let expired = ctx
+        .client
+        .check_deadlines_batch(&vec![&ctx.env, expiring, pending, delivered, 999]);
+
+    assert_eq!(expired, vec![&ctx.env, expiring]);
+    assert_eq!(
+        ctx.client.get_shipment(&expiring).status,
+        ShipmentStatus::Cancelled
+    );
+    assert_eq!(
+        ctx.client.get_shipment(&pending).status,
+        ShipmentStatus::Created
+    );
+    assert_eq!(ctx.client.get_shipment(&pending).escrow_amount, 100);
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.company), 100);
+    assert_eq!(token.balance(&ctx.carrier), 100);
+}
+
+#[test]
+fn test_batch_rejects_oversized_input() {
+    let ctx = setup();
+    let mut ids = Vec::new(&ctx.env);
+    for id in 1..=11u64 {
+        ids.push_back(id);
+    }
+    assert_eq!(
+        ctx.client.try_check_deadlines_batch(&ids),
+        Err(Ok(NavinError::BatchTooLarge))
+    );
+}
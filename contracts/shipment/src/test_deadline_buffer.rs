@@ -0,0 +1,105 @@
+//! Tests for `min_deadline_buffer_seconds` enforcement in both create paths.
+
+use crate::test::*;
+use crate::{ContractConfig, NavinError, NavinShipmentClient, ShipmentInput};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const BUFFER: u64 = 3600;
+
+fn setup() -> (Env, NavinShipmentClient<'static>, Address) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    (env, client, company)
+}
+
+fn try_create(
+    env: &Env,
+    client: &NavinShipmentClient,
+    company: &Address,
+    seed: u8,
+    deadline: u64,
+) -> Result<u64, NavinError> {
+    client
+        .try_create_shipment(
+            company,
+            &Address::generate(env),
+            &Address::generate(env),
+            &BytesN::from_array(env, &[seed; 32]),
+            &Vec::new(env),
+            &deadline,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn batch_of_one(env: &Env, deadline: u64) -> Vec<ShipmentInput> {
+    let mut shipments = Vec::new(env);
+    shipments.push_back(ShipmentInput {
+        receiver: Address::generate(env),
+        carrier: Address::generate(env),
+        data_hash: BytesN::from_array(env, &[2u8; 32]),
+        payment_milestones: Vec::new(env),
+        deadline,
+    });
+    shipments
+}
+
+#[test]
+fn test_default_buffer_is_one_hour() {
+    assert_eq!(
+        ContractConfig::default().min_deadline_buffer_seconds,
+        BUFFER
+    );
+}
+
+#[test]
+fn test_create_accepts_deadline_at_buffer_boundary() {
+    let (env, client, company) = setup();
+    let now = env.ledger().timestamp();
+    assert!(try_create(&env, &client, &company, 1, now + BUFFER).is_ok());
+}
+
+#[test]
+fn test_create_rejects_deadline_below_buffer() {
+    let (env, client, company) = setup();
+    let now = env.ledger().timestamp();
+    assert_eq!(
+        try_create(&env, &client, &company, 1, now + BUFFER - 1),
+        Err(NavinError::InvalidTimestamp)
+    );
+}
+
+#[test]
+fn test_batch_enforces_buffer() {
+    let (env, client, company) = setup();
+    let now = env.ledger().timestamp();
+
+    let result = client.try_create_shipments_batch(&company, &batch_of_one(&env, now + BUFFER - 1));
+    assert_eq!(result, Err(Ok(NavinError::InvalidTimestamp)));
+
+    let ids = client.create_shipments_batch(&company, &batch_of_one(&env, now + BUFFER));
+    assert_eq!(ids.len(), 1);
+}
+
+#[test]
+fn test_zero_buffer_still_requires_future_deadline() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            min_deadline_buffer_seconds: 0,
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let now = env.ledger().timestamp();
+
+    assert!(try_create(&env, &client, &company, 1, now + 1).is_ok());
+    assert_eq!(
+        try_create(&env, &client, &company, 2, now),
+        Err(NavinError::InvalidTimestamp)
+    );
+}
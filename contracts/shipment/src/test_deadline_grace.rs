@@ -1,10 +1,13 @@
 //! Tests for issue #6 — deadline grace-period boundary tests.
 //!
 //! Verifies the exact boundary semantics of `deadline_grace_seconds` as used
-//! by `check_deadline`.  The expiry threshold is:
+//! by `check_deadline`.  For shipments in carrier custody the expiry threshold
+//! is:
 //!
 //!   `expiry = deadline + deadline_grace_seconds`
 //!
+//! while `Created` shipments expire at the deadline itself.
+//!
 //! * `timestamp < expiry`  → `NotExpired` (shipment untouched)
 //! * `timestamp >= expiry` → shipment cancelled
 //!
@@ -79,7 +82,7 @@ mod tests {
     fn zero_grace_at_deadline_not_expired() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let id = create_with_deadline(&env, &client, &admin, 0x01, deadline);
 
         // timestamp == deadline → NOT yet >= expiry (>= means strictly at or past)
@@ -98,7 +101,7 @@ mod tests {
     fn zero_grace_one_second_past_deadline_cancels_shipment() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let id = create_with_deadline(&env, &client, &admin, 0x02, deadline);
 
         // expiry = deadline; timestamp = deadline + 1 >= expiry → cancels.
@@ -120,11 +123,12 @@ mod tests {
     fn grace_period_inside_window_is_not_expired() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let grace = 300u64;
         let id = create_with_deadline(&env, &client, &admin, 0x03, deadline);
 
         set_grace(&client, &admin, grace);
+        start_transit(&env, &client, id);
 
         // timestamp = deadline + grace - 1 → still inside grace window.
         test_utils::set_ledger_time(&env, deadline + grace - 1);
@@ -138,8 +142,8 @@ mod tests {
         let shipment = client.get_shipment(&id);
         assert_eq!(
             shipment.status,
-            ShipmentStatus::Created,
-            "shipment status must remain Created while still inside the grace window"
+            ShipmentStatus::InTransit,
+            "shipment status must remain InTransit while still inside the grace window"
         );
     }
 
@@ -151,11 +155,12 @@ mod tests {
     fn grace_period_at_exact_boundary_expires() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let grace = 300u64;
         let id = create_with_deadline(&env, &client, &admin, 0x04, deadline);
 
         set_grace(&client, &admin, grace);
+        start_transit(&env, &client, id);
 
         // timestamp = deadline + grace → exactly at expiry threshold.
         test_utils::set_ledger_time(&env, deadline + grace);
@@ -177,11 +182,12 @@ mod tests {
     fn grace_period_past_boundary_cancels_shipment_and_clears_escrow() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let grace = 300u64;
         let id = create_with_deadline(&env, &client, &admin, 0x05, deadline);
 
         set_grace(&client, &admin, grace);
+        start_transit(&env, &client, id);
 
         test_utils::set_ledger_time(&env, deadline + grace + 500);
         client.check_deadline(&id);
@@ -202,8 +208,9 @@ mod tests {
     fn increasing_grace_period_makes_previously_expired_time_not_expired() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let id = create_with_deadline(&env, &client, &admin, 0x06, deadline);
+        start_transit(&env, &client, id);
 
         // Zero grace, set time to deadline + 1 → would cancel.
         // But first extend grace to 600 s before checking.
@@ -223,8 +230,9 @@ mod tests {
     fn reducing_grace_period_makes_inside_window_time_expire() {
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let id = create_with_deadline(&env, &client, &admin, 0x07, deadline);
+        start_transit(&env, &client, id);
 
         // Start with a large grace so we are inside the window.
         set_grace(&client, &admin, 600);
@@ -282,7 +290,7 @@ mod tests {
         use crate::NavinError;
         let (env, client, admin) = setup();
         let now = env.ledger().timestamp();
-        let deadline = now + 1000;
+        let deadline = now + 3600;
         let id = create_with_deadline(&env, &client, &admin, 0x08, deadline);
 
         // Retrieve the shipment to get company address for cancellation.
@@ -321,4 +329,70 @@ mod tests {
             "check_deadline on a non-existent shipment must return ShipmentNotFound"
         );
     }
+
+    // ── Grace period for shipments in active transit ─────────────────────────
+
+    /// Helper: move a freshly created shipment into `InTransit`.
+    fn start_transit(env: &Env, client: &NavinShipmentClient<'static>, id: u64) {
+        let carrier = client.get_shipment(&id).carrier;
+        client.update_status(
+            &carrier,
+            &id,
+            &ShipmentStatus::InTransit,
+            &BytesN::from_array(env, &[0x77u8; 32]),
+        );
+    }
+
+    /// An `InTransit` shipment past its deadline but inside the grace window
+    /// keeps moving: check_deadline returns NotExpired.
+    #[test]
+    fn in_transit_inside_grace_window_is_not_cancelled() {
+        use crate::NavinError;
+        let (env, client, admin) = setup();
+        let now = env.ledger().timestamp();
+        let deadline = now + 3600;
+        let grace = 600u64;
+        let id = create_with_deadline(&env, &client, &admin, 0x0C, deadline);
+        set_grace(&client, &admin, grace);
+        start_transit(&env, &client, id);
+
+        test_utils::set_ledger_time(&env, deadline + grace / 2);
+        assert_eq!(
+            client.try_check_deadline(&id),
+            Err(Ok(NavinError::NotExpired)),
+            "in-transit shipment inside the grace window must not expire"
+        );
+        assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+    }
+
+    /// Once the grace window has elapsed, an `InTransit` shipment is cancelled.
+    #[test]
+    fn in_transit_past_grace_window_is_cancelled() {
+        let (env, client, admin) = setup();
+        let now = env.ledger().timestamp();
+        let deadline = now + 3600;
+        let grace = 600u64;
+        let id = create_with_deadline(&env, &client, &admin, 0x0D, deadline);
+        set_grace(&client, &admin, grace);
+        start_transit(&env, &client, id);
+
+        test_utils::set_ledger_time(&env, deadline + grace + 1);
+        client.check_deadline(&id);
+        assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Cancelled);
+    }
+
+    /// A shipment still `Created` gets no grace: it expires exactly at the
+    /// deadline even with a grace window configured.
+    #[test]
+    fn created_shipment_expires_at_deadline_despite_grace() {
+        let (env, client, admin) = setup();
+        let now = env.ledger().timestamp();
+        let deadline = now + 3600;
+        let id = create_with_deadline(&env, &client, &admin, 0x0E, deadline);
+        set_grace(&client, &admin, 600);
+
+        test_utils::set_ledger_time(&env, deadline);
+        client.check_deadline(&id);
+        assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Cancelled);
+    }
 }
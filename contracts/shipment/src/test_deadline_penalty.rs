@@ -0,0 +1,84 @@
+//! Tests for the configurable carrier penalty on deadline expiry.
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, dummy_hash};
+use crate::{CarrierPolicy, ContractConfig, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const DEADLINE_SECS: u64 = 3600;
+
+fn enable_penalty(client: &NavinShipmentClient, admin: &Address) {
+    let cfg = ContractConfig {
+        carrier_policy: CarrierPolicy {
+            penalize_deadline_miss: true,
+            ..CarrierPolicy::default()
+        },
+        ..ContractConfig::default()
+    };
+    client.update_config(admin, &cfg);
+}
+
+/// Create a shipment and return `(carrier, shipment_id)`.
+fn create(env: &Env, client: &NavinShipmentClient, admin: &Address) -> (Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &(env.ledger().timestamp() + DEADLINE_SECS),
+    );
+    (carrier, id)
+}
+
+fn pick_up(env: &Env, client: &NavinShipmentClient, carrier: &Address, id: u64) {
+    client.update_status(
+        carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(env, &[2u8; 32]),
+    );
+}
+
+#[test]
+fn test_expired_in_transit_shipment_penalizes_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_penalty(&client, &admin);
+    let (carrier, id) = create(&env, &client, &admin);
+    pick_up(&env, &client, &carrier, id);
+
+    advance_ledger_time(&env, DEADLINE_SECS + 1);
+    client.check_deadline(&id);
+
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Cancelled);
+    assert_eq!(client.get_carrier_deadline_misses(&carrier), 1);
+}
+
+#[test]
+fn test_expired_created_shipment_does_not_penalize_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_penalty(&client, &admin);
+    let (carrier, id) = create(&env, &client, &admin);
+
+    advance_ledger_time(&env, DEADLINE_SECS + 1);
+    client.check_deadline(&id);
+
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Cancelled);
+    assert_eq!(client.get_carrier_deadline_misses(&carrier), 0);
+}
+
+#[test]
+fn test_no_penalty_when_disabled() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = create(&env, &client, &admin);
+    pick_up(&env, &client, &carrier, id);
+
+    advance_ledger_time(&env, DEADLINE_SECS + 1);
+    client.check_deadline(&id);
+
+    assert_eq!(client.get_carrier_deadline_misses(&carrier), 0);
+}
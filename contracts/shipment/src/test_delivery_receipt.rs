@@ -0,0 +1,64 @@
+//! Tests for the `DeliveryReceipt` returned by `confirm_delivery`.
+
+use crate::{test_utils, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+
+fn setup() -> (Env, NavinShipmentClient<'static>, Address, Address) {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+    (env, client, admin, token)
+}
+
+#[test]
+fn test_receipt_reports_remaining_escrow_paid() {
+    let (env, client, admin, token) = setup();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut milestones = Vec::new(&env);
+    milestones.push_back((symbol_short!("pickup"), 40));
+    milestones.push_back((symbol_short!("dropoff"), 60));
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+    // Pay the first milestone so only part of the escrow remains.
+    client.record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("pickup"),
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+    let remaining = client.get_shipment(&id).escrow_amount;
+    assert_eq!(remaining, 600);
+
+    let receipt = client.confirm_delivery(&receiver, &id, &BytesN::from_array(&env, &[4u8; 32]));
+
+    assert_eq!(receipt.shipment_id, id);
+    assert_eq!(receipt.released_amount, remaining);
+    assert_eq!(receipt.carrier, carrier);
+    assert_eq!(receipt.timestamp, env.ledger().timestamp());
+    assert_eq!(TokenClient::new(&env, &token).balance(&carrier), 1_000);
+    assert_eq!(client.get_shipment(&id).escrow_amount, 0);
+}
@@ -0,0 +1,79 @@
+//! Tests for auto-advancing `Created` shipments on the departure checkpoint.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+
+fn setup(departure: bool) -> (Env, NavinShipmentClient<'static>, Address, u64) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    if departure {
+        let mut cfg = client.get_contract_config();
+        cfg.departure_checkpoint = Some(symbol_short!("pickup"));
+        client.update_config(&admin, &cfg);
+    }
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    (env, client, carrier, id)
+}
+
+#[test]
+fn test_departure_checkpoint_advances_to_in_transit() {
+    let (env, client, carrier, id) = setup(true);
+
+    client.record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("pickup"),
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+    let history = client.get_status_history(&id);
+    assert_eq!(
+        history.get(history.len() - 1).unwrap().0,
+        ShipmentStatus::InTransit
+    );
+}
+
+#[test]
+fn test_other_checkpoint_on_created_still_rejected() {
+    let (env, client, carrier, id) = setup(true);
+
+    let result = client.try_record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    assert_eq!(result, Err(Ok(NavinError::InvalidStatus)));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+}
+
+#[test]
+fn test_no_auto_advance_by_default() {
+    let (env, client, carrier, id) = setup(false);
+
+    let result = client.try_record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("pickup"),
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    assert_eq!(result, Err(Ok(NavinError::InvalidStatus)));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+}
@@ -0,0 +1,220 @@
+//! Tests for `raise_dispute_with_bond` and bond settlement on resolution.
+
+use crate::{test_utils, DisputeResolution, NavinError, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const ESCROW: i128 = 1_000;
+const BOND: i128 = 100;
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    receiver: Address,
+    carrier: Address,
+    token: Address,
+    shipment_id: u64,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let sac = StellarAssetClient::new(&env, &token);
+    sac.mint(&company, &ESCROW);
+    sac.mint(&receiver, &BOND);
+    sac.mint(&carrier, &BOND);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 3600),
+    );
+    client.deposit_escrow(&company, &shipment_id, &ESCROW);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        receiver,
+        carrier,
+        token,
+        shipment_id,
+    }
+}
+
+fn balance(ctx: &Ctx, who: &Address) -> i128 {
+    TokenClient::new(&ctx.env, &ctx.token).balance(who)
+}
+
+fn resolve(ctx: &Ctx, resolution: DisputeResolution) {
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &ctx.shipment_id,
+        &resolution,
+        &BytesN::from_array(&ctx.env, &[8u8; 32]),
+    );
+}
+
+fn reason(ctx: &Ctx) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[9u8; 32])
+}
+
+#[test]
+fn test_bond_is_locked_on_dispute() {
+    let ctx = setup();
+    ctx.client
+        .raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    assert_eq!(balance(&ctx, &ctx.receiver), 0);
+    assert_eq!(balance(&ctx, &ctx.client.address), ESCROW + BOND);
+}
+
+#[test]
+fn test_bond_returned_when_disputer_wins() {
+    let ctx = setup();
+    ctx.client
+        .raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    resolve(&ctx, DisputeResolution::RefundToCompany);
+
+    assert_eq!(balance(&ctx, &ctx.receiver), BOND);
+    assert_eq!(balance(&ctx, &ctx.company), ESCROW);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_bond_forfeited_to_carrier_when_disputer_loses() {
+    let ctx = setup();
+    ctx.client
+        .raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    resolve(&ctx, DisputeResolution::ReleaseToCarrier);
+
+    assert_eq!(balance(&ctx, &ctx.receiver), 0);
+    assert_eq!(balance(&ctx, &ctx.carrier), BOND + ESCROW + BOND);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_carrier_bond_forfeited_to_sender_on_refund() {
+    let ctx = setup();
+    ctx.client
+        .raise_dispute_with_bond(&ctx.carrier, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    resolve(&ctx, DisputeResolution::RefundToCompany);
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 0);
+    assert_eq!(balance(&ctx, &ctx.company), ESCROW + BOND);
+}
+
+#[test]
+fn test_zero_bond_rejected() {
+    let ctx = setup();
+    let result =
+        ctx.client
+            .try_raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &0);
+    assert_eq!(result, Err(Ok(NavinError::InvalidAmount)));
+}
+
+#[test]
+fn test_bond_rejected_on_unfunded_shipment() {
+    let ctx = setup();
+    let unfunded = ctx.client.create_shipment(
+        &ctx.company,
+        &ctx.receiver,
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+        &Vec::new(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, 3600),
+    );
+
+    let result =
+        ctx.client
+            .try_raise_dispute_with_bond(&ctx.receiver, &unfunded, &reason(&ctx), &BOND);
+    assert_eq!(result, Err(Ok(NavinError::InsufficientFunds)));
+    assert_eq!(balance(&ctx, &ctx.receiver), BOND);
+}
+
+#[test]
+fn test_bond_returned_on_force_cancel() {
+    let ctx = setup();
+    ctx.client
+        .raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    ctx.client.force_cancel_shipment(
+        &ctx.admin,
+        &ctx.shipment_id,
+        &BytesN::from_array(&ctx.env, &[7u8; 32]),
+    );
+
+    assert_eq!(balance(&ctx, &ctx.receiver), BOND);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+    assert_eq!(ctx.client.get_outstanding_liabilities(), 0);
+}
+
+#[test]
+fn test_bond_settled_on_multisig_force_release() {
+    let ctx = setup();
+    let admin2 = Address::generate(&ctx.env);
+    let mut admins = Vec::new(&ctx.env);
+    admins.push_back(ctx.admin.clone());
+    admins.push_back(admin2.clone());
+    ctx.client.init_multisig(&ctx.admin, &admins, &2);
+
+    ctx.client
+        .raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    let proposal_id = ctx.client.propose_action(
+        &ctx.admin,
+        &crate::AdminAction::ForceRelease(ctx.shipment_id),
+    );
+    ctx.client.approve_action(&admin2, &proposal_id);
+    ctx.client.execute_proposal(&proposal_id);
+
+    assert_eq!(balance(&ctx, &ctx.receiver), 0);
+    assert_eq!(balance(&ctx, &ctx.carrier), BOND + ESCROW + BOND);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+    assert_eq!(ctx.client.get_outstanding_liabilities(), 0);
+}
+
+#[test]
+fn test_bond_returned_on_multisig_force_refund() {
+    let ctx = setup();
+    let admin2 = Address::generate(&ctx.env);
+    let mut admins = Vec::new(&ctx.env);
+    admins.push_back(ctx.admin.clone());
+    admins.push_back(admin2.clone());
+    ctx.client.init_multisig(&ctx.admin, &admins, &2);
+
+    ctx.client
+        .raise_dispute_with_bond(&ctx.receiver, &ctx.shipment_id, &reason(&ctx), &BOND);
+
+    let proposal_id = ctx.client.propose_action(
+        &ctx.admin,
+        &crate::AdminAction::ForceRefund(ctx.shipment_id),
+    );
+    ctx.client.approve_action(&admin2, &proposal_id);
+    ctx.client.execute_proposal(&proposal_id);
+
+    assert_eq!(balance(&ctx, &ctx.receiver), BOND);
+    assert_eq!(balance(&ctx, &ctx.company), ESCROW);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
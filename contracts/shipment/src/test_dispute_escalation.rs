@@ -0,0 +1,119 @@
+//! Tests for dispute escalation to multisig resolution.
+
+use crate::test::*;
+use crate::test_utils::dummy_hash;
+use crate::types::*;
+use crate::NavinError;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Dispute {
+    shipment_id: u64,
+    receiver: Address,
+    admin1: Address,
+    admin2: Address,
+}
+
+fn disputed_shipment(env: &Env, client: &crate::NavinShipmentClient, admin: &Address) -> Dispute {
+    let admin1 = Address::generate(env);
+    let admin2 = Address::generate(env);
+    let mut admins = Vec::new(env);
+    admins.push_back(admin1.clone());
+    admins.push_back(admin2.clone());
+    admins.push_back(Address::generate(env));
+    client.init_multisig(admin, &admins, &2);
+
+    let company = Address::generate(env);
+    let receiver = Address::generate(env);
+    client.add_company(admin, &company);
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &Address::generate(env),
+        &dummy_hash(env),
+        &Vec::new(env),
+        &(env.ledger().timestamp() + 3600),
+    );
+    client.deposit_escrow(&company, &shipment_id, &1_000);
+    client.raise_dispute(
+        &receiver,
+        &shipment_id,
+        &BytesN::from_array(env, &[5u8; 32]),
+    );
+
+    Dispute {
+        shipment_id,
+        receiver,
+        admin1,
+        admin2,
+    }
+}
+
+#[test]
+fn test_escalated_dispute_rejects_lone_admin_resolution() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let d = disputed_shipment(&env, &client, &admin);
+
+    client.escalate_dispute(&d.receiver, &d.shipment_id);
+
+    let reason = BytesN::from_array(&env, &[6u8; 32]);
+    let result = client.try_resolve_dispute(
+        &admin,
+        &d.shipment_id,
+        &DisputeResolution::RefundToCompany,
+        &reason,
+    );
+    assert_eq!(result, Err(Ok(NavinError::DisputeEscalated)));
+
+    let split = client.try_resolve_dispute_split(&admin, &d.shipment_id, &5_000, &reason);
+    assert_eq!(split, Err(Ok(NavinError::DisputeEscalated)));
+    assert_eq!(
+        client.get_shipment(&d.shipment_id).status,
+        ShipmentStatus::Disputed
+    );
+}
+
+#[test]
+fn test_escalated_dispute_resolved_by_passed_proposal() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let d = disputed_shipment(&env, &client, &admin);
+
+    client.escalate_dispute(&d.receiver, &d.shipment_id);
+
+    let action = AdminAction::ResolveDispute(
+        d.shipment_id,
+        DisputeResolution::RefundToCompany,
+        BytesN::from_array(&env, &[6u8; 32]),
+    );
+    let proposal_id = client.propose_action(&d.admin1, &action);
+
+    // A single approval (the proposer's) is not enough.
+    assert_eq!(
+        client.get_shipment(&d.shipment_id).status,
+        ShipmentStatus::Disputed
+    );
+
+    client.approve_action(&d.admin2, &proposal_id);
+
+    let shipment = client.get_shipment(&d.shipment_id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.escrow_amount, 0);
+}
+
+#[test]
+fn test_escalate_requires_party_and_disputed_status() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let d = disputed_shipment(&env, &client, &admin);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_escalate_dispute(&outsider, &d.shipment_id),
+        Err(Ok(NavinError::Unauthorized))
+    );
+
+    client.escalate_dispute(&d.receiver, &d.shipment_id);
+    assert_eq!(
+        client.try_escalate_dispute(&d.receiver, &d.shipment_id),
+        Err(Ok(NavinError::DisputeEscalated))
+    );
+}
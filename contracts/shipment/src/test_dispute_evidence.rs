@@ -0,0 +1,105 @@
+//! Tests for dispute evidence submission and the `get_dispute_evidence` listing.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{ContractConfig, NavinError, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+
+struct Parties {
+    company: Address,
+    receiver: Address,
+    carrier: Address,
+}
+
+fn disputed_shipment(env: &Env, client: &NavinShipmentClient, admin: &Address) -> (u64, Parties) {
+    let parties = Parties {
+        company: Address::generate(env),
+        receiver: Address::generate(env),
+        carrier: Address::generate(env),
+    };
+    client.add_company(admin, &parties.company);
+    client.add_carrier(admin, &parties.carrier);
+    let id = client.create_shipment(
+        &parties.company,
+        &parties.receiver,
+        &parties.carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    client.raise_dispute(&parties.company, &id, &BytesN::from_array(env, &[9u8; 32]));
+    (id, parties)
+}
+
+#[test]
+fn test_parties_submit_evidence_in_order() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, parties) = disputed_shipment(&env, &client, &admin);
+    let first = BytesN::from_array(&env, &[1u8; 32]);
+    let second = BytesN::from_array(&env, &[2u8; 32]);
+    let third = BytesN::from_array(&env, &[3u8; 32]);
+
+    assert_eq!(client.get_dispute_evidence(&id), Vec::new(&env));
+
+    client.add_dispute_evidence_hash(&parties.company, &id, &first);
+    client.add_dispute_evidence_hash(&parties.receiver, &id, &second);
+    client.add_dispute_evidence_hash(&parties.carrier, &id, &third);
+
+    assert_eq!(
+        client.get_dispute_evidence(&id),
+        vec![&env, first, second, third]
+    );
+}
+
+#[test]
+fn test_non_party_evidence_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (id, _parties) = disputed_shipment(&env, &client, &admin);
+
+    assert_eq!(
+        client.try_add_dispute_evidence_hash(
+            &Address::generate(&env),
+            &id,
+            &BytesN::from_array(&env, &[1u8; 32]),
+        ),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(client.get_dispute_evidence(&id).len(), 0);
+}
+
+#[test]
+fn test_evidence_cap_enforced() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            max_evidence_per_dispute: 2,
+            ..ContractConfig::default()
+        },
+    );
+    let (id, parties) = disputed_shipment(&env, &client, &admin);
+
+    client.add_dispute_evidence_hash(&parties.company, &id, &BytesN::from_array(&env, &[1u8; 32]));
+    client.add_dispute_evidence_hash(&parties.carrier, &id, &BytesN::from_array(&env, &[2u8; 32]));
+
+    assert_eq!(
+        client.try_add_dispute_evidence_hash(
+            &parties.receiver,
+            &id,
+            &BytesN::from_array(&env, &[3u8; 32]),
+        ),
+        Err(Ok(NavinError::EvidenceLimitExceeded))
+    );
+    assert_eq!(client.get_dispute_evidence(&id).len(), 2);
+}
+
+#[test]
+fn test_get_dispute_evidence_unknown_shipment() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+
+    assert_eq!(
+        client.try_get_dispute_evidence(&999),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
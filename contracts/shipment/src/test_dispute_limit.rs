@@ -0,0 +1,93 @@
+//! Tests for the `max_disputes_per_shipment` limit.
+//!
+//! Resolving a dispute currently leaves the shipment terminal, so earlier
+//! dispute rounds are simulated by seeding the per-shipment dispute counter.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{
+    ContractConfig, DataKey, DisputePolicy, NavinError, NavinShipmentClient, ShipmentStatus,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    prior_disputes: u32,
+) -> (Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeCount(id), &prior_disputes);
+    });
+    (company, id)
+}
+
+fn dispute_count(env: &Env, client: &NavinShipmentClient, id: u64) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputeCount(id))
+            .unwrap_or(0)
+    })
+}
+
+#[test]
+fn test_dispute_allowed_below_cap_and_counted() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = setup_shipment(&env, &client, &admin, 2);
+
+    client.raise_dispute(&company, &id, &BytesN::from_array(&env, &[9u8; 32]));
+
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Disputed);
+    assert_eq!(dispute_count(&env, &client, id), 3);
+}
+
+#[test]
+fn test_dispute_rejected_at_cap() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = setup_shipment(&env, &client, &admin, 3);
+
+    assert_eq!(
+        client.try_raise_dispute(&company, &id, &BytesN::from_array(&env, &[9u8; 32])),
+        Err(Ok(NavinError::DisputeLimitReached))
+    );
+    assert_eq!(
+        client.try_update_status(&admin, &id, &ShipmentStatus::Disputed, &dummy_hash(&env)),
+        Err(Ok(NavinError::DisputeLimitReached))
+    );
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+}
+
+#[test]
+fn test_dispute_limit_disabled_with_zero() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            dispute_policy: DisputePolicy {
+                max_disputes_per_shipment: 0,
+                ..DisputePolicy::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    let (company, id) = setup_shipment(&env, &client, &admin, 10);
+
+    client.raise_dispute(&company, &id, &BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Disputed);
+}
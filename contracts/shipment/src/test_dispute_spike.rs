@@ -0,0 +1,97 @@
+//! Tests for automatic pausing on dispute spikes.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup(threshold: u32) -> (Env, NavinShipmentClient<'static>, Address, Address, Address) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut cfg = client.get_contract_config();
+    cfg.dispute_policy.dispute_spike_threshold = threshold;
+    cfg.dispute_policy.dispute_spike_window = 3_600;
+    client.update_config(&admin, &cfg);
+
+    (env, client, admin, company, carrier)
+}
+
+fn create_and_dispute(
+    env: &Env,
+    client: &NavinShipmentClient,
+    company: &Address,
+    carrier: &Address,
+    seed: u8,
+) {
+    let id = client.create_shipment(
+        company,
+        &Address::generate(env),
+        carrier,
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &test_utils::future_deadline(env, 86_400),
+    );
+    client.raise_dispute(company, &id, &BytesN::from_array(env, &[seed; 32]));
+}
+
+#[test]
+fn test_dispute_spike_auto_pauses() {
+    let (env, client, _admin, company, carrier) = setup(3);
+
+    create_and_dispute(&env, &client, &company, &carrier, 1);
+    create_and_dispute(&env, &client, &company, &carrier, 2);
+    assert!(!client.is_paused());
+
+    create_and_dispute(&env, &client, &company, &carrier, 3);
+    assert!(client.is_paused());
+}
+
+#[test]
+fn test_disputes_outside_window_do_not_pause() {
+    let (env, client, _admin, company, carrier) = setup(3);
+
+    create_and_dispute(&env, &client, &company, &carrier, 1);
+    create_and_dispute(&env, &client, &company, &carrier, 2);
+    env.ledger().with_mut(|l| l.timestamp += 3_600);
+    create_and_dispute(&env, &client, &company, &carrier, 3);
+
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_auto_pause_disabled_by_default() {
+    let (env, client, _admin, company, carrier) = setup(0);
+
+    for seed in 1..=5 {
+        create_and_dispute(&env, &client, &company, &carrier, seed);
+    }
+
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_only_admin_can_lift_auto_pause() {
+    let (env, client, admin, company, carrier) = setup(2);
+    let guardian = Address::generate(&env);
+    client.add_guardian(&admin, &guardian);
+
+    create_and_dispute(&env, &client, &company, &carrier, 1);
+    create_and_dispute(&env, &client, &company, &carrier, 2);
+    assert!(client.is_paused());
+
+    let result = client.try_unpause(&guardian);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+    assert!(client.is_paused());
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+
+    // A later manual pause can again be lifted by a guardian.
+    client.pause(&guardian);
+    client.unpause(&guardian);
+    assert!(!client.is_paused());
+}
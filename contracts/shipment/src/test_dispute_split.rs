@@ -0,0 +1,199 @@
+//! Tests for split dispute resolution (`resolve_dispute_split`).
+//!
+//! Verifies that the escrow is divided between carrier and company by basis
+//! points, that both transfers land and are reported through `escrow_released`
+//! and `escrow_refunded`, that the resolution note is recorded, and that
+//! out-of-range shares are rejected.
+
+extern crate std;
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{
+    token::StellarAssetClient, Address, BytesN, Env, FromVal, Symbol, TryFromVal, Vec,
+};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    carrier: Address,
+    token: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let contract_id = env.register(NavinShipment, ());
+    let client = NavinShipmentClient::new(&env, &contract_id);
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        carrier,
+        token,
+    }
+}
+
+fn disputed_shipment(ctx: &Ctx, amount: i128) -> u64 {
+    StellarAssetClient::new(&ctx.env, &ctx.token).mint(&ctx.company, &amount);
+    let deadline = ctx.env.ledger().timestamp() + 3600;
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &Vec::new(&ctx.env),
+        &deadline,
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &amount);
+    ctx.client
+        .raise_dispute(&ctx.company, &id, &BytesN::from_array(&ctx.env, &[9u8; 32]));
+    id
+}
+
+fn reason(ctx: &Ctx) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[4u8; 32])
+}
+
+fn balance(ctx: &Ctx, who: &Address) -> i128 {
+    soroban_sdk::token::TokenClient::new(&ctx.env, &ctx.token).balance(who)
+}
+
+#[test]
+fn split_70_30_transfers_both_legs() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+
+    ctx.client
+        .resolve_dispute_split(&ctx.admin, &id, &7_000, &reason(&ctx));
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 7_000);
+    assert_eq!(balance(&ctx, &ctx.company), 3_000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+
+    let shipment = ctx.client.get_shipment(&id);
+    assert_eq!(shipment.escrow_amount, 0);
+    assert_eq!(shipment.status, ShipmentStatus::Delivered);
+    assert!(shipment.finalized);
+}
+
+#[test]
+fn split_favouring_company_cancels_shipment() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+
+    ctx.client
+        .resolve_dispute_split(&ctx.admin, &id, &5_000, &reason(&ctx));
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 5_000);
+    assert_eq!(balance(&ctx, &ctx.company), 5_000);
+    assert_eq!(
+        ctx.client.get_shipment(&id).status,
+        ShipmentStatus::Cancelled
+    );
+}
+
+#[test]
+fn split_above_full_share_is_rejected() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+
+    let result = ctx
+        .client
+        .try_resolve_dispute_split(&ctx.admin, &id, &10_001, &reason(&ctx));
+    assert_eq!(result, Err(Ok(NavinError::InvalidAmount)));
+    assert_eq!(ctx.client.get_escrow_balance(&id), 10_000);
+}
+
+#[test]
+fn split_by_non_admin_is_rejected() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+    let outsider = Address::generate(&ctx.env);
+
+    let result = ctx
+        .client
+        .try_resolve_dispute_split(&outsider, &id, &7_000, &reason(&ctx));
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+/// Amounts carried by every event published under `topic`, in order.
+fn escrow_event_amounts(ctx: &Ctx, topic: &str) -> std::vec::Vec<(Address, i128)> {
+    let topic = Symbol::new(&ctx.env, topic);
+    let mut amounts = std::vec::Vec::new();
+    for (_, topics, data) in ctx.env.events().all().iter() {
+        let matches = topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(&ctx.env, &t).ok())
+            .is_some_and(|t| t == topic);
+        if matches {
+            let (_id, to, _token, amount, _version, _counter, _key): (
+                u64,
+                Address,
+                Address,
+                i128,
+                u32,
+                u32,
+                BytesN<32>,
+            ) = FromVal::from_val(&ctx.env, &data);
+            amounts.push((to, amount));
+        }
+    }
+    amounts
+}
+
+#[test]
+fn split_emits_released_and_refunded_amounts() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+
+    ctx.client
+        .resolve_dispute_split(&ctx.admin, &id, &5_000, &reason(&ctx));
+
+    assert_eq!(
+        escrow_event_amounts(&ctx, "escrow_released"),
+        std::vec![(ctx.carrier.clone(), 5_000)]
+    );
+    assert_eq!(
+        escrow_event_amounts(&ctx, "escrow_refunded"),
+        std::vec![(ctx.company.clone(), 5_000)]
+    );
+}
+
+#[test]
+fn split_records_resolution_note() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+
+    ctx.client
+        .resolve_dispute_split(&ctx.admin, &id, &7_000, &reason(&ctx));
+
+    assert_eq!(ctx.client.get_resolution_note(&id), Some(reason(&ctx)));
+}
+
+#[test]
+fn split_without_reason_is_rejected() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 10_000);
+
+    let result = ctx.client.try_resolve_dispute_split(
+        &ctx.admin,
+        &id,
+        &7_000,
+        &BytesN::from_array(&ctx.env, &[0u8; 32]),
+    );
+    assert_eq!(result, Err(Ok(NavinError::DisputeReasonHashMissing)));
+    assert_eq!(ctx.client.get_escrow_balance(&id), 10_000);
+}
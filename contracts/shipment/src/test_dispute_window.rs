@@ -0,0 +1,98 @@
+//! Tests for `auto_resolve_dispute` and the `dispute_window_seconds` setting.
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, dummy_hash, future_deadline};
+use crate::{ContractConfig, DisputePolicy, NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn disputed_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    dispute_window_seconds: u64,
+) -> u64 {
+    client.update_config(
+        admin,
+        &ContractConfig {
+            dispute_policy: DisputePolicy {
+                dispute_window_seconds,
+                ..DisputePolicy::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    client.deposit_escrow(&company, &id, &5_000);
+    client.raise_dispute(&company, &id, &BytesN::from_array(env, &[9u8; 32]));
+    id
+}
+
+#[test]
+fn test_auto_resolve_before_window_fails() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let id = disputed_shipment(&env, &client, &admin, 3_600);
+
+    advance_ledger_time(&env, 3_599);
+    assert_eq!(
+        client.try_auto_resolve_dispute(&id),
+        Err(Ok(NavinError::NotExpired))
+    );
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Disputed);
+}
+
+#[test]
+fn test_auto_resolve_after_window_refunds_company() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let id = disputed_shipment(&env, &client, &admin, 3_600);
+
+    advance_ledger_time(&env, 3_600);
+    client.auto_resolve_dispute(&id);
+
+    let shipment = client.get_shipment(&id);
+    assert_eq!(shipment.status, ShipmentStatus::Cancelled);
+    assert_eq!(shipment.escrow_amount, 0);
+    assert_eq!(client.get_escrow_balance(&id), 0);
+    assert!(client.get_resolution_note(&id).is_some());
+}
+
+#[test]
+fn test_auto_resolve_disabled_by_default() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let id = disputed_shipment(&env, &client, &admin, 0);
+
+    advance_ledger_time(&env, 30 * 86_400);
+    assert_eq!(
+        client.try_auto_resolve_dispute(&id),
+        Err(Ok(NavinError::NotExpired))
+    );
+}
+
+#[test]
+fn test_auto_resolve_requires_disputed_status() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let id = disputed_shipment(&env, &client, &admin, 3_600);
+    client.resolve_dispute(
+        &admin,
+        &id,
+        &crate::DisputeResolution::ReleaseToCarrier,
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+
+    advance_ledger_time(&env, 3_600);
+    assert_eq!(
+        client.try_auto_resolve_dispute(&id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
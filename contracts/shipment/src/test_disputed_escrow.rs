@@ -0,0 +1,115 @@
+//! Tests for `get_disputed_escrow_total`.
+
+use crate::{test_utils, DisputeResolution, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    carrier: Address,
+    token: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        carrier,
+        token,
+    }
+}
+
+fn funded_shipment(ctx: &Ctx, seed: u8, amount: i128) -> u64 {
+    StellarAssetClient::new(&ctx.env, &ctx.token).mint(&ctx.company, &amount);
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[seed; 32]),
+        &Vec::new(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, 86_400),
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &amount);
+    id
+}
+
+fn dispute(ctx: &Ctx, id: u64) {
+    ctx.client
+        .raise_dispute(&ctx.company, &id, &BytesN::from_array(&ctx.env, &[9u8; 32]));
+}
+
+#[test]
+fn test_disputed_escrow_rises_and_falls() {
+    let ctx = setup();
+    let first = funded_shipment(&ctx, 1, 1_000);
+    let second = funded_shipment(&ctx, 2, 400);
+    let _undisputed = funded_shipment(&ctx, 3, 250);
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 0);
+
+    dispute(&ctx, first);
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 1_000);
+    dispute(&ctx, second);
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 1_400);
+
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &first,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&ctx.env, &[8u8; 32]),
+    );
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 400);
+
+    ctx.client.resolve_dispute_split(
+        &ctx.admin,
+        &second,
+        &5_000,
+        &BytesN::from_array(&ctx.env, &[8u8; 32]),
+    );
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 0);
+}
+
+#[test]
+fn test_unfunded_dispute_adds_nothing() {
+    let ctx = setup();
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &Vec::new(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, 86_400),
+    );
+
+    dispute(&ctx, id);
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 0);
+}
+
+#[test]
+fn test_force_cancel_of_disputed_shipment_clears_total() {
+    let ctx = setup();
+    let id = funded_shipment(&ctx, 1, 700);
+    dispute(&ctx, id);
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 700);
+
+    ctx.client
+        .force_cancel_shipment(&ctx.admin, &id, &BytesN::from_array(&ctx.env, &[7u8; 32]));
+    assert_eq!(ctx.client.get_disputed_escrow_total(), 0);
+}
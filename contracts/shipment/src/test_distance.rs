@@ -0,0 +1,86 @@
+//! Tests for carrier-reported distance accumulation.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+/// Create a shipment with a registered carrier and force it into `status`.
+fn shipment_with_status(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    status: ShipmentStatus,
+) -> (Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 3600),
+    );
+    env.as_contract(&client.address, || {
+        let mut shipment = crate::storage::get_shipment(env, shipment_id).unwrap();
+        shipment.status = status;
+        crate::storage::set_shipment(env, &shipment);
+    });
+    (carrier, shipment_id)
+}
+
+#[test]
+fn test_record_distance_accumulates() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = shipment_with_status(&env, &client, &admin, ShipmentStatus::InTransit);
+
+    assert_eq!(client.get_distance(&id), 0);
+    assert_eq!(client.record_distance(&carrier, &id, &1_500), 1_500);
+    assert_eq!(client.record_distance(&carrier, &id, &2_250), 3_750);
+    assert_eq!(client.get_distance(&id), 3_750);
+}
+
+#[test]
+fn test_record_distance_rejects_overflow() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = shipment_with_status(&env, &client, &admin, ShipmentStatus::InTransit);
+
+    client.record_distance(&carrier, &id, &(u64::MAX - 10));
+    let result = client.try_record_distance(&carrier, &id, &11);
+    assert_eq!(result, Err(Ok(NavinError::ArithmeticError)));
+    assert_eq!(client.get_distance(&id), u64::MAX - 10);
+}
+
+#[test]
+fn test_record_distance_requires_assigned_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_carrier, id) = shipment_with_status(&env, &client, &admin, ShipmentStatus::InTransit);
+
+    let other = Address::generate(&env);
+    client.add_carrier(&admin, &other);
+    let result = client.try_record_distance(&other, &id, &100);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_record_distance_requires_in_transit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = shipment_with_status(&env, &client, &admin, ShipmentStatus::Created);
+
+    let result = client.try_record_distance(&carrier, &id, &100);
+    assert_eq!(result, Err(Ok(NavinError::InvalidStatus)));
+}
+
+#[test]
+fn test_get_distance_unknown_shipment() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+    assert_eq!(
+        client.try_get_distance(&999),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
@@ -0,0 +1,37 @@
+//! Tests for `error_catalog`.
+
+use crate::{NavinError, NavinShipment, NavinShipmentClient};
+use soroban_sdk::{Env, Symbol};
+
+#[test]
+fn test_error_catalog_contains_known_codes() {
+    let env = Env::default();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+
+    let catalog = client.error_catalog();
+
+    assert_eq!(
+        catalog.get(NavinError::AlreadyInitialized as u32),
+        Some(Symbol::new(&env, "AlreadyInitialized"))
+    );
+    assert_eq!(catalog.get(3), Some(Symbol::new(&env, "Unauthorized")));
+    assert_eq!(catalog.get(4), Some(Symbol::new(&env, "ShipmentNotFound")));
+    assert_eq!(
+        catalog.get(NavinError::CheckpointAckMismatch as u32),
+        Some(Symbol::new(&env, "CheckpointAckMismatch"))
+    );
+    assert_eq!(catalog.get(0), None);
+}
+
+#[test]
+fn test_error_catalog_covers_every_variant() {
+    let env = Env::default();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+
+    let catalog = client.error_catalog();
+
+    for (error, _) in crate::errors::ERROR_CATALOG.iter() {
+        let code = *error as u32;
+        assert!(catalog.contains_key(code), "missing error code {code}");
+    }
+}
@@ -0,0 +1,128 @@
+//! Tests for `get_escrow_breakdown` and the `locked + released + refunded == total`
+//! accounting identity.
+
+use crate::{test_utils, DisputeResolution, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    carrier: Address,
+    token: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        carrier,
+        token,
+    }
+}
+
+fn funded_shipment(ctx: &Ctx, seed: u8, amount: i128) -> u64 {
+    StellarAssetClient::new(&ctx.env, &ctx.token).mint(&ctx.company, &amount);
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[seed; 32]),
+        &Vec::new(&ctx.env),
+        &(ctx.env.ledger().timestamp() + 3600),
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &amount);
+    id
+}
+
+fn assert_identity(ctx: &Ctx) {
+    let (locked, released, refunded, total) = ctx.client.get_escrow_breakdown();
+    assert_eq!(locked + released + refunded, total);
+}
+
+#[test]
+fn test_breakdown_empty_after_initialize() {
+    let ctx = setup();
+    assert_eq!(ctx.client.get_escrow_breakdown(), (0, 0, 0, 0));
+}
+
+#[test]
+fn test_breakdown_tracks_deposits_releases_and_refunds() {
+    let ctx = setup();
+
+    let released = funded_shipment(&ctx, 1, 1_000);
+    let refunded = funded_shipment(&ctx, 2, 400);
+    let _locked = funded_shipment(&ctx, 3, 250);
+    assert_eq!(ctx.client.get_escrow_breakdown(), (1_650, 0, 0, 1_650));
+
+    ctx.client.raise_dispute(
+        &ctx.company,
+        &released,
+        &BytesN::from_array(&ctx.env, &[9u8; 32]),
+    );
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &released,
+        &DisputeResolution::ReleaseToCarrier,
+        &BytesN::from_array(&ctx.env, &[8u8; 32]),
+    );
+    assert_eq!(ctx.client.get_escrow_breakdown(), (650, 1_000, 0, 1_650));
+    assert_identity(&ctx);
+
+    ctx.client.refund_escrow(&ctx.company, &refunded);
+    assert_eq!(ctx.client.get_escrow_breakdown(), (250, 1_000, 400, 1_650));
+    assert_identity(&ctx);
+}
+
+#[test]
+fn test_breakdown_split_resolution_keeps_identity() {
+    let ctx = setup();
+    let id = funded_shipment(&ctx, 1, 1_000);
+    ctx.client
+        .raise_dispute(&ctx.company, &id, &BytesN::from_array(&ctx.env, &[9u8; 32]));
+    ctx.client.resolve_dispute_split(
+        &ctx.admin,
+        &id,
+        &7_000,
+        &BytesN::from_array(&ctx.env, &[8u8; 32]),
+    );
+
+    assert_eq!(ctx.client.get_escrow_breakdown(), (0, 700, 300, 1_000));
+    assert_identity(&ctx);
+}
+
+#[test]
+fn test_breakdown_batch_cancel_books_refund_once_transferred() {
+    let ctx = setup();
+    let first = funded_shipment(&ctx, 1, 300);
+    let second = funded_shipment(&ctx, 2, 200);
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+
+    ctx.client.cancel_shipments_batch(
+        &ctx.company,
+        &vec![&ctx.env, first, second],
+        &BytesN::from_array(&ctx.env, &[9u8; 32]),
+    );
+
+    assert_eq!(ctx.client.get_escrow_breakdown(), (0, 0, 500, 500));
+    assert_eq!(token.balance(&ctx.company), 500);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_identity(&ctx);
+}
@@ -0,0 +1,115 @@
+//! Tests for the optional oracle-based minimum escrow value check.
+
+use crate::test::*;
+use crate::test_utils::future_deadline;
+use crate::{ContractConfig, NavinError, NavinShipmentClient, ORACLE_PRICE_SCALE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, Vec};
+
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, price: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("price"), &price);
+    }
+
+    pub fn price(env: Env, _asset: Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("price"))
+            .unwrap_or(0)
+    }
+}
+
+/// Creates a shipment and configures an oracle quoting `price` with a
+/// minimum escrow value of 100 USD.
+fn setup_with_oracle(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    price: i128,
+) -> (Address, u64) {
+    let oracle = env.register(MockOracle, ());
+    MockOracleClient::new(env, &oracle).set_price(&price);
+    client.update_config(
+        admin,
+        &ContractConfig {
+            price_oracle: Some(oracle),
+            min_escrow_usd: 100 * ORACLE_PRICE_SCALE,
+            ..ContractConfig::default()
+        },
+    );
+
+    let company = Address::generate(env);
+    client.add_company(admin, &company);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &Address::generate(env),
+        &BytesN::from_array(env, &[7u8; 32]),
+        &Vec::new(env),
+        &future_deadline(env, 3600),
+    );
+    (company, id)
+}
+
+#[test]
+fn test_deposit_above_minimum_value_succeeds() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    // 1 token = 2 USD, so 100 tokens is worth 200 USD.
+    let (company, id) = setup_with_oracle(&env, &client, &admin, 2 * ORACLE_PRICE_SCALE);
+
+    client.deposit_escrow(&company, &id, &(100 * ORACLE_PRICE_SCALE));
+    assert_eq!(
+        client.get_shipment(&id).escrow_amount,
+        100 * ORACLE_PRICE_SCALE
+    );
+}
+
+#[test]
+fn test_deposit_below_minimum_value_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    // 1 token = 0.5 USD, so 100 tokens is only worth 50 USD.
+    let (company, id) = setup_with_oracle(&env, &client, &admin, ORACLE_PRICE_SCALE / 2);
+
+    let result = client.try_deposit_escrow(&company, &id, &(100 * ORACLE_PRICE_SCALE));
+    assert_eq!(result, Err(Ok(NavinError::EscrowBelowMinimumValue)));
+}
+
+#[test]
+fn test_deposit_rejected_when_oracle_price_invalid() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = setup_with_oracle(&env, &client, &admin, 0);
+
+    let result = client.try_deposit_escrow(&company, &id, &(100 * ORACLE_PRICE_SCALE));
+    assert_eq!(result, Err(Ok(NavinError::PriceOracleUnavailable)));
+}
+
+#[test]
+fn test_check_skipped_without_oracle() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            min_escrow_usd: 100 * ORACLE_PRICE_SCALE,
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[8u8; 32]),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+
+    client.deposit_escrow(&company, &id, &1);
+    assert_eq!(client.get_shipment(&id).escrow_amount, 1);
+}
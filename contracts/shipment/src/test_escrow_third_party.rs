@@ -0,0 +1,76 @@
+//! Tests for restricting escrow deposits to the shipment's sender.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    token: Address,
+    other: Address,
+    id: u64,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let sender = Address::generate(&env);
+    let other = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &sender);
+    client.add_company(&admin, &other);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &sender,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&other, &1_000);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        token,
+        other,
+        id,
+    }
+}
+
+#[test]
+fn test_non_sender_deposit_rejected_by_default() {
+    let ctx = setup();
+
+    let result = ctx.client.try_deposit_escrow(&ctx.other, &ctx.id, &500);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+    assert_eq!(ctx.client.get_escrow_balance(&ctx.id), 0);
+}
+
+#[test]
+fn test_non_sender_deposit_allowed_when_enabled() {
+    let ctx = setup();
+
+    let mut cfg = ctx.client.get_contract_config();
+    cfg.escrow_policy.allow_third_party_escrow = true;
+    ctx.client.update_config(&ctx.admin, &cfg);
+
+    ctx.client.deposit_escrow(&ctx.other, &ctx.id, &500);
+    assert_eq!(ctx.client.get_escrow_balance(&ctx.id), 500);
+    assert_eq!(
+        TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.other),
+        500
+    );
+}
@@ -0,0 +1,122 @@
+//! Tests for `top_up_escrow` adding funds to an existing deposit.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    token: Address,
+    company: Address,
+    carrier: Address,
+    id: u64,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut milestones = Vec::new(&env);
+    milestones.push_back((symbol_short!("port"), 40));
+    milestones.push_back((symbol_short!("dropoff"), 60));
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &2_000);
+    client.deposit_escrow(&company, &id, &1_000);
+
+    Ctx {
+        env,
+        client,
+        token,
+        company,
+        carrier,
+        id,
+    }
+}
+
+#[test]
+fn test_top_up_accumulates_escrow() {
+    let ctx = setup();
+    ctx.client.top_up_escrow(&ctx.company, &ctx.id, &500);
+
+    let shipment = ctx.client.get_shipment(&ctx.id);
+    assert_eq!(shipment.escrow_amount, 1_500);
+    assert_eq!(shipment.total_escrow, 1_500);
+    assert_eq!(
+        TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.client.address),
+        1_500
+    );
+    assert_eq!(ctx.client.get_analytics().total_escrow_volume, 1_500);
+}
+
+#[test]
+fn test_milestone_payout_uses_topped_up_total() {
+    let ctx = setup();
+    ctx.client.top_up_escrow(&ctx.company, &ctx.id, &500);
+
+    ctx.client.update_status(
+        &ctx.carrier,
+        &ctx.id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+    );
+    ctx.client.record_milestone(
+        &ctx.carrier,
+        &ctx.id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&ctx.env, &[3u8; 32]),
+    );
+
+    assert_eq!(
+        TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.carrier),
+        600
+    );
+    assert_eq!(ctx.client.get_shipment(&ctx.id).escrow_amount, 900);
+}
+
+#[test]
+fn test_top_up_rejected_after_created() {
+    let ctx = setup();
+    ctx.client.update_status(
+        &ctx.carrier,
+        &ctx.id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+    );
+
+    assert_eq!(
+        ctx.client.try_top_up_escrow(&ctx.company, &ctx.id, &500),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_top_up_rejects_non_positive_amount() {
+    let ctx = setup();
+
+    assert_eq!(
+        ctx.client.try_top_up_escrow(&ctx.company, &ctx.id, &0),
+        Err(Ok(NavinError::InvalidAmount))
+    );
+    assert_eq!(
+        ctx.client.try_top_up_escrow(&ctx.company, &ctx.id, &-5),
+        Err(Ok(NavinError::InvalidAmount))
+    );
+}
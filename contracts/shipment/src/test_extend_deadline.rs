@@ -0,0 +1,120 @@
+//! Tests for `extend_deadline`.
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, BytesN, Env, Symbol, TryFromVal, Vec};
+
+const WINDOW: u64 = 3_600;
+
+fn shipment(env: &Env, client: &NavinShipmentClient, admin: &Address) -> (Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, WINDOW),
+    );
+    (company, id)
+}
+
+fn emitted(env: &Env, topic: &str) -> bool {
+    let topic = Symbol::new(env, topic);
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(env, &t).ok())
+            .is_some_and(|t| t == topic)
+    })
+}
+
+#[test]
+fn test_sender_extends_deadline() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = shipment(&env, &client, &admin);
+    let new_deadline = client.get_shipment(&id).deadline + WINDOW;
+
+    client.extend_deadline(&company, &id, &new_deadline);
+    assert!(emitted(&env, "deadline_extended"));
+    assert_eq!(client.get_shipment(&id).deadline, new_deadline);
+
+    // The original deadline has passed, but the shipment is no longer overdue.
+    advance_ledger_time(&env, WINDOW + 1);
+    assert_eq!(
+        client.try_check_deadline(&id),
+        Err(Ok(NavinError::NotExpired))
+    );
+}
+
+#[test]
+fn test_admin_extends_deadline() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, id) = shipment(&env, &client, &admin);
+    let new_deadline = client.get_shipment(&id).deadline + WINDOW;
+
+    client.extend_deadline(&admin, &id, &new_deadline);
+    assert_eq!(client.get_shipment(&id).deadline, new_deadline);
+}
+
+#[test]
+fn test_past_deadline_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = shipment(&env, &client, &admin);
+    let deadline = client.get_shipment(&id).deadline;
+
+    advance_ledger_time(&env, 2 * WINDOW);
+    assert_eq!(
+        client.try_extend_deadline(&company, &id, &(deadline + WINDOW)),
+        Err(Ok(NavinError::InvalidTimestamp))
+    );
+    assert_eq!(client.get_shipment(&id).deadline, deadline);
+}
+
+#[test]
+fn test_shrinking_deadline_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = shipment(&env, &client, &admin);
+    let deadline = client.get_shipment(&id).deadline;
+
+    assert_eq!(
+        client.try_extend_deadline(&company, &id, &(deadline - 1)),
+        Err(Ok(NavinError::InvalidTimestamp))
+    );
+    assert_eq!(
+        client.try_extend_deadline(&company, &id, &deadline),
+        Err(Ok(NavinError::InvalidTimestamp))
+    );
+}
+
+#[test]
+fn test_terminal_shipment_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, id) = shipment(&env, &client, &admin);
+    let deadline = client.get_shipment(&id).deadline;
+
+    client.cancel_shipment(&company, &id, &BytesN::from_array(&env, &[5u8; 32]));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Cancelled);
+
+    assert_eq!(
+        client.try_extend_deadline(&company, &id, &(deadline + WINDOW)),
+        Err(Ok(NavinError::ShipmentAlreadyCompleted))
+    );
+}
+
+#[test]
+fn test_non_sender_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, id) = shipment(&env, &client, &admin);
+    let deadline = client.get_shipment(&id).deadline;
+
+    assert_eq!(
+        client.try_extend_deadline(&Address::generate(&env), &id, &(deadline + WINDOW)),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
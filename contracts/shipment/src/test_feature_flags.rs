@@ -0,0 +1,42 @@
+//! Tests for `get_feature_flags`.
+
+use crate::test::*;
+
+#[test]
+fn test_feature_flags_default_off() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+
+    let flags = client.get_feature_flags();
+    assert!(!flags.paused);
+    assert!(!flags.platform_fee);
+    assert!(!flags.release_delay);
+    assert!(!flags.allow_third_party_escrow);
+    assert!(!flags.revoke_old_admin_company_role);
+}
+
+#[test]
+fn test_feature_flags_reflect_config_updates() {
+    let (_env, client, admin, _token) = setup_initialized_shipment_env();
+
+    let mut cfg = client.get_contract_config();
+    cfg.escrow_policy.release_delay_seconds = 3_600;
+    cfg.escrow_policy.allow_third_party_escrow = true;
+    cfg.require_receiver_approval = !cfg.require_receiver_approval;
+    client.update_config(&admin, &cfg);
+
+    let flags = client.get_feature_flags();
+    assert!(flags.release_delay);
+    assert!(flags.allow_third_party_escrow);
+    assert_eq!(
+        flags.require_receiver_approval,
+        cfg.require_receiver_approval
+    );
+}
+
+#[test]
+fn test_feature_flags_reflect_pause() {
+    let (_env, client, admin, _token) = setup_initialized_shipment_env();
+
+    client.pause(&admin);
+    assert!(client.get_feature_flags().paused);
+}
@@ -0,0 +1,67 @@
+//! Tests for escrow deposits made with fee-on-transfer tokens.
+
+use crate::{test_utils, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+// ── Token that burns 10 % of every transfer ─────────────────────────────────
+
+#[contract]
+struct FeeOnTransferToken;
+
+#[contractimpl]
+impl FeeOnTransferToken {
+    pub fn decimals(_env: Env) -> u32 {
+        crate::types::EXPECTED_TOKEN_DECIMALS
+    }
+
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let fee = amount / 10;
+        let from_balance = Self::balance(env.clone(), from.clone());
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&from, &(from_balance - amount));
+        env.storage()
+            .persistent()
+            .set(&to, &(to_balance + amount - fee));
+    }
+}
+
+#[test]
+fn test_escrow_records_net_received_amount() {
+    let (env, admin) = test_utils::setup_env();
+    let token = env.register(FeeOnTransferToken, ());
+    let token_client = FeeOnTransferTokenClient::new(&env, &token);
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    token_client.mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+
+    assert_eq!(token_client.balance(&client.address), 900);
+    assert_eq!(client.get_escrow_balance(&id), 900);
+    assert_eq!(client.get_shipment(&id).escrow_amount, 900);
+}
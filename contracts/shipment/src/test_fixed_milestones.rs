@@ -0,0 +1,154 @@
+//! Tests for absolute-amount payment milestones (`set_fixed_payment_milestones`).
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    milestones: &Vec<(Symbol, u32)>,
+) -> (Address, Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        milestones,
+        &future_deadline(env, 86_400),
+    );
+    (company, carrier, id)
+}
+
+fn fixed(env: &Env, entries: &[(Symbol, i128)]) -> Vec<(Symbol, i128)> {
+    let mut milestones = Vec::new(env);
+    for entry in entries {
+        milestones.push_back(entry.clone());
+    }
+    milestones
+}
+
+#[test]
+fn test_fixed_milestones_release_configured_amounts() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, carrier, id) = setup_shipment(&env, &client, &admin, &Vec::new(&env));
+    client.set_fixed_payment_milestones(
+        &company,
+        &id,
+        &fixed(
+            &env,
+            &[(symbol_short!("pickup"), 300), (symbol_short!("port"), 700)],
+        ),
+    );
+    client.deposit_escrow(&company, &id, &1_000);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+
+    client.record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("pickup"),
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+    assert_eq!(client.get_escrow_balance(&id), 700);
+
+    client.record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+    assert_eq!(client.get_escrow_balance(&id), 0);
+
+    assert_eq!(
+        client.try_record_milestone(
+            &carrier,
+            &id,
+            &symbol_short!("port"),
+            &BytesN::from_array(&env, &[4u8; 32]),
+        ),
+        Err(Ok(NavinError::MilestoneAlreadyPaid))
+    );
+}
+
+#[test]
+fn test_fixed_milestones_require_matching_deposit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, _carrier, id) = setup_shipment(&env, &client, &admin, &Vec::new(&env));
+    client.set_fixed_payment_milestones(
+        &company,
+        &id,
+        &fixed(
+            &env,
+            &[(symbol_short!("pickup"), 300), (symbol_short!("port"), 700)],
+        ),
+    );
+
+    assert_eq!(
+        client.try_deposit_escrow(&company, &id, &900),
+        Err(Ok(NavinError::MilestoneSumInvalid))
+    );
+    assert_eq!(client.get_escrow_balance(&id), 0);
+
+    client.deposit_escrow(&company, &id, &1_000);
+    assert_eq!(client.get_escrow_balance(&id), 1_000);
+}
+
+#[test]
+fn test_fixed_milestones_exclusive_with_percentages() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let mut pct = Vec::new(&env);
+    pct.push_back((symbol_short!("pickup"), 100u32));
+    let (company, _carrier, id) = setup_shipment(&env, &client, &admin, &pct);
+
+    assert_eq!(
+        client.try_set_fixed_payment_milestones(
+            &company,
+            &id,
+            &fixed(&env, &[(symbol_short!("port"), 500)]),
+        ),
+        Err(Ok(NavinError::InvalidPaymentMilestones))
+    );
+}
+
+#[test]
+fn test_fixed_milestones_input_validation() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, _carrier, id) = setup_shipment(&env, &client, &admin, &Vec::new(&env));
+
+    assert_eq!(
+        client.try_set_fixed_payment_milestones(
+            &company,
+            &id,
+            &fixed(&env, &[(symbol_short!("pickup"), 0)]),
+        ),
+        Err(Ok(NavinError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_fixed_payment_milestones(
+            &company,
+            &id,
+            &fixed(
+                &env,
+                &[(symbol_short!("pickup"), 10), (symbol_short!("pickup"), 20)],
+            ),
+        ),
+        Err(Ok(NavinError::DuplicatePaymentMilestone))
+    );
+
+    client.deposit_escrow(&company, &id, &1_000);
+    assert_eq!(
+        client.try_set_fixed_payment_milestones(
+            &company,
+            &id,
+            &fixed(&env, &[(symbol_short!("pickup"), 1_000)]),
+        ),
+        Err(Ok(NavinError::EscrowLocked))
+    );
+}
@@ -0,0 +1,64 @@
+//! Tests for the `handoff_cooldown_seconds` throttle on carrier handoffs.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup(cooldown: u64) -> (Env, NavinShipmentClient<'static>, Address, Address, u64) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier_a = Address::generate(&env);
+    let carrier_b = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier_a);
+    client.add_carrier(&admin, &carrier_b);
+
+    let mut cfg = client.get_contract_config();
+    cfg.carrier_policy.handoff_cooldown_seconds = cooldown;
+    client.update_config(&admin, &cfg);
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier_a,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    (env, client, carrier_a, carrier_b, id)
+}
+
+#[test]
+fn test_rapid_handoff_rejected_during_cooldown() {
+    let (env, client, carrier_a, carrier_b, id) = setup(600);
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.handoff_shipment(&carrier_a, &carrier_b, &id, &hash);
+    assert_eq!(
+        client.try_handoff_shipment(&carrier_b, &carrier_a, &id, &hash),
+        Err(Ok(NavinError::HandoffCooldown))
+    );
+    assert_eq!(client.get_shipment(&id).carrier, carrier_b);
+}
+
+#[test]
+fn test_handoff_allowed_after_cooldown() {
+    let (env, client, carrier_a, carrier_b, id) = setup(600);
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.handoff_shipment(&carrier_a, &carrier_b, &id, &hash);
+    test_utils::advance_ledger_time(&env, 600);
+    client.handoff_shipment(&carrier_b, &carrier_a, &id, &hash);
+    assert_eq!(client.get_shipment(&id).carrier, carrier_a);
+}
+
+#[test]
+fn test_zero_cooldown_allows_back_to_back_handoffs() {
+    let (env, client, carrier_a, carrier_b, id) = setup(0);
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.handoff_shipment(&carrier_a, &carrier_b, &id, &hash);
+    client.handoff_shipment(&carrier_b, &carrier_a, &id, &hash);
+    assert_eq!(client.get_shipment(&id).carrier, carrier_a);
+}
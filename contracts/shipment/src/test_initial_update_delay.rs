@@ -0,0 +1,80 @@
+//! Tests for the `initial_update_delay` configuration parameter.
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, dummy_hash, future_deadline};
+use crate::{CarrierPolicy, ContractConfig, NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    carrier: &Address,
+    initial_update_delay: u64,
+) -> u64 {
+    client.update_config(
+        admin,
+        &ContractConfig {
+            carrier_policy: CarrierPolicy {
+                initial_update_delay,
+                ..CarrierPolicy::default()
+            },
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, carrier);
+    client.create_shipment(
+        &company,
+        &Address::generate(env),
+        carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    )
+}
+
+#[test]
+fn test_first_update_immediate_by_default() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier, 0);
+
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+}
+
+#[test]
+fn test_first_update_before_initial_delay_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier, 300);
+
+    advance_ledger_time(&env, 299);
+    let result =
+        client.try_update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(result, Err(Ok(NavinError::RateLimitExceeded)));
+}
+
+#[test]
+fn test_first_update_after_initial_delay_allowed() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier, 300);
+
+    advance_ledger_time(&env, 300);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+}
+
+#[test]
+fn test_admin_bypasses_initial_delay() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    let id = setup_shipment(&env, &client, &admin, &carrier, 300);
+
+    client.update_status(&admin, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+}
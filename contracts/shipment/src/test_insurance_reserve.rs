@@ -0,0 +1,217 @@
+//! Tests for the per-shipment dispute insurance reserve.
+//!
+//! The reserve is claimable after a lost dispute and paid out with the escrow
+//! on every other terminal path, so the contract never keeps it.
+
+use crate::{
+    test_utils, DisputeResolution, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    token: Address,
+    company: Address,
+    receiver: Address,
+    carrier: Address,
+    id: u64,
+}
+
+/// Deposit 10_000 with a 5% insurance share.
+fn funded() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let mut cfg = client.get_contract_config();
+    cfg.escrow_policy.insurance_bps = 500;
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let receiver = Address::generate(&env);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &10_000);
+    client.deposit_escrow(&company, &id, &10_000);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        token,
+        company,
+        receiver,
+        carrier,
+        id,
+    }
+}
+
+/// Deposit 10_000 with a 5% insurance share and open a dispute.
+fn setup() -> Ctx {
+    let ctx = funded();
+    ctx.client.raise_dispute(
+        &ctx.company,
+        &ctx.id,
+        &BytesN::from_array(&ctx.env, &[9u8; 32]),
+    );
+    ctx
+}
+
+fn hash(ctx: &Ctx, seed: u8) -> BytesN<32> {
+    BytesN::from_array(&ctx.env, &[seed; 32])
+}
+
+fn balance(ctx: &Ctx, who: &Address) -> i128 {
+    TokenClient::new(&ctx.env, &ctx.token).balance(who)
+}
+
+fn resolve(ctx: &Ctx, resolution: DisputeResolution) {
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &ctx.id,
+        &resolution,
+        &BytesN::from_array(&ctx.env, &[7u8; 32]),
+    );
+}
+
+#[test]
+fn test_reserve_funded_on_deposit() {
+    let ctx = setup();
+    assert_eq!(ctx.client.get_escrow_balance(&ctx.id), 9_500);
+    assert_eq!(
+        TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.client.address),
+        10_000
+    );
+}
+
+#[test]
+fn test_reserve_paid_after_company_wins_dispute() {
+    let ctx = setup();
+    resolve(&ctx, DisputeResolution::RefundToCompany);
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.company), 9_500);
+
+    assert_eq!(
+        ctx.client.claim_shipment_insurance(&ctx.company, &ctx.id),
+        500
+    );
+    assert_eq!(token.balance(&ctx.company), 10_000);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+
+    assert_eq!(
+        ctx.client
+            .try_claim_shipment_insurance(&ctx.company, &ctx.id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_reserve_not_claimable_when_carrier_wins() {
+    let ctx = setup();
+    resolve(&ctx, DisputeResolution::ReleaseToCarrier);
+
+    assert_eq!(
+        ctx.client
+            .try_claim_shipment_insurance(&ctx.company, &ctx.id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_reserve_released_to_carrier_with_carrier_dispute_win() {
+    let ctx = setup();
+    resolve(&ctx, DisputeResolution::ReleaseToCarrier);
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 10_000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_reserve_released_to_carrier_on_delivery() {
+    let ctx = funded();
+    ctx.client.update_status(
+        &ctx.carrier,
+        &ctx.id,
+        &ShipmentStatus::InTransit,
+        &hash(&ctx, 2),
+    );
+    ctx.client
+        .confirm_delivery(&ctx.receiver, &ctx.id, &hash(&ctx, 3));
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 10_000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_reserve_refunded_on_cancel() {
+    let ctx = funded();
+    ctx.client
+        .cancel_shipment(&ctx.company, &ctx.id, &hash(&ctx, 4));
+
+    assert_eq!(balance(&ctx, &ctx.company), 10_000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_reserve_refunded_on_refund_escrow() {
+    let ctx = funded();
+    ctx.client.refund_escrow(&ctx.company, &ctx.id);
+
+    assert_eq!(balance(&ctx, &ctx.company), 10_000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_reserve_follows_split_winner() {
+    let ctx = setup();
+    ctx.client
+        .resolve_dispute_split(&ctx.admin, &ctx.id, &6_000, &hash(&ctx, 7));
+
+    // 60% of the 9_500 escrow plus the 500 reserve go to the carrier.
+    assert_eq!(balance(&ctx, &ctx.carrier), 6_200);
+    assert_eq!(balance(&ctx, &ctx.company), 3_800);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_reserve_claimable_after_split_favouring_company() {
+    let ctx = setup();
+    ctx.client
+        .resolve_dispute_split(&ctx.admin, &ctx.id, &4_000, &hash(&ctx, 7));
+
+    assert_eq!(balance(&ctx, &ctx.carrier), 3_800);
+    assert_eq!(
+        ctx.client.claim_shipment_insurance(&ctx.company, &ctx.id),
+        500
+    );
+    assert_eq!(balance(&ctx, &ctx.company), 6_200);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
+
+#[test]
+fn test_reserve_refunded_on_expiry() {
+    let ctx = funded();
+    test_utils::advance_ledger_time(&ctx.env, 86_401);
+    ctx.client.check_deadline(&ctx.id);
+
+    assert_eq!(balance(&ctx, &ctx.company), 10_000);
+    assert_eq!(balance(&ctx, &ctx.client.address), 0);
+}
@@ -0,0 +1,23 @@
+//! Tests for the non-erroring `is_initialized` query.
+
+use crate::{test_utils, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_is_initialized_false_before_initialize() {
+    let (env, _admin) = test_utils::setup_env();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+
+    assert!(!client.is_initialized());
+}
+
+#[test]
+fn test_is_initialized_true_after_initialize() {
+    let (env, admin) = test_utils::setup_env();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    let token = Address::generate(&env);
+
+    client.initialize(&admin, &token);
+    assert!(client.is_initialized());
+}
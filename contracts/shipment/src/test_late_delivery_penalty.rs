@@ -0,0 +1,87 @@
+//! Tests for `late_delivery_penalty_bps`.
+
+use crate::{test_utils, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    token: Address,
+    carrier: Address,
+    receiver: Address,
+    id: u64,
+}
+
+/// Funded, in-transit shipment due in one hour with a 10% late penalty.
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let mut cfg = client.get_contract_config();
+    cfg.escrow_policy.late_delivery_penalty_bps = 1_000;
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &hash,
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 3_600),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &hash);
+
+    Ctx {
+        env,
+        client,
+        token,
+        carrier,
+        receiver,
+        id,
+    }
+}
+
+#[test]
+fn test_on_time_delivery_pays_no_penalty() {
+    let ctx = setup();
+    ctx.client.confirm_delivery(
+        &ctx.receiver,
+        &ctx.id,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+    );
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.carrier), 1_000);
+    assert_eq!(token.balance(&ctx.receiver), 0);
+}
+
+#[test]
+fn test_late_delivery_pays_penalty_to_receiver() {
+    let ctx = setup();
+    test_utils::advance_ledger_time(&ctx.env, 3_601);
+    ctx.client.confirm_delivery(
+        &ctx.receiver,
+        &ctx.id,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+    );
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.receiver), 100);
+    assert_eq!(token.balance(&ctx.carrier), 900);
+    assert_eq!(ctx.client.get_shipment(&ctx.id).escrow_amount, 0);
+}
@@ -0,0 +1,108 @@
+//! Tests for `get_outstanding_liabilities` and `is_solvent`.
+
+use crate::{test_utils, DataKey, DisputeResolution, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const ESCROW: i128 = 1_000;
+const BOND: i128 = 100;
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    receiver: Address,
+    shipment_id: u64,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let sac = StellarAssetClient::new(&env, &token);
+    sac.mint(&company, &ESCROW);
+    sac.mint(&receiver, &BOND);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 3600),
+    );
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        receiver,
+        shipment_id,
+    }
+}
+
+#[test]
+fn test_no_liabilities_before_deposits() {
+    let ctx = setup();
+
+    assert_eq!(ctx.client.get_outstanding_liabilities(), 0);
+    assert!(ctx.client.is_solvent());
+}
+
+#[test]
+fn test_liabilities_track_escrow_and_bonds() {
+    let ctx = setup();
+
+    ctx.client
+        .deposit_escrow(&ctx.company, &ctx.shipment_id, &ESCROW);
+    assert_eq!(ctx.client.get_outstanding_liabilities(), ESCROW);
+    assert!(ctx.client.is_solvent());
+
+    ctx.client.raise_dispute_with_bond(
+        &ctx.receiver,
+        &ctx.shipment_id,
+        &BytesN::from_array(&ctx.env, &[9u8; 32]),
+        &BOND,
+    );
+    assert_eq!(ctx.client.get_outstanding_liabilities(), ESCROW + BOND);
+    assert!(ctx.client.is_solvent());
+
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &ctx.shipment_id,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&ctx.env, &[8u8; 32]),
+    );
+    assert_eq!(ctx.client.get_outstanding_liabilities(), 0);
+    assert!(ctx.client.is_solvent());
+}
+
+#[test]
+fn test_insolvent_when_liabilities_exceed_balance() {
+    let ctx = setup();
+    ctx.client
+        .deposit_escrow(&ctx.company, &ctx.shipment_id, &ESCROW);
+
+    ctx.env.as_contract(&ctx.client.address, || {
+        ctx.env
+            .storage()
+            .instance()
+            .set(&DataKey::TotalEscrowLocked, &(ESCROW + 1));
+    });
+
+    assert_eq!(ctx.client.get_outstanding_liabilities(), ESCROW + 1);
+    assert!(!ctx.client.is_solvent());
+}
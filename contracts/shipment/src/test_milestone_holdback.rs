@@ -0,0 +1,134 @@
+//! Tests for `allow_milestone_holdback`: milestones under 100% with the
+//! remainder released on delivery.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    token: Address,
+    company: Address,
+    carrier: Address,
+    receiver: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+
+    Ctx {
+        receiver: Address::generate(&env),
+        env,
+        client,
+        admin,
+        token,
+        company,
+        carrier,
+    }
+}
+
+fn eighty_percent_milestones(env: &Env) -> Vec<(Symbol, u32)> {
+    let mut milestones = Vec::new(env);
+    milestones.push_back((symbol_short!("pickup"), 40));
+    milestones.push_back((symbol_short!("port"), 40));
+    milestones
+}
+
+fn enable_holdback(ctx: &Ctx) {
+    let mut cfg = ctx.client.get_contract_config();
+    cfg.escrow_policy.allow_milestone_holdback = true;
+    ctx.client.update_config(&ctx.admin, &cfg);
+}
+
+#[test]
+fn test_partial_milestones_rejected_by_default() {
+    let ctx = setup();
+    let result = ctx.client.try_create_shipment(
+        &ctx.company,
+        &ctx.receiver,
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &eighty_percent_milestones(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, 86_400),
+    );
+    assert_eq!(result, Err(Ok(NavinError::MilestoneSumInvalid)));
+}
+
+#[test]
+fn test_holdback_released_on_delivery() {
+    let ctx = setup();
+    enable_holdback(&ctx);
+
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &ctx.receiver,
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &eighty_percent_milestones(&ctx.env),
+        &test_utils::future_deadline(&ctx.env, 86_400),
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &1_000);
+    ctx.client.update_status(
+        &ctx.carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+    );
+    ctx.client.record_milestone(
+        &ctx.carrier,
+        &id,
+        &symbol_short!("pickup"),
+        &BytesN::from_array(&ctx.env, &[3u8; 32]),
+    );
+    ctx.client.record_milestone(
+        &ctx.carrier,
+        &id,
+        &symbol_short!("port"),
+        &BytesN::from_array(&ctx.env, &[4u8; 32]),
+    );
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.carrier), 800);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 200);
+
+    ctx.client.confirm_delivery(
+        &ctx.receiver,
+        &id,
+        &BytesN::from_array(&ctx.env, &[5u8; 32]),
+    );
+
+    assert_eq!(token.balance(&ctx.carrier), 1_000);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 0);
+}
+
+#[test]
+fn test_holdback_still_rejects_over_100() {
+    let ctx = setup();
+    enable_holdback(&ctx);
+
+    let mut milestones = eighty_percent_milestones(&ctx.env);
+    milestones.push_back((symbol_short!("dropoff"), 30));
+    let result = ctx.client.try_create_shipment(
+        &ctx.company,
+        &ctx.receiver,
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(&ctx.env, 86_400),
+    );
+    assert_eq!(result, Err(Ok(NavinError::MilestoneSumInvalid)));
+}
@@ -0,0 +1,68 @@
+//! Tests for `is_milestone_paid`.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+
+fn setup() -> (Env, NavinShipmentClient<'static>, Address, Address) {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+    (env, client, admin, token)
+}
+
+#[test]
+fn test_is_milestone_paid_per_checkpoint() {
+    let (env, client, admin, token) = setup();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut milestones = Vec::new(&env);
+    milestones.push_back((symbol_short!("pickup"), 30));
+    milestones.push_back((symbol_short!("port"), 30));
+    milestones.push_back((symbol_short!("dropoff"), 40));
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    assert!(!client.is_milestone_paid(&id, &symbol_short!("pickup")));
+
+    client.record_milestone(
+        &carrier,
+        &id,
+        &symbol_short!("pickup"),
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    assert!(client.is_milestone_paid(&id, &symbol_short!("pickup")));
+    assert!(!client.is_milestone_paid(&id, &symbol_short!("port")));
+    assert!(!client.is_milestone_paid(&id, &symbol_short!("dropoff")));
+    assert!(!client.is_milestone_paid(&id, &symbol_short!("unknown")));
+}
+
+#[test]
+fn test_is_milestone_paid_unknown_shipment() {
+    let (_env, client, _admin, _token) = setup();
+    let result = client.try_is_milestone_paid(&999, &symbol_short!("pickup"));
+    assert_eq!(result, Err(Ok(NavinError::ShipmentNotFound)));
+}
@@ -0,0 +1,107 @@
+//! Tests that truncated milestone shares never strand escrow dust.
+
+use crate::{test_utils, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    token: Address,
+    carrier: Address,
+    id: u64,
+}
+
+/// Shipment funded with 1001 tokens and milestones of 33/33/34, in transit.
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut milestones = Vec::new(&env);
+    milestones.push_back((symbol_short!("pickup"), 33));
+    milestones.push_back((symbol_short!("port"), 33));
+    milestones.push_back((symbol_short!("dropoff"), 34));
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_001);
+    client.deposit_escrow(&company, &id, &1_001);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    Ctx {
+        env,
+        client,
+        token,
+        carrier,
+        id,
+    }
+}
+
+#[test]
+fn test_final_milestone_releases_remainder() {
+    let ctx = setup();
+    for (i, name) in [
+        symbol_short!("pickup"),
+        symbol_short!("port"),
+        symbol_short!("dropoff"),
+    ]
+    .iter()
+    .enumerate()
+    {
+        ctx.client.record_milestone(
+            &ctx.carrier,
+            &ctx.id,
+            name,
+            &BytesN::from_array(&ctx.env, &[10 + i as u8; 32]),
+        );
+    }
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.carrier), 1_001);
+    assert_eq!(token.balance(&ctx.client.address), 0);
+    assert_eq!(ctx.client.get_shipment(&ctx.id).escrow_amount, 0);
+}
+
+#[test]
+fn test_batch_final_milestone_releases_remainder() {
+    let ctx = setup();
+    let mut batch = Vec::new(&ctx.env);
+    batch.push_back((
+        symbol_short!("pickup"),
+        BytesN::from_array(&ctx.env, &[10u8; 32]),
+    ));
+    batch.push_back((
+        symbol_short!("port"),
+        BytesN::from_array(&ctx.env, &[11u8; 32]),
+    ));
+    batch.push_back((
+        symbol_short!("dropoff"),
+        BytesN::from_array(&ctx.env, &[12u8; 32]),
+    ));
+    ctx.client
+        .record_milestones_batch(&ctx.carrier, &ctx.id, &batch);
+
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    assert_eq!(token.balance(&ctx.carrier), 1_001);
+    assert_eq!(ctx.client.get_shipment(&ctx.id).escrow_amount, 0);
+}
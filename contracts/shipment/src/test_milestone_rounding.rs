@@ -0,0 +1,160 @@
+//! # Milestone Rounding Direction Tests
+//!
+//! Verifies `ContractConfig.rounding_favors_carrier`:
+//! - Default (truncate): intermediate payouts round down, the final milestone
+//!   absorbs the remainder.
+//! - Carrier-favoring: intermediate payouts round up.
+//! - In both modes the carrier receives exactly the deposited escrow.
+
+#![cfg(test)]
+
+use crate::{
+    ContractConfig, NavinShipment, NavinShipmentClient, ShipmentStatus, FRACTIONAL_MILESTONE_PCTS,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+const ESCROW: i128 = 1_001;
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    token: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = crate::test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+    Ctx {
+        env,
+        client,
+        admin,
+        token,
+    }
+}
+
+fn names() -> [Symbol; 3] {
+    [
+        symbol_short!("pickup"),
+        symbol_short!("hub"),
+        symbol_short!("dropoff"),
+    ]
+}
+
+/// Create an in-transit shipment with the fractional (17/33/50) milestones.
+fn create_shipment(ctx: &Ctx) -> (u64, Address) {
+    let env = &ctx.env;
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    ctx.client.add_company(&ctx.admin, &company);
+    ctx.client.add_carrier(&ctx.admin, &carrier);
+    ctx.client.add_carrier_to_whitelist(&company, &carrier);
+
+    let mut milestones = Vec::new(env);
+    for (name, pct) in names().iter().zip(FRACTIONAL_MILESTONE_PCTS.iter()) {
+        milestones.push_back((name.clone(), *pct));
+    }
+
+    let id = ctx.client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &BytesN::from_array(env, &[7u8; 32]),
+        &milestones,
+        &(env.ledger().timestamp() + 86_400),
+    );
+    StellarAssetClient::new(env, &ctx.token).mint(&company, &ESCROW);
+    ctx.client.deposit_escrow(&company, &id, &ESCROW);
+
+    env.as_contract(&ctx.client.address, || {
+        let mut s = crate::storage::get_shipment(env, id).unwrap();
+        s.status = ShipmentStatus::InTransit;
+        crate::storage::set_shipment(env, &s);
+    });
+
+    (id, carrier)
+}
+
+/// Release every milestone in order and return the carrier's balance after each.
+fn release_all(ctx: &Ctx, id: u64, carrier: &Address) -> [i128; 3] {
+    let token = TokenClient::new(&ctx.env, &ctx.token);
+    let mut balances = [0i128; 3];
+    for (i, name) in names().iter().enumerate() {
+        ctx.client.release_milestone_payment(carrier, &id, name);
+        balances[i] = token.balance(carrier);
+    }
+    balances
+}
+
+#[test]
+fn test_truncating_rounding_conserves_total() {
+    let ctx = setup();
+    let (id, carrier) = create_shipment(&ctx);
+
+    let balances = release_all(&ctx, id, &carrier);
+
+    // 17 % of 1001 = 170.17 -> 170; 33 % = 330.33 -> 330; remainder 501.
+    assert_eq!(balances, [170, 500, ESCROW]);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 0);
+}
+
+#[test]
+fn test_carrier_favoring_rounding_conserves_total() {
+    let ctx = setup();
+    let cfg = ContractConfig {
+        rounding_favors_carrier: true,
+        ..ContractConfig::default()
+    };
+    ctx.client.update_config(&ctx.admin, &cfg);
+    let (id, carrier) = create_shipment(&ctx);
+
+    let balances = release_all(&ctx, id, &carrier);
+
+    // 17 % -> 171; 33 % -> 331; remainder 499.
+    assert_eq!(balances, [171, 502, ESCROW]);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 0);
+}
+
+#[test]
+fn test_rounding_is_noop_for_exact_splits() {
+    let ctx = setup();
+    let cfg = ContractConfig {
+        rounding_favors_carrier: true,
+        ..ContractConfig::default()
+    };
+    ctx.client.update_config(&ctx.admin, &cfg);
+    let env = &ctx.env;
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    ctx.client.add_company(&ctx.admin, &company);
+    ctx.client.add_carrier(&ctx.admin, &carrier);
+
+    let mut milestones = Vec::new(env);
+    milestones.push_back((symbol_short!("half"), 50u32));
+    milestones.push_back((symbol_short!("rest"), 50u32));
+    let id = ctx.client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &BytesN::from_array(env, &[8u8; 32]),
+        &milestones,
+        &(env.ledger().timestamp() + 86_400),
+    );
+    StellarAssetClient::new(env, &ctx.token).mint(&company, &1_000);
+    ctx.client.deposit_escrow(&company, &id, &1_000);
+    env.as_contract(&ctx.client.address, || {
+        let mut s = crate::storage::get_shipment(env, id).unwrap();
+        s.status = ShipmentStatus::InTransit;
+        crate::storage::set_shipment(env, &s);
+    });
+
+    ctx.client
+        .release_milestone_payment(&carrier, &id, &symbol_short!("half"));
+    assert_eq!(TokenClient::new(env, &ctx.token).balance(&carrier), 500);
+}
@@ -78,6 +78,7 @@ fn deploy_nvn(env: &Env, admin: &Address) -> Address {
         admin,
         &soroban_sdk::String::from_str(env, "Navin Token"),
         &soroban_sdk::String::from_str(env, "NVN"),
+        &7,
         &1_000_000_000,
     );
     addr
@@ -0,0 +1,87 @@
+//! Tests for `needs_approval_from`.
+
+use crate::test::*;
+use crate::test_utils::advance_past_multisig_expiry;
+use crate::{AdminAction, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+struct Signers {
+    admin1: Address,
+    admin2: Address,
+    admin3: Address,
+}
+
+fn setup_multisig(
+    env: &soroban_sdk::Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+    threshold: u32,
+) -> Signers {
+    let signers = Signers {
+        admin1: Address::generate(env),
+        admin2: Address::generate(env),
+        admin3: Address::generate(env),
+    };
+    let mut admins = Vec::new(env);
+    admins.push_back(signers.admin1.clone());
+    admins.push_back(signers.admin2.clone());
+    admins.push_back(signers.admin3.clone());
+    client.init_multisig(admin, &admins, &threshold);
+    signers
+}
+
+#[test]
+fn test_needs_approval_true_for_pending_signer() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let s = setup_multisig(&env, &client, &admin, 3);
+
+    let id = client.propose_action(&s.admin1, &AdminAction::ForceRefund(1));
+
+    assert!(!client.needs_approval_from(&id, &s.admin1));
+    assert!(client.needs_approval_from(&id, &s.admin2));
+    assert!(client.needs_approval_from(&id, &s.admin3));
+}
+
+#[test]
+fn test_needs_approval_false_after_signer_approved() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let s = setup_multisig(&env, &client, &admin, 3);
+
+    let id = client.propose_action(&s.admin1, &AdminAction::ForceRefund(1));
+    client.approve_action(&s.admin2, &id);
+
+    assert!(!client.needs_approval_from(&id, &s.admin2));
+    assert!(client.needs_approval_from(&id, &s.admin3));
+}
+
+#[test]
+fn test_needs_approval_false_for_non_admin() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let s = setup_multisig(&env, &client, &admin, 3);
+
+    let id = client.propose_action(&s.admin1, &AdminAction::ForceRefund(1));
+    assert!(!client.needs_approval_from(&id, &Address::generate(&env)));
+}
+
+#[test]
+fn test_needs_approval_false_once_expired() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let s = setup_multisig(&env, &client, &admin, 3);
+
+    let id = client.propose_action(&s.admin1, &AdminAction::ForceRefund(1));
+    advance_past_multisig_expiry(&env);
+
+    assert!(!client.needs_approval_from(&id, &s.admin2));
+}
+
+#[test]
+fn test_needs_approval_unknown_proposal() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let s = setup_multisig(&env, &client, &admin, 2);
+
+    assert_eq!(
+        client.try_needs_approval_from(&99, &s.admin1),
+        Err(Ok(NavinError::ProposalNotFound))
+    );
+}
@@ -0,0 +1,129 @@
+//! Tests for the opt-in on-chain notification inbox.
+//!
+//! Verifies that only opted-in recipients accumulate inbox entries, that the
+//! inbox is bounded by `MAX_INBOX_ENTRIES`, and that `clear_inbox` empties it.
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        test_utils, NavinShipment, NavinShipmentClient, NotificationType, ShipmentStatus,
+        MAX_INBOX_ENTRIES,
+    };
+    use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, BytesN, Env, Vec};
+
+    #[contract]
+    struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+
+        pub fn decimals(_env: Env) -> u32 {
+            7
+        }
+    }
+
+    fn setup() -> (Env, NavinShipmentClient<'static>, Address, Address) {
+        let (env, admin) = test_utils::setup_env();
+        let contract_id = env.register(NavinShipment, ());
+        let client = NavinShipmentClient::new(&env, &contract_id);
+        let token_id = env.register(MockToken, ());
+        client.initialize(&admin, &token_id);
+
+        let company = Address::generate(&env);
+        let carrier = Address::generate(&env);
+        client.add_company(&admin, &company);
+        client.add_carrier(&admin, &carrier);
+        client.add_carrier_to_whitelist(&company, &carrier);
+
+        (env, client, company, carrier)
+    }
+
+    fn create(
+        env: &Env,
+        client: &NavinShipmentClient,
+        company: &Address,
+        receiver: &Address,
+        carrier: &Address,
+        seed: u8,
+    ) -> u64 {
+        let deadline = test_utils::future_deadline(env, 7200);
+        client.create_shipment(
+            company,
+            receiver,
+            carrier,
+            &BytesN::from_array(env, &[seed; 32]),
+            &Vec::new(env),
+            &deadline,
+        )
+    }
+
+    #[test]
+    fn opted_in_recipient_receives_notifications() {
+        let (env, client, company, carrier) = setup();
+        let receiver = Address::generate(&env);
+        client.set_inbox_opt_in(&receiver, &true);
+
+        let id = create(&env, &client, &company, &receiver, &carrier, 1);
+        client.update_status(
+            &carrier,
+            &id,
+            &ShipmentStatus::InTransit,
+            &BytesN::from_array(&env, &[2u8; 32]),
+        );
+
+        let inbox = client.get_inbox(&receiver);
+        assert_eq!(inbox.len(), 2);
+        assert_eq!(inbox.get(0).unwrap().0, NotificationType::ShipmentCreated);
+        assert_eq!(inbox.get(0).unwrap().1, id);
+        assert_eq!(inbox.get(1).unwrap().0, NotificationType::StatusChanged);
+
+        // The carrier never opted in, so nothing is stored for it.
+        assert_eq!(client.get_inbox(&carrier).len(), 0);
+    }
+
+    #[test]
+    fn clear_inbox_removes_all_entries() {
+        let (env, client, company, carrier) = setup();
+        let receiver = Address::generate(&env);
+        client.set_inbox_opt_in(&receiver, &true);
+
+        create(&env, &client, &company, &receiver, &carrier, 1);
+        assert_eq!(client.get_inbox(&receiver).len(), 1);
+
+        client.clear_inbox(&receiver);
+        assert_eq!(client.get_inbox(&receiver).len(), 0);
+    }
+
+    #[test]
+    fn opting_out_stops_new_entries() {
+        let (env, client, company, carrier) = setup();
+        let receiver = Address::generate(&env);
+        client.set_inbox_opt_in(&receiver, &true);
+        create(&env, &client, &company, &receiver, &carrier, 1);
+
+        client.set_inbox_opt_in(&receiver, &false);
+        test_utils::advance_past_rate_limit(&env);
+        create(&env, &client, &company, &receiver, &carrier, 2);
+
+        assert_eq!(client.get_inbox(&receiver).len(), 1);
+    }
+
+    #[test]
+    fn inbox_is_bounded_and_drops_oldest() {
+        let (env, client, company, carrier) = setup();
+        let receiver = Address::generate(&env);
+        client.set_inbox_opt_in(&receiver, &true);
+
+        let mut last_id = 0;
+        for seed in 0..(MAX_INBOX_ENTRIES + 2) {
+            last_id = create(&env, &client, &company, &receiver, &carrier, seed as u8);
+            test_utils::advance_past_rate_limit(&env);
+        }
+
+        let inbox = client.get_inbox(&receiver);
+        assert_eq!(inbox.len(), MAX_INBOX_ENTRIES);
+        assert_eq!(inbox.get(0).unwrap().1, 3);
+        assert_eq!(inbox.get(MAX_INBOX_ENTRIES - 1).unwrap().1, last_id);
+    }
+}
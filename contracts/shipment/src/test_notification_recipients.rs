@@ -0,0 +1,73 @@
+//! Tests for per-company extra notification recipients.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NotificationType};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+#[test]
+fn test_extra_recipients_are_notified() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let operator = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(operator.clone());
+    client.set_notification_recipients(&company, &recipients);
+    assert_eq!(client.get_notification_recipients(&company), recipients);
+
+    // The operator records notifications in its inbox so we can observe them.
+    client.set_inbox_opt_in(&operator, &true);
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+
+    let inbox = client.get_inbox(&operator);
+    assert_eq!(inbox.len(), 1);
+    let (kind, shipment_id, _) = inbox.get(0).unwrap();
+    assert_eq!(kind, NotificationType::ShipmentCreated);
+    assert_eq!(shipment_id, id);
+}
+
+#[test]
+fn test_recipients_over_cap_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    let mut cfg = client.get_contract_config();
+    cfg.max_notification_recipients = 5;
+    client.update_config(&admin, &cfg);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(Address::generate(&env));
+    recipients.push_back(Address::generate(&env));
+    client.set_notification_recipients(&company, &recipients);
+
+    recipients.push_back(Address::generate(&env));
+    assert_eq!(
+        client.try_set_notification_recipients(&company, &recipients),
+        Err(Ok(NavinError::TooManyNotificationRecipients))
+    );
+    assert_eq!(client.get_notification_recipients(&company).len(), 2);
+}
+
+#[test]
+fn test_non_company_cannot_set_recipients() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_notification_recipients(&outsider, &Vec::new(&env)),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
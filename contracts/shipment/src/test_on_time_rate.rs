@@ -0,0 +1,66 @@
+//! Tests for `get_carrier_on_time_rate`.
+
+extern crate std;
+
+use crate::test::*;
+use crate::{test_utils, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn create_in_transit(
+    env: &Env,
+    client: &crate::NavinShipmentClient,
+    company: &Address,
+    carrier: &Address,
+    receiver: &Address,
+    seed: u8,
+) -> u64 {
+    let id = client.create_shipment(
+        company,
+        receiver,
+        carrier,
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &test_utils::future_deadline(env, 7_200),
+    );
+    client.update_status(
+        carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(env, &[seed.wrapping_add(100); 32]),
+    );
+    id
+}
+
+#[test]
+fn test_on_time_rate_zero_without_deliveries() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    assert_eq!(client.get_carrier_on_time_rate(&carrier), 0);
+}
+
+#[test]
+fn test_on_time_rate_mixes_on_time_and_late() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let ids: std::vec::Vec<u64> = (1..=4)
+        .map(|seed| create_in_transit(&env, &client, &company, &carrier, &receiver, seed))
+        .collect();
+
+    for id in &ids[..3] {
+        let hash = BytesN::from_array(&env, &[50u8 + *id as u8; 32]);
+        client.confirm_delivery(&receiver, id, &hash);
+    }
+    assert_eq!(client.get_carrier_on_time_rate(&carrier), 100);
+
+    // Let the last shipment's deadline pass before confirming it.
+    env.ledger().with_mut(|l| l.timestamp += 7_201);
+    client.confirm_delivery(&receiver, &ids[3], &BytesN::from_array(&env, &[60u8; 32]));
+
+    assert_eq!(client.get_carrier_on_time_rate(&carrier), 75);
+}
@@ -155,6 +155,35 @@ mod tests {
         assert_eq!(counter, 1);
     }
 
+    #[test]
+    fn test_escrow_settlement_fails_when_paused() {
+        let (env, client, admin, token_contract) = setup_test_env();
+        let company = Address::generate(&env);
+        let carrier = Address::generate(&env);
+        let receiver = Address::generate(&env);
+
+        client.initialize(&admin, &token_contract);
+        client.add_company(&admin, &company);
+        client.add_carrier(&admin, &carrier);
+
+        let hash = BytesN::from_array(&env, &[1u8; 32]);
+        let deadline = future_deadline(&env, 86400);
+        let shipment_id =
+            client.create_shipment(&company, &receiver, &carrier, &hash, &Vec::new(&env), &deadline);
+        client.deposit_escrow(&company, &shipment_id, &1_000_i128);
+
+        client.pause(&admin);
+
+        assert_eq!(
+            client.try_refund_escrow(&company, &shipment_id),
+            Err(Ok(crate::NavinError::ContractPaused))
+        );
+        assert_eq!(
+            client.try_release_escrow(&receiver, &shipment_id),
+            Err(Ok(crate::NavinError::ContractPaused))
+        );
+    }
+
     #[test]
     fn test_pause_unpause_operation_succeeds() {
         let (env, client, admin, token_contract) = setup_test_env();
@@ -0,0 +1,29 @@
+//! Tests for `peek_next_shipment_id`.
+
+use crate::test::*;
+use crate::test_utils;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+#[test]
+fn test_peek_matches_next_created_id() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    for seed in 1..=2u8 {
+        let predicted = client.peek_next_shipment_id();
+        // Peeking does not reserve the id.
+        assert_eq!(client.peek_next_shipment_id(), predicted);
+
+        let id = client.create_shipment(
+            &company,
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &BytesN::from_array(&env, &[seed; 32]),
+            &Vec::new(&env),
+            &test_utils::future_deadline(&env, 86_400),
+        );
+        assert_eq!(id, predicted);
+    }
+}
@@ -0,0 +1,57 @@
+//! Tests for `get_pending_governance`.
+
+use crate::test::*;
+use crate::types::AdminAction;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+#[test]
+fn test_pending_governance_empty_by_default() {
+    let (_env, client, admin, _token) = setup_initialized_shipment_env();
+
+    let pending = client.get_pending_governance();
+    assert_eq!(pending.admin, admin);
+    assert_eq!(pending.proposed_admin, None);
+    assert_eq!(pending.pending_admin_transfers.len(), 0);
+}
+
+#[test]
+fn test_pending_governance_reflects_proposed_admin() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let successor = Address::generate(&env);
+
+    client.transfer_admin(&admin, &successor);
+
+    let pending = client.get_pending_governance();
+    assert_eq!(pending.admin, admin);
+    assert_eq!(pending.proposed_admin, Some(successor.clone()));
+
+    client.accept_admin_transfer(&successor);
+    let pending = client.get_pending_governance();
+    assert_eq!(pending.admin, successor);
+    assert_eq!(pending.proposed_admin, None);
+}
+
+#[test]
+fn test_pending_governance_lists_open_transfer_proposals() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let signer = Address::generate(&env);
+    let mut signers = Vec::new(&env);
+    signers.push_back(admin.clone());
+    signers.push_back(signer.clone());
+    signers.push_back(Address::generate(&env));
+    client.init_multisig(&admin, &signers, &2);
+
+    let new_admin = Address::generate(&env);
+    let proposal_id =
+        client.propose_action(&signer, &AdminAction::TransferAdmin(new_admin.clone()));
+
+    let pending = client.get_pending_governance();
+    assert_eq!(pending.signers, signers);
+    assert_eq!(pending.threshold, 2);
+    assert_eq!(pending.pending_admin_transfers.len(), 1);
+    assert_eq!(
+        pending.pending_admin_transfers.get(0).unwrap(),
+        (proposal_id, new_admin)
+    );
+}
@@ -0,0 +1,72 @@
+//! Tests for `record_pickup` and `verify_pickup_proof`.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup_shipment(env: &Env, client: &NavinShipmentClient, admin: &Address) -> (Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    (carrier, id)
+}
+
+#[test]
+fn test_record_pickup_moves_shipment_in_transit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = setup_shipment(&env, &client, &admin);
+    let pickup_hash = BytesN::from_array(&env, &[9u8; 32]);
+
+    client.record_pickup(&carrier, &id, &pickup_hash);
+
+    let shipment = client.get_shipment(&id);
+    assert_eq!(shipment.status, ShipmentStatus::InTransit);
+    assert_eq!(shipment.data_hash, pickup_hash);
+    assert_eq!(
+        client.try_record_pickup(&carrier, &id, &pickup_hash),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
+
+#[test]
+fn test_verify_pickup_proof_matching_and_mismatching() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, id) = setup_shipment(&env, &client, &admin);
+    let pickup_hash = BytesN::from_array(&env, &[9u8; 32]);
+
+    assert!(!client.verify_pickup_proof(&id, &pickup_hash));
+
+    client.record_pickup(&carrier, &id, &pickup_hash);
+
+    assert!(client.verify_pickup_proof(&id, &pickup_hash));
+    assert!(!client.verify_pickup_proof(&id, &BytesN::from_array(&env, &[8u8; 32])));
+    assert_eq!(
+        client.try_verify_pickup_proof(&999, &pickup_hash),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
+
+#[test]
+fn test_record_pickup_requires_assigned_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_carrier, id) = setup_shipment(&env, &client, &admin);
+    let other = Address::generate(&env);
+    client.add_carrier(&admin, &other);
+
+    assert_eq!(
+        client.try_record_pickup(&other, &id, &BytesN::from_array(&env, &[9u8; 32])),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+}
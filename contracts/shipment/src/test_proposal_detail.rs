@@ -0,0 +1,84 @@
+//! Tests for `get_proposal_detail`.
+
+use crate::test::*;
+use crate::test_utils::{advance_ledger_time, advance_past_multisig_expiry};
+use crate::{AdminAction, NavinError, ProposalStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+fn setup_multisig(
+    env: &soroban_sdk::Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+    threshold: u32,
+) -> (Address, Address, Address) {
+    let signers = (
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    );
+    let mut admins = Vec::new(env);
+    admins.push_back(signers.0.clone());
+    admins.push_back(signers.1.clone());
+    admins.push_back(signers.2.clone());
+    client.init_multisig(admin, &admins, &threshold);
+    signers
+}
+
+#[test]
+fn test_proposal_detail_tracks_approvals() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (a1, a2, a3) = setup_multisig(&env, &client, &admin, 3);
+
+    let id = client.propose_action(&a1, &AdminAction::TransferAdmin(a3.clone()));
+    let detail = client.get_proposal_detail(&id);
+    assert_eq!(detail.proposal.id, id);
+    assert_eq!(detail.status, ProposalStatus::Pending);
+    assert_eq!(detail.approvals, detail.proposal.approvals.len());
+    assert_eq!(detail.approvals, 1);
+    assert_eq!(detail.threshold, 3);
+    assert_eq!(
+        detail.seconds_to_expiry,
+        detail.proposal.expires_at - env.ledger().timestamp()
+    );
+
+    advance_ledger_time(&env, 100);
+    client.approve_action(&a2, &id);
+    let detail = client.get_proposal_detail(&id);
+    assert_eq!(detail.status, ProposalStatus::Pending);
+    assert_eq!(detail.approvals, 2);
+    assert_eq!(
+        detail.seconds_to_expiry,
+        detail.proposal.expires_at - env.ledger().timestamp()
+    );
+
+    // The final approval reaches the threshold and auto-executes.
+    client.approve_action(&a3, &id);
+    let detail = client.get_proposal_detail(&id);
+    assert!(detail.proposal.executed);
+    assert_eq!(detail.status, ProposalStatus::Executed);
+    assert_eq!(detail.approvals, 3);
+}
+
+#[test]
+fn test_proposal_detail_expired() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (a1, _a2, a3) = setup_multisig(&env, &client, &admin, 2);
+
+    let id = client.propose_action(&a1, &AdminAction::TransferAdmin(a3));
+    advance_past_multisig_expiry(&env);
+
+    let detail = client.get_proposal_detail(&id);
+    assert_eq!(detail.status, ProposalStatus::Expired);
+    assert_eq!(detail.seconds_to_expiry, 0);
+    assert_eq!(detail.approvals, 1);
+}
+
+#[test]
+fn test_proposal_detail_unknown_proposal() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+    assert_eq!(
+        client.try_get_proposal_detail(&99),
+        Err(Ok(NavinError::ProposalNotFound))
+    );
+}
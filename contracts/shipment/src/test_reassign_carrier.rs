@@ -0,0 +1,120 @@
+//! Tests for `reassign_carrier`.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+fn setup_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    (company, carrier, id)
+}
+
+#[test]
+fn test_company_reassigns_carrier_before_pickup() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, old_carrier, id) = setup_shipment(&env, &client, &admin);
+    let new_carrier = Address::generate(&env);
+    client.add_carrier(&admin, &new_carrier);
+
+    client.reassign_carrier(&company, &id, &new_carrier);
+
+    let shipment = client.get_shipment(&id);
+    assert_eq!(shipment.carrier, new_carrier);
+    assert_eq!(shipment.status, ShipmentStatus::Created);
+    assert!(!client
+        .get_carrier_shipment_ids(&old_carrier, &0, &10)
+        .contains(id));
+    assert!(client
+        .get_carrier_shipment_ids(&new_carrier, &0, &10)
+        .contains(id));
+
+    // The old carrier can no longer move the shipment; the new one can.
+    assert_eq!(
+        client.try_update_status(
+            &old_carrier,
+            &id,
+            &ShipmentStatus::InTransit,
+            &dummy_hash(&env)
+        ),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    client.update_status(
+        &new_carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &dummy_hash(&env),
+    );
+}
+
+#[test]
+fn test_admin_can_reassign_carrier() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, _carrier, id) = setup_shipment(&env, &client, &admin);
+    let new_carrier = Address::generate(&env);
+    client.add_carrier(&admin, &new_carrier);
+
+    client.reassign_carrier(&admin, &id, &new_carrier);
+    assert_eq!(client.get_shipment(&id).carrier, new_carrier);
+}
+
+#[test]
+fn test_reassign_carrier_unauthorized_caller() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_company, carrier, id) = setup_shipment(&env, &client, &admin);
+    let new_carrier = Address::generate(&env);
+    client.add_carrier(&admin, &new_carrier);
+
+    let outsider = Address::generate(&env);
+    client.add_company(&admin, &outsider);
+    assert_eq!(
+        client.try_reassign_carrier(&outsider, &id, &new_carrier),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_reassign_carrier(&carrier, &id, &new_carrier),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(client.get_shipment(&id).carrier, carrier);
+}
+
+#[test]
+fn test_reassign_carrier_requires_carrier_role() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, _carrier, id) = setup_shipment(&env, &client, &admin);
+
+    assert_eq!(
+        client.try_reassign_carrier(&company, &id, &Address::generate(&env)),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_reassign_carrier_rejected_after_pickup() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (company, carrier, id) = setup_shipment(&env, &client, &admin);
+    let new_carrier = Address::generate(&env);
+    client.add_carrier(&admin, &new_carrier);
+
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(&env));
+    assert_eq!(
+        client.try_reassign_carrier(&company, &id, &new_carrier),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
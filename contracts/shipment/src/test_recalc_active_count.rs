@@ -0,0 +1,73 @@
+//! Tests for `recalc_active_count` and the per-company shipment index.
+
+use crate::test::*;
+use crate::test_utils::future_deadline;
+use crate::{ContractConfig, NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn enable_index(client: &NavinShipmentClient, admin: &Address) {
+    client.update_config(
+        admin,
+        &ContractConfig {
+            index_company_shipments: true,
+            ..ContractConfig::default()
+        },
+    );
+}
+
+fn create(env: &Env, client: &NavinShipmentClient, company: &Address, seed: u8) -> u64 {
+    client.create_shipment(
+        company,
+        &Address::generate(env),
+        &Address::generate(env),
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &future_deadline(env, 3600),
+    )
+}
+
+#[test]
+fn test_recalc_fixes_corrupted_counter() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_index(&client, &admin);
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    create(&env, &client, &company, 1);
+    create(&env, &client, &company, 2);
+    let delivered = create(&env, &client, &company, 3);
+    env.as_contract(&client.address, || {
+        let mut shipment = crate::storage::get_shipment(&env, delivered).unwrap();
+        shipment.status = ShipmentStatus::Delivered;
+        crate::storage::set_shipment(&env, &shipment);
+        // Corrupt the counter so it no longer reflects reality.
+        crate::storage::set_active_shipment_count(&env, &company, 42);
+    });
+    assert_eq!(client.get_active_shipment_count(&company), 42);
+
+    assert_eq!(client.recalc_active_count(&admin, &company), 2);
+    assert_eq!(client.get_active_shipment_count(&company), 2);
+}
+
+#[test]
+fn test_recalc_requires_index_enabled() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    create(&env, &client, &company, 1);
+
+    let result = client.try_recalc_active_count(&admin, &company);
+    assert_eq!(result, Err(Ok(NavinError::CompanyIndexDisabled)));
+}
+
+#[test]
+fn test_recalc_rejects_non_admin() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_index(&client, &admin);
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    let result = client.try_recalc_active_count(&company, &company);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
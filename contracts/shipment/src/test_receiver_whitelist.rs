@@ -0,0 +1,181 @@
+//! Tests for the receiver-side carrier whitelist.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{ContractConfig, NavinError, ShipmentInput};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+fn enable_flag(client: &crate::NavinShipmentClient, admin: &Address) {
+    let cfg = ContractConfig {
+        require_receiver_approval: true,
+        ..ContractConfig::default()
+    };
+    client.update_config(admin, &cfg);
+}
+
+fn registered_receiver(
+    env: &soroban_sdk::Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+) -> Address {
+    let receiver = Address::generate(env);
+    client.add_receiver(admin, &receiver);
+    receiver
+}
+
+#[test]
+fn test_unapproved_carrier_allowed_by_default() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    assert!(!client.is_receiver_whitelisted(&receiver, &carrier));
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(client.get_shipment(&id).carrier, carrier);
+}
+
+#[test]
+fn test_unapproved_carrier_rejected_when_required() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let receiver = registered_receiver(&env, &client, &admin);
+    let approved = Address::generate(&env);
+    client.receiver_whitelist_carrier(&receiver, &approved);
+
+    let result = client.try_create_shipment(
+        &company,
+        &receiver,
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(result, Err(Ok(NavinError::CarrierNotWhitelisted)));
+}
+
+#[test]
+fn test_approved_carrier_accepted_when_required() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let receiver = registered_receiver(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    client.receiver_whitelist_carrier(&receiver, &carrier);
+    assert!(client.is_receiver_whitelisted(&receiver, &carrier));
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(client.get_shipment(&id).carrier, carrier);
+}
+
+#[test]
+fn test_approval_is_scoped_to_receiver() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let carrier = Address::generate(&env);
+    client.receiver_whitelist_carrier(&registered_receiver(&env, &client, &admin), &carrier);
+
+    let mut inputs = Vec::new(&env);
+    inputs.push_back(ShipmentInput {
+        receiver: Address::generate(&env),
+        carrier,
+        data_hash: dummy_hash(&env),
+        payment_milestones: Vec::new(&env),
+        deadline: future_deadline(&env, 3600),
+    });
+    let result = client.try_create_shipments_batch(&company, &inputs);
+    assert_eq!(result, Err(Ok(NavinError::CarrierNotWhitelisted)));
+}
+
+#[test]
+fn test_duplicate_receiver_approval_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let receiver = registered_receiver(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+
+    client.receiver_whitelist_carrier(&receiver, &carrier);
+    let result = client.try_receiver_whitelist_carrier(&receiver, &carrier);
+    assert_eq!(result, Err(Ok(NavinError::CarrierAlreadyWhitelisted)));
+}
+
+#[test]
+fn test_unregistered_receiver_cannot_approve() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+
+    let result = client.try_receiver_whitelist_carrier(&receiver, &carrier);
+    assert_eq!(result, Err(Ok(NavinError::ReceiverNotRegistered)));
+    assert!(!client.is_receiver_whitelisted(&receiver, &carrier));
+}
+
+#[test]
+fn test_removed_carrier_rejected_when_required() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let receiver = registered_receiver(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    client.receiver_whitelist_carrier(&receiver, &carrier);
+    client.receiver_unwhitelist_carrier(&receiver, &carrier);
+    assert!(!client.is_receiver_whitelisted(&receiver, &carrier));
+
+    let result = client.try_create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(result, Err(Ok(NavinError::CarrierNotWhitelisted)));
+}
+
+#[test]
+fn test_unregistered_receiver_cannot_remove() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+
+    let result =
+        client.try_receiver_unwhitelist_carrier(&Address::generate(&env), &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::ReceiverNotRegistered)));
+}
+
+#[test]
+fn test_approval_stored_in_persistent_storage() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let receiver = registered_receiver(&env, &client, &admin);
+    let carrier = Address::generate(&env);
+    client.receiver_whitelist_carrier(&receiver, &carrier);
+
+    let key = crate::types::DataKey::ReceiverCarrierWhitelist(receiver, carrier);
+    env.as_contract(&client.address, || {
+        assert!(env.storage().persistent().has(&key));
+        assert!(!env.storage().instance().has(&key));
+    });
+}
@@ -0,0 +1,108 @@
+//! Tests for the `require_registered_receiver` configuration flag.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{ContractConfig, NavinError, ShipmentInput};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+fn enable_flag(client: &crate::NavinShipmentClient, admin: &Address) {
+    let cfg = ContractConfig {
+        require_registered_receiver: true,
+        ..ContractConfig::default()
+    };
+    client.update_config(admin, &cfg);
+}
+
+#[test]
+fn test_unregistered_receiver_allowed_by_default() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    let receiver = Address::generate(&env);
+    assert!(!client.is_receiver(&receiver));
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(id, 1);
+}
+
+#[test]
+fn test_unregistered_receiver_rejected_when_required() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let result = client.try_create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(result, Err(Ok(NavinError::ReceiverNotRegistered)));
+}
+
+#[test]
+fn test_registered_receiver_accepted_when_required() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let receiver = Address::generate(&env);
+    client.add_receiver(&admin, &receiver);
+    assert!(client.is_receiver(&receiver));
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+    assert_eq!(client.get_shipment(&id).receiver, receiver);
+}
+
+#[test]
+fn test_batch_rejects_unregistered_receiver_when_required() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    enable_flag(&client, &admin);
+
+    let registered = Address::generate(&env);
+    client.add_receiver(&admin, &registered);
+
+    let mut inputs = Vec::new(&env);
+    for receiver in [registered, Address::generate(&env)] {
+        inputs.push_back(ShipmentInput {
+            receiver,
+            carrier: Address::generate(&env),
+            data_hash: dummy_hash(&env),
+            payment_milestones: Vec::new(&env),
+            deadline: future_deadline(&env, 3600),
+        });
+    }
+
+    let result = client.try_create_shipments_batch(&company, &inputs);
+    assert_eq!(result, Err(Ok(NavinError::ReceiverNotRegistered)));
+}
+
+#[test]
+fn test_add_receiver_requires_admin() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let outsider = Address::generate(&env);
+
+    let result = client.try_add_receiver(&outsider, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
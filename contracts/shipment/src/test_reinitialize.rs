@@ -0,0 +1,177 @@
+//! Tests for the multisig-gated `reinitialize` recovery path.
+
+use crate::test::*;
+use crate::test_utils::dummy_hash;
+use crate::{ContractConfig, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, Env, Vec};
+
+fn setup_multisig(
+    env: &Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+) -> (Address, Address) {
+    let admin1 = Address::generate(env);
+    let admin2 = Address::generate(env);
+    let mut admins = Vec::new(env);
+    admins.push_back(admin1.clone());
+    admins.push_back(admin2.clone());
+    admins.push_back(Address::generate(env));
+    client.init_multisig(admin, &admins, &2);
+    (admin1, admin2)
+}
+
+fn stored_token(env: &Env, client: &crate::NavinShipmentClient) -> Address {
+    env.as_contract(&client.address, || {
+        crate::storage::get_token_contract(env).unwrap()
+    })
+}
+
+#[test]
+fn test_reinitialize_applies_after_threshold_approvals() {
+    let (env, client, admin, token_contract) = setup_initialized_shipment_env();
+    let (admin1, admin2) = setup_multisig(&env, &client, &admin);
+
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let shipment_id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &(env.ledger().timestamp() + 3600),
+    );
+
+    let custom = ContractConfig {
+        batch_operation_limit: 20,
+        ..ContractConfig::default()
+    };
+    client.update_config(&admin, &custom);
+
+    let new_token = Address::generate(&env);
+    let proposal_id = client.reinitialize(&admin1, &new_token);
+
+    // Nothing changes until the multisig threshold is met.
+    assert_eq!(stored_token(&env, &client), token_contract);
+
+    client.approve_action(&admin2, &proposal_id);
+
+    assert_eq!(stored_token(&env, &client), new_token);
+    assert_eq!(client.get_contract_config(), ContractConfig::default());
+    // Shipments and the admin survive the reset.
+    assert_eq!(client.get_shipment(&shipment_id).sender, company);
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_reinitialize_requires_multisig_admin() {
+    let (env, client, admin, _token_contract) = setup_initialized_shipment_env();
+    setup_multisig(&env, &client, &admin);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_reinitialize(&outsider, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::NotAnAdmin)));
+}
+
+#[test]
+fn test_reinitialize_rejected_while_escrow_locked() {
+    let (env, client, admin, _token_contract) = setup_initialized_shipment_env();
+    let (admin1, _admin2) = setup_multisig(&env, &client, &admin);
+
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let shipment_id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &(env.ledger().timestamp() + 3600),
+    );
+    client.deposit_escrow(&company, &shipment_id, &1_000);
+
+    let result = client.try_reinitialize(&admin1, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::EscrowLocked)));
+}
+
+#[test]
+fn test_reinitialize_execution_rechecks_escrow() {
+    let (env, client, admin, token_contract) = setup_initialized_shipment_env();
+    let (admin1, admin2) = setup_multisig(&env, &client, &admin);
+
+    let proposal_id = client.reinitialize(&admin1, &Address::generate(&env));
+
+    // Escrow deposited while the proposal is pending blocks execution.
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let shipment_id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &(env.ledger().timestamp() + 3600),
+    );
+    client.deposit_escrow(&company, &shipment_id, &1_000);
+
+    let result = client.try_approve_action(&admin2, &proposal_id);
+    assert_eq!(result, Err(Ok(NavinError::EscrowLocked)));
+    assert_eq!(stored_token(&env, &client), token_contract);
+}
+
+#[test]
+fn test_reinitialize_allowed_once_escrow_refunded() {
+    let (env, client, admin, _token_contract) = setup_initialized_shipment_env();
+    let (admin1, admin2) = setup_multisig(&env, &client, &admin);
+
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let shipment_id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &(env.ledger().timestamp() + 3600),
+    );
+    client.deposit_escrow(&company, &shipment_id, &1_000);
+    client.refund_escrow(&company, &shipment_id);
+
+    let new_token = Address::generate(&env);
+    let proposal_id = client.reinitialize(&admin1, &new_token);
+    client.approve_action(&admin2, &proposal_id);
+    assert_eq!(stored_token(&env, &client), new_token);
+}
+
+#[test]
+fn test_reinitialize_rejected_while_dispute_bond_held() {
+    let (env, client, admin, _token_contract) = setup_initialized_shipment_env();
+    let (admin1, _admin2) = setup_multisig(&env, &client, &admin);
+
+    env.as_contract(&client.address, || {
+        crate::storage::set_dispute_bond(&env, 1, &Address::generate(&env), 100);
+    });
+
+    let result = client.try_reinitialize(&admin1, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::EscrowLocked)));
+}
+
+#[test]
+fn test_reinitialize_rejected_while_contract_holds_untracked_balance() {
+    let (env, admin) = crate::test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = crate::NavinShipmentClient::new(&env, &env.register(crate::NavinShipment, ()));
+    client.initialize(&admin, &token);
+    let (admin1, _admin2) = setup_multisig(&env, &client, &admin);
+
+    // Escrow deposited before the running totals existed is invisible to them.
+    StellarAssetClient::new(&env, &token).mint(&client.address, &1_000);
+    assert_eq!(client.get_outstanding_liabilities(), 0);
+
+    let result = client.try_reinitialize(&admin1, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::EscrowLocked)));
+}
@@ -0,0 +1,47 @@
+//! Tests for `get_relationship`.
+
+use crate::test::*;
+use crate::test_utils::future_deadline;
+use crate::{NavinError, Relationship};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+#[test]
+fn test_relationship_for_each_party() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &future_deadline(&env, 3600),
+    );
+
+    assert_eq!(client.get_relationship(&id, &company), Relationship::Sender);
+    assert_eq!(
+        client.get_relationship(&id, &receiver),
+        Relationship::Receiver
+    );
+    assert_eq!(
+        client.get_relationship(&id, &carrier),
+        Relationship::Carrier
+    );
+    assert_eq!(client.get_relationship(&id, &admin), Relationship::Admin);
+    assert_eq!(
+        client.get_relationship(&id, &Address::generate(&env)),
+        Relationship::None
+    );
+}
+
+#[test]
+fn test_relationship_unknown_shipment() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let result = client.try_get_relationship(&99, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::ShipmentNotFound)));
+}
@@ -0,0 +1,123 @@
+//! Tests for the post-delivery escrow release delay.
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    token: Address,
+    carrier: Address,
+    receiver: Address,
+    id: u64,
+}
+
+fn setup(delay: u64) -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let mut cfg = client.get_contract_config();
+    cfg.escrow_policy.release_delay_seconds = delay;
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    Ctx {
+        env,
+        client,
+        token,
+        carrier,
+        receiver,
+        id,
+    }
+}
+
+fn confirm(ctx: &Ctx) -> i128 {
+    ctx.client
+        .confirm_delivery(
+            &ctx.receiver,
+            &ctx.id,
+            &BytesN::from_array(&ctx.env, &[3u8; 32]),
+        )
+        .released_amount
+}
+
+fn carrier_balance(ctx: &Ctx) -> i128 {
+    TokenClient::new(&ctx.env, &ctx.token).balance(&ctx.carrier)
+}
+
+#[test]
+fn test_release_withheld_until_delay_elapses() {
+    let ctx = setup(3_600);
+
+    assert_eq!(confirm(&ctx), 0);
+    assert_eq!(carrier_balance(&ctx), 0);
+    assert_eq!(ctx.client.get_escrow_balance(&ctx.id), 1_000);
+
+    ctx.env.ledger().with_mut(|l| l.timestamp += 1_800);
+    assert_eq!(
+        ctx.client.try_finalize_release(&ctx.id),
+        Err(Ok(NavinError::ReleaseDelayActive))
+    );
+    assert_eq!(
+        ctx.client.try_release_escrow(&ctx.receiver, &ctx.id),
+        Err(Ok(NavinError::ReleaseDelayActive))
+    );
+
+    ctx.env.ledger().with_mut(|l| l.timestamp += 1_800);
+    ctx.client.finalize_release(&ctx.id);
+
+    assert_eq!(carrier_balance(&ctx), 1_000);
+    let shipment = ctx.client.get_shipment(&ctx.id);
+    assert_eq!(shipment.escrow_amount, 0);
+    assert!(shipment.finalized);
+}
+
+#[test]
+fn test_zero_delay_releases_immediately() {
+    let ctx = setup(0);
+
+    assert_eq!(confirm(&ctx), 1_000);
+    assert_eq!(carrier_balance(&ctx), 1_000);
+    assert_eq!(
+        ctx.client.try_finalize_release(&ctx.id),
+        Err(Ok(NavinError::ShipmentFinalized))
+    );
+}
+
+#[test]
+fn test_finalize_release_without_pending_release() {
+    let ctx = setup(3_600);
+
+    assert_eq!(
+        ctx.client.try_finalize_release(&ctx.id),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+}
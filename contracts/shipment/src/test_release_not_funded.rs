@@ -0,0 +1,70 @@
+//! Tests for the distinct `release_escrow` errors on unfunded and already
+//! released shipments.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn delivered_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    deposit: i128,
+) -> (Address, u64) {
+    let company = Address::generate(env);
+    let receiver = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(env),
+        &Vec::new(env),
+        &future_deadline(env, 86_400),
+    );
+    if deposit > 0 {
+        client.deposit_escrow(&company, &id, &deposit);
+    }
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &dummy_hash(env));
+    client.confirm_delivery(&receiver, &id, &BytesN::from_array(env, &[3u8; 32]));
+    (receiver, id)
+}
+
+#[test]
+fn test_release_never_funded_returns_not_funded() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (receiver, id) = delivered_shipment(&env, &client, &admin, 0);
+
+    assert_eq!(
+        client.try_release_escrow(&receiver, &id),
+        Err(Ok(NavinError::NotFunded))
+    );
+}
+
+#[test]
+fn test_release_already_released_returns_finalized() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (receiver, id) = delivered_shipment(&env, &client, &admin, 5_000);
+
+    // confirm_delivery releases the escrow and finalizes the shipment.
+    assert_eq!(client.get_escrow_balance(&id), 0);
+    assert_eq!(
+        client.try_release_escrow(&receiver, &id),
+        Err(Ok(NavinError::ShipmentFinalized))
+    );
+}
+
+#[test]
+fn test_release_not_funded_still_requires_authorization() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (_receiver, id) = delivered_shipment(&env, &client, &admin, 0);
+
+    assert_eq!(
+        client.try_release_escrow(&Address::generate(&env), &id),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
@@ -0,0 +1,80 @@
+//! Tests for `remove_company` and `remove_carrier`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, Role, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, Address, BytesN, Vec};
+
+#[test]
+fn test_removed_carrier_cannot_record_milestone() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &hash,
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    client.update_status(&carrier, &id, &ShipmentStatus::InTransit, &hash);
+
+    client.remove_carrier(&admin, &carrier);
+    assert_eq!(client.get_role(&carrier), Role::Unassigned);
+    assert_eq!(
+        client.try_record_milestone(&carrier, &id, &symbol_short!("port"), &hash),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_remove_company_blocked_by_active_shipments() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+
+    assert_eq!(
+        client.try_remove_company(&admin, &company),
+        Err(Ok(NavinError::HasActiveShipments))
+    );
+    assert_eq!(client.get_role(&company), Role::Company);
+}
+
+#[test]
+fn test_remove_company_without_shipments() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    client.remove_company(&admin, &company);
+    assert_eq!(client.get_role(&company), Role::Unassigned);
+    assert_eq!(
+        client.try_remove_company(&admin, &company),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_remove_roles_admin_only() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    client.add_carrier(&admin, &carrier);
+
+    assert_eq!(
+        client.try_remove_carrier(&Address::generate(&env), &carrier),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
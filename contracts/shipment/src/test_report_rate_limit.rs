@@ -0,0 +1,77 @@
+//! Tests for the `min_report_interval` throttle on carrier reports.
+
+use crate::test::*;
+use crate::{test_utils, BreachType, GeofenceEvent, NavinError, NavinShipmentClient, Severity};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup(interval: u64) -> (Env, NavinShipmentClient<'static>, Address, u64) {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut cfg = client.get_contract_config();
+    cfg.min_report_interval = interval;
+    client.update_config(&admin, &cfg);
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    (env, client, carrier, id)
+}
+
+#[test]
+fn test_rapid_geofence_report_throttled() {
+    let (env, client, carrier, id) = setup(300);
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.report_geofence_event(&carrier, &id, &GeofenceEvent::ZoneEntry, &hash);
+    assert_eq!(
+        client.try_report_geofence_event(&carrier, &id, &GeofenceEvent::ZoneExit, &hash),
+        Err(Ok(NavinError::RateLimitExceeded))
+    );
+
+    test_utils::advance_ledger_time(&env, 300);
+    client.report_geofence_event(&carrier, &id, &GeofenceEvent::ZoneExit, &hash);
+}
+
+#[test]
+fn test_report_kinds_throttled_independently() {
+    let (env, client, carrier, id) = setup(300);
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.report_geofence_event(&carrier, &id, &GeofenceEvent::ZoneEntry, &hash);
+    client.report_condition_breach(
+        &carrier,
+        &id,
+        &BreachType::TemperatureHigh,
+        &Severity::Low,
+        &hash,
+    );
+    assert_eq!(
+        client.try_report_condition_breach(
+            &carrier,
+            &id,
+            &BreachType::TemperatureHigh,
+            &Severity::Low,
+            &hash,
+        ),
+        Err(Ok(NavinError::RateLimitExceeded))
+    );
+}
+
+#[test]
+fn test_zero_interval_disables_throttle() {
+    let (env, client, carrier, id) = setup(0);
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.report_geofence_event(&carrier, &id, &GeofenceEvent::ZoneEntry, &hash);
+    client.report_geofence_event(&carrier, &id, &GeofenceEvent::ZoneExit, &hash);
+}
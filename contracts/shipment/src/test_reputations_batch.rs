@@ -0,0 +1,67 @@
+//! Tests for `get_reputations_batch`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, ShipmentStatus, MAX_BATCH_QUERY_SIZE};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Vec};
+
+#[test]
+fn test_reputations_batch_mixes_known_and_unknown() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 7_200),
+    );
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+    client.confirm_delivery(&receiver, &id, &BytesN::from_array(&env, &[3u8; 32]));
+
+    let unknown = Address::generate(&env);
+    let mut carriers = Vec::new(&env);
+    carriers.push_back(unknown.clone());
+    carriers.push_back(carrier.clone());
+
+    let reps = client.get_reputations_batch(&carriers);
+    assert_eq!(reps.len(), 2);
+
+    let first = reps.get(0).unwrap();
+    assert_eq!(first.carrier, unknown);
+    assert_eq!(first.deliveries, 0);
+    assert_eq!(first.on_time_deliveries, 0);
+    assert_eq!(first.deadline_misses, 0);
+    assert_eq!(first.breach_penalty, 0);
+
+    let second = reps.get(1).unwrap();
+    assert_eq!(second.carrier, carrier);
+    assert_eq!(second.deliveries, 1);
+    assert_eq!(second.on_time_deliveries, 1);
+}
+
+#[test]
+fn test_reputations_batch_rejects_oversized_request() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+
+    let mut carriers = Vec::new(&env);
+    for _ in 0..=MAX_BATCH_QUERY_SIZE {
+        carriers.push_back(Address::generate(&env));
+    }
+
+    assert_eq!(
+        client.try_get_reputations_batch(&carriers),
+        Err(Ok(NavinError::BatchTooLarge))
+    );
+}
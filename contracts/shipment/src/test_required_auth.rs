@@ -0,0 +1,58 @@
+//! Tests for `required_auth`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Symbol, Vec};
+
+#[test]
+fn test_required_auth_per_function() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+
+    let signer = |name: &str| client.required_auth(&Symbol::new(&env, name), &id);
+    assert_eq!(signer("confirm_delivery"), receiver);
+    assert_eq!(signer("record_milestone"), carrier);
+    assert_eq!(signer("deposit_escrow"), company);
+    assert_eq!(signer("resolve_dispute"), admin);
+}
+
+#[test]
+fn test_required_auth_rejects_unknown_function() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+
+    assert_eq!(
+        client.try_required_auth(&Symbol::new(&env, "raise_dispute"), &id),
+        Err(Ok(NavinError::InvalidSymbol))
+    );
+    assert_eq!(
+        client.try_required_auth(&Symbol::new(&env, "confirm_delivery"), &999),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
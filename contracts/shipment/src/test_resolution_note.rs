@@ -0,0 +1,54 @@
+//! Tests for the dispute resolution note stored by `resolve_dispute`.
+
+use crate::{test_utils, DisputeResolution, NavinError, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn setup() -> (Env, NavinShipmentClient<'static>, Address, u64) {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+    StellarAssetClient::new(&env, &token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+    client.raise_dispute(&company, &id, &BytesN::from_array(&env, &[9u8; 32]));
+
+    (env, client, admin, id)
+}
+
+#[test]
+fn test_resolution_note_stored_after_resolution() {
+    let (env, client, admin, id) = setup();
+    assert_eq!(client.get_resolution_note(&id), None);
+
+    let note = BytesN::from_array(&env, &[7u8; 32]);
+    client.resolve_dispute(&admin, &id, &DisputeResolution::RefundToCompany, &note);
+
+    assert_eq!(client.get_resolution_note(&id), Some(note));
+}
+
+#[test]
+fn test_resolution_note_unknown_shipment() {
+    let (_env, client, _admin, _id) = setup();
+    assert_eq!(
+        client.try_get_resolution_note(&999),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
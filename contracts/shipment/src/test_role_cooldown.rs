@@ -0,0 +1,75 @@
+//! Tests for the per-address role change cooldown.
+
+use crate::test::*;
+use crate::test_utils::advance_ledger_time;
+use crate::{ContractConfig, NavinError, NavinShipmentClient, Role};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+const COOLDOWN: u64 = 3600;
+
+fn enable_cooldown(client: &NavinShipmentClient, admin: &Address) {
+    let cfg = ContractConfig {
+        role_change_cooldown: COOLDOWN,
+        ..ContractConfig::default()
+    };
+    client.update_config(admin, &cfg);
+}
+
+#[test]
+fn test_rapid_revoke_and_regrant_blocked() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_cooldown(&client, &admin);
+    let company = Address::generate(&env);
+
+    client.add_company(&admin, &company);
+    assert_eq!(
+        client.try_revoke_role(&admin, &company),
+        Err(Ok(NavinError::RoleChangeCooldown))
+    );
+
+    advance_ledger_time(&env, COOLDOWN);
+    client.revoke_role(&admin, &company);
+
+    assert_eq!(
+        client.try_add_company(&admin, &company),
+        Err(Ok(NavinError::RoleChangeCooldown))
+    );
+    assert_eq!(client.get_role(&company), Role::Unassigned);
+}
+
+#[test]
+fn test_regrant_allowed_after_cooldown() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_cooldown(&client, &admin);
+    let carrier = Address::generate(&env);
+
+    client.add_carrier(&admin, &carrier);
+    advance_ledger_time(&env, COOLDOWN);
+    client.revoke_role(&admin, &carrier);
+    advance_ledger_time(&env, COOLDOWN);
+    client.add_carrier(&admin, &carrier);
+
+    assert_eq!(client.get_role(&carrier), Role::Carrier);
+}
+
+#[test]
+fn test_cooldown_is_per_address() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    enable_cooldown(&client, &admin);
+
+    client.add_company(&admin, &Address::generate(&env));
+    client.add_company(&admin, &Address::generate(&env));
+}
+
+#[test]
+fn test_no_cooldown_by_default() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+
+    client.add_company(&admin, &company);
+    client.revoke_role(&admin, &company);
+    client.add_company(&admin, &company);
+
+    assert_eq!(client.get_role(&company), Role::Company);
+}
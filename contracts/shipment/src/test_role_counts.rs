@@ -0,0 +1,49 @@
+//! Tests for the Company/Carrier role counters exposed by `get_role_counts`.
+
+use crate::test::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+#[test]
+fn test_role_counts_track_grants() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+
+    // The admin is granted the Company role on initialization.
+    assert_eq!(client.get_role_counts(), (1, 0));
+
+    client.add_company(&admin, &Address::generate(&env));
+    client.add_carrier(&admin, &Address::generate(&env));
+    client.add_carrier(&admin, &Address::generate(&env));
+
+    assert_eq!(client.get_role_counts(), (2, 2));
+}
+
+#[test]
+fn test_role_counts_track_revocations() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+    assert_eq!(client.get_role_counts(), (2, 1));
+
+    client.revoke_role(&admin, &carrier);
+    assert_eq!(client.get_role_counts(), (2, 0));
+
+    client.revoke_role(&admin, &company);
+    assert_eq!(client.get_role_counts(), (1, 0));
+
+    // Revoking an address without a role leaves the counters untouched.
+    client.revoke_role(&admin, &carrier);
+    assert_eq!(client.get_role_counts(), (1, 0));
+}
+
+#[test]
+fn test_role_counts_ignore_suspension() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let carrier = Address::generate(&env);
+    client.add_carrier(&admin, &carrier);
+
+    client.suspend_carrier(&admin, &carrier);
+    assert_eq!(client.get_role_counts(), (1, 1));
+}
@@ -0,0 +1,67 @@
+//! Tests for `get_shipments_in_range`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, NavinShipmentClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, BytesN, Env, Vec};
+
+/// Create one shipment at each of the given ledger timestamps.
+fn create_at(env: &Env, client: &NavinShipmentClient, admin: &Address, times: &[u64]) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+
+    for (i, ts) in times.iter().enumerate() {
+        env.ledger().with_mut(|l| l.timestamp = *ts);
+        client.create_shipment(
+            &company,
+            &Address::generate(env),
+            &carrier,
+            &BytesN::from_array(env, &[i as u8 + 1; 32]),
+            &Vec::new(env),
+            &test_utils::future_deadline(env, 86_400),
+        );
+    }
+}
+
+#[test]
+fn test_shipments_in_range_filters_window() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    create_at(&env, &client, &admin, &[1_000, 2_000, 3_000, 4_000, 5_000]);
+
+    assert_eq!(
+        client.get_shipments_in_range(&2_000, &4_000, &10),
+        vec![&env, 2, 3, 4]
+    );
+    assert_eq!(
+        client.get_shipments_in_range(&2_500, &3_500, &10),
+        vec![&env, 3]
+    );
+    assert_eq!(client.get_shipments_in_range(&6_000, &7_000, &10).len(), 0);
+}
+
+#[test]
+fn test_shipments_in_range_respects_max() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    create_at(&env, &client, &admin, &[1_000, 2_000, 3_000]);
+
+    assert_eq!(
+        client.get_shipments_in_range(&0, &10_000, &2),
+        vec![&env, 1, 2]
+    );
+}
+
+#[test]
+fn test_shipments_in_range_rejects_bad_arguments() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+
+    assert_eq!(
+        client.try_get_shipments_in_range(&5, &1, &10),
+        Err(Ok(NavinError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_get_shipments_in_range(&0, &10, &0),
+        Err(Ok(NavinError::InvalidConfig))
+    );
+}
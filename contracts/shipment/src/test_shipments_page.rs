@@ -0,0 +1,83 @@
+//! Tests for `get_shipments_page`.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{DataKey, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+fn create_shipments(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    count: u32,
+) -> Address {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    for _ in 0..count {
+        client.create_shipment(
+            &company,
+            &Address::generate(env),
+            &carrier,
+            &dummy_hash(env),
+            &Vec::new(env),
+            &future_deadline(env, 86_400),
+        );
+    }
+    company
+}
+
+#[test]
+fn test_shipments_page_contiguous_range() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    create_shipments(&env, &client, &admin, 5);
+
+    let page = client.get_shipments_page(&2, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, 2);
+    assert_eq!(page.get(1).unwrap().id, 3);
+    assert_eq!(page.get(2).unwrap().id, 4);
+
+    // Resuming after the last id seen returns the tail.
+    let rest = client.get_shipments_page(&5, &3);
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest.get(0).unwrap().id, 5);
+}
+
+#[test]
+fn test_shipments_page_includes_cancelled_and_skips_missing() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = create_shipments(&env, &client, &admin, 4);
+
+    client.cancel_shipment(&company, &2, &dummy_hash(&env));
+    env.as_contract(&client.address, || {
+        env.storage().persistent().remove(&DataKey::Shipment(3));
+    });
+
+    let page = client.get_shipments_page(&1, &10);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(1).unwrap().id, 2);
+    assert_eq!(page.get(1).unwrap().status, ShipmentStatus::Cancelled);
+    assert_eq!(page.get(2).unwrap().id, 4);
+}
+
+#[test]
+fn test_shipments_page_start_beyond_counter_is_empty() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    create_shipments(&env, &client, &admin, 2);
+
+    assert_eq!(client.get_shipments_page(&3, &10).len(), 0);
+    assert_eq!(client.get_shipments_page(&100, &10).len(), 0);
+}
+
+#[test]
+fn test_shipments_page_capped_by_batch_limit() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    create_shipments(&env, &client, &admin, 12);
+
+    let limit = client.get_contract_config().batch_operation_limit;
+    assert_eq!(client.get_shipments_page(&1, &50).len(), limit);
+}
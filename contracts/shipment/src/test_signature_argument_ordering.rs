@@ -195,7 +195,7 @@ fn token_refund_boundary_uses_from_to_amount_order() {
 #[test]
 fn create_shipment_and_status_update_auth_args_are_stable() {
     let ctx = setup();
-    let deadline = test_utils::future_deadline(&ctx.env, 1800);
+    let deadline = test_utils::future_deadline(&ctx.env, 3600);
     let data_hash = hash32(&ctx.env, 11);
     let event_hash = hash32(&ctx.env, 12);
     let milestones: Vec<(Symbol, u32)> = Vec::new(&ctx.env);
@@ -0,0 +1,149 @@
+//! Tests for `record_milestone_signed`.
+
+extern crate std;
+
+use crate::{test_utils, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+fn setup() -> (Env, NavinShipmentClient<'static>, Address, Address) {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+    (env, client, admin, token)
+}
+
+/// Create an in-transit shipment with a single "port" milestone worth 40%.
+fn in_transit_shipment(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    token: &Address,
+    carrier: &Address,
+) -> u64 {
+    let company = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, carrier);
+
+    let mut milestones = Vec::new(env);
+    milestones.push_back((symbol_short!("port"), 40));
+    milestones.push_back((symbol_short!("dropoff"), 60));
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(env),
+        carrier,
+        &BytesN::from_array(env, &[1u8; 32]),
+        &milestones,
+        &test_utils::future_deadline(env, 86_400),
+    );
+    StellarAssetClient::new(env, token).mint(&company, &1_000);
+    client.deposit_escrow(&company, &id, &1_000);
+    client.update_status(
+        carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(env, &[2u8; 32]),
+    );
+    id
+}
+
+fn sign_attestation(
+    env: &Env,
+    key: &SigningKey,
+    shipment_id: u64,
+    checkpoint: &Symbol,
+    data_hash: &BytesN<32>,
+) -> BytesN<64> {
+    let mut payload = Bytes::new(env);
+    payload.append(&shipment_id.to_xdr(env));
+    payload.append(&checkpoint.clone().to_xdr(env));
+    payload.append(&data_hash.clone().to_xdr(env));
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(env, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_signed_milestone_releases_payment() {
+    let (env, client, admin, token) = setup();
+    let carrier = Address::generate(&env);
+    let id = in_transit_shipment(&env, &client, &admin, &token, &carrier);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_carrier_public_key(
+        &carrier,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+    );
+
+    let checkpoint = symbol_short!("port");
+    let hash = BytesN::from_array(&env, &[3u8; 32]);
+    let signature = sign_attestation(&env, &key, id, &checkpoint, &hash);
+    client.record_milestone_signed(&id, &checkpoint, &hash, &signature);
+
+    assert!(client.is_milestone_paid(&id, &checkpoint));
+    assert_eq!(client.get_escrow_balance(&id), 600);
+}
+
+#[test]
+fn test_signed_milestone_rejects_invalid_signature() {
+    let (env, client, admin, token) = setup();
+    let carrier = Address::generate(&env);
+    let id = in_transit_shipment(&env, &client, &admin, &token, &carrier);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.set_carrier_public_key(
+        &carrier,
+        &BytesN::from_array(&env, &key.verifying_key().to_bytes()),
+    );
+
+    // Signed by a different key.
+    let checkpoint = symbol_short!("port");
+    let hash = BytesN::from_array(&env, &[3u8; 32]);
+    let forged = sign_attestation(
+        &env,
+        &SigningKey::from_bytes(&[9u8; 32]),
+        id,
+        &checkpoint,
+        &hash,
+    );
+    assert!(client
+        .try_record_milestone_signed(&id, &checkpoint, &hash, &forged)
+        .is_err());
+
+    // Valid signature over a different data hash.
+    let other = sign_attestation(
+        &env,
+        &key,
+        id,
+        &checkpoint,
+        &BytesN::from_array(&env, &[4u8; 32]),
+    );
+    assert!(client
+        .try_record_milestone_signed(&id, &checkpoint, &hash, &other)
+        .is_err());
+
+    assert!(!client.is_milestone_paid(&id, &checkpoint));
+    assert_eq!(client.get_escrow_balance(&id), 1_000);
+}
+
+#[test]
+fn test_signed_milestone_requires_registered_key() {
+    let (env, client, admin, token) = setup();
+    let carrier = Address::generate(&env);
+    let id = in_transit_shipment(&env, &client, &admin, &token, &carrier);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    let checkpoint = symbol_short!("port");
+    let hash = BytesN::from_array(&env, &[3u8; 32]);
+    let signature = sign_attestation(&env, &key, id, &checkpoint, &hash);
+
+    assert_eq!(
+        client.try_record_milestone_signed(&id, &checkpoint, &hash, &signature),
+        Err(Ok(NavinError::CarrierKeyNotRegistered))
+    );
+}
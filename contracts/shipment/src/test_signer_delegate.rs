@@ -0,0 +1,96 @@
+//! Tests for delegated multisig approvals.
+
+use crate::test::*;
+use crate::{AdminAction, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Vec};
+
+fn setup_multisig(
+    env: &soroban_sdk::Env,
+    client: &crate::NavinShipmentClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    let signers = (
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    );
+    let mut admins = Vec::new(env);
+    admins.push_back(signers.0.clone());
+    admins.push_back(signers.1.clone());
+    admins.push_back(signers.2.clone());
+    client.init_multisig(admin, &admins, &3);
+    signers
+}
+
+#[test]
+fn test_delegate_approval_recorded_for_signer() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (a1, a2, a3) = setup_multisig(&env, &client, &admin);
+    let delegate = Address::generate(&env);
+
+    client.set_signer_delegate(&a2, &Some(delegate.clone()));
+    let id = client.propose_action(&a1, &AdminAction::TransferAdmin(a3));
+    client.approve_as_delegate(&delegate, &a2, &id);
+
+    let proposal = client.get_proposal(&id);
+    assert_eq!(proposal.approvals.len(), 2);
+    assert!(proposal.approvals.contains(&a2));
+    assert!(!proposal.approvals.contains(&delegate));
+
+    // The underlying signer cannot approve a second time.
+    assert_eq!(
+        client.try_approve_action(&a2, &id),
+        Err(Ok(NavinError::AlreadyApproved))
+    );
+}
+
+#[test]
+fn test_unauthorized_delegate_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (a1, a2, a3) = setup_multisig(&env, &client, &admin);
+    let delegate = Address::generate(&env);
+    let impostor = Address::generate(&env);
+
+    client.set_signer_delegate(&a2, &Some(delegate));
+    let id = client.propose_action(&a1, &AdminAction::TransferAdmin(a3.clone()));
+
+    assert_eq!(
+        client.try_approve_as_delegate(&impostor, &a2, &id),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    // A delegate for one signer cannot approve for another.
+    assert_eq!(
+        client.try_approve_as_delegate(&impostor, &a3, &id),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(client.get_proposal(&id).approvals.len(), 1);
+}
+
+#[test]
+fn test_revoked_delegate_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (a1, a2, a3) = setup_multisig(&env, &client, &admin);
+    let delegate = Address::generate(&env);
+
+    client.set_signer_delegate(&a2, &Some(delegate.clone()));
+    client.set_signer_delegate(&a2, &None);
+    let id = client.propose_action(&a1, &AdminAction::TransferAdmin(a3));
+
+    assert_eq!(
+        client.try_approve_as_delegate(&delegate, &a2, &id),
+        Err(Ok(NavinError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_non_admin_cannot_set_delegate() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    setup_multisig(&env, &client, &admin);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_signer_delegate(&outsider, &Some(Address::generate(&env))),
+        Err(Ok(NavinError::NotAnAdmin))
+    );
+}
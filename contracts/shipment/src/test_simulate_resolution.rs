@@ -0,0 +1,160 @@
+//! Tests for the read-only dispute resolution previews
+//! (`simulate_resolution` / `simulate_resolution_split`).
+//!
+//! Each simulation is checked against the balances produced by the real
+//! resolution on the same shipment.
+
+use crate::{test_utils, DisputeResolution, NavinError, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    company: Address,
+    carrier: Address,
+    token: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    Ctx {
+        env,
+        client,
+        admin,
+        company,
+        carrier,
+        token,
+    }
+}
+
+fn disputed_shipment(ctx: &Ctx, amount: i128) -> u64 {
+    StellarAssetClient::new(&ctx.env, &ctx.token).mint(&ctx.company, &amount);
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[1u8; 32]),
+        &Vec::new(&ctx.env),
+        &(ctx.env.ledger().timestamp() + 3600),
+    );
+    ctx.client.deposit_escrow(&ctx.company, &id, &amount);
+    ctx.client
+        .raise_dispute(&ctx.company, &id, &BytesN::from_array(&ctx.env, &[9u8; 32]));
+    id
+}
+
+fn balance(ctx: &Ctx, who: &Address) -> i128 {
+    TokenClient::new(&ctx.env, &ctx.token).balance(who)
+}
+
+#[test]
+fn test_simulate_release_matches_resolution() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 1_000);
+
+    let (recipient, amount) = ctx
+        .client
+        .simulate_resolution(&id, &DisputeResolution::ReleaseToCarrier);
+    assert_eq!(recipient, ctx.carrier);
+
+    let before = balance(&ctx, &recipient);
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &id,
+        &DisputeResolution::ReleaseToCarrier,
+        &BytesN::from_array(&ctx.env, &[4u8; 32]),
+    );
+    assert_eq!(balance(&ctx, &recipient) - before, amount);
+}
+
+#[test]
+fn test_simulate_refund_matches_resolution() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 750);
+
+    let (recipient, amount) = ctx
+        .client
+        .simulate_resolution(&id, &DisputeResolution::RefundToCompany);
+    assert_eq!(recipient, ctx.company);
+    assert_eq!(amount, 750);
+
+    ctx.client.resolve_dispute(
+        &ctx.admin,
+        &id,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&ctx.env, &[4u8; 32]),
+    );
+    assert_eq!(balance(&ctx, &ctx.company), amount);
+}
+
+#[test]
+fn test_simulate_split_matches_resolution() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 1_001);
+
+    let (to_carrier, to_company) = ctx.client.simulate_resolution_split(&id, &6_500);
+    assert_eq!(to_carrier + to_company, 1_001);
+
+    ctx.client.resolve_dispute_split(
+        &ctx.admin,
+        &id,
+        &6_500,
+        &BytesN::from_array(&ctx.env, &[4u8; 32]),
+    );
+    assert_eq!(balance(&ctx, &ctx.carrier), to_carrier);
+    assert_eq!(balance(&ctx, &ctx.company), to_company);
+}
+
+#[test]
+fn test_simulation_does_not_mutate_state() {
+    let ctx = setup();
+    let id = disputed_shipment(&ctx, 500);
+    let before = ctx.client.get_shipment(&id);
+
+    ctx.client
+        .simulate_resolution(&id, &DisputeResolution::ReleaseToCarrier);
+    ctx.client.simulate_resolution_split(&id, &5_000);
+
+    let after = ctx.client.get_shipment(&id);
+    assert_eq!(after.status, before.status);
+    assert_eq!(after.escrow_amount, before.escrow_amount);
+    assert_eq!(after.integration_nonce, before.integration_nonce);
+    assert_eq!(balance(&ctx, &ctx.carrier), 0);
+}
+
+#[test]
+fn test_simulation_rejects_undisputed_shipment() {
+    let ctx = setup();
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(&ctx.env),
+        &ctx.carrier,
+        &BytesN::from_array(&ctx.env, &[2u8; 32]),
+        &Vec::new(&ctx.env),
+        &(ctx.env.ledger().timestamp() + 3600),
+    );
+
+    assert_eq!(
+        ctx.client
+            .try_simulate_resolution(&id, &DisputeResolution::ReleaseToCarrier),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+    assert_eq!(
+        ctx.client.try_simulate_resolution_split(&id, &10_001),
+        Err(Ok(NavinError::InvalidAmount))
+    );
+}
@@ -0,0 +1,62 @@
+//! Tests for `get_stale_shipments`.
+
+use crate::test::*;
+use crate::{test_utils, NavinError, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, BytesN, Vec};
+
+#[test]
+fn test_stale_shipments_filtered_by_inactivity() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let mut ids = Vec::new(&env);
+    for seed in 1..=4u8 {
+        ids.push_back(client.create_shipment(
+            &company,
+            &receiver,
+            &carrier,
+            &BytesN::from_array(&env, &[seed; 32]),
+            &Vec::new(&env),
+            &test_utils::future_deadline(&env, 864_000),
+        ));
+    }
+    let (idle, touched, delivered, idle_too) = (
+        ids.get_unchecked(0),
+        ids.get_unchecked(1),
+        ids.get_unchecked(2),
+        ids.get_unchecked(3),
+    );
+
+    let hash = BytesN::from_array(&env, &[9u8; 32]);
+    client.update_status(&carrier, &delivered, &ShipmentStatus::InTransit, &hash);
+    client.confirm_delivery(&receiver, &delivered, &hash);
+
+    test_utils::advance_ledger_time(&env, 7_200);
+    client.update_status(&carrier, &touched, &ShipmentStatus::InTransit, &hash);
+    test_utils::advance_ledger_time(&env, 100);
+
+    assert_eq!(
+        client.get_stale_shipments(&3_600, &0, &10),
+        vec![&env, idle, idle_too]
+    );
+    assert_eq!(
+        client.get_stale_shipments(&3_600, &1, &10),
+        vec![&env, idle_too]
+    );
+    assert_eq!(client.get_stale_shipments(&3_600, &0, &1), vec![&env, idle]);
+    assert_eq!(client.get_stale_shipments(&10_000, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_stale_shipments_rejects_zero_limit() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+    assert_eq!(
+        client.try_get_stale_shipments(&3_600, &0, &0),
+        Err(Ok(NavinError::InvalidConfig))
+    );
+}
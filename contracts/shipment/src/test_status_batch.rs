@@ -0,0 +1,143 @@
+//! Tests for `update_status_batch`.
+
+use crate::test::*;
+use crate::test_utils::{dummy_hash, future_deadline};
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn carrier_shipments(
+    env: &Env,
+    client: &NavinShipmentClient,
+    admin: &Address,
+    count: u32,
+) -> (Address, Vec<u64>) {
+    let company = Address::generate(env);
+    let carrier = Address::generate(env);
+    client.add_company(admin, &company);
+    client.add_carrier(admin, &carrier);
+    let mut ids = Vec::new(env);
+    for _ in 0..count {
+        ids.push_back(client.create_shipment(
+            &company,
+            &Address::generate(env),
+            &carrier,
+            &dummy_hash(env),
+            &Vec::new(env),
+            &future_deadline(env, 86_400),
+        ));
+    }
+    (carrier, ids)
+}
+
+fn updates(env: &Env, entries: &[(u64, ShipmentStatus)]) -> Vec<(u64, ShipmentStatus, BytesN<32>)> {
+    let mut batch = Vec::new(env);
+    for (id, status) in entries {
+        batch.push_back((
+            *id,
+            status.clone(),
+            BytesN::from_array(env, &[*id as u8 + 1; 32]),
+        ));
+    }
+    batch
+}
+
+#[test]
+fn test_batch_updates_three_shipments() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, ids) = carrier_shipments(&env, &client, &admin, 3);
+
+    let batch = updates(
+        &env,
+        &[
+            (ids.get(0).unwrap(), ShipmentStatus::InTransit),
+            (ids.get(1).unwrap(), ShipmentStatus::InTransit),
+            (ids.get(2).unwrap(), ShipmentStatus::InTransit),
+        ],
+    );
+    client.update_status_batch(&carrier, &batch);
+
+    for id in ids.iter() {
+        assert_eq!(client.get_shipment(&id).status, ShipmentStatus::InTransit);
+    }
+}
+
+#[test]
+fn test_invalid_transition_rolls_back_batch() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, ids) = carrier_shipments(&env, &client, &admin, 3);
+
+    let batch = updates(
+        &env,
+        &[
+            (ids.get(0).unwrap(), ShipmentStatus::InTransit),
+            (ids.get(1).unwrap(), ShipmentStatus::InTransit),
+            (ids.get(2).unwrap(), ShipmentStatus::AtCheckpoint),
+        ],
+    );
+    assert_eq!(
+        client.try_update_status_batch(&carrier, &batch),
+        Err(Ok(NavinError::InvalidStatus))
+    );
+
+    for id in ids.iter() {
+        assert_eq!(client.get_shipment(&id).status, ShipmentStatus::Created);
+    }
+}
+
+#[test]
+fn test_batch_rejects_foreign_shipment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, ids) = carrier_shipments(&env, &client, &admin, 1);
+    let (_other, other_ids) = carrier_shipments(&env, &client, &admin, 1);
+
+    let batch = updates(
+        &env,
+        &[
+            (ids.get(0).unwrap(), ShipmentStatus::InTransit),
+            (other_ids.get(0).unwrap(), ShipmentStatus::InTransit),
+        ],
+    );
+    assert_eq!(
+        client.try_update_status_batch(&carrier, &batch),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert_eq!(
+        client.get_shipment(&ids.get(0).unwrap()).status,
+        ShipmentStatus::Created
+    );
+}
+
+#[test]
+fn test_batch_rejects_duplicate_shipment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, ids) = carrier_shipments(&env, &client, &admin, 1);
+    let id = ids.get(0).unwrap();
+
+    let batch = updates(
+        &env,
+        &[
+            (id, ShipmentStatus::InTransit),
+            (id, ShipmentStatus::AtCheckpoint),
+        ],
+    );
+    assert_eq!(
+        client.try_update_status_batch(&carrier, &batch),
+        Err(Ok(NavinError::InvalidShipmentInput))
+    );
+}
+
+#[test]
+fn test_batch_too_large() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let (carrier, ids) = carrier_shipments(&env, &client, &admin, 11);
+
+    let mut batch = Vec::new(&env);
+    for id in ids.iter() {
+        batch.push_back((id, ShipmentStatus::InTransit, dummy_hash(&env)));
+    }
+    assert_eq!(
+        client.try_update_status_batch(&carrier, &batch),
+        Err(Ok(NavinError::BatchTooLarge))
+    );
+}
@@ -0,0 +1,110 @@
+//! Tests for the per-shipment status transition history.
+
+use crate::test::*;
+use crate::test_utils::{advance_past_rate_limit, dummy_hash, future_deadline};
+use crate::{DisputeResolution, NavinError, ShipmentStatus, MAX_STATUS_HISTORY};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, BytesN, Vec};
+
+#[test]
+fn test_status_history_records_each_transition() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 86_400),
+    );
+    let created_at = env.ledger().timestamp();
+    client.deposit_escrow(&company, &id, &1_000);
+
+    advance_past_rate_limit(&env);
+    let in_transit_at = env.ledger().timestamp();
+    client.update_status(
+        &carrier,
+        &id,
+        &ShipmentStatus::InTransit,
+        &BytesN::from_array(&env, &[2u8; 32]),
+    );
+
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    let disputed_at = env.ledger().timestamp();
+    client.raise_dispute(&receiver, &id, &BytesN::from_array(&env, &[3u8; 32]));
+
+    env.ledger().with_mut(|l| l.timestamp += 10);
+    let cancelled_at = env.ledger().timestamp();
+    client.resolve_dispute(
+        &admin,
+        &id,
+        &DisputeResolution::RefundToCompany,
+        &BytesN::from_array(&env, &[4u8; 32]),
+    );
+
+    let history = client.get_status_history(&id);
+    assert_eq!(history.len(), 4);
+    assert_eq!(
+        history.get(0).unwrap(),
+        (ShipmentStatus::Created, created_at, company)
+    );
+    assert_eq!(
+        history.get(1).unwrap(),
+        (ShipmentStatus::InTransit, in_transit_at, carrier)
+    );
+    assert_eq!(
+        history.get(2).unwrap(),
+        (ShipmentStatus::Disputed, disputed_at, receiver)
+    );
+    assert_eq!(
+        history.get(3).unwrap(),
+        (ShipmentStatus::Cancelled, cancelled_at, admin)
+    );
+}
+
+#[test]
+fn test_status_history_is_bounded() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let id = client.create_shipment(
+        &company,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &dummy_hash(&env),
+        &Vec::new(&env),
+        &future_deadline(&env, 86_400),
+    );
+
+    // Admin bypasses the status rate limit; bounce between two states.
+    let hash = BytesN::from_array(&env, &[2u8; 32]);
+    client.update_status(&admin, &id, &ShipmentStatus::InTransit, &hash);
+    for _ in 0..MAX_STATUS_HISTORY {
+        client.update_status(&admin, &id, &ShipmentStatus::AtCheckpoint, &hash);
+        client.update_status(&admin, &id, &ShipmentStatus::InTransit, &hash);
+    }
+
+    let history = client.get_status_history(&id);
+    assert_eq!(history.len(), MAX_STATUS_HISTORY);
+    // The oldest entries (including `Created`) have been dropped.
+    assert_eq!(history.get(0).unwrap().0, ShipmentStatus::AtCheckpoint);
+    assert_eq!(
+        history.get(MAX_STATUS_HISTORY - 1).unwrap().0,
+        ShipmentStatus::InTransit
+    );
+}
+
+#[test]
+fn test_status_history_unknown_shipment() {
+    let (_env, client, _admin, _token) = setup_initialized_shipment_env();
+    assert_eq!(
+        client.try_get_status_history(&999),
+        Err(Ok(NavinError::ShipmentNotFound))
+    );
+}
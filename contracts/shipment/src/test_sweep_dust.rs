@@ -0,0 +1,220 @@
+//! # Dust Sweep Tests
+//!
+//! Verifies `sweep_dust`:
+//! - Residue below `dust_threshold` on `Delivered`/`Cancelled` shipments is
+//!   transferred to the treasury and the shipment is finalized.
+//! - Active shipments, residue at or above the threshold and shipments with a
+//!   delayed release awaiting `finalize_release` are skipped, even once the
+//!   delay has elapsed.
+//! - The batch limit and admin-only access are enforced.
+
+#![cfg(test)]
+
+use crate::{ContractConfig, NavinError, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+const DUST_THRESHOLD: i128 = 10;
+
+struct Ctx {
+    env: Env,
+    client: NavinShipmentClient<'static>,
+    admin: Address,
+    token: Address,
+    company: Address,
+}
+
+fn setup() -> Ctx {
+    let (env, admin) = crate::test_utils::setup_env();
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let cfg = ContractConfig {
+        dust_threshold: DUST_THRESHOLD,
+        ..ContractConfig::default()
+    };
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    Ctx {
+        env,
+        client,
+        admin,
+        token,
+        company,
+    }
+}
+
+/// Create a funded shipment and force it into `status`, leaving `escrow` behind.
+fn shipment_with_residue(ctx: &Ctx, seed: u8, escrow: i128, status: ShipmentStatus) -> u64 {
+    let env = &ctx.env;
+    let id = ctx.client.create_shipment(
+        &ctx.company,
+        &Address::generate(env),
+        &Address::generate(env),
+        &BytesN::from_array(env, &[seed; 32]),
+        &Vec::new(env),
+        &(env.ledger().timestamp() + 86_400),
+    );
+    StellarAssetClient::new(env, &ctx.token).mint(&ctx.company, &escrow);
+    ctx.client.deposit_escrow(&ctx.company, &id, &escrow);
+
+    env.as_contract(&ctx.client.address, || {
+        let mut s = crate::storage::get_shipment(env, id).unwrap();
+        s.status = status;
+        crate::storage::set_shipment(env, &s);
+    });
+    id
+}
+
+#[test]
+fn test_sweep_dust_from_completed_shipments() {
+    let ctx = setup();
+    let delivered = shipment_with_residue(&ctx, 1, 3, ShipmentStatus::Delivered);
+    let cancelled = shipment_with_residue(&ctx, 2, 7, ShipmentStatus::Cancelled);
+    let treasury = Address::generate(&ctx.env);
+
+    let mut ids = Vec::new(&ctx.env);
+    ids.push_back(delivered);
+    ids.push_back(cancelled);
+    let swept = ctx.client.sweep_dust(&ctx.admin, &ids, &treasury);
+
+    assert_eq!(swept, 10);
+    assert_eq!(
+        TokenClient::new(&ctx.env, &ctx.token).balance(&treasury),
+        10
+    );
+    for id in [delivered, cancelled] {
+        let shipment = ctx.client.get_shipment(&id);
+        assert_eq!(shipment.escrow_amount, 0);
+        assert!(shipment.finalized);
+        assert_eq!(ctx.client.get_escrow_balance(&id), 0);
+    }
+}
+
+#[test]
+fn test_sweep_dust_skips_ineligible_shipments() {
+    let ctx = setup();
+    let dust = shipment_with_residue(&ctx, 1, 4, ShipmentStatus::Delivered);
+    let large = shipment_with_residue(&ctx, 2, DUST_THRESHOLD, ShipmentStatus::Delivered);
+    let active = shipment_with_residue(&ctx, 3, 4, ShipmentStatus::InTransit);
+    let treasury = Address::generate(&ctx.env);
+
+    let mut ids = Vec::new(&ctx.env);
+    ids.push_back(dust);
+    ids.push_back(large);
+    ids.push_back(active);
+    ids.push_back(999);
+    let swept = ctx.client.sweep_dust(&ctx.admin, &ids, &treasury);
+
+    assert_eq!(swept, 4);
+    assert_eq!(TokenClient::new(&ctx.env, &ctx.token).balance(&treasury), 4);
+    assert_eq!(
+        ctx.client.get_shipment(&large).escrow_amount,
+        DUST_THRESHOLD
+    );
+    assert_eq!(ctx.client.get_shipment(&active).escrow_amount, 4);
+}
+
+#[test]
+fn test_sweep_dust_disabled_by_default() {
+    let ctx = setup();
+    ctx.client
+        .update_config(&ctx.admin, &ContractConfig::default());
+    let id = shipment_with_residue(&ctx, 1, 1, ShipmentStatus::Delivered);
+
+    let mut ids = Vec::new(&ctx.env);
+    ids.push_back(id);
+    let swept = ctx
+        .client
+        .sweep_dust(&ctx.admin, &ids, &Address::generate(&ctx.env));
+
+    assert_eq!(swept, 0);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 1);
+}
+
+#[test]
+fn test_sweep_dust_enforces_batch_limit() {
+    let ctx = setup();
+    let limit = ctx.client.get_contract_config().batch_operation_limit;
+
+    let mut ids = Vec::new(&ctx.env);
+    for id in 1..=(limit as u64 + 1) {
+        ids.push_back(id);
+    }
+    let result = ctx
+        .client
+        .try_sweep_dust(&ctx.admin, &ids, &Address::generate(&ctx.env));
+    assert_eq!(result, Err(Ok(NavinError::BatchTooLarge)));
+}
+
+#[test]
+fn test_sweep_dust_requires_admin() {
+    let ctx = setup();
+    let outsider = Address::generate(&ctx.env);
+
+    let result = ctx
+        .client
+        .try_sweep_dust(&outsider, &Vec::new(&ctx.env), &outsider);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_sweep_dust_skips_pending_release() {
+    let ctx = setup();
+    let id = shipment_with_residue(&ctx, 1, 4, ShipmentStatus::Delivered);
+    let release_at = ctx.env.ledger().timestamp() + 3600;
+    ctx.env.as_contract(&ctx.client.address, || {
+        crate::storage::set_release_at(&ctx.env, id, release_at);
+    });
+
+    let mut ids = Vec::new(&ctx.env);
+    ids.push_back(id);
+    let swept = ctx
+        .client
+        .sweep_dust(&ctx.admin, &ids, &Address::generate(&ctx.env));
+
+    assert_eq!(swept, 0);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 4);
+}
+
+#[test]
+fn test_sweep_dust_skips_elapsed_unfinalized_release() {
+    let ctx = setup();
+    let id = shipment_with_residue(&ctx, 1, 4, ShipmentStatus::Delivered);
+    let release_at = ctx.env.ledger().timestamp();
+    ctx.env.as_contract(&ctx.client.address, || {
+        crate::storage::set_release_at(&ctx.env, id, release_at);
+    });
+
+    let mut ids = Vec::new(&ctx.env);
+    ids.push_back(id);
+    let swept = ctx
+        .client
+        .sweep_dust(&ctx.admin, &ids, &Address::generate(&ctx.env));
+
+    assert_eq!(swept, 0);
+    assert_eq!(ctx.client.get_shipment(&id).escrow_amount, 4);
+    ctx.env.as_contract(&ctx.client.address, || {
+        assert_eq!(
+            crate::storage::get_release_at(&ctx.env, id),
+            Some(release_at)
+        );
+    });
+}
+
+#[test]
+fn test_dust_threshold_above_cap_rejected() {
+    let ctx = setup();
+    let cfg = ContractConfig {
+        dust_threshold: crate::config::MAX_DUST_THRESHOLD + 1,
+        ..ContractConfig::default()
+    };
+    let result = ctx.client.try_update_config(&ctx.admin, &cfg);
+    assert_eq!(result, Err(Ok(NavinError::InvalidConfig)));
+}
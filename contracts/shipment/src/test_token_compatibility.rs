@@ -48,6 +48,7 @@ fn setup_test(variant: TokenVariant) -> TestContext {
                 &admin,
                 &soroban_sdk::String::from_str(&env, "Navin Token"),
                 &soroban_sdk::String::from_str(&env, "NVN"),
+                &7,
                 &1_000_000_000,
             );
             token_addr
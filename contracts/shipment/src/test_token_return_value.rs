@@ -0,0 +1,49 @@
+//! Escrow transfers must succeed against tokens whose `transfer` returns a
+//! value instead of unit.
+
+use crate::{test_utils, NavinShipment, NavinShipmentClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
+
+#[contract]
+struct ValueReturningToken;
+
+#[contractimpl]
+impl ValueReturningToken {
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_escrow_succeeds_with_value_returning_token() {
+    let (env, admin) = test_utils::setup_env();
+    let token = env.register(ValueReturningToken {}, ());
+    let client = NavinShipmentClient::new(&env, &env.register(NavinShipment, ()));
+    client.initialize(&admin, &token);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &Vec::new(&env),
+        &test_utils::future_deadline(&env, 86_400),
+    );
+
+    client.deposit_escrow(&company, &id, &1_000);
+    assert_eq!(client.get_escrow_balance(&id), 1_000);
+
+    client.refund_escrow(&company, &id);
+    assert_eq!(client.get_escrow_balance(&id), 0);
+}
@@ -0,0 +1,90 @@
+//! Tests for `transfer_shipment_ownership`.
+
+use crate::test::*;
+use crate::test_utils::future_deadline;
+use crate::{NavinError, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn create(env: &Env, client: &NavinShipmentClient, company: &Address) -> u64 {
+    client.create_shipment(
+        company,
+        &Address::generate(env),
+        &Address::generate(env),
+        &BytesN::from_array(env, &[1u8; 32]),
+        &Vec::new(env),
+        &future_deadline(env, 3600),
+    )
+}
+
+#[test]
+fn test_transfer_moves_sender_and_active_counts() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company_a = Address::generate(&env);
+    let company_b = Address::generate(&env);
+    client.add_company(&admin, &company_a);
+    client.add_company(&admin, &company_b);
+    let id = create(&env, &client, &company_a);
+
+    client.transfer_shipment_ownership(&company_a, &company_b, &id);
+
+    assert_eq!(client.get_shipment(&id).sender, company_b);
+    assert_eq!(client.get_active_shipment_count(&company_a), 0);
+    assert_eq!(client.get_active_shipment_count(&company_b), 1);
+}
+
+#[test]
+fn test_admin_can_transfer_ownership() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company_a = Address::generate(&env);
+    let company_b = Address::generate(&env);
+    client.add_company(&admin, &company_a);
+    client.add_company(&admin, &company_b);
+    let id = create(&env, &client, &company_a);
+
+    client.transfer_shipment_ownership(&admin, &company_b, &id);
+    assert_eq!(client.get_shipment(&id).sender, company_b);
+}
+
+#[test]
+fn test_transfer_requires_company_role() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+    let id = create(&env, &client, &company);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_transfer_shipment_ownership(&company, &outsider, &id);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_transfer_rejects_unrelated_caller() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company_a = Address::generate(&env);
+    let company_b = Address::generate(&env);
+    client.add_company(&admin, &company_a);
+    client.add_company(&admin, &company_b);
+    let id = create(&env, &client, &company_a);
+
+    let result = client.try_transfer_shipment_ownership(&company_b, &company_b, &id);
+    assert_eq!(result, Err(Ok(NavinError::Unauthorized)));
+}
+
+#[test]
+fn test_transfer_rejects_terminal_shipment() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let company_a = Address::generate(&env);
+    let company_b = Address::generate(&env);
+    client.add_company(&admin, &company_a);
+    client.add_company(&admin, &company_b);
+    let id = create(&env, &client, &company_a);
+    env.as_contract(&client.address, || {
+        let mut shipment = crate::storage::get_shipment(&env, id).unwrap();
+        shipment.status = ShipmentStatus::Delivered;
+        crate::storage::set_shipment(&env, &shipment);
+    });
+
+    let result = client.try_transfer_shipment_ownership(&company_a, &company_b, &id);
+    assert_eq!(result, Err(Ok(NavinError::ShipmentAlreadyCompleted)));
+}
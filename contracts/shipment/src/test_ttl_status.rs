@@ -0,0 +1,88 @@
+extern crate std;
+
+use crate::{types::DataKey, NavinShipment, NavinShipmentClient, ShipmentStatus};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{storage::Persistent, Address as _, Ledger},
+    Address, BytesN, Env,
+};
+
+#[contract]
+struct TtlStatusMockToken;
+
+#[contractimpl]
+impl TtlStatusMockToken {
+    pub fn decimals(_env: soroban_sdk::Env) -> u32 {
+        7
+    }
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+/// Create a shipment, force it into `status`, let its TTL decay below the
+/// threshold, then extend it and return the resulting TTL.
+fn ttl_after_extension(status: ShipmentStatus) -> u32 {
+    let (env, admin) = crate::test_utils::setup_env();
+    let token = env.register(TtlStatusMockToken {}, ());
+    let cid = env.register(NavinShipment, ());
+    let client = NavinShipmentClient::new(&env, &cid);
+
+    env.as_contract(&cid, || {
+        env.storage().instance().extend_ttl(100_000, 100_000);
+    });
+
+    client.initialize(&admin, &token);
+
+    let mut cfg = client.get_contract_config();
+    cfg.shipment_ttl_threshold = 10_000;
+    cfg.shipment_ttl_extension = 50_000;
+    cfg.settled_ttl_extension = 20_000;
+    client.update_config(&admin, &cfg);
+
+    let company = Address::generate(&env);
+    let carrier = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    client.add_company(&admin, &company);
+    client.add_carrier(&admin, &carrier);
+
+    let shipment_id = client.create_shipment(
+        &company,
+        &receiver,
+        &carrier,
+        &BytesN::from_array(&env, &[0x01u8; 32]),
+        &soroban_sdk::Vec::new(&env),
+        &(env.ledger().timestamp() + 86_400),
+    );
+
+    env.as_contract(&cid, || {
+        let mut shipment = crate::storage::get_shipment(&env, shipment_id).unwrap();
+        shipment.status = status;
+        crate::storage::set_shipment(&env, &shipment);
+    });
+
+    env.ledger().with_mut(|l| {
+        l.sequence_number += 45_000;
+    });
+
+    client.extend_shipment_ttl(&shipment_id);
+
+    env.as_contract(&cid, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Shipment(shipment_id))
+    })
+}
+
+#[test]
+fn test_delivered_shipment_uses_settled_extension() {
+    assert_eq!(ttl_after_extension(ShipmentStatus::Delivered), 20_000);
+}
+
+#[test]
+fn test_cancelled_shipment_uses_settled_extension() {
+    assert_eq!(ttl_after_extension(ShipmentStatus::Cancelled), 20_000);
+}
+
+#[test]
+fn test_active_shipment_uses_standard_extension() {
+    assert_eq!(ttl_after_extension(ShipmentStatus::InTransit), 50_000);
+}
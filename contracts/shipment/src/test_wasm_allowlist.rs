@@ -0,0 +1,76 @@
+//! Tests for the upgrade WASM allowlist (`allow_wasm_hash`).
+
+use crate::test::*;
+use crate::{AdminAction, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+fn upload_upgrade_wasm(env: &Env) -> BytesN<32> {
+    let wasm: &[u8] = include_bytes!("../test_wasms/upgrade_test.wasm");
+    env.deployer().upload_contract_wasm(wasm)
+}
+
+fn stored_version(env: &Env, contract: &Address) -> u32 {
+    env.as_contract(contract, || crate::storage::get_version(env))
+}
+
+#[test]
+fn test_upgrade_to_allowlisted_hash() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let wasm_hash = upload_upgrade_wasm(&env);
+
+    client.allow_wasm_hash(&admin, &wasm_hash);
+    assert!(client.is_wasm_hash_allowed(&wasm_hash));
+
+    client.upgrade(&admin, &wasm_hash, &2);
+    assert_eq!(stored_version(&env, &client.address), 2);
+}
+
+#[test]
+fn test_upgrade_to_unlisted_hash_rejected() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let wasm_hash = upload_upgrade_wasm(&env);
+
+    assert!(!client.is_wasm_hash_allowed(&wasm_hash));
+    assert_eq!(
+        client.try_upgrade(&admin, &wasm_hash, &2),
+        Err(Ok(NavinError::WasmNotAllowlisted))
+    );
+    assert_eq!(client.get_version(), 1);
+}
+
+#[test]
+fn test_multisig_upgrade_requires_allowlisted_hash() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    let wasm_hash = upload_upgrade_wasm(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let mut admins = Vec::new(&env);
+    admins.push_back(admin1.clone());
+    admins.push_back(admin2.clone());
+    client.init_multisig(&admin, &admins, &2);
+
+    let proposal_id = client.propose_action(&admin1, &AdminAction::Upgrade(wasm_hash.clone()));
+    assert_eq!(
+        client.try_approve_action(&admin2, &proposal_id),
+        Err(Ok(NavinError::WasmNotAllowlisted))
+    );
+    assert!(!client.get_proposal(&proposal_id).executed);
+
+    client.allow_wasm_hash(&admin, &wasm_hash);
+    client.approve_action(&admin2, &proposal_id);
+    assert_eq!(stored_version(&env, &client.address), 2);
+}
+
+#[test]
+fn test_allow_wasm_hash_admin_only() {
+    let (env, client, _admin, _token) = setup_initialized_shipment_env();
+    let outsider = Address::generate(&env);
+    let wasm_hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    assert_eq!(
+        client.try_allow_wasm_hash(&outsider, &wasm_hash),
+        Err(Ok(NavinError::Unauthorized))
+    );
+    assert!(!client.is_wasm_hash_allowed(&wasm_hash));
+}
@@ -0,0 +1,70 @@
+//! Tests for the `max_whitelist_size` cap on company carrier whitelists.
+
+use crate::test::*;
+use crate::{ContractConfig, NavinError};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Address;
+
+const CAP: u32 = 3;
+
+#[test]
+fn test_whitelist_accepts_up_to_cap_and_rejects_one_more() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            max_whitelist_size: CAP,
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    for _ in 0..CAP {
+        client.add_carrier_to_whitelist(&company, &Address::generate(&env));
+    }
+
+    let result = client.try_add_carrier_to_whitelist(&company, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(NavinError::WhitelistFull)));
+}
+
+#[test]
+fn test_removal_frees_whitelist_slot() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            max_whitelist_size: 1,
+            ..ContractConfig::default()
+        },
+    );
+    let company = Address::generate(&env);
+    client.add_company(&admin, &company);
+
+    let first = Address::generate(&env);
+    client.add_carrier_to_whitelist(&company, &first);
+    client.remove_carrier_from_whitelist(&company, &first);
+
+    let second = Address::generate(&env);
+    client.add_carrier_to_whitelist(&company, &second);
+    assert!(client.is_carrier_whitelisted(&company, &second));
+}
+
+#[test]
+fn test_whitelist_cap_is_per_company() {
+    let (env, client, admin, _token) = setup_initialized_shipment_env();
+    client.update_config(
+        &admin,
+        &ContractConfig {
+            max_whitelist_size: 1,
+            ..ContractConfig::default()
+        },
+    );
+    let company_a = Address::generate(&env);
+    let company_b = Address::generate(&env);
+    client.add_company(&admin, &company_a);
+    client.add_company(&admin, &company_b);
+
+    client.add_carrier_to_whitelist(&company_a, &Address::generate(&env));
+    client.add_carrier_to_whitelist(&company_b, &Address::generate(&env));
+}
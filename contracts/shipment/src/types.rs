@@ -128,6 +128,108 @@ pub enum DataKey {
     CreationQuotaConfig,
     /// Deterministic action digest stored on proposal creation.
     ProposalDigest(u64),
+    /// Whether an address has opted into the on-chain notification inbox.
+    InboxOptIn(Address),
+    /// Bounded on-chain notification inbox for an opted-in address.
+    Inbox(Address),
+    /// Set when a dispute has been escalated to multisig resolution.
+    DisputeEscalated(u64),
+    /// Registered receiver flag (receiver -> bool).
+    RegisteredReceiver(Address),
+    /// Carrier whitelist for a receiver — (receiver, carrier) -> bool.
+    ReceiverCarrierWhitelist(Address, Address),
+    /// Accumulated carrier-reported distance in meters for a shipment.
+    Distance(u64),
+    /// Bounded status transition history for a shipment.
+    StatusHistory(u64),
+    /// Number of shipments a carrier let expire past the deadline (carrier -> u32).
+    CarrierDeadlineMisses(Address),
+    /// Ledger timestamp of the last role grant/revocation for an address.
+    RoleChangedAt(Address),
+    /// Ids of every shipment created by a company (company -> Vec<u64>).
+    CompanyShipments(Address),
+    /// Number of carriers on a company's whitelist (company -> u32).
+    WhitelistSize(Address),
+    /// Escrow currently locked across all shipments.
+    TotalEscrowLocked,
+    /// Cumulative escrow released to carriers (and swept or charged as fees).
+    TotalEscrowReleased,
+    /// Cumulative escrow refunded to companies.
+    TotalEscrowRefunded,
+    /// Accumulated weighted breach penalty for a carrier (carrier -> u32).
+    CarrierBreachPenalty(Address),
+    /// Bond locked by the party that raised a dispute (shipment_id -> (Address, i128)).
+    DisputeBond(u64),
+    /// Whether checkpoint arrivals need the receiver's ack before milestone payment (shipment_id -> bool).
+    CheckpointAckRequired(u64),
+    /// Checkpoint hash confirmed by the receiver and not yet consumed by a payment (shipment_id -> BytesN<32>).
+    CheckpointAck(u64),
+    /// Deliveries confirmed for a carrier (carrier -> u32).
+    CarrierDeliveries(Address),
+    /// Deliveries a carrier confirmed on or before the deadline (carrier -> u32).
+    CarrierOnTimeDeliveries(Address),
+    /// Timestamps of disputes inside the current spike-detection window.
+    RecentDisputes,
+    /// Whether the contract was paused automatically by dispute spike detection.
+    AutoPaused,
+    /// Delegate allowed to approve proposals on a multisig signer's behalf (signer -> Address).
+    SignerDelegate(Address),
+    /// Extra addresses notified of a company's shipment lifecycle actions (company -> Vec<Address>).
+    NotificationRecipients(Address),
+    /// Escrow held by shipments currently in `Disputed` status (i128).
+    DisputedEscrowTotal,
+    /// Earliest timestamp a delivered shipment's withheld escrow may be released (shipment_id -> u64).
+    ReleaseAt(u64),
+    /// Hash of the off-chain rationale behind a dispute resolution (shipment_id -> BytesN<32>).
+    ResolutionNote(u64),
+    /// Timestamp of the last carrier report of a given kind (shipment_id, kind -> u64).
+    LastReport(u64, Symbol),
+    /// SHA-256 of the parameters a shipment was created with (shipment_id -> BytesN<32>).
+    CreationHash(u64),
+    /// Escrow set aside as dispute insurance for a shipment (shipment_id -> i128).
+    InsuranceReserve(u64),
+    /// Marks a shipment whose carrier lost a dispute, making its reserve claimable (shipment_id -> bool).
+    InsuranceClaimable(u64),
+    /// Ledger timestamp of a shipment's most recent carrier handoff (shipment_id -> u64).
+    LastHandoffAt(u64),
+    /// Number of addresses currently holding the Company role (u64).
+    CompanyCount,
+    /// Number of addresses currently holding the Carrier role (u64).
+    CarrierCount,
+    /// Compact record left behind when a settled shipment is archived (shipment_id -> ArchivedShipment).
+    ShipmentArchiveRecord(u64),
+    /// IDs of shipments currently assigned to a carrier (Address -> Vec<u64>).
+    CarrierShipments(Address),
+    /// Ed25519 public key a carrier signs milestone attestations with (carrier -> BytesN<32>).
+    CarrierPublicKey(Address),
+    /// Whether the assigned carrier accepted a shipment (shipment_id -> bool).
+    AssignmentAccepted(u64),
+    /// Hash of the proof-of-pickup recorded by the carrier (shipment_id -> BytesN<32>).
+    PickupHash(u64),
+    /// Ledger timestamp at which a shipment last entered `AtCheckpoint` (shipment_id -> u64).
+    CheckpointEnteredAt(u64),
+    /// Number of checkpoint stays exceeding `max_checkpoint_dwell` (carrier -> u32).
+    CarrierCheckpointOverstays(Address),
+    /// Disputes resolved against a carrier (carrier -> u32).
+    CarrierDisputeLosses(Address),
+    /// Condition breaches recorded against a carrier (carrier -> u32).
+    CarrierBreaches(Address),
+    /// Whether the receiver has rated the carrier for a shipment (shipment_id -> bool).
+    Rated(u64),
+    /// Receiver ratings for a carrier as (count, sum of scores) (carrier -> (u32, u64)).
+    CarrierRatings(Address),
+    /// Ledger timestamp at which a shipment entered `Disputed` (shipment_id -> u64).
+    DisputeRaisedAt(u64),
+    /// Absolute-amount payment milestones for a shipment (shipment_id -> Vec<(Symbol, i128)>).
+    FixedPaymentMilestones(u64),
+    /// Number of disputes raised on a shipment (shipment_id -> u32).
+    DisputeCount(u64),
+    /// Timestamp the current carrier was assigned by reassignment (shipment_id -> u64).
+    AssignedAt(u64),
+    /// Sum of dispute bonds currently held by the contract (-> i128).
+    TotalDisputeBonds,
+    /// Governance-approved upgrade target (wasm_hash -> bool).
+    AllowedWasmHash(BytesN<32>),
 }
 
 /// Structured reason codes for escrow freeze events.
@@ -174,6 +276,8 @@ pub enum Role {
     Guardian,
     /// An operator that can perform operational tasks.
     Operator,
+    /// An arbitrator that can resolve disputed shipments.
+    Arbitrator,
     /// No role assigned.
     Unassigned,
 }
@@ -401,6 +505,190 @@ pub enum BreachType {
     TamperDetected,
 }
 
+/// An address's relationship to a shipment, as returned by `get_relationship`.
+///
+/// When an address fills several roles the first match wins, in the order
+/// `Sender`, `Receiver`, `Carrier`, `Admin`.
+///
+/// # Examples
+/// ```rust
+/// use crate::types::Relationship;
+/// let relationship = Relationship::Carrier;
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Relationship {
+    /// The company that created the shipment.
+    Sender,
+    /// The shipment's receiver.
+    Receiver,
+    /// The currently assigned carrier.
+    Carrier,
+    /// The contract admin, unrelated to the shipment otherwise.
+    Admin,
+    /// No relationship to the shipment.
+    None,
+}
+
+/// Reputation penalty applied per `BreachType` when a carrier reports a breach.
+///
+/// Stored in `ContractConfig.breach_weights` so serious breaches (tampering,
+/// impacts) can hurt a carrier's record more than environmental drift.
+///
+/// # Examples
+/// ```rust
+/// use crate::types::{BreachType, BreachWeights};
+/// let weights = BreachWeights::default();
+/// assert!(weights.weight_for(&BreachType::TamperDetected) > weights.weight_for(&BreachType::TemperatureHigh));
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BreachWeights {
+    pub temperature_high: u32,
+    pub temperature_low: u32,
+    pub humidity_high: u32,
+    pub impact: u32,
+    pub tamper_detected: u32,
+}
+
+impl BreachWeights {
+    /// Penalty weight for the given breach type.
+    pub fn weight_for(&self, breach_type: &BreachType) -> u32 {
+        match breach_type {
+            BreachType::TemperatureHigh => self.temperature_high,
+            BreachType::TemperatureLow => self.temperature_low,
+            BreachType::HumidityHigh => self.humidity_high,
+            BreachType::Impact => self.impact,
+            BreachType::TamperDetected => self.tamper_detected,
+        }
+    }
+}
+
+impl Default for BreachWeights {
+    fn default() -> Self {
+        Self {
+            temperature_high: 1,
+            temperature_low: 1,
+            humidity_high: 1,
+            impact: 3,
+            tamper_detected: 5,
+        }
+    }
+}
+
+/// Escrow handling knobs, stored in `ContractConfig.escrow_policy`.
+///
+/// Grouped into their own struct because Soroban caps a `contracttype`
+/// struct at 40 fields.
+///
+/// # Examples
+/// ```rust
+/// use crate::types::EscrowPolicy;
+/// let policy = EscrowPolicy::default();
+/// assert_eq!(policy.insurance_bps, 0);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct EscrowPolicy {
+    /// Cooling-off period in seconds between `confirm_delivery` and the escrow
+    /// release to the carrier. When non-zero, the remaining escrow is held
+    /// until `finalize_release` is called after the delay. `0` releases
+    /// immediately on confirmation.
+    /// Must be <= 2,592,000 (30 days).
+    pub release_delay_seconds: u64,
+    /// When `true`, any Company may fund a shipment's escrow. By default only
+    /// the shipment's sender or the admin may deposit.
+    pub allow_third_party_escrow: bool,
+    /// Share of each escrow deposit, in basis points, diverted into a
+    /// per-shipment insurance reserve that pays the company if the carrier
+    /// loses a dispute. 0 disables the reserve.
+    /// Must be <= 1000 (10%).
+    pub insurance_bps: u32,
+    /// When `true`, payment milestones may sum to less than 100%. The
+    /// remainder is held back and released on `confirm_delivery`.
+    pub allow_milestone_holdback: bool,
+    /// Share of the escrow, in basis points, paid to the receiver instead of
+    /// the carrier when `confirm_delivery` happens after the deadline.
+    /// 0 disables the penalty. Must be <= 10,000 (100%).
+    pub late_delivery_penalty_bps: u32,
+}
+
+/// Carrier obligation knobs, stored in `ContractConfig.carrier_policy`.
+///
+/// # Examples
+/// ```rust
+/// use crate::types::CarrierPolicy;
+/// let policy = CarrierPolicy::default();
+/// assert!(!policy.require_carrier_acceptance);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct CarrierPolicy {
+    /// When `check_deadline` expires a shipment the carrier had already picked
+    /// up (`InTransit`, `AtCheckpoint`, `PartiallyDelivered`), record a
+    /// deadline miss against the carrier and emit `carrier_deadline_miss`.
+    /// Shipments still `Created` are never penalized, as the carrier never
+    /// took custody.
+    pub penalize_deadline_miss: bool,
+    /// Minimum seconds between successive handoffs of the same shipment.
+    /// 0 disables the cooldown. Must be <= 604,800 (7 days).
+    pub handoff_cooldown_seconds: u64,
+    /// When `true`, `confirm_delivery` adds the delivering carrier to the
+    /// sender's whitelist if it is not already listed and there is room.
+    pub auto_whitelist_on_delivery: bool,
+    /// When `true`, a shipment cannot move from `Created` to `InTransit` until
+    /// its assigned carrier calls `accept_assignment`.
+    pub require_carrier_acceptance: bool,
+    /// Seconds the assigned carrier has to accept a shipment before anyone may
+    /// call `expire_assignment` to refund its escrow to the company. A non-zero
+    /// value also refunds escrow when the carrier declines. 0 disables auto-refund.
+    pub acceptance_timeout_seconds: u64,
+    /// Minimum seconds between a shipment's `created_at` and its first
+    /// non-admin `update_status`. Later updates are governed by
+    /// `min_status_update_interval`. 0 disables the check.
+    pub initial_update_delay: u64,
+    /// Maximum seconds a shipment may stay `AtCheckpoint` before the carrier is
+    /// penalized on the next transition out. 0 disables the check.
+    pub max_checkpoint_dwell: u64,
+}
+
+/// Dispute handling knobs, stored in `ContractConfig.dispute_policy`.
+///
+/// # Examples
+/// ```rust
+/// use crate::types::DisputePolicy;
+/// let policy = DisputePolicy::default();
+/// assert_eq!(policy.max_disputes_per_shipment, 3);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisputePolicy {
+    /// Number of disputes within `dispute_spike_window` seconds that
+    /// automatically pauses the contract. Once auto-paused, only the admin
+    /// can unpause. `0` disables the check. Must be <= 100.
+    pub dispute_spike_threshold: u32,
+    /// Length in seconds of the rolling window used by
+    /// `dispute_spike_threshold`. Must be > 0 and <= 2,592,000 (30 days).
+    pub dispute_spike_window: u64,
+    /// Seconds after a dispute is raised before anyone may call
+    /// `auto_resolve_dispute` to refund the company. 0 disables auto-resolution.
+    pub dispute_window_seconds: u64,
+    /// Maximum number of disputes that may be raised on a single shipment.
+    /// Further attempts fail with `DisputeLimitReached`. 0 disables the limit.
+    pub max_disputes_per_shipment: u32,
+}
+
+impl Default for DisputePolicy {
+    fn default() -> Self {
+        Self {
+            dispute_spike_threshold: 0,
+            dispute_spike_window: 3600,
+            dispute_window_seconds: 0,
+            max_disputes_per_shipment: 3,
+        }
+    }
+}
+
 /// Severity levels for condition breach events used for downstream analytics and alerting.
 ///
 /// # Examples
@@ -513,6 +801,26 @@ pub enum GeofenceEvent {
     RouteDeviation,
 }
 
+/// Receipt returned by `confirm_delivery` describing the final payout.
+///
+/// # Examples
+/// ```rust
+/// // let receipt = client.confirm_delivery(&receiver, &shipment_id, &pod_hash);
+/// // assert_eq!(receipt.carrier, carrier);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeliveryReceipt {
+    /// Delivered shipment identifier.
+    pub shipment_id: u64,
+    /// Escrow released to the carrier by this confirmation.
+    pub released_amount: i128,
+    /// Carrier that received the payout.
+    pub carrier: Address,
+    /// Ledger timestamp of the confirmation.
+    pub timestamp: u64,
+}
+
 /// Input data for creating a shipment in a batch.
 ///
 /// # Examples
@@ -632,6 +940,11 @@ pub enum AdminAction {
     ForceRelease(u64),
     /// Force refund escrow for a shipment to company.
     ForceRefund(u64),
+    /// Re-run initialization of the safe fields (token contract, config)
+    /// without touching shipments. Used for recovery after a redeploy.
+    Reinitialize(Address),
+    /// Resolve an escalated dispute: (shipment_id, resolution, reason_hash).
+    ResolveDispute(u64, DisputeResolution, BytesN<32>),
 }
 
 /// Multi-signature proposal for critical admin actions.
@@ -659,6 +972,68 @@ pub struct Proposal {
     pub executed: bool,
 }
 
+/// Lifecycle status of a multi-signature proposal, derived from its
+/// approvals, expiry, and execution flag.
+///
+/// # Examples
+/// ```rust
+/// // let status = ProposalStatus::Pending;
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProposalStatus {
+    /// Still collecting approvals.
+    Pending,
+    /// Approval threshold reached but not yet executed.
+    Approved,
+    /// Proposal has been executed.
+    Executed,
+    /// Proposal expired before being executed.
+    Expired,
+}
+
+/// A proposal together with fields derived from it for signer UIs.
+///
+/// # Examples
+/// ```rust
+/// // let detail = contract.get_proposal_detail(&env, 1);
+/// ```
+#[contracttype]
+#[derive(Clone)]
+pub struct ProposalDetail {
+    /// The raw proposal.
+    pub proposal: Proposal,
+    /// Computed lifecycle status.
+    pub status: ProposalStatus,
+    /// Number of approvals collected so far.
+    pub approvals: u32,
+    /// Approvals required for execution.
+    pub threshold: u32,
+    /// Seconds until the proposal expires; 0 once expired.
+    pub seconds_to_expiry: u64,
+}
+
+/// Snapshot of control changes that have been proposed but not yet applied.
+///
+/// # Examples
+/// ```rust
+/// // let pending = contract.get_pending_governance(&env);
+/// ```
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingGovernance {
+    /// Current admin.
+    pub admin: Address,
+    /// Successor proposed via `transfer_admin`, awaiting `accept_admin_transfer`.
+    pub proposed_admin: Option<Address>,
+    /// Current multisig signers.
+    pub signers: Vec<Address>,
+    /// Current multisig approval threshold.
+    pub threshold: u32,
+    /// Open `TransferAdmin` multisig proposals as (proposal_id, new_admin).
+    pub pending_admin_transfers: Vec<(u64, Address)>,
+}
+
 /// Notification types for backend indexing and push notifications.
 ///
 /// # Examples
@@ -803,6 +1178,98 @@ pub struct TtlHealthSummary {
     pub query_timestamp: u64,
 }
 
+/// Reputation counters stored for a carrier.
+///
+/// Unknown carriers report all counters as zero.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CarrierReputation {
+    /// Carrier the counters belong to.
+    pub carrier: Address,
+    /// Total deliveries confirmed for the carrier.
+    pub deliveries: u32,
+    /// Deliveries confirmed on or before their deadline.
+    pub on_time_deliveries: u32,
+    /// Shipments whose deadline expired while in the carrier's custody.
+    pub deadline_misses: u32,
+    /// Accumulated weighted condition-breach penalty.
+    pub breach_penalty: u32,
+    /// Number of condition breaches reported, regardless of weight.
+    pub breaches: u32,
+    /// Disputes resolved against the carrier.
+    pub dispute_losses: u32,
+    /// Checkpoint stays that exceeded `max_checkpoint_dwell`.
+    pub checkpoint_overstays: u32,
+    /// Number of receiver ratings submitted for the carrier.
+    pub ratings: u32,
+    /// Average receiver rating scaled by 100 (e.g. 450 = 4.5 stars); 0 if unrated.
+    pub average_rating: u32,
+}
+
+/// Snapshot of which optional contract behaviours are currently active.
+///
+/// Bundles the boolean switches from `ContractConfig` together with runtime
+/// state (pause, platform fee) so clients can discover enabled features in a
+/// single call.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureFlags {
+    /// Contract is paused (manually or automatically).
+    pub paused: bool,
+    /// Contract was paused automatically by the dispute spike guard.
+    pub auto_paused: bool,
+    /// A platform fee is configured with a non-zero rate.
+    pub platform_fee: bool,
+    /// Escrow deposits are checked against a USD minimum via the price oracle.
+    pub min_escrow_value: bool,
+    /// Delivery escrow is withheld for `release_delay_seconds` after delivery.
+    pub release_delay: bool,
+    /// Dispute spikes automatically pause the contract.
+    pub dispute_spike_guard: bool,
+    /// Deadline breaches automatically raise a dispute.
+    pub auto_dispute_breach: bool,
+    /// Milestone rounding dust is paid to the carrier.
+    pub rounding_favors_carrier: bool,
+    /// Receivers must be registered before shipments can target them.
+    pub require_registered_receiver: bool,
+    /// Receivers must approve shipments before they proceed.
+    pub require_receiver_approval: bool,
+    /// Carriers are penalized for missing delivery deadlines.
+    pub penalize_deadline_miss: bool,
+    /// Company shipment indexes are maintained.
+    pub index_company_shipments: bool,
+    /// Admin transfer revokes the outgoing admin's Company role.
+    pub revoke_old_admin_company_role: bool,
+    /// Any Company may fund a shipment's escrow.
+    pub allow_third_party_escrow: bool,
+    /// Milestones may sum to under 100% with the remainder held until delivery.
+    pub allow_milestone_holdback: bool,
+    /// Delivering carriers are added to the sender's whitelist.
+    pub auto_whitelist_on_delivery: bool,
+    /// Carriers must accept an assignment before the shipment can depart.
+    pub require_carrier_acceptance: bool,
+}
+
+/// Compact summary kept after a settled shipment's full record is removed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArchivedShipment {
+    /// Shipment identifier.
+    pub id: u64,
+    /// Terminal status the shipment settled in.
+    pub status: ShipmentStatus,
+    /// Total amount that was deposited in escrow.
+    pub total_escrow: i128,
+    /// Escrow still held when the shipment was archived (zero once settled).
+    pub final_escrow: i128,
+    /// SHA-256 hash of the off-chain shipment data.
+    pub data_hash: BytesN<32>,
+    /// Hash of the creation parameters, if one was recorded.
+    pub creation_hash: Option<BytesN<32>>,
+    /// Ledger timestamp when the shipment was archived.
+    pub archived_at: u64,
+}
+
 /// Configuration for platform revenue collection.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -817,3 +1284,15 @@ pub struct FeeConfig {
 /// integer-division rounding behavior during milestone payouts.
 /// Values (17, 33, 50) sum to exactly 100.
 pub const FRACTIONAL_MILESTONE_PCTS: [u32; 3] = [17, 33, 50];
+
+/// Maximum number of entries retained in an address's notification inbox.
+/// When full, the oldest entry is dropped to make room for the newest.
+pub const MAX_INBOX_ENTRIES: u32 = 50;
+
+/// Maximum number of status transitions retained per shipment.
+/// When full, the oldest transition is dropped to make room for the newest.
+pub const MAX_STATUS_HISTORY: u32 = 32;
+
+/// Fixed-point scale for price oracle quotes and `ContractConfig.min_escrow_usd`
+/// (7 decimals, matching the Stellar token standard).
+pub const ORACLE_PRICE_SCALE: i128 = 10_000_000;
@@ -337,7 +337,7 @@ pub fn validate_amount(amount: i128) -> Result<(), NavinError> {
 /// * `Err(NavinError::InvalidAmount)` otherwise.
 pub fn validate_positive_amount(amount: i128) -> Result<(), NavinError> {
     if amount <= 0 {
-        return Err(NavinError::InsufficientFunds);
+        return Err(NavinError::InvalidAmount);
     }
     if amount > MAX_AMOUNT {
         return Err(NavinError::InvalidAmount);
@@ -11,35 +11,42 @@ mod test_utils;
 
 pub use errors::*;
 
+/// Maximum number of decimal places a token may be initialized with.
+pub const MAX_DECIMALS: u32 = 18;
+
 #[contract]
 pub struct NavinToken;
 
 #[contractimpl]
 impl NavinToken {
-    /// Initialize the token with admin, name, symbol, and total supply
+    /// Initialize the token with admin, name, symbol, decimals, and total supply
     pub fn initialize(
         env: Env,
         admin: Address,
         name: String,
         symbol: String,
+        decimals: u32,
         total_supply: i128,
     ) -> Result<(), TokenError> {
         if storage::is_initialized(&env) {
             return Err(TokenError::AlreadyInitialized);
         }
 
-        if total_supply <= 0 {
+        if total_supply <= 0 || decimals > MAX_DECIMALS {
             return Err(TokenError::InvalidAmount);
         }
 
         storage::set_admin(&env, &admin);
         storage::set_name(&env, &name);
         storage::set_symbol(&env, &symbol);
+        storage::set_decimals(&env, decimals);
         storage::set_total_supply(&env, total_supply);
         storage::set_balance(&env, &admin, total_supply);
 
-        env.events()
-            .publish((symbol_short!("init"),), (admin.clone(), total_supply));
+        env.events().publish(
+            (symbol_short!("init"),),
+            (admin.clone(), decimals, total_supply),
+        );
 
         Ok(())
     }
@@ -61,8 +68,11 @@ impl NavinToken {
     }
 
     /// Get token decimals
-    pub fn decimals(_env: Env) -> Result<u32, TokenError> {
-        Ok(7)
+    pub fn decimals(env: Env) -> Result<u32, TokenError> {
+        if !storage::is_initialized(&env) {
+            return Err(TokenError::NotInitialized);
+        }
+        Ok(storage::get_decimals(&env))
     }
 
     /// Get token symbol
@@ -158,7 +168,7 @@ impl NavinToken {
         storage::set_allowance(&env, &from, &spender, allowance - amount);
 
         env.events()
-            .publish((symbol_short!("tr_from"),), (from, to, spender, amount));
+            .publish((symbol_short!("transfer"),), (from, to, amount));
 
         Ok(())
     }
@@ -192,6 +202,70 @@ impl NavinToken {
         Ok(())
     }
 
+    /// Increase a spender's allowance relative to its current value
+    pub fn increase_allowance(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        delta: i128,
+    ) -> Result<(), TokenError> {
+        if !storage::is_initialized(&env) {
+            return Err(TokenError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        if delta < 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+
+        if owner == spender {
+            return Err(TokenError::SameAccount);
+        }
+
+        let amount = storage::get_allowance(&env, &owner, &spender)
+            .checked_add(delta)
+            .ok_or(TokenError::InvalidAmount)?;
+        storage::set_allowance(&env, &owner, &spender, amount);
+
+        env.events()
+            .publish((symbol_short!("approve"),), (owner, spender, amount));
+
+        Ok(())
+    }
+
+    /// Decrease a spender's allowance relative to its current value, clamping at zero
+    pub fn decrease_allowance(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        delta: i128,
+    ) -> Result<(), TokenError> {
+        if !storage::is_initialized(&env) {
+            return Err(TokenError::NotInitialized);
+        }
+
+        owner.require_auth();
+
+        if delta < 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+
+        if owner == spender {
+            return Err(TokenError::SameAccount);
+        }
+
+        let amount = storage::get_allowance(&env, &owner, &spender)
+            .saturating_sub(delta)
+            .max(0);
+        storage::set_allowance(&env, &owner, &spender, amount);
+
+        env.events()
+            .publish((symbol_short!("approve"),), (owner, spender, amount));
+
+        Ok(())
+    }
+
     /// Get allowance of spender for owner's tokens
     pub fn allowance(env: Env, owner: Address, spender: Address) -> Result<i128, TokenError> {
         if !storage::is_initialized(&env) {
@@ -256,6 +330,45 @@ impl NavinToken {
         Ok(())
     }
 
+    /// Burn tokens from an address using the caller's allowance
+    pub fn burn_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
+        if !storage::is_initialized(&env) {
+            return Err(TokenError::NotInitialized);
+        }
+
+        spender.require_auth();
+
+        if amount <= 0 {
+            return Err(TokenError::InvalidAmount);
+        }
+
+        let allowance = storage::get_allowance(&env, &from, &spender);
+        if allowance < amount {
+            return Err(TokenError::InsufficientAllowance);
+        }
+
+        let from_balance = storage::get_balance(&env, &from);
+        if from_balance < amount {
+            return Err(TokenError::InsufficientBalance);
+        }
+
+        // Update allowance, balance and supply
+        storage::set_allowance(&env, &from, &spender, allowance - amount);
+        storage::set_balance(&env, &from, from_balance - amount);
+        let current_supply = storage::get_total_supply(&env);
+        storage::set_total_supply(&env, current_supply - amount);
+
+        env.events()
+            .publish((symbol_short!("burn"),), (from, amount));
+
+        Ok(())
+    }
+
     // ========================================================================
     // Metadata Allowlist Management (Admin Only)
     // ========================================================================
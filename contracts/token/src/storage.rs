@@ -6,6 +6,7 @@ pub enum DataKey {
     Admin,
     Name,
     Symbol,
+    Decimals,
     TotalSupply,
     Balance(Address),
     Allowance(Address, Address),
@@ -50,6 +51,20 @@ pub fn set_symbol(env: &Env, symbol: &String) {
     env.storage().instance().set(&DataKey::Symbol, symbol);
 }
 
+/// Get the token decimals, defaulting to 7 for instances initialized before
+/// decimals were configurable
+pub fn get_decimals(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Decimals)
+        .unwrap_or(7)
+}
+
+/// Set the token decimals
+pub fn set_decimals(env: &Env, decimals: u32) {
+    env.storage().instance().set(&DataKey::Decimals, &decimals);
+}
+
 /// Get the total supply
 pub fn get_total_supply(env: &Env) -> i128 {
     env.storage().instance().get(&DataKey::TotalSupply).unwrap()
@@ -2,8 +2,12 @@
 
 extern crate std;
 
-use crate::{test_utils::setup_env, NavinToken, NavinTokenClient};
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Symbol};
+use crate::{test_utils::setup_env, NavinToken, NavinTokenClient, TokenError};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _},
+    Address, Env, String, Symbol, TryFromVal,
+};
 
 fn setup_token_env() -> (Env, NavinTokenClient<'static>, Address) {
     let (env, admin) = setup_env();
@@ -16,7 +20,7 @@ fn setup_token_env() -> (Env, NavinTokenClient<'static>, Address) {
 fn initialize_token(client: &NavinTokenClient, env: &Env, admin: &Address, total_supply: i128) {
     let name = String::from_str(env, "NavinToken");
     let symbol = String::from_str(env, "NVN");
-    client.initialize(admin, &name, &symbol, &total_supply);
+    client.initialize(admin, &name, &symbol, &7, &total_supply);
 }
 
 // ============================================================================
@@ -33,6 +37,50 @@ fn test_initialize() {
     assert_eq!(client.symbol(), String::from_str(&env, "NVN"));
     assert_eq!(client.total_supply(), 1_000_000);
     assert_eq!(client.balance(&admin), 1_000_000);
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn test_initialize_custom_decimals() {
+    let (env, client, admin) = setup_token_env();
+    client.initialize(
+        &admin,
+        &String::from_str(&env, "NavinToken"),
+        &String::from_str(&env, "NVN"),
+        &18,
+        &1_000_000,
+    );
+
+    assert_eq!(client.decimals(), 18);
+}
+
+#[test]
+fn test_decimals_defaults_to_seven_when_unset() {
+    let (env, client, admin) = setup_token_env();
+    initialize_token(&client, &env, &admin, 1_000_000);
+
+    // Instances initialized before decimals were stored have no Decimals key.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .remove(&crate::storage::DataKey::Decimals);
+    });
+
+    assert_eq!(client.decimals(), 7);
+}
+
+#[test]
+fn test_initialize_rejects_excess_decimals() {
+    let (env, client, admin) = setup_token_env();
+    let result = client.try_initialize(
+        &admin,
+        &String::from_str(&env, "NavinToken"),
+        &String::from_str(&env, "NVN"),
+        &19,
+        &1_000_000,
+    );
+
+    assert_eq!(result, Err(Ok(TokenError::InvalidAmount)));
 }
 
 #[test]
@@ -116,6 +164,48 @@ fn test_approve_and_transfer_from() {
     assert_eq!(client.allowance(&admin, &spender), 100);
 }
 
+#[test]
+fn test_increase_and_decrease_allowance() {
+    let (env, client, admin) = setup_token_env();
+    initialize_token(&client, &env, &admin, 1_000_000);
+
+    let spender = Address::generate(&env);
+    client.approve(&admin, &spender, &300);
+
+    client.increase_allowance(&admin, &spender, &200);
+    assert_eq!(client.allowance(&admin, &spender), 500);
+
+    client.decrease_allowance(&admin, &spender, &150);
+    assert_eq!(client.allowance(&admin, &spender), 350);
+}
+
+#[test]
+fn test_increase_allowance_overflow() {
+    let (env, client, admin) = setup_token_env();
+    initialize_token(&client, &env, &admin, 1_000_000);
+
+    let spender = Address::generate(&env);
+    client.approve(&admin, &spender, &i128::MAX);
+
+    assert_eq!(
+        client.try_increase_allowance(&admin, &spender, &1),
+        Err(Ok(TokenError::InvalidAmount))
+    );
+    assert_eq!(client.allowance(&admin, &spender), i128::MAX);
+}
+
+#[test]
+fn test_decrease_allowance_clamps_to_zero() {
+    let (env, client, admin) = setup_token_env();
+    initialize_token(&client, &env, &admin, 1_000_000);
+
+    let spender = Address::generate(&env);
+    client.approve(&admin, &spender, &100);
+
+    client.decrease_allowance(&admin, &spender, &1_000);
+    assert_eq!(client.allowance(&admin, &spender), 0);
+}
+
 // ============================================================================
 // Metadata Allowlist Tests
 // ============================================================================
@@ -360,3 +450,62 @@ fn test_multiple_allowed_keys() {
     assert!(client.is_metadata_key_allowed(&key1));
     assert!(client.is_metadata_key_allowed(&key3));
 }
+
+// ============================================================================
+// SEP-41 Conformance Tests
+// ============================================================================
+
+fn last_event_topic(env: &Env) -> Symbol {
+    let (_, topics, _) = env.events().all().last().unwrap();
+    Symbol::try_from_val(env, &topics.get(0).unwrap()).unwrap()
+}
+
+#[test]
+fn test_sep41_surface() {
+    let (env, client, admin) = setup_token_env();
+    initialize_token(&client, &env, &admin, 1_000_000);
+
+    let holder = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let recipient = Address::generate(&env);
+
+    assert_eq!(client.decimals(), 7);
+
+    client.transfer(&admin, &holder, &1_000);
+    assert_eq!(last_event_topic(&env), symbol_short!("transfer"));
+    assert_eq!(client.balance(&holder), 1_000);
+
+    client.approve(&holder, &spender, &600);
+    assert_eq!(last_event_topic(&env), symbol_short!("approve"));
+    assert_eq!(client.allowance(&holder, &spender), 600);
+
+    client.transfer_from(&spender, &holder, &recipient, &200);
+    assert_eq!(last_event_topic(&env), symbol_short!("transfer"));
+    assert_eq!(client.balance(&recipient), 200);
+    assert_eq!(client.allowance(&holder, &spender), 400);
+
+    client.burn_from(&spender, &holder, &300);
+    assert_eq!(last_event_topic(&env), symbol_short!("burn"));
+    assert_eq!(client.balance(&holder), 500);
+    assert_eq!(client.allowance(&holder, &spender), 100);
+    assert_eq!(client.total_supply(), 999_700);
+
+    client.burn(&admin, &holder, &100);
+    assert_eq!(last_event_topic(&env), symbol_short!("burn"));
+    assert_eq!(client.balance(&holder), 400);
+}
+
+#[test]
+fn test_burn_from_requires_allowance() {
+    let (env, client, admin) = setup_token_env();
+    initialize_token(&client, &env, &admin, 1_000_000);
+
+    let spender = Address::generate(&env);
+    client.approve(&admin, &spender, &50);
+
+    assert_eq!(
+        client.try_burn_from(&spender, &admin, &100),
+        Err(Ok(TokenError::InsufficientAllowance))
+    );
+    assert_eq!(client.total_supply(), 1_000_000);
+}
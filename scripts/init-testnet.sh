@@ -37,6 +37,7 @@ stellar contract invoke \
   --admin "$ADMIN_ADDRESS" \
   --name "Navin Token" \
   --symbol "NAV" \
+  --decimals 7 \
   --total_supply 10000000000000000
 
 echo "Token contract initialized successfully"